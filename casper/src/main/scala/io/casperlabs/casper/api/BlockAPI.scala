@@ -300,8 +300,8 @@ object BlockAPI {
           .query(stateHash, cltype.protobuf.Mappings.toProto(k), Nil, protocolVersion)
           .rethrow
       accountKey <- MonadThrowable[F].fromOption(
-                     ByteArray32(accountKey.toByteArray),
-                     error("Account key must be 32 bytes long")
+                     cltype.PublicKey(accountKey.toByteArray),
+                     error("Account key must be 32 or 33 bytes long")
                    )
       account <- getState(cltype.Key.Account(accountKey)).flatMap {
                   case cltype.StoredValue.Account(account) => account.pure[F]
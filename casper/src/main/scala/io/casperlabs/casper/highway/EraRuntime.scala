@@ -108,6 +108,10 @@ class EraRuntime[F[_]: Sync: Clock: Metrics: Log: EraStorage: FinalityStorageRea
     */
   val isSwitchBoundary = isCrossing(end)(_, _)
 
+  /** The switch block pays out the unbonds due in the era that follows this one. */
+  private def unbondPayoutEraHeight(isSwitchBlock: Boolean): Option[Long] =
+    if (isSwitchBlock) Some(conf.eraHeight(end)) else None
+
   private implicit class MessageOps(msg: Message) {
 
     /** Convert the round of the message to a time equivalent for comparisons with critical block boundaries. */
@@ -295,6 +299,9 @@ class EraRuntime[F[_]: Sync: Clock: Metrics: Log: EraStorage: FinalityStorageRea
                           isBookingBlock = isBookingBoundary(
                             choice.block.roundInstant,
                             conf.toInstant(roundId)
+                          ),
+                          unbondPayoutEraHeight = unbondPayoutEraHeight(
+                            isSwitchBoundary(choice.block.roundInstant, conf.toInstant(roundId))
                           )
                         )
                         .timerGauge("lambda_block")
@@ -673,7 +680,13 @@ class EraRuntime[F[_]: Sync: Clock: Metrics: Log: EraStorage: FinalityStorageRea
           )
       semaphore      <- validatorSemaphoreMap.getOrAdd(PublicKey(block.getHeader.validatorPublicKey))
       isBookingBlock <- message.isBookingBlock
-      _              <- messageExecutor.validateAndAdd(semaphore, block, isBookingBlock)
+      isSwitchBlock  <- message.isSwitchBlock
+      _ <- messageExecutor.validateAndAdd(
+            semaphore,
+            block,
+            isBookingBlock,
+            unbondPayoutEraHeight(isSwitchBlock)
+          )
     } yield Validated(message)
 }
 
@@ -2,6 +2,7 @@ package io.casperlabs.casper.highway
 
 import java.util.concurrent.TimeUnit
 import java.time.{Instant, LocalDateTime, ZoneId}
+import scala.annotation.tailrec
 import scala.concurrent.duration._
 
 final case class HighwayConf(
@@ -86,6 +87,18 @@ final case class HighwayConf(
     (1 until multiplier.toInt).foldLeft(endTick)((t, _) => eraEnd(t))
   }
 
+  /** Number of eras between the genesis era and the one starting at `start`;
+    * the genesis era has height 0. This is what the PoS contract counts its
+    * bonding and unbonding delays in.
+    */
+  def eraHeight(start: Instant): Long = {
+    @tailrec
+    def loop(height: Long, nextStart: Instant): Long =
+      if (nextStart isAfter start) height else loop(height + 1, eraEnd(nextStart))
+
+    if (start isBefore genesisEraEnd) 0L else loop(1L, eraEnd(genesisEraEnd))
+  }
+
   /** Any time we create a block it may have to be a booking block,
     * in which case we have to execute the auction. There will be
     * exactly one booking boundary per era, except in the genesis
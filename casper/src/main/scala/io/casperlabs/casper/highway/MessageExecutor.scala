@@ -52,7 +52,12 @@ class MessageExecutor[F[_]: Concurrent: Log: Time: Metrics: BlockStorage: DagSto
   /** Validate, execute and persist an incoming block.
     * The blocks made by the MessageProducer don't have to be passed here.
     */
-  def validateAndAdd(semaphore: Semaphore[F], block: Block, isBookingBlock: Boolean): F[Unit] =
+  def validateAndAdd(
+      semaphore: Semaphore[F],
+      block: Block,
+      isBookingBlock: Boolean,
+      unbondPayoutEraHeight: Option[Long] = None
+  ): F[Unit] =
     // If the block timestamp is in the future, wait some time before adding it,
     // so we won't include it as a justification from the future.
     Validation.preTimestamp[F](block).attempt.flatMap {
@@ -61,12 +66,12 @@ class MessageExecutor[F[_]: Concurrent: Log: Time: Metrics: BlockStorage: DagSto
           s"${block.blockHash.show -> "block"} is ahead for $delay from now, will retry adding later"
         ) >>
           Time[F].sleep(delay) >>
-          validateAndAdd(semaphore, block, isBookingBlock)
+          validateAndAdd(semaphore, block, isBookingBlock, unbondPayoutEraHeight)
 
       case Right(None) =>
         semaphore.withPermit {
           for {
-            (status, effects) <- computeEffects(block, isBookingBlock)
+            (status, effects) <- computeEffects(block, isBookingBlock, unbondPayoutEraHeight)
             _                 <- addEffects(status, block, effects)
           } yield ()
         }
@@ -201,7 +206,8 @@ class MessageExecutor[F[_]: Concurrent: Log: Time: Metrics: BlockStorage: DagSto
   // NOTE: Don't call this on genesis, genesis is presumed to be already computed and saved.
   def computeEffects(
       block: Block,
-      isBookingBlock: Boolean
+      isBookingBlock: Boolean,
+      unbondPayoutEraHeight: Option[Long] = None
   ): F[(BlockStatus, BlockEffects)] =
     Metrics[F].timer("computeEffects") {
       val hashPrefix = block.blockHash.show
@@ -218,7 +224,12 @@ class MessageExecutor[F[_]: Concurrent: Log: Time: Metrics: BlockStorage: DagSto
               s"Computing the pre-state hash of $isBookingBlock ${hashPrefix -> "block"}"
             )
         preStateHash <- ExecEngineUtil
-                         .computePrestate[F](merged, block.mainRank, upgrades) //TODO: This should probably use p-rank
+                         .computePrestate[F](
+                           merged,
+                           block.mainRank, //TODO: This should probably use p-rank
+                           upgrades,
+                           unbondPayoutEraHeight
+                         )
                          .timer("computePrestate")
         preStateBonds = merged.parents.headOption.getOrElse(block).getHeader.getState.bonds
         _             <- Log[F].debug(s"Computing the effects for ${hashPrefix -> "block"}")
@@ -56,13 +56,16 @@ trait MessageProducer[F[_]] {
     * and the justifications selected when the caller started their operation,
     * select deploys from the buffer, and create a (possibly empty) block,
     * persisting it to the block store.
+    * Switch blocks pass the height of the era they start, so the bonds and
+    * unbonds due in it are applied before the deploys.
     */
   def block(
       keyBlockHash: BlockHash,
       roundId: Ticks,
       mainParent: Message.Block,
       justifications: Map[PublicKeyBS, Set[Message]],
-      isBookingBlock: Boolean
+      isBookingBlock: Boolean,
+      unbondPayoutEraHeight: Option[Long] = None
   ): F[Message.Block]
 }
 
@@ -118,7 +121,8 @@ object MessageProducer {
           roundId: Ticks,
           mainParent: Message.Block,
           justifications: Map[PublicKeyBS, Set[Message]],
-          isBookingBlock: Boolean
+          isBookingBlock: Boolean,
+          unbondPayoutEraHeight: Option[Long]
       ): F[Message.Block] =
         for {
           dag          <- DagStorage[F].getRepresentation
@@ -152,7 +156,8 @@ object MessageProducer {
                            props.protocolVersion,
                            props.mainRank,
                            props.configuration.deployConfig.maxBlockSizeBytes,
-                           upgrades
+                           upgrades,
+                           unbondPayoutEraHeight
                          )
 
           magicBit = scala.util.Random.nextBoolean()
@@ -661,6 +661,14 @@ object ProtoUtil {
     val argsF: Try[ByteString] = code.args.toList.traverse(cltype.protobuf.Mappings.fromArg) match {
       case Left(err) =>
         Try(throw new SmartContractEngineError(s"Error parsing deploy arguments: $err"))
+      // Args are passed by name if they all have one, so that contracts can read them by name;
+      // contracts reading them by position still see them in the same order.
+      case Right(args) if args.nonEmpty && code.args.forall(_.name.nonEmpty) =>
+        Try(
+          ByteString.copyFrom(
+            ToBytes.toBytes(cltype.RuntimeArgs(code.args.map(_.name).zip(args)))
+          )
+        )
       case Right(args) =>
         Try(
           ByteString.copyFrom(
@@ -98,10 +98,12 @@ object ExecEngineUtil {
       protocolVersion: state.ProtocolVersion,
       mainRank: MainRank,
       maxBlockSizeBytes: Int,
-      upgrades: Seq[ChainSpec.UpgradePoint]
+      upgrades: Seq[ChainSpec.UpgradePoint],
+      unbondPayoutEraHeight: Option[Long] = None
   ): F[DeploysCheckpoint] = Metrics[F].timer("computeDeploysCheckpoint") {
     for {
-      preStateHash <- computePrestate[F](merged, mainRank, upgrades).timer("computePrestate")
+      preStateHash <- computePrestate[F](merged, mainRank, upgrades, unbondPayoutEraHeight)
+                       .timer("computePrestate")
       DeploySelectionResult(commuting, conflicting, preconditionFailures) <- DeploySelection[F]
                                                                               .select(
                                                                                 preStateHash,
@@ -250,7 +252,9 @@ object ExecEngineUtil {
   def computePrestate[F[_]: MonadThrowable: ExecutionEngineService: Log](
       merged: MergeResult[TransformMap, Block],
       rank: MainRank, // Rank of the block we are creating on top of the parents; can be way ahead because of justifications.
-      upgrades: Seq[ChainSpec.UpgradePoint]
+      upgrades: Seq[ChainSpec.UpgradePoint],
+      // Height of the era whose due bonds and unbonds have to be applied before the block, if it's a switch block.
+      unbondPayoutEraHeight: Option[Long] = None
   ): F[StateHash] = {
     val mergedStateHash: F[StateHash] = merged match {
       case MergeResult.EmptyMerge =>
@@ -284,6 +288,15 @@ object ExecEngineUtil {
                 .rethrow
                 .map(_.postStateHash)
           // NOTE: We are dropping the effects here, so they won't be part of the block.
+        } flatMap { upgradedStateHash =>
+          unbondPayoutEraHeight.fold(upgradedStateHash.pure[F]) { eraHeight =>
+            Log[F].info(s"Paying out unbonds due in era $eraHeight") *>
+              ExecutionEngineService[F]
+                .unbondPayout(upgradedStateHash, eraHeight, protocolVersion)
+                .rethrow
+                .map(_.postStateHash)
+            // NOTE: Like the upgrades, the payout is committed by the EE and isn't part of the block effects.
+          }
         }
       } else {
         postStateHash.pure[F]
@@ -325,6 +325,13 @@ object HashSetCasperTestNode {
         Applicative[F].pure[Either[Throwable, cltype.StoredValue]](
           Left(new Exception("Method `query` not implemented on this instance!"))
         )
+
+      override def unbondPayout(
+          prestate: ByteString,
+          eraHeight: Long,
+          protocolVersion: ProtocolVersion
+      ): F[Either[Throwable, ExecutionEngineService.CommitResult]] =
+        ExecutionEngineService.CommitResult(prestate, bonds).asRight[Throwable].pure[F]
     }
 
   private def pad(x: Array[Byte], length: Int): Array[Byte] =
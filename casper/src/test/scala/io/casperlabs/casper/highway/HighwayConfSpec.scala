@@ -81,6 +81,20 @@ class HighwayConfSpec extends WordSpec with Matchers with TickUtils {
     }
   }
 
+  "eraHeight" should {
+    "count the eras following the extended genesis era" in {
+      val conf = init.copy(
+        genesisEraStart = date(2019, 12, 16),
+        bookingDuration = days(10),
+        eraDuration = EraDuration.FixedLength(days(7))
+      )
+      conf.eraHeight(conf.genesisEraStart) shouldBe 0
+      conf.eraHeight(date(2019, 12, 30)) shouldBe 1
+      conf.eraHeight(date(2020, 1, 6)) shouldBe 2
+      conf.eraHeight(date(2020, 1, 13)) shouldBe 3
+    }
+  }
+
   "criticalBoundaries" should {
     "collect all booking block ticks for the genesis era" in {
       val conf = init.copy(
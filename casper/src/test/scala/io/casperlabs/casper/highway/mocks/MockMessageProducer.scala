@@ -98,7 +98,8 @@ class MockMessageProducer[F[_]: Sync: BlockStorageWriter: DagStorage](
       roundId: Ticks,
       mainParent: Message.Block,
       justifications: Map[PublicKeyBS, Set[Message]],
-      isBookingBlock: Boolean
+      isBookingBlock: Boolean,
+      unbondPayoutEraHeight: Option[Long]
   ): F[Message.Block] =
     withParent(mainParent) { _ =>
       val unsigned = BlockSummary()
@@ -5,6 +5,9 @@ import io.casperlabs.casper.Estimator.BlockHash
 import io.casperlabs.casper._
 import io.casperlabs.casper.consensus._, Block.Justification
 import io.casperlabs.casper.consensus.state.ProtocolVersion
+import io.casperlabs.models.bytesrepr.FromBytes
+import io.casperlabs.models.cltype
+import io.casperlabs.models.cltype.protobuf.dsl
 import org.scalacheck.{Arbitrary, Gen}
 import org.scalacheck.Arbitrary.arbitrary
 import org.scalacheck.Gen.listOfN
@@ -56,4 +59,34 @@ class ProtoUtilTest extends FlatSpec with Matchers with GeneratorDrivenPropertyC
       result should contain theSameElementsAs ((justificationsHashes ++ parentsHashes).toSet)
     }
   }
+
+  "deployCodeToDeployPayload" should "pass args by name if they all have one" in {
+    val code = Deploy
+      .Code()
+      .withArgs(
+        Seq(
+          Deploy.Arg(name = "value").withValue(dsl.instances.u64(42)),
+          Deploy.Arg(name = "memo").withValue(dsl.instances.string("hello"))
+        )
+      )
+    val args = ProtoUtil.deployCodeToDeployPayload(code).get.getDeployCode.args.toByteArray
+
+    val runtimeArgs = FromBytes.deserialize(cltype.RuntimeArgs.deserializer, args).right.get
+    runtimeArgs.args.map(_._1) shouldBe Seq("value", "memo")
+  }
+
+  it should "pass args by position if any has no name" in {
+    val code = Deploy
+      .Code()
+      .withArgs(
+        Seq(
+          Deploy.Arg(name = "value").withValue(dsl.instances.u64(42)),
+          Deploy.Arg().withValue(dsl.instances.string("hello"))
+        )
+      )
+    val args = ProtoUtil.deployCodeToDeployPayload(code).get.getDeployCode.args.toByteArray
+
+    val positionalArgs = FromBytes.deserialize(FromBytes.seq(cltype.CLValue.deserializer), args)
+    positionalArgs.right.get.map(_.clType) shouldBe Seq(cltype.CLType.U64, cltype.CLType.String)
+  }
 }
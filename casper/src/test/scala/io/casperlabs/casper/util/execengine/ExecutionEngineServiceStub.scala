@@ -132,6 +132,12 @@ object ExecutionEngineServiceStub {
         path: Seq[String],
         protocolVersion: ProtocolVersion
     ): F[Either[Throwable, cltype.StoredValue]] = queryFunc(state, baseKey, path)
+
+    override def unbondPayout(
+        prestate: ByteString,
+        eraHeight: Long,
+        protocolVersion: ProtocolVersion
+    ): F[Either[Throwable, ExecutionEngineService.CommitResult]] = commitFunc(prestate, Seq.empty)
   }
 
   def failExec[F[_]: Applicative](bonds: Seq[Bond] = Seq.empty): ExecutionEngineService[F] =
@@ -222,6 +228,16 @@ object ExecutionEngineServiceStub {
         .asInstanceOf[cltype.StoredValue])
         .asRight[Throwable]
         .pure[F]
+
+    override def unbondPayout(
+        prestate: ByteString,
+        eraHeight: Long,
+        protocolVersion: ProtocolVersion
+    ): F[Either[Throwable, ExecutionEngineService.CommitResult]] =
+      ExecutionEngineService
+        .CommitResult(prestate, bonds)
+        .asRight[Throwable]
+        .pure[F]
   }
 
 }
@@ -17,6 +17,9 @@ export declare function add(key_ptr: usize, key_size: usize, value_ptr: usize, v
 @external("env", "add_local")
 export declare function add_local(key_ptr: usize, key_size: usize, value_ptr: usize, value_size: usize): void;
 /** @hidden */
+@external("env", "remove")
+export declare function remove(key_ptr: usize, key_size: usize): void;
+/** @hidden */
 @external("env", "new_uref")
 export declare function new_uref(key_ptr: usize, value_ptr: usize, value_size: usize): void;
 /** @hidden */
@@ -136,3 +139,36 @@ export declare function get_main_purse(dest_ptr: usize): void;
 /** @hidden */
 @external("env", "read_host_buffer")
 export declare function read_host_buffer(dest_ptr: usize, dest_size: u32, bytes_written: usize): i32;
+/** @hidden */
+@external("env", "emit_event")
+export declare function emit_event(name_ptr: usize, name_size: usize, value_ptr: usize, value_size: usize): void;
+/** @hidden */
+@external("env", "try_call_contract")
+export declare function try_call_contract(key_ptr: usize, key_size: u32, args_ptr: usize, args_size: u32, result_size: usize): i32;
+/** @hidden */
+@external("env", "store_contract")
+export declare function store_contract(entry_points_ptr: usize, entry_points_size: usize, named_keys_ptr: usize, named_keys_size: usize, uref_addr_ptr: usize): void;
+/** @hidden */
+@external("env", "store_contract_at_hash")
+export declare function store_contract_at_hash(entry_points_ptr: usize, entry_points_size: usize, named_keys_ptr: usize, named_keys_size: usize, hash_ptr: usize): void;
+/** @hidden */
+@external("env", "call_entry_point")
+export declare function call_entry_point(key_ptr: usize, key_size: u32, entry_point_ptr: usize, entry_point_size: u32, args_ptr: usize, args_size: u32, result_size: usize): i32;
+/** @hidden */
+@external("env", "get_named_arg_size")
+export declare function get_named_arg_size(name_ptr: usize, name_size: u32, dest_size: u32): i32;
+/** @hidden */
+@external("env", "get_named_arg")
+export declare function get_named_arg(name_ptr: usize, name_size: u32, dest_ptr: usize, dest_size: u32): i32;
+/** @hidden */
+@external("env", "blake2b")
+export declare function blake2b(input_ptr: usize, input_size: u32, dest_ptr: usize, dest_size: u32): i32;
+/** @hidden */
+@external("env", "ed25519_verify")
+export declare function ed25519_verify(public_key_ptr: usize, public_key_size: u32, message_ptr: usize, message_size: u32, signature_ptr: usize, signature_size: u32): i32;
+/** @hidden */
+@external("env", "secp256k1_verify")
+export declare function secp256k1_verify(public_key_ptr: usize, public_key_size: u32, digest_ptr: usize, digest_size: u32, signature_ptr: usize, signature_size: u32): i32;
+/** @hidden */
+@external("env", "random_bytes")
+export declare function random_bytes(dest_ptr: usize, dest_size: u32): void;
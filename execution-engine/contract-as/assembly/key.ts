@@ -4,7 +4,7 @@ import {KEY_UREF_SERIALIZED_LENGTH} from "./constants";
 import {URef} from "./uref";
 import {CLValue} from "./clvalue";
 import {Error, ErrorCode} from "./error";
-import {checkTypedArrayEqual, typedToArray} from "./utils";
+import {arrayToTyped, checkTypedArrayEqual, typedToArray} from "./utils";
 import {Result, Ref, Error as BytesreprError} from "./bytesrepr";
 
 /**
@@ -24,6 +24,26 @@ export enum KeyVariant {
  */
 export const PUBLIC_KEY_ED25519_ID: u8 = 0;
 
+/**
+ * The ID of a SECP256K1 public key.
+ */
+export const PUBLIC_KEY_SECP256K1_ID: u8 = 1;
+
+/** The length in bytes of an ED25519 public key. */
+const ED25519_LENGTH: i32 = 32;
+
+/** The length in bytes of a compressed SECP256K1 public key. */
+const SECP256K1_LENGTH: i32 = 33;
+
+/**
+ * Serialized in front of a SECP256K1 public key. It is a non-canonical encoding of an ED25519
+ * point, so ED25519 keys keep their untagged encoding.
+ */
+const SECP256K1_MARKER: u8[] = [
+    0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+];
+
 /** A cryptographic public key. */
 export class PublicKey {
     /**
@@ -48,18 +68,33 @@ export class PublicKey {
 
     /** Deserializes a `PublicKey` from an array of bytes. */
     static fromBytes(bytes: Uint8Array): Result<PublicKey> {
-        if (bytes.length < 32) {
+        if (bytes.length < ED25519_LENGTH) {
             return new Result<PublicKey>(null, BytesreprError.EarlyEndOfStream, 0);
         }
 
-        let publicKeyBytes = bytes.subarray(0, 32);
-        let publicKey = new PublicKey(PUBLIC_KEY_ED25519_ID, publicKeyBytes);
+        let ed25519Bytes = bytes.subarray(0, ED25519_LENGTH);
+        if (!checkTypedArrayEqual(ed25519Bytes, arrayToTyped(SECP256K1_MARKER))) {
+            let publicKey = new PublicKey(PUBLIC_KEY_ED25519_ID, ed25519Bytes);
+            let ref = new Ref<PublicKey>(publicKey);
+            return new Result<PublicKey>(ref, BytesreprError.Ok, ED25519_LENGTH);
+        }
+
+        const length = ED25519_LENGTH + SECP256K1_LENGTH;
+        if (bytes.length < length) {
+            return new Result<PublicKey>(null, BytesreprError.EarlyEndOfStream, 0);
+        }
+
+        let publicKeyBytes = bytes.subarray(ED25519_LENGTH, length);
+        let publicKey = new PublicKey(PUBLIC_KEY_SECP256K1_ID, publicKeyBytes);
         let ref = new Ref<PublicKey>(publicKey);
-        return new Result<PublicKey>(ref, BytesreprError.Ok, 32);
+        return new Result<PublicKey>(ref, BytesreprError.Ok, length);
     }
 
     /** Serializes a `PublicKey` into an array of bytes. */
     toBytes(): Array<u8> {
+        if (this.variant == PUBLIC_KEY_SECP256K1_ID) {
+            return SECP256K1_MARKER.concat(typedToArray(this.bytes));
+        }
         return typedToArray(this.bytes);
     }
 }
@@ -5,16 +5,37 @@
 use alloc::vec;
 use alloc::vec::Vec;
 
-use casperlabs_types::{bytesrepr::ToBytes, CLTyped, CLValue, CLValueError};
+use casperlabs_types::{bytesrepr::ToBytes, CLTyped, CLValue, CLValueError, RuntimeArgs};
 
 /// Types which implement [`ArgsParser`] can be parsed into an ABI-compliant byte representation
 /// suitable for passing as arguments to a contract.
 ///
 /// It is primarily implemented for n-ary tuples of values which themselves implement [`ToBytes`]
-/// and [`CLTyped`].
+/// and [`CLTyped`], which are passed by position, and for [`RuntimeArgs`], which are passed by
+/// name.
 pub trait ArgsParser {
     /// Parses the arguments to a `Vec` of [`CLValue`]s.
     fn parse(self) -> Result<Vec<CLValue>, CLValueError>;
+
+    /// Parses and serializes the arguments in the form they're passed to the host.
+    fn serialize(self) -> Result<Vec<u8>, CLValueError>
+    where
+        Self: Sized,
+    {
+        self.parse()?
+            .into_bytes()
+            .map_err(CLValueError::Serialization)
+    }
+}
+
+impl ArgsParser for RuntimeArgs {
+    fn parse(self) -> Result<Vec<CLValue>, CLValueError> {
+        Ok(self.to_values())
+    }
+
+    fn serialize(self) -> Result<Vec<u8>, CLValueError> {
+        self.into_bytes().map_err(CLValueError::Serialization)
+    }
 }
 
 impl ArgsParser for () {
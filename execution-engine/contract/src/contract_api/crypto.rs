@@ -0,0 +1,77 @@
+//! Functions for hashing data and verifying signatures.
+//!
+//! These are run natively by the host, which is much cheaper than running the equivalent code in
+//! Wasm.
+
+use casperlabs_types::{
+    account::{Ed25519, Secp256k1},
+    api_error, BLAKE2B_DIGEST_LENGTH,
+};
+
+use crate::{ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+
+/// The length in bytes of an Ed25519 signature.
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+/// The length in bytes of a compact secp256k1 signature.
+pub const SECP256K1_SIGNATURE_LENGTH: usize = 64;
+
+/// Returns the 32-byte BLAKE2b hash of `data`.
+pub fn blake2b<T: AsRef<[u8]>>(data: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let data = data.as_ref();
+    let mut digest = [0u8; BLAKE2B_DIGEST_LENGTH];
+    let ret = unsafe {
+        ext_ffi::blake2b(
+            data.as_ptr(),
+            data.len(),
+            digest.as_mut_ptr(),
+            BLAKE2B_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+    digest
+}
+
+/// Returns `true` if `signature` is a valid Ed25519 signature of `message` by `public_key`.
+pub fn ed25519_verify(
+    public_key: Ed25519,
+    message: &[u8],
+    signature: &[u8; ED25519_SIGNATURE_LENGTH],
+) -> bool {
+    let public_key = public_key.as_bytes();
+    let result = unsafe {
+        ext_ffi::ed25519_verify(
+            public_key.as_ptr(),
+            public_key.len(),
+            message.as_ptr(),
+            message.len(),
+            signature.as_ptr(),
+            signature.len(),
+        )
+    };
+    result != 0
+}
+
+/// Returns `true` if `signature` is a valid compact secp256k1 signature of `digest` by
+/// `public_key`.
+///
+/// Unlike [`ed25519_verify`], the message must already be hashed down to 32 bytes, e.g. by
+/// [`blake2b`].
+pub fn secp256k1_verify(
+    public_key: Secp256k1,
+    digest: &[u8; BLAKE2B_DIGEST_LENGTH],
+    signature: &[u8; SECP256K1_SIGNATURE_LENGTH],
+) -> bool {
+    let public_key = public_key.as_bytes();
+    let result = unsafe {
+        ext_ffi::secp256k1_verify(
+            public_key.as_ptr(),
+            public_key.len(),
+            digest.as_ptr(),
+            digest.len(),
+            signature.as_ptr(),
+            signature.len(),
+        )
+    };
+    result != 0
+}
@@ -1,6 +1,7 @@
 //! Contains support for writing smart contracts.
 
 pub mod account;
+pub mod crypto;
 pub mod runtime;
 pub mod storage;
 pub mod system;
@@ -12,7 +13,7 @@ use alloc::{
 
 use casperlabs_types::{bytesrepr::ToBytes, ApiError};
 
-use crate::unwrap_or_revert::UnwrapOrRevert;
+use crate::{args_parser::ArgsParser, unwrap_or_revert::UnwrapOrRevert};
 
 #[allow(clippy::zero_ptr)]
 fn alloc_bytes(n: usize) -> *mut u8 {
@@ -38,3 +39,10 @@ fn to_ptr<T: ToBytes>(t: T) -> (*const u8, usize, Vec<u8>) {
     let size = bytes.len();
     (ptr, size, bytes)
 }
+
+fn args_to_ptr<A: ArgsParser>(args: A) -> (*const u8, usize, Vec<u8>) {
+    let bytes = args.serialize().unwrap_or_revert();
+    let ptr = bytes.as_ptr();
+    let size = bytes.len();
+    (ptr, size, bytes)
+}
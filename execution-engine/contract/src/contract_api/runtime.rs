@@ -16,6 +16,9 @@ use casperlabs_types::{
 
 use crate::{args_parser::ArgsParser, contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
 
+/// The number of bytes returned by [`random_bytes`].
+pub const RANDOM_BYTES_COUNT: usize = 32;
+
 /// Returns the given [`CLValue`] to the host, terminating the currently running module.
 ///
 /// Note this function is only relevant to contracts stored on chain which are invoked via
@@ -47,9 +50,7 @@ pub fn revert<T: Into<ApiError>>(error: T) -> ! {
 pub fn call_contract<A: ArgsParser, T: CLTyped + FromBytes>(c_ptr: ContractRef, args: A) -> T {
     let contract_key: Key = c_ptr.into();
     let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
-    let (args_ptr, args_size, _bytes2) = ArgsParser::parse(args)
-        .map(contract_api::to_ptr)
-        .unwrap_or_revert();
+    let (args_ptr, args_size, _bytes2) = contract_api::args_to_ptr(args);
 
     let bytes_written = {
         let mut bytes_written = MaybeUninit::uninit();
@@ -82,6 +83,101 @@ pub fn call_contract<A: ArgsParser, T: CLTyped + FromBytes>(c_ptr: ContractRef,
     bytesrepr::deserialize(serialized_result).unwrap_or_revert()
 }
 
+/// Calls the function exported as `entry_point` by the given stored contract, passing the given
+/// arguments to it.
+///
+/// The contract must have been stored with `entry_point` as one of its entry points, e.g. via
+/// [`storage::store_contract`](crate::contract_api::storage::store_contract), and `args` must
+/// match the types declared for it, otherwise execution stops with an error.  Values are returned
+/// from the called function as for [`call_contract`].
+#[allow(clippy::ptr_arg)]
+pub fn call_entry_point<A: ArgsParser, T: CLTyped + FromBytes>(
+    c_ptr: ContractRef,
+    entry_point: &str,
+    args: A,
+) -> T {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
+    let (entry_point_ptr, entry_point_size, _bytes2) = contract_api::to_ptr(entry_point);
+    let (args_ptr, args_size, _bytes3) = contract_api::args_to_ptr(args);
+
+    let bytes_written = {
+        let mut bytes_written = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::call_entry_point(
+                key_ptr,
+                key_size,
+                entry_point_ptr,
+                entry_point_size,
+                args_ptr,
+                args_size,
+                bytes_written.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { bytes_written.assume_init() }
+    };
+
+    let serialized_result = if bytes_written == 0 {
+        // If no bytes were written, the host buffer hasn't been set and hence shouldn't be read.
+        vec![]
+    } else {
+        // NOTE: as in `call_contract`, this is a copy of the contents of `read_host_buffer()`.
+        let bytes_ptr = contract_api::alloc_bytes(bytes_written);
+        let mut dest: Vec<u8> =
+            unsafe { Vec::from_raw_parts(bytes_ptr, bytes_written, bytes_written) };
+        read_host_buffer_into(&mut dest).unwrap_or_revert();
+        dest
+    };
+
+    bytesrepr::deserialize(serialized_result).unwrap_or_revert()
+}
+
+/// Calls the given stored contract, passing the given arguments to it.
+///
+/// Unlike [`call_contract`], if the stored contract calls [`revert`], then the [`ApiError`] it
+/// reverted with is returned as an `Err` and execution of the caller continues.  Anything the
+/// stored contract wrote to global state or emitted as events is discarded in that case, but the
+/// gas it used is still charged.
+#[allow(clippy::ptr_arg)]
+pub fn try_call_contract<A: ArgsParser, T: CLTyped + FromBytes>(
+    c_ptr: ContractRef,
+    args: A,
+) -> Result<T, ApiError> {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
+    let (args_ptr, args_size, _bytes2) = contract_api::args_to_ptr(args);
+
+    let bytes_written = {
+        let mut bytes_written = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::try_call_contract(
+                key_ptr,
+                key_size,
+                args_ptr,
+                args_size,
+                bytes_written.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret)?;
+        unsafe { bytes_written.assume_init() }
+    };
+
+    let serialized_result = if bytes_written == 0 {
+        // If no bytes were written, the host buffer hasn't been set and hence shouldn't be read.
+        vec![]
+    } else {
+        // NOTE: as in `call_contract`, this is a copy of the contents of `read_host_buffer()`.
+        let bytes_ptr = contract_api::alloc_bytes(bytes_written);
+        let mut dest: Vec<u8> =
+            unsafe { Vec::from_raw_parts(bytes_ptr, bytes_written, bytes_written) };
+        read_host_buffer_into(&mut dest).unwrap_or_revert();
+        dest
+    };
+
+    Ok(bytesrepr::deserialize(serialized_result).unwrap_or_revert())
+}
+
 /// Takes the name of a (non-mangled) `extern "C"` function to store as a contract under the given
 /// [`URef`] which should already reference a stored contract.
 ///
@@ -130,6 +226,39 @@ pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, bytesrepr::Error>> {
     Some(bytesrepr::deserialize(arg_bytes))
 }
 
+fn get_named_arg_size(name: &str) -> Option<usize> {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let mut arg_size: usize = 0;
+    let ret =
+        unsafe { ext_ffi::get_named_arg_size(name_ptr, name_size, &mut arg_size as *mut usize) };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => revert(e),
+    }
+}
+
+/// Returns the argument passed under `name` to the host for the current module invocation.
+///
+/// Returns `None` if there's no such argument, including when the caller passed its args by
+/// position rather than as [`RuntimeArgs`](casperlabs_types::RuntimeArgs).
+pub fn get_named_arg<T: FromBytes>(name: &str) -> Option<Result<T, bytesrepr::Error>> {
+    let arg_size = get_named_arg_size(name)?;
+
+    let arg_bytes = {
+        let res = {
+            let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+            let data_ptr = contract_api::alloc_bytes(arg_size);
+            let ret = unsafe { ext_ffi::get_named_arg(name_ptr, name_size, data_ptr, arg_size) };
+            let data = unsafe { Vec::from_raw_parts(data_ptr, arg_size, arg_size) };
+            api_error::result_from(ret).map(|_| data)
+        };
+        // Assumed to be safe as `get_named_arg_size` checks the argument already
+        res.unwrap_or_revert()
+    };
+    Some(bytesrepr::deserialize(arg_bytes))
+}
+
 /// Returns the caller of the current context, i.e. the [`PublicKey`] of the account which made the
 /// deploy request.
 pub fn get_caller() -> PublicKey {
@@ -166,6 +295,19 @@ pub fn get_phase() -> Phase {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns 32 bytes from a generator seeded with the current deploy hash, [`Phase`] and
+/// [`BlockTime`].
+///
+/// Every call returns new bytes, but they are reproducible: running the same deploy in the same
+/// phase at the same block time always yields the same sequence, so validators agree on the
+/// outcome.  As the seed is public, the bytes are unsuitable for anything which must stay
+/// unpredictable to the deployer.
+pub fn random_bytes() -> [u8; RANDOM_BYTES_COUNT] {
+    let mut bytes = [0u8; RANDOM_BYTES_COUNT];
+    unsafe { ext_ffi::random_bytes(bytes.as_mut_ptr(), RANDOM_BYTES_COUNT) };
+    bytes
+}
+
 /// Returns the requested named [`Key`] from the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -251,6 +393,16 @@ pub fn is_valid_uref(uref: URef) -> bool {
     result != 0
 }
 
+/// Emits an event named `name` carrying `value`.
+///
+/// Events are returned along with the result of the deploy, in the order they were emitted.  They
+/// are discarded if the session code reverts.
+pub fn emit_event(name: &str, value: CLValue) {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let (value_ptr, value_size, _bytes2) = contract_api::to_ptr(value);
+    unsafe { ext_ffi::emit_event(name_ptr, name_size, value_ptr, value_size) }
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
@@ -6,7 +6,8 @@ use core::{convert::From, mem::MaybeUninit};
 use casperlabs_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    AccessRights, ApiError, CLTyped, CLValue, ContractRef, Key, URef, KEY_UREF_SERIALIZED_LENGTH,
+    AccessRights, ApiError, CLTyped, CLValue, ContractRef, EntryPoint, Key, URef,
+    KEY_UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -76,6 +77,15 @@ pub fn write<T: CLTyped + ToBytes>(uref: URef, value: T) {
     }
 }
 
+/// Removes the value under `uref` from the global state.
+pub fn remove(uref: URef) {
+    let key = Key::from(uref);
+    let (key_ptr, key_size, _bytes) = contract_api::to_ptr(key);
+    unsafe {
+        ext_ffi::remove(key_ptr, key_size);
+    }
+}
+
 /// Writes `value` under `key` in the context-local partition of global state.
 pub fn write_local<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
     let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(key);
@@ -138,6 +148,52 @@ pub fn store_function_at_hash(name: &str, named_keys: BTreeMap<String, Key>) ->
     ContractRef::Hash(addr)
 }
 
+/// Stores the serialized bytes of the exported, non-mangled `extern "C"` functions named by
+/// `entry_points` as a new contract under a URef generated by the host.
+///
+/// Each of the functions can then be called via
+/// [`runtime::call_entry_point`](crate::contract_api::runtime::call_entry_point), and the host
+/// checks the args passed to it against the types declared by its [`EntryPoint`].
+pub fn store_contract(
+    entry_points: Vec<EntryPoint>,
+    named_keys: BTreeMap<String, Key>,
+) -> ContractRef {
+    let (entry_points_ptr, entry_points_size, _bytes1) = contract_api::to_ptr(entry_points);
+    let (keys_ptr, keys_size, _bytes2) = contract_api::to_ptr(named_keys);
+    let mut addr = [0u8; 32];
+    unsafe {
+        ext_ffi::store_contract(
+            entry_points_ptr,
+            entry_points_size,
+            keys_ptr,
+            keys_size,
+            addr.as_mut_ptr(),
+        );
+    }
+    ContractRef::URef(URef::new(addr, AccessRights::READ_ADD_WRITE))
+}
+
+/// Stores the serialized bytes of the exported, non-mangled `extern "C"` functions named by
+/// `entry_points` as a new contract at an immutable address generated by the host.
+pub fn store_contract_at_hash(
+    entry_points: Vec<EntryPoint>,
+    named_keys: BTreeMap<String, Key>,
+) -> ContractRef {
+    let (entry_points_ptr, entry_points_size, _bytes1) = contract_api::to_ptr(entry_points);
+    let (keys_ptr, keys_size, _bytes2) = contract_api::to_ptr(named_keys);
+    let mut addr = [0u8; 32];
+    unsafe {
+        ext_ffi::store_contract_at_hash(
+            entry_points_ptr,
+            entry_points_size,
+            keys_ptr,
+            keys_size,
+            addr.as_mut_ptr(),
+        );
+    }
+    ContractRef::Hash(addr)
+}
+
 /// Returns a new unforgeable pointer, where the value is initialized to `init`.
 pub fn new_uref<T: CLTyped + ToBytes>(init: T) -> URef {
     let key_ptr = contract_api::alloc_bytes(Key::max_serialized_length());
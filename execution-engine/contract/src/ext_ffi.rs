@@ -14,6 +14,7 @@ extern "C" {
     );
     pub fn add(key_ptr: *const u8, key_size: usize, value_ptr: *const u8, value_size: usize);
     pub fn add_local(key_ptr: *const u8, key_size: usize, value_ptr: *const u8, value_size: usize);
+    pub fn remove(key_ptr: *const u8, key_size: usize);
     pub fn new_uref(key_ptr: *mut u8, value_ptr: *const u8, value_size: usize);
     pub fn store_function(
         function_name_ptr: *const u8,
@@ -104,4 +105,70 @@ extern "C" {
     ) -> i32;
     pub fn get_main_purse(dest_ptr: *mut u8);
     pub fn read_host_buffer(dest_ptr: *mut u8, dest_size: usize, bytes_written: *mut usize) -> i32;
+    pub fn emit_event(
+        name_ptr: *const u8,
+        name_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    );
+    pub fn try_call_contract(
+        key_ptr: *const u8,
+        key_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
+    pub fn store_contract(
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        uref_addr_ptr: *mut u8,
+    );
+    pub fn store_contract_at_hash(
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        hash_ptr: *mut u8,
+    );
+    pub fn get_named_arg_size(name_ptr: *const u8, name_size: usize, dest_size: *mut usize) -> i32;
+    pub fn get_named_arg(
+        name_ptr: *const u8,
+        name_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn call_entry_point(
+        key_ptr: *const u8,
+        key_size: usize,
+        entry_point_ptr: *const u8,
+        entry_point_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
+    pub fn blake2b(
+        input_ptr: *const u8,
+        input_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn ed25519_verify(
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+    ) -> i32;
+    pub fn secp256k1_verify(
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+        digest_ptr: *const u8,
+        digest_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+    ) -> i32;
+    pub fn random_bytes(dest_ptr: *mut u8, dest_size: usize);
 }
@@ -18,12 +18,16 @@ const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+const POS_BONDING_DELAY: &str = "pos_bonding_delay";
+const POS_UNBONDING_DELAY: &str = "pos_unbonding_delay";
 const POS_FUNCTION_NAME: &str = "pos_ext";
 
 #[repr(u32)]
 enum Args {
     MintURef = 0,
     GenesisValidators = 1,
+    BondingDelay = 2,
+    UnbondingDelay = 3,
 }
 
 #[no_mangle]
@@ -43,6 +47,14 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
 
+    let bonding_delay: u64 = runtime::get_arg(Args::BondingDelay as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let unbonding_delay: u64 = runtime::get_arg(Args::UnbondingDelay as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
     let stakes = Stakes::new(genesis_validators);
 
     // Add genesis validators to PoS contract object.
@@ -58,11 +70,19 @@ pub extern "C" fn call() {
     let payment_purse = mint_purse(&mint, U512::zero());
     let rewards_purse = mint_purse(&mint, U512::zero());
 
-    // Include PoS purses in its named_keys
+    // Include PoS purses and delays in its named_keys
     [
         (POS_BONDING_PURSE, bonding_purse),
         (POS_PAYMENT_PURSE, payment_purse),
         (POS_REWARDS_PURSE, rewards_purse),
+        (
+            POS_BONDING_DELAY,
+            read_only(storage::new_uref(bonding_delay)),
+        ),
+        (
+            POS_UNBONDING_DELAY,
+            read_only(storage::new_uref(unbonding_delay)),
+        ),
     ]
     .iter()
     .for_each(|(name, uref)| {
@@ -77,6 +97,10 @@ pub extern "C" fn call() {
     runtime::ret(return_value);
 }
 
+fn read_only(uref: URef) -> URef {
+    URef::new(uref.addr(), AccessRights::READ)
+}
+
 fn mint_purse(mint: &ContractRef, amount: U512) -> URef {
     let result: Result<URef, mint::Error> = runtime::call_contract(mint.clone(), ("mint", amount));
 
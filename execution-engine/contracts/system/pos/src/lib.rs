@@ -2,9 +2,12 @@
 
 extern crate alloc;
 
+use core::convert::TryFrom;
+
 use alloc::{
     collections::{BTreeMap, BTreeSet},
     string::String,
+    vec::Vec,
 };
 
 use contract::{
@@ -12,11 +15,13 @@ use contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    EraProvider, MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes,
+    StakesProvider,
 };
 use types::{
-    account::PublicKey, system_contract_errors::pos::Error, ApiError, BlockTime, CLValue, Key,
-    Phase, TransferResult, URef, U512,
+    account::{PublicKey, SECP256K1_LENGTH},
+    system_contract_errors::pos::Error,
+    ApiError, BlockTime, CLValue, Key, Phase, TransferResult, URef, U512,
 };
 
 const METHOD_BOND: &str = "bond";
@@ -25,12 +30,51 @@ const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
 const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
 const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
 const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+const METHOD_SLASH: &str = "slash";
+const METHOD_UNBOND_PAYOUT: &str = "unbond_payout";
 
 const BONDING_KEY: u8 = 1;
 const UNBONDING_KEY: u8 = 2;
+const ERA_HEIGHT_KEY: u8 = 3;
+
+const POS_BONDING_DELAY: &str = "pos_bonding_delay";
+const POS_UNBONDING_DELAY: &str = "pos_unbonding_delay";
 
 pub struct ProofOfStakeContract;
 
+impl EraProvider for ProofOfStakeContract {
+    /// Reads the current era height from the local state of the contract.
+    fn read_era_height(&mut self) -> u64 {
+        storage::read_local(&ERA_HEIGHT_KEY)
+            .unwrap_or_default()
+            .unwrap_or_default()
+    }
+
+    /// Writes the current era height to the local state of the contract.
+    fn write_era_height(&mut self, era_height: u64) -> Result<(), Error> {
+        storage::write_local(ERA_HEIGHT_KEY, era_height);
+        Ok(())
+    }
+
+    /// Reads the bonding delay from the uref stored under the contract's known urefs.
+    fn read_bonding_delay(&mut self) -> Result<u64, Error> {
+        read_delay(POS_BONDING_DELAY).ok_or(Error::BondingDelayNotFound)
+    }
+
+    /// Reads the unbonding delay from the uref stored under the contract's known urefs.
+    fn read_unbonding_delay(&mut self) -> Result<u64, Error> {
+        read_delay(POS_UNBONDING_DELAY).ok_or(Error::UnbondingDelayNotFound)
+    }
+}
+
+fn read_delay(name: &str) -> Option<u64> {
+    match runtime::get_key(name)? {
+        Key::URef(uref) => storage::read(uref).ok()?,
+        _ => None,
+    }
+}
+
 impl MintProvider for ProofOfStakeContract {
     fn transfer_purse_to_account(
         &mut self,
@@ -119,14 +163,14 @@ impl StakesProvider for ProofOfStakeContract {
             let hex_key = split_name
                 .next()
                 .ok_or(Error::StakesKeyDeserializationFailed)?;
-            if hex_key.len() != 64 {
+            let mut key_bytes = [0u8; SECP256K1_LENGTH];
+            if hex_key.len() > 2 * key_bytes.len() {
                 return Err(Error::StakesKeyDeserializationFailed);
             }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+            let bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+                .map_err(|_| Error::StakesKeyDeserializationFailed)?;
+            let pub_key = PublicKey::try_from(&key_bytes[..bytes_written])
                 .map_err(|_| Error::StakesKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            let pub_key = PublicKey::ed25519_from(key_bytes);
             let balance = split_name
                 .next()
                 .and_then(|b| U512::from_dec_str(b).ok())
@@ -232,6 +276,27 @@ pub fn delegate() {
                 .finalize_payment(amount_spent, account)
                 .unwrap_or_revert();
         }
+        // Type of this method: `fn distribute_rewards(rewards: Vec<(PublicKey, U512)>)`
+        METHOD_DISTRIBUTE_REWARDS => {
+            let rewards: Vec<(PublicKey, U512)> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract.distribute_rewards(rewards).unwrap_or_revert();
+        }
+        // Type of this method: `fn slash(slashes: Vec<(PublicKey, U512)>)`
+        METHOD_SLASH => {
+            let slashes: Vec<(PublicKey, U512)> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract.slash(slashes).unwrap_or_revert();
+        }
+        // Type of this method: `fn unbond_payout(era_height: u64)`
+        METHOD_UNBOND_PAYOUT => {
+            let era_height: u64 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract.unbond_payout(era_height).unwrap_or_revert();
+        }
         _ => {}
     }
 }
@@ -0,0 +1,63 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{crypto, runtime},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, bytesrepr::FromBytes, ApiError, BLAKE2B_DIGEST_LENGTH};
+
+const ARG_MESSAGE: &str = "message";
+const ARG_DIGEST: &str = "digest";
+const ARG_ED25519_PUBLIC_KEY: &str = "ed25519_public_key";
+const ARG_ED25519_SIGNATURE: &str = "ed25519_signature";
+const ARG_SECP256K1_PUBLIC_KEY: &str = "secp256k1_public_key";
+const ARG_SECP256K1_SIGNATURE: &str = "secp256k1_signature";
+const ARG_EXPECT_VALID: &str = "expect_valid";
+
+#[repr(u16)]
+enum Error {
+    DigestMismatch = 0,
+    UnexpectedEd25519Result,
+    UnexpectedSecp256k1Result,
+}
+
+fn get_named_arg<T: FromBytes>(name: &str) -> T {
+    runtime::get_named_arg(name)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: Vec<u8> = get_named_arg(ARG_MESSAGE);
+    let expected_digest: [u8; BLAKE2B_DIGEST_LENGTH] = get_named_arg(ARG_DIGEST);
+    let expect_valid: bool = get_named_arg(ARG_EXPECT_VALID);
+
+    let digest = crypto::blake2b(&message);
+    if digest != expected_digest {
+        runtime::revert(ApiError::User(Error::DigestMismatch as u16));
+    }
+
+    let ed25519_public_key = match get_named_arg(ARG_ED25519_PUBLIC_KEY) {
+        PublicKey::Ed25519(ed25519) => ed25519,
+        _ => runtime::revert(ApiError::InvalidArgument),
+    };
+    let ed25519_signature = get_named_arg(ARG_ED25519_SIGNATURE);
+    if crypto::ed25519_verify(ed25519_public_key, &message, &ed25519_signature) != expect_valid {
+        runtime::revert(ApiError::User(Error::UnexpectedEd25519Result as u16));
+    }
+
+    let secp256k1_public_key = match get_named_arg(ARG_SECP256K1_PUBLIC_KEY) {
+        PublicKey::Secp256k1(secp256k1) => secp256k1,
+        _ => runtime::revert(ApiError::InvalidArgument),
+    };
+    let secp256k1_signature = get_named_arg(ARG_SECP256K1_SIGNATURE);
+    if crypto::secp256k1_verify(secp256k1_public_key, &digest, &secp256k1_signature) != expect_valid
+    {
+        runtime::revert(ApiError::User(Error::UnexpectedSecp256k1Result as u16));
+    }
+}
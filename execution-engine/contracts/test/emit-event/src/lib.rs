@@ -0,0 +1,29 @@
+#![no_std]
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, CLValue};
+
+const FIRST_EVENT: &str = "first";
+const SECOND_EVENT: &str = "second";
+
+#[repr(u16)]
+enum Error {
+    Reverted = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let value: u64 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let should_revert: bool = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    runtime::emit_event(FIRST_EVENT, CLValue::from_t(value).unwrap_or_revert());
+    runtime::emit_event(SECOND_EVENT, CLValue::from_t(value + 1).unwrap_or_revert());
+
+    if should_revert {
+        runtime::revert(ApiError::User(Error::Reverted as u16));
+    }
+}
@@ -0,0 +1,33 @@
+#![no_std]
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key};
+
+const QUOTIENT_KEY: &str = "quotient";
+
+#[repr(u16)]
+enum Error {
+    MissingDividend = 0,
+    MissingDivisor,
+    InvalidDividend,
+    InvalidDivisor,
+}
+
+/// Divides the `f64`s whose bits are passed as args, and stores the bits of the quotient, which is
+/// NaN when both are zero.
+#[no_mangle]
+pub extern "C" fn call() {
+    let dividend: u64 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingDividend as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidDividend as u16));
+    let divisor: u64 = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingDivisor as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidDivisor as u16));
+
+    let quotient = f64::from_bits(dividend) / f64::from_bits(divisor);
+    let quotient_uref = storage::new_uref(quotient.to_bits());
+    runtime::put_key(QUOTIENT_KEY, Key::from(quotient_uref));
+}
@@ -0,0 +1,41 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, U512};
+
+const ARG_MESSAGE: &str = "message";
+const ARG_AMOUNT: &str = "amount";
+const DEFAULT_AMOUNT: u64 = 42;
+
+#[repr(u16)]
+enum Error {
+    MissingMessage = 0,
+    InvalidMessage,
+    InvalidAmount,
+    MissingPositionalArg,
+    InvalidPositionalArg,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: String = runtime::get_named_arg(ARG_MESSAGE)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingMessage as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidMessage as u16));
+    assert_eq!(message, "Hello, world!");
+
+    // `amount` is optional, so callers which predate it can still call this contract.
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT)
+        .map(|result| result.unwrap_or_revert_with(ApiError::User(Error::InvalidAmount as u16)))
+        .unwrap_or_else(|| U512::from(DEFAULT_AMOUNT));
+    assert_eq!(amount, U512::from(DEFAULT_AMOUNT));
+
+    // Named args can still be read by position, in the order they were inserted.
+    let positional_message: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingPositionalArg as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidPositionalArg as u16));
+    assert_eq!(positional_message, message);
+}
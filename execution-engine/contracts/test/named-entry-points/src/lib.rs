@@ -0,0 +1,79 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLType, CLValue, EntryPoint, Parameter};
+
+const ADD_ONE: &str = "add_one";
+const DOUBLE: &str = "double";
+const VALUE_ARG: &str = "value";
+const INITIAL_VALUE: u64 = 3;
+
+#[repr(u16)]
+enum CustomError {
+    UnexpectedResult = 0,
+}
+
+fn get_value_arg() -> u64 {
+    runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+#[no_mangle]
+pub extern "C" fn add_one() {
+    let value = get_value_arg();
+    runtime::ret(CLValue::from_t(value + 1).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn double() {
+    let value = get_value_arg();
+    runtime::ret(CLValue::from_t(value * 2).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_point: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let pass_invalid_args: bool = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let entry_points = vec![
+        EntryPoint::new(
+            ADD_ONE,
+            vec![Parameter::new(VALUE_ARG, CLType::U64)],
+            CLType::U64,
+        ),
+        EntryPoint::new(
+            DOUBLE,
+            vec![Parameter::new(VALUE_ARG, CLType::U64)],
+            CLType::U64,
+        ),
+    ];
+    let reference = storage::store_contract_at_hash(entry_points, BTreeMap::new());
+
+    let result: u64 = if pass_invalid_args {
+        // The host rejects the call before running the entry point
+        runtime::call_entry_point(reference, &entry_point, (INITIAL_VALUE as u32,))
+    } else {
+        runtime::call_entry_point(reference, &entry_point, (INITIAL_VALUE,))
+    };
+
+    let expected = match entry_point.as_str() {
+        ADD_ONE => INITIAL_VALUE + 1,
+        DOUBLE => INITIAL_VALUE * 2,
+        _ => runtime::revert(ApiError::User(CustomError::UnexpectedResult as u16)),
+    };
+    if result != expected {
+        runtime::revert(ApiError::User(CustomError::UnexpectedResult as u16));
+    }
+}
@@ -2,7 +2,7 @@
 
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use contract::{
     contract_api::{account, runtime, system},
@@ -24,13 +24,19 @@ fn unbond(pos: &ContractRef, amount: Option<U512>) {
     runtime::call_contract::<_, ()>(pos.clone(), (POS_UNBOND, amount));
 }
 
+fn slash(pos: &ContractRef, slashes: Vec<(PublicKey, U512)>) {
+    runtime::call_contract::<_, ()>(pos.clone(), (POS_SLASH, slashes));
+}
+
 const POS_BOND: &str = "bond";
 const POS_UNBOND: &str = "unbond";
+const POS_SLASH: &str = "slash";
 
 const TEST_BOND: &str = "bond";
 const TEST_BOND_FROM_MAIN_PURSE: &str = "bond-from-main-purse";
 const TEST_SEED_NEW_ACCOUNT: &str = "seed_new_account";
 const TEST_UNBOND: &str = "unbond";
+const TEST_SLASH: &str = "slash";
 
 #[no_mangle]
 pub extern "C" fn call() {
@@ -71,6 +77,11 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
         unbond(&pos_pointer, maybe_amount);
+    } else if command == TEST_SLASH {
+        let slashes: Vec<(PublicKey, U512)> = runtime::get_arg(1)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        slash(&pos_pointer, slashes);
     } else {
         runtime::revert(ApiError::User(Error::UnknownCommand as u16));
     }
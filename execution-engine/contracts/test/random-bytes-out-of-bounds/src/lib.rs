@@ -0,0 +1,10 @@
+#![no_std]
+
+use contract::ext_ffi;
+
+/// Asks for more random bytes than fit in the contract's memory.
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut byte = 0u8;
+    unsafe { ext_ffi::random_bytes(&mut byte, usize::max_value()) };
+}
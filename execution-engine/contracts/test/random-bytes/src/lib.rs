@@ -0,0 +1,20 @@
+#![no_std]
+
+use contract::contract_api::{runtime, storage};
+
+const RANDOM_BYTES_1_KEY: &str = "random_bytes_1";
+const RANDOM_BYTES_2_KEY: &str = "random_bytes_2";
+
+/// Stores two consecutive results of `random_bytes` under named keys.
+#[no_mangle]
+pub extern "C" fn call() {
+    let random_bytes_1 = runtime::random_bytes();
+    let random_bytes_2 = runtime::random_bytes();
+    assert_ne!(
+        random_bytes_1, random_bytes_2,
+        "consecutive calls should return different bytes"
+    );
+
+    runtime::put_key(RANDOM_BYTES_1_KEY, storage::new_uref(random_bytes_1).into());
+    runtime::put_key(RANDOM_BYTES_2_KEY, storage::new_uref(random_bytes_2).into());
+}
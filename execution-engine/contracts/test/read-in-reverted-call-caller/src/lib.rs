@@ -0,0 +1,25 @@
+#![no_std]
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, ContractRef, Key};
+
+#[repr(u16)]
+enum Args {
+    AddContract = 0,
+    CheckFirst = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let add: Key = runtime::get_arg(Args::AddContract as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let add = add
+        .into_hash()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+    let check_first: bool = runtime::get_arg(Args::CheckFirst as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    runtime::call_contract::<_, ()>(ContractRef::Hash(add), (check_first,))
+}
@@ -0,0 +1,79 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, ContractRef, Key, URef};
+
+const CHECK_EXT: &str = "check_ext";
+const ADD_EXT: &str = "add_ext";
+const COUNTER_KEY: &str = "counter";
+const CHECK_KEY: &str = "check";
+const ADD_KEY: &str = "add";
+const INITIAL_VALUE: u64 = 0;
+const UNCHECKED_AMOUNT: u64 = 1;
+const CHECKED_AMOUNT: u64 = 10;
+
+#[repr(u16)]
+enum CustomError {
+    CounterUnchanged = 0,
+}
+
+fn counter() -> URef {
+    runtime::get_key(COUNTER_KEY)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert_with(ApiError::GetKey)
+}
+
+/// Reverts if the counter still has its initial value.
+#[no_mangle]
+pub extern "C" fn check_ext() {
+    let value: u64 = storage::read(counter())
+        .unwrap_or_revert_with(ApiError::Read)
+        .unwrap_or_revert_with(ApiError::ValueNotFound);
+    if value == INITIAL_VALUE {
+        runtime::revert(ApiError::User(CustomError::CounterUnchanged as u16));
+    }
+    runtime::ret(CLValue::from_t(()).unwrap_or_revert())
+}
+
+/// Adds to the counter.  If asked to check it first, the amount added depends on whether
+/// `check_ext` reverted.
+#[no_mangle]
+pub extern "C" fn add_ext() {
+    let check_first: bool = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let amount = if check_first {
+        let check = runtime::get_key(CHECK_KEY)
+            .and_then(Key::into_hash)
+            .unwrap_or_revert_with(ApiError::GetKey);
+        match runtime::try_call_contract::<_, ()>(ContractRef::Hash(check), ()) {
+            Ok(()) => CHECKED_AMOUNT,
+            Err(_) => UNCHECKED_AMOUNT,
+        }
+    } else {
+        UNCHECKED_AMOUNT
+    };
+    storage::add(counter(), amount);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let counter = storage::new_uref(INITIAL_VALUE);
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(String::from(COUNTER_KEY), counter.into());
+
+    let check = storage::store_function_at_hash(CHECK_EXT, named_keys.clone());
+    named_keys.insert(String::from(CHECK_KEY), check.into());
+    let add = storage::store_function_at_hash(ADD_EXT, named_keys);
+
+    runtime::put_key(COUNTER_KEY, counter.into());
+    runtime::put_key(ADD_KEY, add.into());
+}
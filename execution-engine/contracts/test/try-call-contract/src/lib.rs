@@ -0,0 +1,74 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, URef};
+
+const CALLEE_EXT: &str = "callee_ext";
+const VALUE_KEY: &str = "value";
+const CALLEE_EVENT: &str = "callee";
+const CALLER_EVENT: &str = "caller";
+const INITIAL_VALUE: u64 = 0;
+const WRITTEN_VALUE: u64 = 1;
+const RETURNED_VALUE: u64 = 2;
+
+#[repr(u16)]
+enum CustomError {
+    CalleeReverted = 0,
+    UnexpectedResult = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn callee_ext() {
+    let uref: URef = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let should_revert: bool = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    storage::write(uref, WRITTEN_VALUE);
+    runtime::emit_event(
+        CALLEE_EVENT,
+        CLValue::from_t(WRITTEN_VALUE).unwrap_or_revert(),
+    );
+
+    if should_revert {
+        runtime::revert(ApiError::User(CustomError::CalleeReverted as u16));
+    }
+    runtime::ret(CLValue::from_t(RETURNED_VALUE).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let should_revert: bool = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let uref = storage::new_uref(INITIAL_VALUE);
+    runtime::put_key(VALUE_KEY, uref.into());
+
+    let reference = storage::store_function_at_hash(CALLEE_EXT, BTreeMap::new());
+    let result: Result<u64, ApiError> =
+        runtime::try_call_contract(reference, (uref, should_revert));
+
+    let expected = if should_revert {
+        Err(ApiError::User(CustomError::CalleeReverted as u16))
+    } else {
+        Ok(RETURNED_VALUE)
+    };
+    if result != expected {
+        runtime::revert(ApiError::User(CustomError::UnexpectedResult as u16));
+    }
+
+    runtime::emit_event(
+        CALLER_EVENT,
+        CLValue::from_t(should_revert).unwrap_or_revert(),
+    );
+}
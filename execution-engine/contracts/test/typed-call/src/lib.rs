@@ -0,0 +1,33 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLType, EntryPoint, Parameter};
+
+const CALL: &str = "call";
+const TYPED_CALL_KEY: &str = "typed_call";
+const VALUE_ARG: &str = "value";
+
+/// Stores this contract with a `call` entry point taking a `u64` when deployed without args, and
+/// does nothing when run as the stored contract.
+#[no_mangle]
+pub extern "C" fn call() {
+    if let Some(value) = runtime::get_arg::<u64>(0) {
+        value.unwrap_or_revert_with(ApiError::InvalidArgument);
+        return;
+    }
+
+    let entry_points = vec![EntryPoint::new(
+        CALL,
+        vec![Parameter::new(VALUE_ARG, CLType::U64)],
+        CLType::Unit,
+    )];
+    let reference = storage::store_contract_at_hash(entry_points, BTreeMap::new());
+    runtime::put_key(TYPED_CALL_KEY, reference.into());
+}
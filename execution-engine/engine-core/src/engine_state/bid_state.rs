@@ -0,0 +1,31 @@
+use engine_shared::newtypes::Blake2bHash;
+use proof_of_stake::Stakes;
+use types::ProtocolVersion;
+
+pub enum BidStateResult {
+    RootNotFound,
+    Success(Stakes),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidStateRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+}
+
+impl BidStateRequest {
+    pub fn new(parent_state_hash: Blake2bHash, protocol_version: ProtocolVersion) -> Self {
+        BidStateRequest {
+            parent_state_hash,
+            protocol_version,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+}
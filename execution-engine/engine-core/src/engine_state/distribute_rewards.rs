@@ -0,0 +1,39 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributeRewardsRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    rewards: Vec<(PublicKey, U512)>,
+}
+
+impl DistributeRewardsRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        rewards: Vec<(PublicKey, U512)>,
+    ) -> Self {
+        DistributeRewardsRequest {
+            parent_state_hash,
+            protocol_version,
+            rewards,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn rewards(&self) -> &[(PublicKey, U512)] {
+        &self.rewards
+    }
+
+    pub fn take_rewards(&mut self) -> Vec<(PublicKey, U512)> {
+        std::mem::replace(&mut self.rewards, Vec::new())
+    }
+}
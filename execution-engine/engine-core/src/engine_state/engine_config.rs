@@ -1,9 +1,13 @@
+use crate::wasm_backend::WasmBackendKind;
+
 /// The runtime configuration of the execution engine
 #[derive(Debug, Copy, Clone, Default)]
 pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
     enable_bonding: bool,
+    parallel_execution: bool,
+    wasm_backend: WasmBackendKind,
 }
 
 impl EngineConfig {
@@ -29,4 +33,25 @@ impl EngineConfig {
         self.enable_bonding = enable_bonding;
         self
     }
+
+    /// Whether deploys requested to run in sequence are run concurrently instead, with the same
+    /// results.
+    pub fn parallel_execution(self) -> bool {
+        self.parallel_execution
+    }
+
+    pub fn with_parallel_execution(mut self, parallel_execution: bool) -> EngineConfig {
+        self.parallel_execution = parallel_execution;
+        self
+    }
+
+    /// The backend which contracts are run on.
+    pub fn wasm_backend(self) -> WasmBackendKind {
+        self.wasm_backend
+    }
+
+    pub fn with_wasm_backend(mut self, wasm_backend: WasmBackendKind) -> EngineConfig {
+        self.wasm_backend = wasm_backend;
+        self
+    }
 }
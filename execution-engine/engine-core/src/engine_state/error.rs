@@ -14,6 +14,10 @@ pub enum Error {
     InvalidPublicKeyLength { expected: usize, actual: usize },
     #[fail(display = "Invalid protocol version: {}", _0)]
     InvalidProtocolVersion(ProtocolVersion),
+    #[fail(display = "Gas price too low: minimum {}, actual {}", _1, _0)]
+    GasPriceTooLow { actual: u64, minimum: u64 },
+    #[fail(display = "Block gas limit reached: {}", _0)]
+    BlockGasLimitReached { limit: u64 },
     #[fail(display = "Invalid upgrade config")]
     InvalidUpgradeConfig,
     #[fail(display = "Wasm preprocessing error: {}", _0)]
@@ -38,6 +42,8 @@ pub enum Error {
     Serialization(bytesrepr::Error),
     #[fail(display = "Mint error: {}", _0)]
     Mint(mint::Error),
+    #[fail(display = "Module cache error: {}", _0)]
+    ModuleCache(String),
 }
 
 impl From<engine_wasm_prep::PreprocessingError> for Error {
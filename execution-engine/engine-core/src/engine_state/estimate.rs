@@ -0,0 +1,65 @@
+use engine_shared::{gas::Gas, motes::Motes, newtypes::Blake2bHash};
+use types::{BlockTime, ProtocolVersion};
+
+use crate::engine_state::{deploy_item::DeployItem, execution_result::ExecutionResult};
+
+pub enum EstimateResult {
+    RootNotFound,
+    /// The deploy could not be run.  The result never carries any effects.
+    Failure(ExecutionResult),
+    Success {
+        /// Gas used by the payment code, or the standard payment if no payment code was given.
+        payment_cost: Gas,
+        /// Gas used by the session code.
+        session_cost: Gas,
+        /// Gas used by the proof-of-stake `finalize_payment` call following the session.
+        finalize_cost: Gas,
+        /// Motes needed to cover `payment_cost` and `session_cost` at the deploy's gas price,
+        /// which is what the deploy is charged.
+        motes: Motes,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct EstimateRequest {
+    parent_state_hash: Blake2bHash,
+    block_time: BlockTime,
+    deploy_item: DeployItem,
+    protocol_version: ProtocolVersion,
+}
+
+impl EstimateRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        block_time: BlockTime,
+        deploy_item: DeployItem,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        EstimateRequest {
+            parent_state_hash,
+            block_time,
+            deploy_item,
+            protocol_version,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+
+    pub fn deploy_item(&self) -> &DeployItem {
+        &self.deploy_item
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn take_deploy_item(self) -> DeployItem {
+        self.deploy_item
+    }
+}
@@ -1,16 +1,55 @@
-use engine_shared::{additive_map::AdditiveMap, transform::Transform};
-use types::Key;
-
-use super::op::Op;
-
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct ExecutionEffect {
-    pub ops: AdditiveMap<Key, Op>,
-    pub transforms: AdditiveMap<Key, Transform>,
-}
-
-impl ExecutionEffect {
-    pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
-        ExecutionEffect { ops, transforms }
-    }
-}
+use std::collections::BTreeSet;
+
+use engine_shared::{additive_map::AdditiveMap, transform::Transform};
+use types::{CLValue, Key};
+
+use super::op::Op;
+
+/// A named value emitted by a contract while a deploy is executed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    name: String,
+    value: CLValue,
+}
+
+impl Event {
+    pub fn new(name: String, value: CLValue) -> Self {
+        Event { name, value }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &CLValue {
+        &self.value
+    }
+
+    pub fn destructure(self) -> (String, CLValue) {
+        (self.name, self.value)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionEffect {
+    pub ops: AdditiveMap<Key, Op>,
+    pub transforms: AdditiveMap<Key, Transform>,
+    /// Events emitted during execution, in the order they were emitted.  Unlike `ops` and
+    /// `transforms` they don't affect global state.
+    pub events: Vec<Event>,
+    /// Keys read by changes which were discarded again, like those of a reverted
+    /// `try_call_contract`.  They have no entry in `ops` for it, but the rest of the effect may
+    /// still depend on their values.
+    pub discarded_reads: BTreeSet<Key>,
+}
+
+impl ExecutionEffect {
+    pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
+        ExecutionEffect {
+            ops,
+            transforms,
+            events: Vec::new(),
+            discarded_reads: BTreeSet::new(),
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use super::{error, execution_effect::ExecutionEffect, op::Op, CONV_RATE};
+use std::collections::BTreeSet;
+
+use super::{
+    error,
+    execution_effect::{Event, ExecutionEffect},
+    op::Op,
+};
 use engine_shared::{
     additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
     stored_value::StoredValue, transform::Transform,
@@ -99,6 +105,21 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns this result with its effect dropped, for results which must never be committed.
+    pub fn without_effect(self) -> ExecutionResult {
+        match self {
+            ExecutionResult::Failure { error, cost, .. } => ExecutionResult::Failure {
+                error,
+                effect: Default::default(),
+                cost,
+            },
+            ExecutionResult::Success { cost, .. } => ExecutionResult::Success {
+                effect: Default::default(),
+                cost,
+            },
+        }
+    }
+
     pub fn effect(&self) -> &ExecutionEffect {
         match self {
             ExecutionResult::Failure { effect, .. } => effect,
@@ -147,14 +168,15 @@ impl ExecutionResult {
     pub fn check_forced_transfer(
         &self,
         payment_purse_balance: Motes,
+        gas_price: u64,
     ) -> Option<ForcedTransferResult> {
-        let payment_result_cost = match Motes::from_gas(self.cost(), CONV_RATE) {
+        let payment_result_cost = match Motes::from_gas(self.cost(), gas_price) {
             Some(cost) => cost,
-            // Multiplying cost by CONV_RATE overflowed the U512 range
+            // Multiplying cost by gas_price overflowed the U512 range
             None => return Some(ForcedTransferResult::InsufficientPayment),
         };
         // payment_code_spec_3_b_ii: if (balance of PoS pay purse) < (gas spent during
-        // payment code execution) * conv_rate, no session
+        // payment code execution) * gas_price, no session
         let insufficient_balance_to_continue = payment_purse_balance < payment_result_cost;
 
         match self {
@@ -179,6 +201,7 @@ impl ExecutionResult {
         account_main_purse_balance: Motes,
         account_main_purse: Key,
         rewards_purse: Key,
+        gas_price: u64,
     ) -> ExecutionResult {
         let effect = make_payment_error_effects(
             max_payment_cost,
@@ -186,7 +209,7 @@ impl ExecutionResult {
             account_main_purse,
             rewards_purse,
         );
-        let cost = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+        let cost = Gas::from_motes(max_payment_cost, gas_price).unwrap_or_default();
         ExecutionResult::Failure {
             error,
             effect,
@@ -266,6 +289,8 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut events = Vec::new();
+        let mut discarded_reads = BTreeSet::new();
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
@@ -277,7 +302,13 @@ impl ExecutionResultBuilder {
                 if result.is_failure() {
                     return Ok(result);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(
+                        &mut ops,
+                        &mut transforms,
+                        &mut events,
+                        &mut discarded_reads,
+                        result.effect(),
+                    );
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -290,7 +321,13 @@ impl ExecutionResultBuilder {
                 if result.is_failure() {
                     ret = result.with_cost(cost);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(
+                        &mut ops,
+                        &mut transforms,
+                        &mut events,
+                        &mut discarded_reads,
+                        result.effect(),
+                    );
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -304,14 +341,23 @@ impl ExecutionResultBuilder {
                         error::Error::Finalization,
                     ));
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(
+                        &mut ops,
+                        &mut transforms,
+                        &mut events,
+                        &mut discarded_reads,
+                        result.effect(),
+                    );
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
         }
 
         // Remove redundant writes to allow more opportunity to commute
-        let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
+        let mut reduced_effect =
+            Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
+        reduced_effect.events = events;
+        reduced_effect.discarded_reads = discarded_reads;
 
         Ok(ret.with_effect(reduced_effect))
     }
@@ -319,6 +365,8 @@ impl ExecutionResultBuilder {
     fn add_effects(
         ops: &mut AdditiveMap<Key, Op>,
         transforms: &mut AdditiveMap<Key, Transform>,
+        events: &mut Vec<Event>,
+        discarded_reads: &mut BTreeSet<Key>,
         effect: &ExecutionEffect,
     ) {
         for (k, op) in effect.ops.iter() {
@@ -327,6 +375,8 @@ impl ExecutionResultBuilder {
         for (k, t) in effect.transforms.iter() {
             transforms.insert_add(*k, t.clone())
         }
+        events.extend(effect.events.iter().cloned());
+        discarded_reads.extend(effect.discarded_reads.iter().cloned());
     }
 
     /// In the case we are writing the same value as was there originally,
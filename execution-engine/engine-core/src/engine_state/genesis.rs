@@ -6,10 +6,18 @@ use rand::{
     Rng,
 };
 
-use engine_shared::{motes::Motes, newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{
+    host_function_costs::{HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH},
+    motes::Motes,
+    newtypes::Blake2bHash,
+    TypeMismatch,
+};
 use engine_storage::global_state::CommitResult;
 use engine_wasm_prep::wasm_costs::WasmCosts;
-use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
+use types::{
+    account::{PublicKey, SECP256K1_LENGTH},
+    bytesrepr, Key, ProtocolVersion, U512,
+};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
@@ -17,6 +25,8 @@ pub const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 pub const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 pub const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 pub const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+pub const POS_BONDING_DELAY: &str = "pos_bonding_delay";
+pub const POS_UNBONDING_DELAY: &str = "pos_unbonding_delay";
 
 pub enum GenesisResult {
     RootNotFound,
@@ -92,7 +102,13 @@ impl GenesisAccount {
 
 impl Distribution<GenesisAccount> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenesisAccount {
-        let public_key = PublicKey::ed25519_from(rng.gen());
+        let public_key = if rng.gen() {
+            PublicKey::ed25519_from(rng.gen())
+        } else {
+            let mut secp256k1_bytes = [0u8; SECP256K1_LENGTH];
+            rng.fill_bytes(&mut secp256k1_bytes);
+            PublicKey::secp256k1_from(secp256k1_bytes)
+        };
 
         let mut u512_array = [0u8; 64];
         rng.fill_bytes(u512_array.as_mut());
@@ -119,6 +135,11 @@ pub struct GenesisConfig {
     standard_payment_installer_bytes: Vec<u8>,
     accounts: Vec<GenesisAccount>,
     wasm_costs: WasmCosts,
+    host_function_costs: HostFunctionCosts,
+    max_payment: u64,
+    block_gas_limit: u64,
+    bonding_delay: u64,
+    unbonding_delay: u64,
 }
 
 impl GenesisConfig {
@@ -132,6 +153,11 @@ impl GenesisConfig {
         standard_payment_installer_bytes: Vec<u8>,
         accounts: Vec<GenesisAccount>,
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        max_payment: u64,
+        block_gas_limit: u64,
+        bonding_delay: u64,
+        unbonding_delay: u64,
     ) -> Self {
         GenesisConfig {
             name,
@@ -142,6 +168,11 @@ impl GenesisConfig {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            max_payment,
+            block_gas_limit,
+            bonding_delay,
+            unbonding_delay,
         }
     }
 
@@ -173,6 +204,30 @@ impl GenesisConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> HostFunctionCosts {
+        self.host_function_costs
+    }
+
+    /// The amount of motes, and so the payment code gas limit, of the genesis protocol version.
+    pub fn max_payment(&self) -> u64 {
+        self.max_payment
+    }
+
+    /// The cumulative gas limit of the deploys of a single execute request at genesis.
+    pub fn block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+    }
+
+    /// The number of eras after which a bonding request becomes effective.
+    pub fn bonding_delay(&self) -> u64 {
+        self.bonding_delay
+    }
+
+    /// The number of eras after which an unbonding request is paid out.
+    pub fn unbonding_delay(&self) -> u64 {
+        self.unbonding_delay
+    }
+
     pub fn get_bonded_validators(&self) -> impl Iterator<Item = (PublicKey, Motes)> + '_ {
         let zero = Motes::zero();
         self.accounts.iter().filter_map(move |genesis_account| {
@@ -235,6 +290,22 @@ impl Distribution<GenesisConfig> for Standard {
             opcodes_div: rng.gen(),
         };
 
+        let host_function_costs = {
+            let bytes: Vec<u8> = iter::repeat(())
+                .map(|_| rng.gen())
+                .take(HOST_FUNCTION_COSTS_SERIALIZED_LENGTH)
+                .collect();
+            bytesrepr::deserialize(bytes).expect("should deserialize host function costs")
+        };
+
+        let max_payment = rng.gen();
+
+        let block_gas_limit = rng.gen();
+
+        let bonding_delay = rng.gen();
+
+        let unbonding_delay = rng.gen();
+
         GenesisConfig {
             name,
             timestamp,
@@ -244,6 +315,11 @@ impl Distribution<GenesisConfig> for Standard {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            max_payment,
+            block_gas_limit,
+            bonding_delay,
+            unbonding_delay,
         }
     }
 }
@@ -1,14 +1,22 @@
+pub mod bid_state;
 pub mod deploy_item;
+pub mod distribute_rewards;
 pub mod engine_config;
 mod error;
+pub mod estimate;
 pub mod executable_deploy_item;
 pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
+pub mod module_cache;
 pub mod op;
+mod parallel;
 pub mod query;
+pub mod slash;
 pub mod system_contract_cache;
+pub mod system_operation;
+pub mod unbond_payout;
 pub mod upgrade;
 pub mod utils;
 
@@ -20,13 +28,15 @@ use std::{
 
 use num_traits::Zero;
 use parity_wasm::elements::Module;
+use rayon::prelude::*;
 
 use contract::args_parser::ArgsParser;
 use engine_shared::{
     account::Account,
     additive_map::AdditiveMap,
-    contract::Contract,
+    contract::{Contract, DEFAULT_ENTRY_POINT},
     gas::Gas,
+    host_function_costs::HostFunctionCosts,
     motes::Motes,
     newtypes::{Blake2bHash, CorrelationId},
     stored_value::StoredValue,
@@ -35,13 +45,14 @@ use engine_shared::{
 };
 use engine_storage::{
     global_state::{CommitResult, StateProvider, StateReader},
-    protocol_data::ProtocolData,
+    protocol_data::{ProtocolData, DEFAULT_MIN_GAS_PRICE},
 };
 use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
 use proof_of_stake::Stakes;
 use types::{
-    account::PublicKey, bytesrepr::ToBytes, system_contract_errors::mint, AccessRights, BlockTime,
-    Key, Phase, ProtocolVersion, URef, KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
+    account::PublicKey, bytesrepr::ToBytes, deserialize_args, system_contract_errors::mint,
+    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, URef, KEY_HASH_LENGTH, U512,
+    UREF_ADDR_LENGTH,
 };
 
 pub use self::{
@@ -50,29 +61,34 @@ pub use self::{
 };
 use crate::{
     engine_state::{
+        bid_state::{BidStateRequest, BidStateResult},
         deploy_item::DeployItem,
+        distribute_rewards::DistributeRewardsRequest,
         error::Error::MissingSystemContract,
+        estimate::{EstimateRequest, EstimateResult},
         executable_deploy_item::ExecutableDeployItem,
         execute_request::ExecuteRequest,
+        execution_effect::ExecutionEffect,
         execution_result::{ExecutionResult, ForcedTransferResult},
         genesis::{
-            GenesisAccount, GenesisConfig, GenesisResult, PLACEHOLDER_KEY, POS_BONDING_PURSE,
-            POS_PAYMENT_PURSE, POS_REWARDS_PURSE,
+            GenesisAccount, GenesisConfig, GenesisResult, PLACEHOLDER_KEY, POS_BONDING_DELAY,
+            POS_BONDING_PURSE, POS_PAYMENT_PURSE, POS_REWARDS_PURSE, POS_UNBONDING_DELAY,
         },
-        query::{QueryRequest, QueryResult},
+        module_cache::{ModuleCache, ModuleCacheKey},
+        parallel::RecordingReader,
+        query::{QueryRequest, QueryResult, QueryWithProofResult},
+        slash::SlashRequest,
         system_contract_cache::SystemContractCache,
+        system_operation::{SystemOperationError, SystemOperationResult},
+        unbond_payout::UnbondPayoutRequest,
         upgrade::{UpgradeConfig, UpgradeResult},
     },
     execution::{self, AddressGenerator, Executor, MINT_NAME, POS_NAME},
-    tracking_copy::{TrackingCopy, TrackingCopyExt},
+    runtime,
+    tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
     KnownKeys,
 };
 
-// TODO?: MAX_PAYMENT && CONV_RATE values are currently arbitrary w/ real values
-// TBD gas * CONV_RATE = motes
-pub const MAX_PAYMENT: u64 = 10_000_000;
-pub const CONV_RATE: u64 = 10;
-
 pub const SYSTEM_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
@@ -82,6 +98,7 @@ const MINT_METHOD_NAME: &str = "mint";
 pub struct EngineState<S> {
     config: EngineConfig,
     system_contract_cache: SystemContractCache,
+    module_cache: ModuleCache,
     state: S,
 }
 
@@ -92,13 +109,21 @@ where
 {
     pub fn new(state: S, config: EngineConfig) -> EngineState<S> {
         let system_contract_cache = Default::default();
+        let module_cache = Default::default();
         EngineState {
             config,
             system_contract_cache,
+            module_cache,
             state,
         }
     }
 
+    /// Replaces the cache of the modules which deploys are run from.
+    pub fn with_module_cache(mut self, module_cache: ModuleCache) -> EngineState<S> {
+        self.module_cache = module_cache;
+        self
+    }
+
     pub fn config(&self) -> &EngineConfig {
         &self.config
     }
@@ -113,6 +138,16 @@ where
         }
     }
 
+    pub fn host_function_costs(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<HostFunctionCosts>, Error> {
+        match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => Ok(Some(*protocol_data.host_function_costs())),
+            None => Ok(None),
+        }
+    }
+
     pub fn get_protocol_data(
         &self,
         protocol_version: ProtocolVersion,
@@ -130,7 +165,7 @@ where
         genesis_config: GenesisConfig,
     ) -> Result<GenesisResult, Error> {
         // Preliminaries
-        let executor = Executor::new(self.config);
+        let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
         let blocktime = BlockTime::new(GENESIS_INITIAL_BLOCKTIME);
         let gas_limit = Gas::new(std::u64::MAX.into());
         let phase = Phase::System;
@@ -139,6 +174,7 @@ where
         let initial_root_hash = self.state.empty_root();
         let protocol_version = genesis_config.protocol_version();
         let wasm_costs = genesis_config.wasm_costs();
+        let host_function_costs = genesis_config.host_function_costs();
         let preprocessor = Preprocessor::new(wasm_costs);
 
         // Spec #3: Create "virtual system account" object.
@@ -248,8 +284,8 @@ where
             }
         };
 
-        // Spec #7: Execute pos installer wasm code, passing the initially bonded validators as an
-        // argument
+        // Spec #7: Execute pos installer wasm code, passing the initially bonded validators and the
+        // bonding and unbonding delays as arguments
         let proof_of_stake_reference: URef = {
             // Spec #6: Compute initially bonded validators as the contents of accounts_path
             // filtered to non-zero staked amounts.
@@ -289,7 +325,7 @@ where
                     blocktime,
                     install_deploy_hash,
                     gas_limit,
-                    address_generator,
+                    Rc::clone(&address_generator),
                     protocol_version,
                     correlation_id,
                     Rc::clone(&tracking_copy),
@@ -331,6 +367,21 @@ where
                     .expect("should convert")
                     .expect("should convert");
 
+                let store_delay = |delay: u64| -> URef {
+                    let uref = {
+                        let addr = address_generator.borrow_mut().create_address();
+                        URef::new(addr, AccessRights::READ)
+                    };
+                    let value = {
+                        let cl_value = CLValue::from_t(delay).expect("should convert");
+                        StoredValue::CLValue(cl_value)
+                    };
+                    tracking_copy.borrow_mut().write(Key::URef(uref), value);
+                    uref
+                };
+                let bonding_delay = store_delay(genesis_config.bonding_delay());
+                let unbonding_delay = store_delay(genesis_config.unbonding_delay());
+
                 let named_keys = {
                     let mut tmp: BTreeMap<String, Key> =
                         stakes.strings().map(|key| (key, PLACEHOLDER_KEY)).collect();
@@ -338,6 +389,8 @@ where
                         (POS_BONDING_PURSE, bonding_purse),
                         (POS_PAYMENT_PURSE, payment_purse),
                         (POS_REWARDS_PURSE, rewards_purse),
+                        (POS_BONDING_DELAY, bonding_delay),
+                        (POS_UNBONDING_DELAY, unbonding_delay),
                     ]
                     .iter()
                     .for_each(|(name, uref)| {
@@ -359,7 +412,12 @@ where
                 let proof_of_stake_installer_module =
                     preprocessor.preprocess(proof_of_stake_installer_bytes)?;
                 let args = {
-                    let args = (mint_reference, bonded_validators);
+                    let args = (
+                        mint_reference,
+                        bonded_validators,
+                        genesis_config.bonding_delay(),
+                        genesis_config.unbonding_delay(),
+                    );
                     ArgsParser::parse(args)
                         .expect("args should convert to `Vec<CLValue>`")
                         .into_bytes()
@@ -395,6 +453,7 @@ where
         // specification.
         let protocol_data = ProtocolData::partial_without_standard_payment(
             wasm_costs,
+            host_function_costs,
             mint_reference,
             proof_of_stake_reference,
         );
@@ -449,6 +508,10 @@ where
         // Spec #2: Associate given CostTable with given ProtocolVersion.
         let protocol_data = ProtocolData::new(
             wasm_costs,
+            host_function_costs,
+            DEFAULT_MIN_GAS_PRICE,
+            genesis_config.max_payment(),
+            genesis_config.block_gas_limit(),
             mint_reference,
             proof_of_stake_reference,
             standard_payment_reference,
@@ -511,7 +574,7 @@ where
                 let contract = tracking_copy
                     .borrow_mut()
                     .get_contract(correlation_id, Key::URef(mint_reference))?;
-                let (bytes, _, _) = contract.destructure();
+                let (bytes, _, _, _) = contract.destructure();
                 engine_wasm_prep::deserialize(&bytes)?
             };
 
@@ -532,9 +595,7 @@ where
                 let base_key = Key::URef(mint_reference);
                 let authorization_keys: BTreeSet<PublicKey> = BTreeSet::new();
                 let account_public_key = account.public_key();
-                // NOTE: As Ed25519 keys are currently supported by chainspec, PublicKey::value
-                // returns raw bytes of it
-                let purse_creation_deploy_hash = account_public_key.value();
+                let purse_creation_deploy_hash = utils::public_key_id(account_public_key);
                 let address_generator = {
                     let generator = AddressGenerator::new(&account_public_key.to_bytes()?, phase);
                     Rc::new(RefCell::new(generator))
@@ -669,9 +730,93 @@ where
             None => *current_protocol_data.wasm_costs(),
         };
 
+        // resolve host function costs for new protocol version
+        let new_host_function_costs = match upgrade_config.host_function_costs() {
+            Some(new_host_function_costs) => new_host_function_costs,
+            None => *current_protocol_data.host_function_costs(),
+        };
+
+        // resolve minimum gas price for new protocol version
+        let new_min_gas_price = match upgrade_config.min_gas_price() {
+            Some(0) => return Err(Error::InvalidUpgradeConfig),
+            Some(new_min_gas_price) => new_min_gas_price,
+            None => current_protocol_data.min_gas_price(),
+        };
+
+        // resolve payment and block gas limits for new protocol version
+        let new_max_payment = match upgrade_config.max_payment() {
+            Some(0) => return Err(Error::InvalidUpgradeConfig),
+            Some(new_max_payment) => new_max_payment,
+            None => current_protocol_data.max_payment(),
+        };
+        let new_block_gas_limit = match upgrade_config.block_gas_limit() {
+            Some(0) => return Err(Error::InvalidUpgradeConfig),
+            Some(new_block_gas_limit) => new_block_gas_limit,
+            None => current_protocol_data.block_gas_limit(),
+        };
+
+        // install the bonding delays in a proof of stake contract which predates them, and
+        // overwrite them when they change
+        let proof_of_stake_key = Key::URef(current_protocol_data.proof_of_stake());
+        let mut proof_of_stake_contract = match tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &proof_of_stake_key)
+        {
+            Ok(Some(StoredValue::Contract(contract))) => contract,
+            Ok(Some(stored_value)) => {
+                return Ok(UpgradeResult::TypeMismatch(
+                    engine_shared::TypeMismatch::new(
+                        "Contract".to_string(),
+                        stored_value.type_name(),
+                    ),
+                ))
+            }
+            Ok(None) => return Ok(UpgradeResult::KeyNotFound(proof_of_stake_key)),
+            Err(error) => return Err(Error::Exec(error.into())),
+        };
+        let mut delays_address_generator = {
+            // seeded w/ the new protocol version so the urefs can't collide with the installer's
+            let bytes = new_protocol_version.value().into_bytes()?;
+            AddressGenerator::new(&Blake2bHash::new(&bytes).value(), Phase::System)
+        };
+        let mut installed_delay = false;
+        for (name, new_delay) in &[
+            (POS_BONDING_DELAY, upgrade_config.bonding_delay()),
+            (POS_UNBONDING_DELAY, upgrade_config.unbonding_delay()),
+        ] {
+            let uref = match (proof_of_stake_contract.named_keys().get(*name), new_delay) {
+                (Some(Key::URef(_)), None) => continue,
+                (Some(Key::URef(uref)), Some(_)) => *uref,
+                _ => {
+                    let addr = delays_address_generator.create_address();
+                    let uref = URef::new(addr, AccessRights::READ);
+                    proof_of_stake_contract
+                        .named_keys_mut()
+                        .insert(String::from(*name), Key::URef(uref));
+                    installed_delay = true;
+                    uref
+                }
+            };
+            // without a configured delay, requests take effect immediately as they used to
+            let value = CLValue::from_t(new_delay.unwrap_or_default()).expect("should convert");
+            tracking_copy
+                .borrow_mut()
+                .write(Key::URef(uref), StoredValue::CLValue(value));
+        }
+        if installed_delay {
+            tracking_copy.borrow_mut().write(
+                proof_of_stake_key,
+                StoredValue::Contract(proof_of_stake_contract),
+            );
+        }
+
         // 3.1.2.2 persist wasm CostTable
         let new_protocol_data = ProtocolData::new(
             new_wasm_costs,
+            new_host_function_costs,
+            new_min_gas_price,
+            new_max_payment,
+            new_block_gas_limit,
             current_protocol_data.mint(),
             current_protocol_data.proof_of_stake(),
             current_protocol_data.standard_payment(),
@@ -681,6 +826,11 @@ where
             .put_protocol_data(new_protocol_version, &new_protocol_data)
             .map_err(Into::into)?;
 
+        // modules preprocessed with the old costs are no longer needed
+        if new_wasm_costs != *current_protocol_data.wasm_costs() {
+            self.module_cache.clear();
+        }
+
         // 3.1.1.1.1.5 upgrade installer is optional except on major version upgrades
         match upgrade_config.upgrade_installer_bytes() {
             None if upgrade_check_result.is_code_required() => {
@@ -747,7 +897,7 @@ where
                 let state = Rc::clone(&tracking_copy);
                 let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-                let executor = Executor::new(self.config);
+                let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
 
                 executor.exec_system(
                     upgrade_installer_module,
@@ -814,22 +964,265 @@ where
             .into())
     }
 
+    pub fn run_query_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        query_request: QueryRequest,
+    ) -> Result<QueryWithProofResult, Error> {
+        let tracking_copy = match self.tracking_copy(query_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(QueryWithProofResult::root_not_found()),
+        };
+
+        let (result, proofs) = tracking_copy
+            .query_with_proof(correlation_id, query_request.key(), query_request.path())
+            .map_err(|err| Error::Exec(err.into()))?;
+
+        Ok(QueryWithProofResult::new(result.into(), proofs))
+    }
+
+    pub fn get_bid_state(
+        &self,
+        correlation_id: CorrelationId,
+        bid_state_request: BidStateRequest,
+    ) -> Result<BidStateResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(bid_state_request.parent_state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(BidStateResult::RootNotFound),
+        };
+
+        let protocol_version = bid_state_request.protocol_version();
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let proof_of_stake_contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(protocol_data.proof_of_stake()))?;
+
+        let stakes = utils::pos_named_keys_to_stakes(proof_of_stake_contract.named_keys());
+
+        Ok(BidStateResult::Success(stakes))
+    }
+
+    pub fn commit_distribute_rewards(
+        &self,
+        correlation_id: CorrelationId,
+        mut distribute_rewards_request: DistributeRewardsRequest,
+    ) -> Result<SystemOperationResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.commit_system_operation(
+            correlation_id,
+            distribute_rewards_request.parent_state_hash(),
+            distribute_rewards_request.protocol_version(),
+            (
+                "distribute_rewards",
+                distribute_rewards_request.take_rewards(),
+            ),
+        )
+    }
+
+    pub fn commit_slash(
+        &self,
+        correlation_id: CorrelationId,
+        mut slash_request: SlashRequest,
+    ) -> Result<SystemOperationResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.commit_system_operation(
+            correlation_id,
+            slash_request.parent_state_hash(),
+            slash_request.protocol_version(),
+            ("slash", slash_request.take_slashes()),
+        )
+    }
+
+    pub fn commit_unbond_payout(
+        &self,
+        correlation_id: CorrelationId,
+        unbond_payout_request: UnbondPayoutRequest,
+    ) -> Result<SystemOperationResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.commit_system_operation(
+            correlation_id,
+            unbond_payout_request.parent_state_hash(),
+            unbond_payout_request.protocol_version(),
+            ("unbond_payout", unbond_payout_request.era_height()),
+        )
+    }
+
+    /// Calls the proof of stake contract as the system account with `args` on top of
+    /// `pre_state_hash`, and commits the effects of the call.
+    fn commit_system_operation(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        args: impl ArgsParser,
+    ) -> Result<SystemOperationResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(SystemOperationResult::RootNotFound),
+        };
+
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => {
+                return Ok(SystemOperationResult::Failure(
+                    SystemOperationError::InvalidProtocolVersion(protocol_version),
+                ))
+            }
+        };
+
+        let args = ArgsParser::parse(args)
+            .expect("args should convert to `Vec<CLValue>`")
+            .into_bytes()?;
+
+        match self.call_proof_of_stake_as_system(
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            pre_state_hash,
+            protocol_version,
+            protocol_data,
+            args,
+        ) {
+            Ok(_) => {}
+            Err(Error::Exec(error)) => return Ok(SystemOperationResult::Failure(error.into())),
+            Err(error) => return Err(error),
+        }
+
+        let effects = tracking_copy.borrow().effect();
+
+        let commit_result = self.apply_effect(
+            correlation_id,
+            protocol_version,
+            pre_state_hash,
+            effects.transforms,
+        )?;
+
+        Ok(SystemOperationResult::from_commit_result(commit_result))
+    }
+
+    /// Calls the proof of stake contract as the system account, passing it `args`.  The effects of
+    /// the call are recorded in `tracking_copy`, which should be checked out at `pre_state_hash`.
+    fn call_proof_of_stake_as_system(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<S::Reader>>>,
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        args: Vec<u8>,
+    ) -> Result<CLValue, Error> {
+        let system_account = {
+            let key = Key::Account(SYSTEM_ACCOUNT_ADDR);
+            match tracking_copy.borrow_mut().read(correlation_id, &key) {
+                Ok(Some(StoredValue::Account(account))) => account,
+                Ok(Some(stored_value)) => {
+                    return Err(Error::Exec(execution::Error::TypeMismatch(
+                        engine_shared::TypeMismatch::new(
+                            "Account".to_string(),
+                            stored_value.type_name(),
+                        ),
+                    )))
+                }
+                Ok(None) => return Err(Error::Exec(execution::Error::KeyNotFound(key))),
+                Err(error) => return Err(Error::Exec(error.into())),
+            }
+        };
+
+        // the proof of stake contract is called from a module which does nothing, so that the
+        // call is dispatched to either the host-side or the wasm implementation
+        let do_nothing = {
+            let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+            preprocessor.preprocess(&wasm::do_nothing_bytes())?
+        };
+
+        let mut named_keys = system_account.named_keys().to_owned();
+        let base_key = Key::Account(SYSTEM_ACCOUNT_ADDR);
+        let authorization_keys = {
+            let mut ret = BTreeSet::new();
+            ret.insert(SYSTEM_ACCOUNT_ADDR);
+            ret
+        };
+        let blocktime = BlockTime::default();
+        let deploy_hash = {
+            // seeds address generator w/ pre state hash and args
+            let mut bytes = pre_state_hash.to_vec();
+            bytes.extend_from_slice(&args);
+            Blake2bHash::new(&bytes).into()
+        };
+        // system calls have no gas limit; approximating with MAX
+        let gas_limit = Gas::new(std::u64::MAX.into());
+        let phase = Phase::System;
+        let address_generator = {
+            let generator = AddressGenerator::new(&deploy_hash, phase);
+            Rc::new(RefCell::new(generator))
+        };
+        let proof_of_stake_key = Key::URef(protocol_data.proof_of_stake());
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
+
+        let (_instance, mut runtime) = executor.create_runtime(
+            do_nothing,
+            Vec::new(),
+            &mut named_keys,
+            base_key,
+            &system_account,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            address_generator,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            phase,
+            protocol_data,
+            system_contract_cache,
+        )?;
+
+        Ok(runtime.call_contract(proof_of_stake_key, args)?)
+    }
+
     pub fn run_execute(
         &self,
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
     ) -> Result<Vec<ExecutionResult>, RootNotFound> {
-        // TODO: do not unwrap
-        let wasm_costs = self
-            .wasm_costs(exec_request.protocol_version)
-            .unwrap()
-            .unwrap();
-        let executor = Executor::new(self.config);
-        let preprocessor = Preprocessor::new(wasm_costs);
+        let deploys = exec_request.take_deploys();
+        let protocol_data =
+            match self.protocol_data_for_deploys(exec_request.protocol_version, deploys.len()) {
+                Ok(protocol_data) => protocol_data,
+                Err(results) => return Ok(results),
+            };
+        let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+        let block_gas_limit = protocol_data.block_gas_limit();
+        let mut block_gas = Gas::default();
 
         let mut results = Vec::new();
 
-        for deploy_item in exec_request.take_deploys() {
+        for deploy_item in deploys {
+            // Deploys are no longer run once the ones before them used up the block gas limit
+            if block_gas.value() >= U512::from(block_gas_limit) {
+                results.push(ExecutionResult::precondition_failure(
+                    Error::BlockGasLimitReached {
+                        limit: block_gas_limit,
+                    },
+                ));
+                continue;
+            }
+
             let result = match deploy_item {
                 Ok(deploy_item) => self.deploy(
                     correlation_id,
@@ -844,7 +1237,10 @@ where
                                                       * below */
             };
             match result {
-                Ok(result) => results.push(result),
+                Ok(result) => {
+                    block_gas = block_gas + result.cost();
+                    results.push(result)
+                }
                 Err(error) => {
                     return Err(error);
                 }
@@ -854,15 +1250,203 @@ where
         Ok(results)
     }
 
-    pub fn get_module(
+    /// Executes the deploys of `exec_request` in order, each one against the state left by the
+    /// ones before it, rather than all of them against `parent_state_hash`.
+    ///
+    /// Returns the result of each deploy together with the effect of the whole sequence relative
+    /// to `parent_state_hash`, which can be committed in a single step.
+    pub fn run_execute_sequential(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<(Vec<ExecutionResult>, ExecutionEffect), RootNotFound> {
+        let deploys = exec_request.take_deploys();
+        let protocol_data =
+            match self.protocol_data_for_deploys(exec_request.protocol_version, deploys.len()) {
+                Ok(protocol_data) => protocol_data,
+                Err(results) => return Ok((results, ExecutionEffect::default())),
+            };
+        let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+        let block_gas_limit = protocol_data.block_gas_limit();
+        let mut block_gas = Gas::default();
+
+        let mut tracking_copy = match self.tracking_copy(exec_request.parent_state_hash) {
+            Ok(Some(tracking_copy)) => tracking_copy,
+            Ok(None) => return Err(RootNotFound::new(exec_request.parent_state_hash)),
+            Err(error) => {
+                let results = precondition_failures(error, deploys.len());
+                return Ok((results, ExecutionEffect::default()));
+            }
+        };
+
+        let mut results = Vec::with_capacity(deploys.len());
+
+        for deploy_item in deploys {
+            // Deploys are no longer run once the ones before them used up the block gas limit
+            if block_gas.value() >= U512::from(block_gas_limit) {
+                results.push(ExecutionResult::precondition_failure(
+                    Error::BlockGasLimitReached {
+                        limit: block_gas_limit,
+                    },
+                ));
+                continue;
+            }
+
+            let result = match deploy_item {
+                Ok(deploy_item) => self.deploy_on_tracking_copy(
+                    correlation_id,
+                    &executor,
+                    &preprocessor,
+                    exec_request.protocol_version,
+                    Rc::new(RefCell::new(tracking_copy.fork())),
+                    BlockTime::new(exec_request.block_time),
+                    deploy_item,
+                ),
+                Err(exec_result) => exec_result,
+            };
+            block_gas = block_gas + result.cost();
+            results.push(apply_result(correlation_id, &mut tracking_copy, result));
+        }
+
+        Ok((results, tracking_copy.effect()))
+    }
+
+    /// Runs the deploys of `exec_request` concurrently, with the same results and combined effect
+    /// as [`run_execute_sequential`](Self::run_execute_sequential).
+    ///
+    /// Every deploy is first run on the parent state on its own thread.  Its result is kept if none
+    /// of the values it read were changed by the deploys before it, where adding to the same number
+    /// doesn't count as a conflict.  Otherwise the deploy is run again on top of them.
+    pub fn run_execute_parallel(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<(Vec<ExecutionResult>, ExecutionEffect), RootNotFound>
+    where
+        S: Sync,
+    {
+        let deploys = exec_request.take_deploys();
+        let protocol_data =
+            match self.protocol_data_for_deploys(exec_request.protocol_version, deploys.len()) {
+                Ok(protocol_data) => protocol_data,
+                Err(results) => return Ok((results, ExecutionEffect::default())),
+            };
+        let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+        let block_gas_limit = protocol_data.block_gas_limit();
+        let mut block_gas = Gas::default();
+
+        let mut tracking_copy = match self.tracking_copy(exec_request.parent_state_hash) {
+            Ok(Some(tracking_copy)) => tracking_copy,
+            Ok(None) => return Err(RootNotFound::new(exec_request.parent_state_hash)),
+            Err(error) => {
+                let results = precondition_failures(error, deploys.len());
+                return Ok((results, ExecutionEffect::default()));
+            }
+        };
+        let mut results = Vec::with_capacity(deploys.len());
+
+        let parent_state_hash = exec_request.parent_state_hash;
+        let protocol_version = exec_request.protocol_version;
+        let blocktime = BlockTime::new(exec_request.block_time);
+
+        // A deploy without a speculative result is simply run in sequence.
+        let speculative_results: Vec<_> = deploys
+            .par_iter()
+            .map(|deploy_item| {
+                let deploy_item = deploy_item.as_ref().ok()?.clone();
+                let reader = self.state.checkout(parent_state_hash).ok()??;
+                let tracking_copy = TrackingCopy::new(RecordingReader::new(reader));
+                let tracking_copy = Rc::new(RefCell::new(tracking_copy));
+                let result = self.deploy_on_tracking_copy(
+                    correlation_id,
+                    &executor,
+                    &preprocessor,
+                    protocol_version,
+                    Rc::clone(&tracking_copy),
+                    blocktime,
+                    deploy_item,
+                );
+                let reads = tracking_copy.borrow().reader().take_reads();
+                Some((result, reads))
+            })
+            .collect();
+
+        for (deploy_item, speculative_result) in deploys.into_iter().zip(speculative_results) {
+            // Deploys are no longer run once the ones before them used up the block gas limit
+            if block_gas.value() >= U512::from(block_gas_limit) {
+                results.push(ExecutionResult::precondition_failure(
+                    Error::BlockGasLimitReached {
+                        limit: block_gas_limit,
+                    },
+                ));
+                continue;
+            }
+
+            let result = match (deploy_item, speculative_result) {
+                (Ok(_), Some((result, reads)))
+                    if parallel::is_valid_on(
+                        correlation_id,
+                        &result,
+                        &reads,
+                        &mut tracking_copy,
+                    ) =>
+                {
+                    result
+                }
+                (Ok(deploy_item), _) => self.deploy_on_tracking_copy(
+                    correlation_id,
+                    &executor,
+                    &preprocessor,
+                    protocol_version,
+                    Rc::new(RefCell::new(tracking_copy.fork())),
+                    blocktime,
+                    deploy_item,
+                ),
+                (Err(exec_result), _) => exec_result,
+            };
+            block_gas = block_gas + result.cost();
+            results.push(apply_result(correlation_id, &mut tracking_copy, result));
+        }
+
+        Ok((results, tracking_copy.effect()))
+    }
+
+    /// Returns the protocol data for `protocol_version`.  Without it none of the `deploy_count`
+    /// deploys of a request can be run, so a precondition failure for each of them is returned
+    /// instead.
+    fn protocol_data_for_deploys(
+        &self,
+        protocol_version: ProtocolVersion,
+        deploy_count: usize,
+    ) -> Result<ProtocolData, Vec<ExecutionResult>> {
+        match self.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => Ok(protocol_data),
+            Ok(None) => Err((0..deploy_count)
+                .map(|_| {
+                    ExecutionResult::precondition_failure(Error::InvalidProtocolVersion(
+                        protocol_version,
+                    ))
+                })
+                .collect()),
+            Err(error) => Err(precondition_failures(error, deploy_count)),
+        }
+    }
+
+    pub fn get_module<R>(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         deploy_item: &ExecutableDeployItem,
         account: &Account,
         correlation_id: CorrelationId,
         preprocessor: &Preprocessor,
         protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
         let stored_contract_key = match deploy_item {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
                 let module = preprocessor.preprocess(&module_bytes)?;
@@ -932,21 +1516,59 @@ where
                 }
             }
         };
-        self.get_module_from_key(
+        let contract = self.get_contract_from_key(
             tracking_copy,
             stored_contract_key,
             correlation_id,
             protocol_version,
-        )
+        )?;
+
+        // Stored contracts are run at their `call` entry point, so the deploy's args are checked
+        // against the types declared for it, if any.
+        let (args, named_args) = deserialize_args(deploy_item.args().to_vec())?;
+        runtime::validate_entry_point_args(&contract, DEFAULT_ENTRY_POINT, &args, &named_args)?;
+
+        let (ret, _, _, _) = contract.destructure();
+        let key = ModuleCacheKey::new(&ret, preprocessor.wasm_costs(), *protocol_version)?;
+        self.module_cache
+            .get_or_insert_with(correlation_id, key, || {
+                engine_wasm_prep::deserialize(&ret).map_err(Into::into)
+            })
+    }
+
+    fn get_module_from_key<R>(
+        &self,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        stored_contract_key: Key,
+        correlation_id: CorrelationId,
+        protocol_version: &ProtocolVersion,
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let contract = self.get_contract_from_key(
+            tracking_copy,
+            stored_contract_key,
+            correlation_id,
+            protocol_version,
+        )?;
+        let (ret, _, _, _) = contract.destructure();
+        let module = engine_wasm_prep::deserialize(&ret)?;
+        Ok(module)
     }
 
-    fn get_module_from_key(
+    fn get_contract_from_key<R>(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         stored_contract_key: Key,
         correlation_id: CorrelationId,
         protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
+    ) -> Result<Contract, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
         let contract = tracking_copy
             .borrow_mut()
             .get_contract(correlation_id, stored_contract_key)?;
@@ -962,9 +1584,7 @@ where
             return Err(error::Error::Exec(exec_error));
         }
 
-        let (ret, _, _) = contract.destructure();
-        let module = engine_wasm_prep::deserialize(&ret)?;
-        Ok(module)
+        Ok(contract)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -978,14 +1598,6 @@ where
         blocktime: BlockTime,
         deploy_item: DeployItem,
     ) -> Result<ExecutionResult, RootNotFound> {
-        // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
-
-        let session = deploy_item.session;
-        let payment = deploy_item.payment;
-        let address = Key::Account(deploy_item.address);
-        let authorization_keys = deploy_item.authorization_keys;
-        let deploy_hash = deploy_item.deploy_hash;
-
         // Create tracking copy (which functions as a deploy context)
         // validation_spec_2: prestate_hash check
         let tracking_copy = match self.tracking_copy(prestate_hash) {
@@ -994,48 +1606,97 @@ where
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
-        // Get addr bytes from `address` (which is actually a Key)
-        // validation_spec_3: account validity
-        let account_addr = match address.into_account() {
-            Some(account_addr) => account_addr,
-            None => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ))
-            }
-        };
-
-        // Get account from tracking copy
-        // validation_spec_3: account validity
-        let account: Account = match tracking_copy
+        Ok(self.deploy_on_tracking_copy(
+            correlation_id,
+            executor,
+            preprocessor,
+            protocol_version,
+            tracking_copy,
+            blocktime,
+            deploy_item,
+        ))
+    }
+
+    /// Reads the account at `account_addr` and checks that `authorization_keys` are allowed to
+    /// deploy on its behalf.
+    fn get_authorized_account<R>(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &Rc<RefCell<TrackingCopy<R>>>,
+        account_addr: PublicKey,
+        authorization_keys: &BTreeSet<PublicKey>,
+    ) -> Result<Account, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let account = tracking_copy
             .borrow_mut()
             .get_account(correlation_id, account_addr)
-        {
-            Ok(account) => account,
-            Err(_) => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ));
-            }
-        };
+            .map_err(|_| Error::Authorization)?;
 
         // Authorize using provided authorization keys
-        // validation_spec_3: account validity
-        if !account.can_authorize(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
-                crate::engine_state::error::Error::Authorization,
-            ));
+        if !account.can_authorize(authorization_keys) {
+            return Err(Error::Authorization);
         }
 
         // Check total key weight against deploy threshold
-        // validation_spec_4: deploy validity
-        if !account.can_deploy_with(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
-                // TODO?:this doesn't happen in execution any longer, should error variant be moved
-                execution::Error::DeploymentAuthorizationFailure.into(),
-            ));
+        if !account.can_deploy_with(authorization_keys) {
+            // TODO?:this doesn't happen in execution any longer, should error variant be moved
+            return Err(execution::Error::DeploymentAuthorizationFailure.into());
         }
 
+        Ok(account)
+    }
+
+    /// Executes a single deploy against the state held by `tracking_copy`.
+    ///
+    /// The returned result carries the effect of the deploy relative to that state; it is not
+    /// applied to `tracking_copy` itself.
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_on_tracking_copy<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        blocktime: BlockTime,
+        deploy_item: DeployItem,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
+
+        let session = deploy_item.session;
+        let payment = deploy_item.payment;
+        let address = Key::Account(deploy_item.address);
+        let authorization_keys = deploy_item.authorization_keys;
+        let deploy_hash = deploy_item.deploy_hash;
+        let gas_price = deploy_item.gas_price;
+
+        // Get addr bytes from `address` (which is actually a Key)
+        // validation_spec_3: account validity
+        let account_addr = match address.into_account() {
+            Some(account_addr) => account_addr,
+            None => return ExecutionResult::precondition_failure(error::Error::Authorization),
+        };
+
+        // Get account from tracking copy and authorize it
+        // validation_spec_3: account validity
+        // validation_spec_4: deploy validity
+        let account: Account = match self.get_authorized_account(
+            correlation_id,
+            &tracking_copy,
+            account_addr,
+            &authorization_keys,
+        ) {
+            Ok(account) => account,
+            Err(error) => return ExecutionResult::precondition_failure(error),
+        };
+
         // Create session code `A` from provided session bytes
         // validation_spec_1: valid wasm bytes
         let session_module = match self.get_module(
@@ -1048,7 +1709,7 @@ where
         ) {
             Ok(module) => module,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
         };
 
@@ -1057,16 +1718,24 @@ where
             Ok(Some(protocol_data)) => protocol_data,
             Ok(None) => {
                 let error = Error::InvalidProtocolVersion(protocol_version);
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(Error::Exec(
-                    error.into(),
-                )));
+                return ExecutionResult::precondition_failure(Error::Exec(error.into()));
             }
         };
 
-        let max_payment_cost: Motes = Motes::new(U512::from(MAX_PAYMENT));
+        // Deployers pay gas * gas_price motes, which must be at least the protocol minimum
+        // validation_spec_4: deploy validity
+        let min_gas_price = protocol_data.min_gas_price();
+        if gas_price < min_gas_price {
+            return ExecutionResult::precondition_failure(Error::GasPriceTooLow {
+                actual: gas_price,
+                minimum: min_gas_price,
+            });
+        }
+
+        let max_payment_cost: Motes = Motes::new(U512::from(protocol_data.max_payment()));
 
         // Get mint system contract details
         // payment_code_spec_6: system contract validity
@@ -1081,13 +1750,13 @@ where
                 .get_contract(correlation_id, Key::URef(mint_reference))
             {
                 Ok(contract) => contract,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                Err(error) => return ExecutionResult::precondition_failure(error.into()),
             };
 
             if !self.system_contract_cache.has(&mint_reference) {
                 let module = match engine_wasm_prep::deserialize(mint_contract.bytes()) {
                     Ok(module) => module,
-                    Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                    Err(error) => return ExecutionResult::precondition_failure(error.into()),
                 };
                 self.system_contract_cache.insert(mint_reference, module);
             }
@@ -1108,7 +1777,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return ExecutionResult::precondition_failure(error.into());
             }
         };
 
@@ -1121,7 +1790,7 @@ where
                 match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE) {
                     Some(key) => *key,
                     None => {
-                        return Ok(ExecutionResult::precondition_failure(Error::Deploy));
+                        return ExecutionResult::precondition_failure(Error::Deploy);
                     }
                 };
 
@@ -1132,7 +1801,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1148,7 +1817,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1160,143 +1829,40 @@ where
             .get_purse_balance(correlation_id, account_main_purse_balance_key)
         {
             Ok(balance) => balance,
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
         };
 
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
         if account_main_purse_balance < max_payment_cost {
-            return Ok(ExecutionResult::precondition_failure(
-                Error::InsufficientPayment,
-            ));
+            return ExecutionResult::precondition_failure(Error::InsufficientPayment);
         }
 
-        // Finalization is executed by system account (currently genesis account)
-        // payment_code_spec_5: system executes finalization
-        let system_account = Account::new(
-            SYSTEM_ACCOUNT_ADDR,
-            Default::default(),
-            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
-            Default::default(),
-            Default::default(),
-        );
-
         // [`ExecutionResultBuilder`] handles merging of multiple execution results
         let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
 
         // Execute provided payment code
         let payment_result = {
             // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
-            // conv_rate)
-            let pay_gas_limit = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
-
-            let module_bytes_is_empty = match payment {
-                ExecutableDeployItem::ModuleBytes {
-                    ref module_bytes, ..
-                } => module_bytes.is_empty(),
-                _ => false,
-            };
-
-            // Create payment code module from bytes
-            // validation_spec_1: valid wasm bytes
-            let maybe_payment_module = if module_bytes_is_empty {
-                let standard_payment = match self.state.get_protocol_data(protocol_version) {
-                    Ok(Some(protocol_data)) => {
-                        Key::URef(protocol_data.standard_payment()).normalize()
-                    }
-                    Ok(None) => {
-                        return Ok(ExecutionResult::precondition_failure(
-                            Error::InvalidProtocolVersion(protocol_version),
-                        ))
-                    }
-                    Err(_) => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
-                };
-                // If not in "use-system-contracts" mode, the returned module is the "do_nothing"
-                // Wasm.
-                self.get_module_from_key(
-                    Rc::clone(&tracking_copy),
-                    standard_payment,
-                    correlation_id,
-                    &protocol_version,
-                )
-            } else {
-                self.get_module(
-                    Rc::clone(&tracking_copy),
-                    &payment,
-                    &account,
-                    correlation_id,
-                    preprocessor,
-                    &protocol_version,
-                )
-            };
-
-            let payment_module = match maybe_payment_module {
-                Ok(module) => module,
-                Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error));
-                }
-            };
-            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
-
-            // payment_code_spec_2: execute payment code
-            let phase = Phase::Payment;
-            if !self.config.use_system_contracts() && module_bytes_is_empty {
-                let mut named_keys = account.named_keys().clone();
-                let address_generator = AddressGenerator::new(&deploy_hash, phase);
+            // gas_price)
+            let pay_gas_limit = Gas::from_motes(max_payment_cost, gas_price).unwrap_or_default();
 
-                let mut runtime = match executor.create_runtime(
-                    payment_module,
-                    payment.take_args(),
-                    &mut named_keys,
-                    address,
-                    &account,
-                    authorization_keys.clone(),
-                    blocktime,
-                    deploy_hash,
-                    pay_gas_limit,
-                    Rc::new(RefCell::new(address_generator)),
-                    protocol_version,
-                    correlation_id,
-                    Rc::clone(&tracking_copy),
-                    phase,
-                    protocol_data,
-                    system_contract_cache,
-                ) {
-                    Ok((_instance, runtime)) => runtime,
-                    Err(error) => {
-                        return Ok(ExecutionResult::precondition_failure(Error::Exec(error)))
-                    }
-                };
-
-                let effects_snapshot = tracking_copy.borrow().effect();
-                match runtime.call_host_standard_payment() {
-                    Ok(()) => ExecutionResult::Success {
-                        effect: runtime.context().effect(),
-                        cost: runtime.context().gas_counter(),
-                    },
-                    Err(error) => ExecutionResult::Failure {
-                        error: error.into(),
-                        effect: effects_snapshot,
-                        cost: runtime.context().gas_counter(),
-                    },
-                }
-            } else {
-                executor.exec(
-                    payment_module,
-                    payment.take_args(),
-                    address,
-                    &account,
-                    authorization_keys.clone(),
-                    blocktime,
-                    deploy_hash,
-                    pay_gas_limit,
-                    protocol_version,
-                    correlation_id,
-                    Rc::clone(&tracking_copy),
-                    phase,
-                    protocol_data,
-                    system_contract_cache,
-                )
+            match self.run_payment(
+                correlation_id,
+                executor,
+                preprocessor,
+                protocol_version,
+                protocol_data,
+                &tracking_copy,
+                &account,
+                payment,
+                authorization_keys.clone(),
+                blocktime,
+                deploy_hash,
+                pay_gas_limit,
+            ) {
+                Ok(payment_result) => payment_result,
+                Err(error) => return ExecutionResult::precondition_failure(error),
             }
         };
 
@@ -1310,7 +1876,7 @@ where
             let payment_purse: Key =
                 match proof_of_stake_contract.named_keys().get(POS_PAYMENT_PURSE) {
                     Some(key) => *key,
-                    None => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
+                    None => return ExecutionResult::precondition_failure(Error::Deploy),
                 };
 
             let purse_balance_key = match tracking_copy.borrow_mut().get_purse_balance_key(
@@ -1320,7 +1886,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             };
 
@@ -1330,23 +1896,26 @@ where
             {
                 Ok(balance) => balance,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
 
-        if let Some(forced_transfer) = payment_result.check_forced_transfer(payment_purse_balance) {
+        if let Some(forced_transfer) =
+            payment_result.check_forced_transfer(payment_purse_balance, gas_price)
+        {
             let error = match forced_transfer {
                 ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
                 ForcedTransferResult::PaymentFailure => payment_result.take_error().unwrap(),
             };
-            return Ok(ExecutionResult::new_payment_code_error(
+            return ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
                 account_main_purse_balance,
                 account_main_purse_balance_key,
                 rewards_purse_balance_key,
-            ));
+                gas_price,
+            );
         }
 
         execution_result_builder.set_payment_execution_result(payment_result);
@@ -1357,10 +1926,10 @@ where
         // session_code_spec_2: execute session code
         let session_result = {
             // payment_code_spec_3_b_i: if (balance of PoS pay purse) >= (gas spent during
-            // payment code execution) * conv_rate, yes session
-            // session_code_spec_1: gas limit = ((balance of PoS payment purse) / conv_rate)
+            // payment code execution) * gas_price, yes session
+            // session_code_spec_1: gas limit = ((balance of PoS payment purse) / gas_price)
             // - (gas spent during payment execution)
-            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, CONV_RATE)
+            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, gas_price)
                 .unwrap_or_default()
                 - payment_result_cost;
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
@@ -1397,83 +1966,404 @@ where
 
         // payment_code_spec_5: run finalize process
         let finalize_result = {
-            let post_session_tc = post_session_rc.borrow();
-            let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
+            //((gas spent during payment code execution) + (gas spent during session code execution)) * gas_price
+            let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), gas_price).expect("motes overflow");
 
-            // validation_spec_1: valid wasm bytes
-            let proof_of_stake_module =
-                match self.system_contract_cache.get(&proof_of_stake_reference) {
-                    Some(module) => module,
-                    None => {
-                        let module =
-                            match engine_wasm_prep::deserialize(proof_of_stake_contract.bytes()) {
-                                Ok(module) => module,
-                                Err(error) => {
-                                    return Ok(ExecutionResult::precondition_failure(error.into()))
-                                }
-                            };
-                        self.system_contract_cache
-                            .insert(proof_of_stake_reference, module.clone());
-                        module
-                    }
-                };
+            match self.finalize_payment(
+                correlation_id,
+                executor,
+                protocol_version,
+                protocol_data,
+                &post_session_rc.borrow(),
+                account_addr,
+                finalize_cost_motes,
+                authorization_keys,
+                blocktime,
+                deploy_hash,
+            ) {
+                Ok(finalize_result) => finalize_result,
+                Err(error) => return ExecutionResult::precondition_failure(error),
+            }
+        };
 
-            let proof_of_stake_args = {
-                //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
-                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), CONV_RATE).expect("motes overflow");
-                let args = ("finalize_payment", finalize_cost_motes.value(), account_addr);
-                ArgsParser::parse(args)
-                    .expect("args should convert to `Vec<CLValue>`")
-                    .into_bytes()
-                    .expect("args should serialize")
-            };
+        execution_result_builder.set_finalize_execution_result(finalize_result);
 
-            // The PoS keys may have changed because of effects during payment and/or
-            // session, so we need to look them up again from the tracking copy
-            let proof_of_stake_contract = match finalization_tc
-                .borrow_mut()
-                .get_contract(correlation_id, Key::URef(proof_of_stake_reference))
-            {
-                Ok(info) => info,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
-            };
+        // We panic here to indicate that the builder was not used properly.
+        let ret = execution_result_builder
+            .build(tracking_copy.borrow().reader(), correlation_id)
+            .expect("ExecutionResultBuilder not initialized properly");
 
-            let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
+        // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
+        // payment_code_spec_6: return properly combined set of transforms and
+        // appropriate error
+        ret
+    }
 
-            let base_key = Key::from(proof_of_stake_reference);
-            let gas_limit = Gas::new(U512::from(std::u64::MAX));
-            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+    /// Runs the payment code of a deploy from `account` against `tracking_copy`, limited to
+    /// `pay_gas_limit`.  Empty payment module bytes select the standard payment contract.
+    ///
+    /// Returns an error if the payment code can't be run at all.
+    #[allow(clippy::too_many_arguments)]
+    fn run_payment<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        tracking_copy: &Rc<RefCell<TrackingCopy<R>>>,
+        account: &Account,
+        payment: ExecutableDeployItem,
+        authorization_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+        pay_gas_limit: Gas,
+    ) -> Result<ExecutionResult, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let module_bytes_is_empty = match payment {
+            ExecutableDeployItem::ModuleBytes {
+                ref module_bytes, ..
+            } => module_bytes.is_empty(),
+            _ => false,
+        };
 
-            executor.exec_finalize(
-                proof_of_stake_module,
-                proof_of_stake_args,
-                &mut proof_of_stake_keys,
-                base_key,
-                &system_account,
+        // Create payment code module from bytes
+        // validation_spec_1: valid wasm bytes
+        let maybe_payment_module = if module_bytes_is_empty {
+            let standard_payment = Key::URef(protocol_data.standard_payment()).normalize();
+            // If not in "use-system-contracts" mode, the returned module is the "do_nothing"
+            // Wasm.
+            self.get_module_from_key(
+                Rc::clone(tracking_copy),
+                standard_payment,
+                correlation_id,
+                &protocol_version,
+            )
+        } else {
+            self.get_module(
+                Rc::clone(tracking_copy),
+                &payment,
+                account,
+                correlation_id,
+                preprocessor,
+                &protocol_version,
+            )
+        };
+
+        let payment_module = maybe_payment_module?;
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        // payment_code_spec_2: execute payment code
+        let phase = Phase::Payment;
+        if !self.config.use_system_contracts() && module_bytes_is_empty {
+            let mut named_keys = account.named_keys().clone();
+            let address_generator = AddressGenerator::new(&deploy_hash, phase);
+
+            let mut runtime = match executor.create_runtime(
+                payment_module,
+                payment.take_args(),
+                &mut named_keys,
+                Key::Account(account.public_key()),
+                account,
                 authorization_keys,
                 blocktime,
                 deploy_hash,
-                gas_limit,
+                pay_gas_limit,
+                Rc::new(RefCell::new(address_generator)),
                 protocol_version,
                 correlation_id,
-                finalization_tc,
-                Phase::FinalizePayment,
+                Rc::clone(tracking_copy),
+                phase,
                 protocol_data,
                 system_contract_cache,
-            )
+            ) {
+                Ok((_instance, runtime)) => runtime,
+                Err(error) => return Err(Error::Exec(error)),
+            };
+
+            let effects_snapshot = tracking_copy.borrow().effect();
+            let payment_result = match runtime.call_host_standard_payment() {
+                Ok(()) => ExecutionResult::Success {
+                    effect: runtime.context().effect(),
+                    cost: runtime.context().gas_counter(),
+                },
+                Err(error) => ExecutionResult::Failure {
+                    error: error.into(),
+                    effect: effects_snapshot,
+                    cost: runtime.context().gas_counter(),
+                },
+            };
+            Ok(payment_result)
+        } else {
+            Ok(executor.exec(
+                payment_module,
+                payment.take_args(),
+                Key::Account(account.public_key()),
+                account,
+                authorization_keys,
+                blocktime,
+                deploy_hash,
+                pay_gas_limit,
+                protocol_version,
+                correlation_id,
+                Rc::clone(tracking_copy),
+                phase,
+                protocol_data,
+                system_contract_cache,
+            ))
+        }
+    }
+
+    /// Runs the proof of stake `finalize_payment` call which charges `account_addr` `cost` motes
+    /// for a deploy, as the system account and on a fork of `tracking_copy`.
+    ///
+    /// Returns an error if the proof of stake contract can't be loaded.
+    #[allow(clippy::too_many_arguments)]
+    fn finalize_payment<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        tracking_copy: &TrackingCopy<R>,
+        account_addr: PublicKey,
+        cost: Motes,
+        authorization_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+    ) -> Result<ExecutionResult, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let finalization_tc = Rc::new(RefCell::new(tracking_copy.fork()));
+        let proof_of_stake_reference = protocol_data.proof_of_stake();
+
+        // The PoS keys may have changed because of effects during payment and/or
+        // session, so we need to look them up again from the tracking copy
+        let proof_of_stake_contract = finalization_tc
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(proof_of_stake_reference))?;
+
+        // validation_spec_1: valid wasm bytes
+        let proof_of_stake_module = match self.system_contract_cache.get(&proof_of_stake_reference)
+        {
+            Some(module) => module,
+            None => {
+                let module = engine_wasm_prep::deserialize(proof_of_stake_contract.bytes())?;
+                self.system_contract_cache
+                    .insert(proof_of_stake_reference, module.clone());
+                module
+            }
         };
 
-        execution_result_builder.set_finalize_execution_result(finalize_result);
+        let proof_of_stake_args =
+            ArgsParser::parse(("finalize_payment", cost.value(), account_addr))
+                .expect("args should convert to `Vec<CLValue>`")
+                .into_bytes()
+                .expect("args should serialize");
 
-        // We panic here to indicate that the builder was not used properly.
-        let ret = execution_result_builder
-            .build(tracking_copy.borrow().reader(), correlation_id)
-            .expect("ExecutionResultBuilder not initialized properly");
+        let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
 
-        // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
-        // payment_code_spec_6: return properly combined set of transforms and
-        // appropriate error
-        Ok(ret)
+        let base_key = Key::from(proof_of_stake_reference);
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        // Finalization is executed by system account (currently genesis account)
+        // payment_code_spec_5: system executes finalization
+        Ok(executor.exec_finalize(
+            proof_of_stake_module,
+            proof_of_stake_args,
+            &mut proof_of_stake_keys,
+            base_key,
+            &system_account(),
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            finalization_tc,
+            Phase::FinalizePayment,
+            protocol_data,
+            system_contract_cache,
+        ))
+    }
+
+    /// Estimates the gas used by the payment and session code of the deploy in
+    /// `estimate_request`.
+    ///
+    /// The payment code is run as it would be for the deploy, limited by the protocol version's
+    /// maximum payment, and the session is then only limited by its block gas limit.  The effects
+    /// of both and of the following `finalize_payment` call are discarded, so an estimate can
+    /// never be committed and no funds are taken from the deployer.
+    pub fn run_estimate(
+        &self,
+        correlation_id: CorrelationId,
+        estimate_request: EstimateRequest,
+    ) -> Result<EstimateResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let tracking_copy = match self.tracking_copy(estimate_request.parent_state_hash())? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(EstimateResult::RootNotFound),
+        };
+
+        let protocol_version = estimate_request.protocol_version();
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => {
+                return Ok(EstimateResult::Failure(
+                    ExecutionResult::precondition_failure(Error::InvalidProtocolVersion(
+                        protocol_version,
+                    )),
+                ))
+            }
+        };
+
+        let blocktime = estimate_request.block_time();
+        let deploy_item = estimate_request.take_deploy_item();
+        let session = deploy_item.session;
+        let payment = deploy_item.payment;
+        let account_addr = deploy_item.address;
+        let authorization_keys = deploy_item.authorization_keys;
+        let deploy_hash = deploy_item.deploy_hash;
+        let gas_price = deploy_item.gas_price;
+
+        let account = match self.get_authorized_account(
+            correlation_id,
+            &tracking_copy,
+            account_addr,
+            &authorization_keys,
+        ) {
+            Ok(account) => account,
+            Err(error) => {
+                return Ok(EstimateResult::Failure(
+                    ExecutionResult::precondition_failure(error),
+                ))
+            }
+        };
+
+        let min_gas_price = protocol_data.min_gas_price();
+        if gas_price < min_gas_price {
+            return Ok(EstimateResult::Failure(
+                ExecutionResult::precondition_failure(Error::GasPriceTooLow {
+                    actual: gas_price,
+                    minimum: min_gas_price,
+                }),
+            ));
+        }
+
+        let executor = Executor::new(self.config, ModuleCache::clone(&self.module_cache));
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
+
+        let session_module = match self.get_module(
+            Rc::clone(&tracking_copy),
+            &session,
+            &account,
+            correlation_id,
+            &preprocessor,
+            &protocol_version,
+        ) {
+            Ok(module) => module,
+            Err(error) => {
+                return Ok(EstimateResult::Failure(
+                    ExecutionResult::precondition_failure(error),
+                ))
+            }
+        };
+
+        let max_payment_cost = Motes::new(U512::from(protocol_data.max_payment()));
+        let payment_result = match self.run_payment(
+            correlation_id,
+            &executor,
+            &preprocessor,
+            protocol_version,
+            protocol_data,
+            &tracking_copy,
+            &account,
+            payment,
+            authorization_keys.clone(),
+            blocktime,
+            deploy_hash,
+            Gas::from_motes(max_payment_cost, gas_price).unwrap_or_default(),
+        ) {
+            Ok(payment_result) => payment_result,
+            Err(error) => {
+                return Ok(EstimateResult::Failure(
+                    ExecutionResult::precondition_failure(error),
+                ))
+            }
+        };
+        if payment_result.is_failure() {
+            return Ok(EstimateResult::Failure(payment_result.without_effect()));
+        }
+        let payment_cost = payment_result.cost();
+
+        let session_tc = Rc::new(RefCell::new(tracking_copy.borrow().fork()));
+        let session_result = executor.exec(
+            session_module,
+            session.take_args(),
+            Key::Account(account_addr),
+            &account,
+            authorization_keys.clone(),
+            blocktime,
+            deploy_hash,
+            Gas::new(U512::from(protocol_data.block_gas_limit())),
+            protocol_version,
+            correlation_id,
+            Rc::clone(&session_tc),
+            Phase::Session,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+        );
+        if session_result.is_failure() {
+            return Ok(EstimateResult::Failure(session_result.without_effect()));
+        }
+        let session_cost = session_result.cost();
+
+        let motes = match Motes::from_gas(payment_cost + session_cost, gas_price) {
+            Some(motes) => motes,
+            None => {
+                return Ok(EstimateResult::Failure(
+                    ExecutionResult::precondition_failure(Error::InsufficientPayment),
+                ))
+            }
+        };
+
+        let finalize_result = match self.finalize_payment(
+            correlation_id,
+            &executor,
+            protocol_version,
+            protocol_data,
+            &session_tc.borrow(),
+            account_addr,
+            motes,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+        ) {
+            Ok(finalize_result) => finalize_result,
+            Err(error) => {
+                return Ok(EstimateResult::Failure(
+                    ExecutionResult::precondition_failure(error),
+                ))
+            }
+        };
+        if finalize_result.is_failure() {
+            return Ok(EstimateResult::Failure(finalize_result.without_effect()));
+        }
+
+        Ok(EstimateResult::Success {
+            payment_cost,
+            session_cost,
+            finalize_cost: finalize_result.cost(),
+            motes,
+        })
     }
 
     pub fn apply_effect(
@@ -1499,7 +2389,8 @@ where
         }
     }
 
-    /// Calculates bonded validators at `root_hash` state.
+    /// Calculates bonded validators at `root_hash` state, decoding the stakes from the named keys
+    /// of the PoS contract just as [`EngineState::get_bid_state`] does.
     ///
     /// Should only be called with a valid root hash after a successful call to
     /// [`StateProvider::commit`]. Will panic if called with an invalid root hash.
@@ -1532,12 +2423,52 @@ where
             _ => return Err(MissingSystemContract("proof of stake".to_string())),
         };
 
-        let bonded_validators = contract
-            .named_keys()
-            .keys()
-            .filter_map(|entry| utils::pos_validator_key_name_to_tuple(entry))
-            .collect::<HashMap<PublicKey, U512>>();
+        let stakes = utils::pos_named_keys_to_stakes(contract.named_keys());
 
-        Ok(bonded_validators)
+        Ok(stakes.iter().map(|(key, weight)| (*key, *weight)).collect())
     }
 }
+
+/// Returns the account which runs system calls such as the finalization of deploys.  It has no
+/// associated keys, so it can't authorize anything itself.
+fn system_account() -> Account {
+    Account::new(
+        SYSTEM_ACCOUNT_ADDR,
+        Default::default(),
+        URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
+        Default::default(),
+        Default::default(),
+    )
+}
+
+/// Returns a result for each of `deploy_count` deploys, none of which can be run: a precondition
+/// failure with `error` for the first one and a generic one for the rest.
+fn precondition_failures(error: Error, deploy_count: usize) -> Vec<ExecutionResult> {
+    let mut results = Vec::with_capacity(deploy_count);
+    results.push(ExecutionResult::precondition_failure(error));
+    results.resize_with(deploy_count, || {
+        ExecutionResult::precondition_failure(Error::Deploy)
+    });
+    results
+}
+
+/// Applies the effect of `result` to `tracking_copy`, returning `result`.  If the effect can't be
+/// applied, the deploy is left out of the sequence and a precondition failure is returned instead.
+fn apply_result<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    result: ExecutionResult,
+) -> ExecutionResult
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let error = match tracking_copy.apply_effect(correlation_id, result.effect().clone()) {
+        Ok(AddResult::Success) => return result,
+        Ok(AddResult::KeyNotFound(key)) => execution::Error::KeyNotFound(key),
+        Ok(AddResult::TypeMismatch(type_mismatch)) => execution::Error::TypeMismatch(type_mismatch),
+        Ok(AddResult::Serialization(error)) => execution::Error::BytesRepr(error),
+        Err(error) => error.into(),
+    };
+    ExecutionResult::precondition_failure(Error::Exec(error))
+}
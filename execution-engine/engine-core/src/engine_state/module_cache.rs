@@ -0,0 +1,351 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use linked_hash_map::LinkedHashMap;
+use parity_wasm::elements::Module;
+
+use engine_shared::{
+    logging::log_metric,
+    newtypes::{Blake2bHash, CorrelationId},
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::{
+    bytesrepr::{self, ToBytes},
+    ProtocolVersion,
+};
+
+use crate::engine_state::error::Error;
+
+/// The number of stored contract modules kept in memory by [`ModuleCache::default`].
+pub const DEFAULT_MODULE_CACHE_CAPACITY: usize = 256;
+
+const MODULE_CACHE_HITS: &str = "module_cache_hits";
+const MODULE_CACHE_DISK_HITS: &str = "module_cache_disk_hits";
+const MODULE_CACHE_MISSES: &str = "module_cache_misses";
+const GET_MODULE: &str = "get_module";
+const GAUGE_METRIC_KEY: &str = "gauge";
+
+const MODULE_FILE_EXTENSION: &str = "wasm";
+const TEMPORARY_FILE_EXTENSION: &str = "tmp";
+
+/// Identifies the module of a stored contract by the contract's bytes, and by the costs and
+/// protocol version it is run under, so that modules cached before an upgrade are never used after
+/// it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ModuleCacheKey {
+    hash: Blake2bHash,
+}
+
+impl ModuleCacheKey {
+    /// Returns the key of the module deserialized from the bytes of a stored contract, which were
+    /// preprocessed when it was stored.
+    pub fn new(
+        contract_bytes: &[u8],
+        wasm_costs: &WasmCosts,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Self, bytesrepr::Error> {
+        let mut bytes = Blake2bHash::new(contract_bytes).value().to_vec();
+        bytes.append(&mut wasm_costs.to_bytes()?);
+        bytes.append(&mut protocol_version.to_bytes()?);
+        Ok(ModuleCacheKey {
+            hash: Blake2bHash::new(&bytes),
+        })
+    }
+
+    fn file_name(&self) -> String {
+        format!("{:x}.{}", self.hash, MODULE_FILE_EXTENSION)
+    }
+}
+
+#[derive(Debug)]
+struct ModuleCacheInner {
+    capacity: usize,
+    modules: LinkedHashMap<ModuleCacheKey, Module>,
+    hits: u64,
+    disk_hits: u64,
+    misses: u64,
+}
+
+/// A bounded cache of the modules of stored contracts, which evicts the least recently used one
+/// when full.
+///
+/// Session and payment code sent with deploys is not cached, as most of it is run once and would
+/// only push out the contracts which are called again and again.  If the cache has a directory, the
+/// modules are also written there, so that they survive restarts.  It holds at most one file per
+/// stored contract and set of wasm costs, and is emptied when an upgrade changes the costs.  The
+/// files there are trusted to hold modules which were instrumented with gas metering, so the
+/// directory must not be writable by anyone else.
+#[derive(Clone, Debug)]
+pub struct ModuleCache {
+    inner: Arc<Mutex<ModuleCacheInner>>,
+    directory: Option<PathBuf>,
+}
+
+impl ModuleCache {
+    /// Creates a cache which keeps up to `capacity` modules in memory, and in `directory` if given.
+    ///
+    /// A capacity of zero keeps none in memory.
+    pub fn new(capacity: usize, directory: Option<PathBuf>) -> Self {
+        let inner = ModuleCacheInner {
+            capacity,
+            modules: LinkedHashMap::new(),
+            hits: 0,
+            disk_hits: 0,
+            misses: 0,
+        };
+        ModuleCache {
+            inner: Arc::new(Mutex::new(inner)),
+            directory,
+        }
+    }
+
+    /// Returns a clone of the module under `key`, calling `make_module` to make it if it isn't
+    /// cached.
+    pub fn get_or_insert_with<F, E>(
+        &self,
+        correlation_id: CorrelationId,
+        key: ModuleCacheKey,
+        make_module: F,
+    ) -> Result<Module, E>
+    where
+        F: FnOnce() -> Result<Module, E>,
+    {
+        if let Some(module) = self.get_from_memory(correlation_id, &key) {
+            return Ok(module);
+        }
+
+        if let Some(module) = self.read_from_disk(&key) {
+            let disk_hits = {
+                let mut inner = self.inner.lock().unwrap();
+                inner.disk_hits += 1;
+                inner.insert(key, module.clone());
+                inner.disk_hits
+            };
+            log_gauge(correlation_id, MODULE_CACHE_DISK_HITS, disk_hits);
+            return Ok(module);
+        }
+
+        let module = make_module()?;
+        if let Err(error) = self.write_to_disk(&key, &module) {
+            log::warn!("Could not write module to cache: {:?}", error);
+        }
+        let misses = {
+            let mut inner = self.inner.lock().unwrap();
+            inner.misses += 1;
+            inner.insert(key, module.clone());
+            inner.misses
+        };
+        log_gauge(correlation_id, MODULE_CACHE_MISSES, misses);
+        Ok(module)
+    }
+
+    /// Removes every cached module, from memory and from the directory.
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().modules.clear();
+        if let Err(error) = self.clear_directory() {
+            log::warn!("Could not clear module cache directory: {:?}", error);
+        }
+    }
+
+    /// Returns the number of modules held in memory.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().modules.len()
+    }
+
+    /// Returns `true` if no modules are held in memory.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get_from_memory(
+        &self,
+        correlation_id: CorrelationId,
+        key: &ModuleCacheKey,
+    ) -> Option<Module> {
+        let (module, hits) = {
+            let mut inner = self.inner.lock().unwrap();
+            let module = inner.modules.get_refresh(key)?.clone();
+            inner.hits += 1;
+            (module, inner.hits)
+        };
+        log_gauge(correlation_id, MODULE_CACHE_HITS, hits);
+        Some(module)
+    }
+
+    /// Returns the module written under `key`, if there is a readable one.
+    fn read_from_disk(&self, key: &ModuleCacheKey) -> Option<Module> {
+        let path = self.directory.as_ref()?.join(key.file_name());
+        let bytes = fs::read(path).ok()?;
+        parity_wasm::deserialize_buffer(&bytes).ok()
+    }
+
+    fn write_to_disk(&self, key: &ModuleCacheKey, module: &Module) -> Result<(), Error> {
+        let directory = match &self.directory {
+            Some(directory) => directory,
+            None => return Ok(()),
+        };
+        let bytes = parity_wasm::serialize(module.clone())?;
+        // Written in full before being moved into place, so that a partly written module is never
+        // read
+        let path = directory.join(key.file_name());
+        let temporary_path = path.with_extension(TEMPORARY_FILE_EXTENSION);
+        fs::write(&temporary_path, bytes)
+            .and_then(|_| fs::rename(&temporary_path, &path))
+            .map_err(|error| Error::ModuleCache(error.to_string()))
+    }
+
+    fn clear_directory(&self) -> io::Result<()> {
+        let directory = match &self.directory {
+            Some(directory) => directory,
+            None => return Ok(()),
+        };
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.extension().map_or(false, |extension| {
+                extension == MODULE_FILE_EXTENSION || extension == TEMPORARY_FILE_EXTENSION
+            }) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ModuleCache {
+    fn default() -> Self {
+        ModuleCache::new(DEFAULT_MODULE_CACHE_CAPACITY, None)
+    }
+}
+
+impl ModuleCacheInner {
+    fn insert(&mut self, key: ModuleCacheKey, module: Module) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.modules.insert(key, module);
+        while self.modules.len() > self.capacity {
+            self.modules.pop_front();
+        }
+    }
+}
+
+fn log_gauge(correlation_id: CorrelationId, metric: &str, value: u64) {
+    log_metric(
+        correlation_id,
+        metric,
+        GET_MODULE,
+        GAUGE_METRIC_KEY,
+        value as f64,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use parity_wasm::{builder::ModuleBuilder, elements::Module};
+    use tempfile::tempdir;
+
+    use engine_shared::newtypes::CorrelationId;
+    use engine_wasm_prep::wasm_costs::WasmCosts;
+    use types::ProtocolVersion;
+
+    use super::{ModuleCache, ModuleCacheKey};
+    use crate::engine_state::error::Error;
+
+    fn key(bytes: &[u8]) -> ModuleCacheKey {
+        ModuleCacheKey::new(bytes, &WasmCosts::default(), ProtocolVersion::V1_0_0).unwrap()
+    }
+
+    fn module(memory_pages: u32) -> Module {
+        ModuleBuilder::new()
+            .memory()
+            .with_min(memory_pages)
+            .build()
+            .build()
+    }
+
+    /// Gets the module under `key` from `cache`, returning it and whether it had to be made.
+    fn get(cache: &ModuleCache, key: ModuleCacheKey, memory_pages: u32) -> (Module, bool) {
+        let made = Cell::new(false);
+        let module = cache
+            .get_or_insert_with(CorrelationId::new(), key, || {
+                made.set(true);
+                Ok(module(memory_pages))
+            })
+            .unwrap();
+        (module, made.get())
+    }
+
+    #[test]
+    fn should_make_module_only_once() {
+        let cache = ModuleCache::new(2, None);
+
+        assert_eq!(get(&cache, key(b"a"), 1), (module(1), true));
+        assert_eq!(get(&cache, key(b"a"), 2), (module(1), false));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn should_evict_least_recently_used_module() {
+        let cache = ModuleCache::new(2, None);
+        get(&cache, key(b"a"), 1);
+        get(&cache, key(b"b"), 2);
+        // makes "b" the least recently used
+        get(&cache, key(b"a"), 1);
+        get(&cache, key(b"c"), 3);
+
+        assert_eq!(cache.len(), 2);
+        assert!(!get(&cache, key(b"a"), 1).1);
+        assert!(get(&cache, key(b"b"), 2).1);
+    }
+
+    #[test]
+    fn should_not_cache_error() {
+        let cache = ModuleCache::new(2, None);
+        let result = cache.get_or_insert_with(CorrelationId::new(), key(b"a"), || {
+            Err(Error::ModuleCache("failed".to_string()))
+        });
+
+        assert!(result.is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn should_key_by_costs_and_protocol_version() {
+        let other_costs = WasmCosts {
+            regular: 1,
+            ..WasmCosts::default()
+        };
+        let other_version = ProtocolVersion::from_parts(2, 0, 0);
+        let original_key = key(b"a");
+
+        assert_ne!(
+            original_key,
+            ModuleCacheKey::new(b"a", &other_costs, ProtocolVersion::V1_0_0).unwrap()
+        );
+        assert_ne!(
+            original_key,
+            ModuleCacheKey::new(b"a", &WasmCosts::default(), other_version).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_read_module_written_by_another_cache() {
+        let temp_dir = tempdir().unwrap();
+        let directory = Some(temp_dir.path().to_path_buf());
+        let cache = ModuleCache::new(2, directory.clone());
+        get(&cache, key(b"a"), 1);
+
+        let restarted_cache = ModuleCache::new(2, directory.clone());
+        assert_eq!(get(&restarted_cache, key(b"a"), 2), (module(1), false));
+
+        restarted_cache.clear();
+        assert!(restarted_cache.is_empty());
+        let cleared_cache = ModuleCache::new(2, directory);
+        assert_eq!(get(&cleared_cache, key(b"a"), 2), (module(2), true));
+    }
+}
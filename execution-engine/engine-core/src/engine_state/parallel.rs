@@ -0,0 +1,250 @@
+//! Support for running the deploys of a block concurrently.
+//!
+//! Each deploy is first run on its own thread against the parent state of the block, while the
+//! values it observes there are recorded.  The results are then merged in order: a result is kept
+//! if none of the values its deploy observed have been changed by the deploys before it, and
+//! otherwise the deploy is run again on top of them, just as sequential execution would.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use engine_shared::{newtypes::CorrelationId, stored_value::StoredValue, transform::Transform};
+use engine_storage::{global_state::StateReader, trie::merkle_proof::TrieMerkleProof};
+use types::Key;
+
+use crate::{
+    engine_state::{execution_result::ExecutionResult, op::Op},
+    tracking_copy::TrackingCopy,
+};
+
+/// The values observed in global state while running a deploy, where `None` means the key had
+/// no value.
+pub type Reads = BTreeMap<Key, Option<StoredValue>>;
+
+/// A [`StateReader`] which records the first value read under each key.
+pub struct RecordingReader<R> {
+    reader: R,
+    reads: RefCell<Reads>,
+}
+
+impl<R> RecordingReader<R> {
+    pub fn new(reader: R) -> Self {
+        RecordingReader {
+            reader,
+            reads: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the values read so far, and clears them.
+    pub fn take_reads(&self) -> Reads {
+        self.reads.replace(BTreeMap::new())
+    }
+}
+
+impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for RecordingReader<R> {
+    type Error = R::Error;
+
+    fn read(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<StoredValue>, Self::Error> {
+        let maybe_value = self.reader.read(correlation_id, key)?;
+        self.reads
+            .borrow_mut()
+            .entry(*key)
+            .or_insert_with(|| maybe_value.clone());
+        Ok(maybe_value)
+    }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<TrieMerkleProof<Key, StoredValue>, Self::Error> {
+        self.reader.read_with_proof(correlation_id, key)
+    }
+}
+
+/// Returns `true` if `result`, obtained by running a deploy which observed `reads`, is also the
+/// result of running that deploy on top of `tracking_copy`, and its effect can be applied there.
+///
+/// A value which can't be read counts as changed, so that the deploy is run again and reports the
+/// error itself.
+pub fn is_valid_on<R: StateReader<Key, StoredValue>>(
+    correlation_id: CorrelationId,
+    result: &ExecutionResult,
+    reads: &Reads,
+    tracking_copy: &mut TrackingCopy<R>,
+) -> bool {
+    reads.iter().all(|(key, observed)| {
+        let current = match tracking_copy.get(correlation_id, key) {
+            Ok(current) => current,
+            Err(_) => return false,
+        };
+        match commuting_add(result, key) {
+            Some(transform) => {
+                current.map_or(false, |value| transform.clone().apply(value).is_ok())
+            }
+            None => current == *observed,
+        }
+    })
+}
+
+/// Returns the transform of `result` under `key` if all the deploy did with the value there was add
+/// a number to it, in which case the addition has the same effect whatever the value was.
+///
+/// The effect of a failed deploy doesn't include that of its session code, which may have read the
+/// value, so only successful deploys are considered.  Neither do its ops include the reads of a
+/// reverted contract call, which are checked for separately.
+fn commuting_add<'a>(result: &'a ExecutionResult, key: &Key) -> Option<&'a Transform> {
+    if !result.is_success() {
+        return None;
+    }
+    let effect = result.effect();
+    if effect.discarded_reads.contains(key) {
+        return None;
+    }
+    match (effect.ops.get(key), effect.transforms.get(key)) {
+        (Some(Op::Add), Some(transform @ Transform::AddInt32(_)))
+        | (Some(Op::Add), Some(transform @ Transform::AddUInt64(_)))
+        | (Some(Op::Add), Some(transform @ Transform::AddUInt128(_)))
+        | (Some(Op::Add), Some(transform @ Transform::AddUInt256(_)))
+        | (Some(Op::Add), Some(transform @ Transform::AddUInt512(_))) => Some(transform),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::{
+        additive_map::AdditiveMap,
+        gas::Gas,
+        newtypes::{Blake2bHash, CorrelationId},
+        stored_value::StoredValue,
+        transform::Transform,
+    };
+    use engine_storage::global_state::{
+        in_memory::InMemoryGlobalState, StateProvider, StateReader,
+    };
+    use types::{CLValue, Key, U512};
+
+    use super::{is_valid_on, RecordingReader};
+    use crate::{
+        engine_state::{
+            execution_effect::ExecutionEffect, execution_result::ExecutionResult, op::Op,
+        },
+        tracking_copy::TrackingCopy,
+    };
+
+    const KEY_1: Key = Key::Hash([1; 32]);
+    const KEY_2: Key = Key::Hash([2; 32]);
+
+    fn u512_value(value: u64) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(U512::from(value)).unwrap())
+    }
+
+    fn success(op: Op, transform: Transform) -> ExecutionResult {
+        let mut ops = AdditiveMap::new();
+        let mut transforms = AdditiveMap::new();
+        ops.insert(KEY_1, op);
+        transforms.insert(KEY_1, transform);
+        ExecutionResult::Success {
+            effect: ExecutionEffect::new(ops, transforms),
+            cost: Gas::default(),
+        }
+    }
+
+    fn global_state() -> (InMemoryGlobalState, Blake2bHash) {
+        InMemoryGlobalState::from_pairs(CorrelationId::new(), &[(KEY_1, u512_value(1))]).unwrap()
+    }
+
+    #[test]
+    fn should_record_first_value_read() {
+        let correlation_id = CorrelationId::new();
+        let (global_state, root_hash) = global_state();
+        let reader = RecordingReader::new(global_state.checkout(root_hash).unwrap().unwrap());
+
+        reader.read(correlation_id, &KEY_1).unwrap();
+        reader.read(correlation_id, &KEY_2).unwrap();
+        reader.read(correlation_id, &KEY_1).unwrap();
+
+        let reads = reader.take_reads();
+        assert_eq!(reads.len(), 2);
+        assert_eq!(reads[&KEY_1], Some(u512_value(1)));
+        assert_eq!(reads[&KEY_2], None);
+        assert!(reader.take_reads().is_empty());
+    }
+
+    #[test]
+    fn should_invalidate_result_when_read_value_changed() {
+        let correlation_id = CorrelationId::new();
+        let (global_state, root_hash) = global_state();
+        let mut tracking_copy =
+            TrackingCopy::new(global_state.checkout(root_hash).unwrap().unwrap());
+        let result = success(Op::Write, Transform::Write(u512_value(2)));
+        let reads = vec![(KEY_1, Some(u512_value(1))), (KEY_2, None)]
+            .into_iter()
+            .collect();
+
+        assert!(is_valid_on(
+            correlation_id,
+            &result,
+            &reads,
+            &mut tracking_copy
+        ));
+
+        tracking_copy.write(KEY_2, u512_value(3));
+        assert!(!is_valid_on(
+            correlation_id,
+            &result,
+            &reads,
+            &mut tracking_copy
+        ));
+    }
+
+    #[test]
+    fn should_keep_result_of_commuting_add() {
+        let correlation_id = CorrelationId::new();
+        let (global_state, root_hash) = global_state();
+        let mut tracking_copy =
+            TrackingCopy::new(global_state.checkout(root_hash).unwrap().unwrap());
+        let add = success(Op::Add, Transform::AddUInt512(U512::one()));
+        let read_and_add = success(Op::Write, Transform::AddUInt512(U512::one()));
+        let reads = vec![(KEY_1, Some(u512_value(1)))].into_iter().collect();
+
+        tracking_copy.write(KEY_1, u512_value(5));
+        assert!(is_valid_on(
+            correlation_id,
+            &add,
+            &reads,
+            &mut tracking_copy
+        ));
+        assert!(!is_valid_on(
+            correlation_id,
+            &read_and_add,
+            &reads,
+            &mut tracking_copy
+        ));
+
+        // the addition no longer commutes once a discarded change has read the value
+        let mut add_after_discarded_read = success(Op::Add, Transform::AddUInt512(U512::one()));
+        if let ExecutionResult::Success { effect, .. } = &mut add_after_discarded_read {
+            effect.discarded_reads.insert(KEY_1);
+        }
+        assert!(!is_valid_on(
+            correlation_id,
+            &add_after_discarded_read,
+            &reads,
+            &mut tracking_copy
+        ));
+
+        // the addition no longer applies once the value is gone
+        tracking_copy.delete(KEY_1);
+        assert!(!is_valid_on(
+            correlation_id,
+            &add,
+            &reads,
+            &mut tracking_copy
+        ));
+    }
+}
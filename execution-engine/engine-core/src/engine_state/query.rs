@@ -1,4 +1,5 @@
 use engine_shared::{newtypes::Blake2bHash, stored_value::StoredValue};
+use engine_storage::trie::merkle_proof::TrieMerkleProof;
 use types::Key;
 
 use crate::tracking_copy::TrackingCopyQueryResult;
@@ -10,6 +11,36 @@ pub enum QueryResult {
     Success(StoredValue),
 }
 
+/// The result of a query, along with proofs of every key visited by it.  The last proof is either a
+/// proof of the inclusion of the queried value, or a proof of the exclusion of the key which
+/// couldn't be found.
+pub struct QueryWithProofResult {
+    result: QueryResult,
+    proofs: Vec<TrieMerkleProof<Key, StoredValue>>,
+}
+
+impl QueryWithProofResult {
+    pub fn new(result: QueryResult, proofs: Vec<TrieMerkleProof<Key, StoredValue>>) -> Self {
+        QueryWithProofResult { result, proofs }
+    }
+
+    pub fn root_not_found() -> Self {
+        QueryWithProofResult::new(QueryResult::RootNotFound, Vec::new())
+    }
+
+    pub fn result(&self) -> &QueryResult {
+        &self.result
+    }
+
+    pub fn proofs(&self) -> &[TrieMerkleProof<Key, StoredValue>] {
+        &self.proofs
+    }
+
+    pub fn take(self) -> (QueryResult, Vec<TrieMerkleProof<Key, StoredValue>>) {
+        (self.result, self.proofs)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryRequest {
     state_hash: Blake2bHash,
@@ -0,0 +1,39 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    slashes: Vec<(PublicKey, U512)>,
+}
+
+impl SlashRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        slashes: Vec<(PublicKey, U512)>,
+    ) -> Self {
+        SlashRequest {
+            parent_state_hash,
+            protocol_version,
+            slashes,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn slashes(&self) -> &[(PublicKey, U512)] {
+        &self.slashes
+    }
+
+    pub fn take_slashes(&mut self) -> Vec<(PublicKey, U512)> {
+        std::mem::replace(&mut self.slashes, Vec::new())
+    }
+}
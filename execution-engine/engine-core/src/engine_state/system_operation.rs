@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use failure::Fail;
+
+use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
+use engine_storage::global_state::CommitResult;
+use types::{
+    account::PublicKey, bytesrepr, system_contract_errors::pos, ApiError, Key, ProtocolVersion,
+    U512,
+};
+
+use crate::execution;
+
+/// An error from a proof of stake operation run by the system account, such as distributing
+/// rewards, slashing or paying out unbonds.
+#[derive(Fail, Debug)]
+pub enum SystemOperationError {
+    #[fail(display = "Invalid protocol version: {}", _0)]
+    InvalidProtocolVersion(ProtocolVersion),
+    #[fail(display = "Insufficient balance in rewards purse")]
+    InsufficientRewards,
+    #[fail(display = "Validator is not bonded")]
+    NotBonded,
+    #[fail(display = "Era height is lower than the current era height")]
+    EraWentBackwards,
+    #[fail(display = "Failed to transfer motes out of a proof of stake purse")]
+    TransferFailed,
+    #[fail(display = "Execution error: {}", _0)]
+    Exec(execution::Error),
+    #[fail(display = "Key not found: {}", _0)]
+    KeyNotFound(Key),
+    #[fail(display = "Type mismatch: {:?}", _0)]
+    TypeMismatch(TypeMismatch),
+    #[fail(display = "Serialization error: {:?}", _0)]
+    Serialization(bytesrepr::Error),
+}
+
+impl From<execution::Error> for SystemOperationError {
+    fn from(error: execution::Error) -> Self {
+        let status = match error {
+            execution::Error::Revert(status) => status,
+            error => return SystemOperationError::Exec(error),
+        };
+        match pos_error(status) {
+            Some(pos::Error::InsufficientRewardsForDistribution) => {
+                SystemOperationError::InsufficientRewards
+            }
+            Some(pos::Error::NotBonded) => SystemOperationError::NotBonded,
+            Some(pos::Error::EraWentBackwards) => SystemOperationError::EraWentBackwards,
+            Some(pos::Error::FailedTransferToValidatorPurse)
+            | Some(pos::Error::SlashTransferFailed)
+            | Some(pos::Error::UnbondTransferFailed) => SystemOperationError::TransferFailed,
+            _ => SystemOperationError::Exec(execution::Error::Revert(status)),
+        }
+    }
+}
+
+/// Returns the proof of stake error which the contract reverted with as `status`, if any.
+fn pos_error(status: u32) -> Option<pos::Error> {
+    [
+        pos::Error::InsufficientRewardsForDistribution,
+        pos::Error::NotBonded,
+        pos::Error::EraWentBackwards,
+        pos::Error::FailedTransferToValidatorPurse,
+        pos::Error::SlashTransferFailed,
+        pos::Error::UnbondTransferFailed,
+    ]
+    .iter()
+    .copied()
+    .find(|error| u32::from(ApiError::from(*error)) == status)
+}
+
+pub enum SystemOperationResult {
+    RootNotFound,
+    Failure(SystemOperationError),
+    Success {
+        post_state_hash: Blake2bHash,
+        bonded_validators: HashMap<PublicKey, U512>,
+    },
+}
+
+impl SystemOperationResult {
+    pub fn from_commit_result(commit_result: CommitResult) -> Self {
+        match commit_result {
+            CommitResult::RootNotFound => SystemOperationResult::RootNotFound,
+            CommitResult::KeyNotFound(key) => {
+                SystemOperationResult::Failure(SystemOperationError::KeyNotFound(key))
+            }
+            CommitResult::TypeMismatch(type_mismatch) => {
+                SystemOperationResult::Failure(SystemOperationError::TypeMismatch(type_mismatch))
+            }
+            CommitResult::Serialization(error) => {
+                SystemOperationResult::Failure(SystemOperationError::Serialization(error))
+            }
+            CommitResult::Success {
+                state_root,
+                bonded_validators,
+            } => SystemOperationResult::Success {
+                post_state_hash: state_root,
+                bonded_validators,
+            },
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::ProtocolVersion;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnbondPayoutRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    era_height: u64,
+}
+
+impl UnbondPayoutRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        era_height: u64,
+    ) -> Self {
+        UnbondPayoutRequest {
+            parent_state_hash,
+            protocol_version,
+            era_height,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn era_height(&self) -> u64 {
+        self.era_height
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{host_function_costs::HostFunctionCosts, newtypes::Blake2bHash, TypeMismatch};
 use engine_storage::global_state::CommitResult;
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::{bytesrepr, Key, ProtocolVersion};
@@ -60,10 +60,17 @@ pub struct UpgradeConfig {
     upgrade_installer_args: Option<Vec<u8>>,
     upgrade_installer_bytes: Option<Vec<u8>>,
     wasm_costs: Option<WasmCosts>,
+    host_function_costs: Option<HostFunctionCosts>,
+    min_gas_price: Option<u64>,
+    max_payment: Option<u64>,
+    block_gas_limit: Option<u64>,
+    bonding_delay: Option<u64>,
+    unbonding_delay: Option<u64>,
     activation_point: Option<ActivationPoint>,
 }
 
 impl UpgradeConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pre_state_hash: Blake2bHash,
         current_protocol_version: ProtocolVersion,
@@ -71,6 +78,12 @@ impl UpgradeConfig {
         upgrade_installer_args: Option<Vec<u8>>,
         upgrade_installer_bytes: Option<Vec<u8>>,
         wasm_costs: Option<WasmCosts>,
+        host_function_costs: Option<HostFunctionCosts>,
+        min_gas_price: Option<u64>,
+        max_payment: Option<u64>,
+        block_gas_limit: Option<u64>,
+        bonding_delay: Option<u64>,
+        unbonding_delay: Option<u64>,
         activation_point: Option<ActivationPoint>,
     ) -> Self {
         UpgradeConfig {
@@ -80,6 +93,12 @@ impl UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            max_payment,
+            block_gas_limit,
+            bonding_delay,
+            unbonding_delay,
             activation_point,
         }
     }
@@ -110,6 +129,30 @@ impl UpgradeConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> Option<HostFunctionCosts> {
+        self.host_function_costs
+    }
+
+    pub fn min_gas_price(&self) -> Option<u64> {
+        self.min_gas_price
+    }
+
+    pub fn max_payment(&self) -> Option<u64> {
+        self.max_payment
+    }
+
+    pub fn block_gas_limit(&self) -> Option<u64> {
+        self.block_gas_limit
+    }
+
+    pub fn bonding_delay(&self) -> Option<u64> {
+        self.bonding_delay
+    }
+
+    pub fn unbonding_delay(&self) -> Option<u64> {
+        self.unbonding_delay
+    }
+
     pub fn activation_point(&self) -> Option<u64> {
         self.activation_point
     }
@@ -1,4 +1,17 @@
-use types::{account::PublicKey, U512};
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use engine_shared::newtypes::Blake2bHash;
+use proof_of_stake::Stakes;
+use types::{account::PublicKey, Key, BLAKE2B_DIGEST_LENGTH, U512};
+
+/// Returns 32 bytes identifying `public_key`.  These are the raw bytes of an Ed25519 key, while the
+/// longer secp256k1 key is hashed down to size.
+pub fn public_key_id(public_key: PublicKey) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    match public_key {
+        PublicKey::Ed25519(ed25519) => ed25519.value(),
+        PublicKey::Secp256k1(secp256k1) => Blake2bHash::new(secp256k1.as_bytes()).value(),
+    }
+}
 
 /// In PoS, the validators are stored under named keys with names formatted as
 /// "v_<hex-formatted-PublicKey>_<bond-amount>".  This function attempts to parse such a string back
@@ -9,13 +22,8 @@ pub fn pos_validator_key_name_to_tuple(pos_key_name: &str) -> Option<(PublicKey,
         None
     } else {
         let hex_key: &str = split_bond.next()?;
-        if hex_key.len() != 64 {
-            return None;
-        }
-        let mut key_bytes = [0u8; 32];
-        let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes).ok()?;
-        debug_assert!(_bytes_written == key_bytes.len());
-        let pub_key = PublicKey::ed25519_from(key_bytes);
+        let key_bytes = base16::decode(hex_key).ok()?;
+        let pub_key = PublicKey::try_from(key_bytes.as_slice()).ok()?;
         let balance = split_bond.next().and_then(|b| {
             if b.is_empty() {
                 None
@@ -27,13 +35,35 @@ pub fn pos_validator_key_name_to_tuple(pos_key_name: &str) -> Option<(PublicKey,
     }
 }
 
+/// Collects the validator stakes encoded in the named keys of the PoS contract.  Named keys which
+/// are not in the "v_<hex-formatted-PublicKey>_<bond-amount>" format are ignored.
+pub fn pos_named_keys_to_stakes(named_keys: &BTreeMap<String, Key>) -> Stakes {
+    let stakes = named_keys
+        .keys()
+        .filter_map(|name| pos_validator_key_name_to_tuple(name))
+        .collect();
+    Stakes::new(stakes)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use hex_fmt::HexFmt;
 
-    use types::{account::PublicKey, U512};
+    use types::{account::PublicKey, Key, U512};
 
-    use super::pos_validator_key_name_to_tuple;
+    use super::{pos_named_keys_to_stakes, pos_validator_key_name_to_tuple, public_key_id};
+
+    #[test]
+    fn should_identify_public_keys() {
+        let ed25519 = PublicKey::ed25519_from([1u8; 32]);
+        assert_eq!(public_key_id(ed25519), [1u8; 32]);
+
+        let secp256k1 = PublicKey::secp256k1_from([1u8; 33]);
+        let other_secp256k1 = PublicKey::secp256k1_from([2u8; 33]);
+        assert_ne!(public_key_id(secp256k1), public_key_id(other_secp256k1));
+    }
 
     #[test]
     fn should_parse_string_to_validator_tuple() {
@@ -48,6 +78,16 @@ mod tests {
         assert_eq!(parsed_stake, stake);
     }
 
+    #[test]
+    fn should_parse_string_with_secp256k1_key_to_validator_tuple() {
+        let public_key = PublicKey::secp256k1_from([1u8; 33]);
+        let stake = U512::from(100);
+        let named_key_name = format!("v_{}_{}", HexFmt(&public_key.as_bytes()), stake);
+
+        let parsed = pos_validator_key_name_to_tuple(&named_key_name);
+        assert_eq!(parsed, Some((public_key, stake)));
+    }
+
     #[test]
     fn should_not_parse_string_to_validator_tuple() {
         let public_key = PublicKey::ed25519_from([1u8; 32]);
@@ -62,7 +102,7 @@ mod tests {
         let short_key = format!("v_{}_{}", HexFmt(&[1u8; 31]), stake);
         assert!(pos_validator_key_name_to_tuple(&short_key).is_none());
 
-        let long_key = format!("v_{}00_{}", HexFmt(&public_key.as_bytes()), stake);
+        let long_key = format!("v_{}0000_{}", HexFmt(&public_key.as_bytes()), stake);
         assert!(pos_validator_key_name_to_tuple(&long_key).is_none());
 
         let bad_key = format!("v_{}0g_{}", HexFmt(&[1u8; 31]), stake);
@@ -83,4 +123,28 @@ mod tests {
         let no_stake = format!("v_{}", HexFmt(&public_key.as_bytes()));
         assert!(pos_validator_key_name_to_tuple(&no_stake).is_none());
     }
+
+    #[test]
+    fn should_collect_stakes_from_named_keys() {
+        let public_key_1 = PublicKey::ed25519_from([1u8; 32]);
+        let public_key_2 = PublicKey::ed25519_from([2u8; 32]);
+        let stake_1 = U512::from(100);
+        let stake_2 = U512::from(200);
+
+        let named_keys: BTreeMap<String, Key> = vec![
+            format!("v_{}_{}", HexFmt(&public_key_1.as_bytes()), stake_1),
+            format!("v_{}_{}", HexFmt(&public_key_2.as_bytes()), stake_2),
+            "pos_bonding_purse".to_string(),
+        ]
+        .into_iter()
+        .map(|name| (name, Key::Hash([0u8; 32])))
+        .collect();
+
+        let stakes = pos_named_keys_to_stakes(&named_keys);
+        let expected: BTreeMap<PublicKey, U512> =
+            vec![(public_key_1, stake_1), (public_key_2, stake_2)]
+                .into_iter()
+                .collect();
+        assert_eq!(stakes.0, expected);
+    }
 }
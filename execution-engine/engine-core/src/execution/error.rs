@@ -5,7 +5,7 @@ use parity_wasm::elements;
 use engine_shared::TypeMismatch;
 use types::{
     account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
-    bytesrepr, system_contract_errors, AccessRights, CLValueError, Key, URef,
+    bytesrepr, system_contract_errors, AccessRights, CLType, CLValueError, Key, URef,
 };
 
 use crate::resolvers::error::ResolverError;
@@ -47,6 +47,14 @@ pub enum Error {
     CLValue(CLValueError),
     HostBufferEmpty,
     UnsupportedWasmStart,
+    /// The called contract has no entry point with the given name
+    NoSuchEntryPoint(String),
+    /// The types of the args passed to an entry point don't match those it declares
+    InvalidArguments {
+        entry_point: String,
+        expected: Vec<CLType>,
+        actual: Vec<CLType>,
+    },
 }
 
 impl fmt::Display for Error {
@@ -5,26 +5,29 @@ use std::{
 };
 
 use parity_wasm::elements::Module;
-use wasmi::ModuleRef;
 
 use engine_shared::{
     account::Account, gas::Gas, newtypes::CorrelationId, stored_value::StoredValue,
 };
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use types::{
-    account::PublicKey,
-    bytesrepr::{self, FromBytes},
-    BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion,
+    account::PublicKey, bytesrepr::FromBytes, deserialize_args, BlockTime, CLTyped, CLValue, Key,
+    Phase, ProtocolVersion,
 };
 
 use crate::{
     engine_state::{
-        execution_result::ExecutionResult, system_contract_cache::SystemContractCache, EngineConfig,
+        execution_result::ExecutionResult, module_cache::ModuleCache,
+        system_contract_cache::SystemContractCache, EngineConfig,
+    },
+    execution::{
+        address_generator::AddressGenerator, random_generator::RandomGenerator, Error,
+        FN_STORE_ID_INITIAL,
     },
-    execution::{address_generator::AddressGenerator, Error, FN_STORE_ID_INITIAL},
     runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
     runtime_context::{self, RuntimeContext},
     tracking_copy::TrackingCopy,
+    wasm_backend::WasmInstance,
 };
 
 macro_rules! on_fail_charge {
@@ -70,12 +73,16 @@ macro_rules! on_fail_charge {
 
 pub struct Executor {
     config: EngineConfig,
+    module_cache: ModuleCache,
 }
 
 #[allow(clippy::too_many_arguments)]
 impl Executor {
-    pub fn new(config: EngineConfig) -> Self {
-        Executor { config }
+    pub fn new(config: EngineConfig, module_cache: ModuleCache) -> Self {
+        Executor {
+            config,
+            module_cache,
+        }
     }
 
     pub fn config(&self) -> EngineConfig {
@@ -103,8 +110,11 @@ impl Executor {
         R: StateReader<Key, StoredValue>,
         R::Error: Into<Error>,
     {
-        let (instance, memory) =
-            on_fail_charge!(instance_and_memory(parity_module.clone(), protocol_version));
+        let (instance, memory) = on_fail_charge!(instance_and_memory(
+            parity_module.clone(),
+            protocol_version,
+            self.config.wasm_backend()
+        ));
 
         let mut named_keys = account.named_keys().clone();
 
@@ -118,26 +128,24 @@ impl Executor {
             };
 
         let address_generator = AddressGenerator::new(&deploy_hash, phase);
+        let random_generator = RandomGenerator::new(&deploy_hash, phase, blocktime);
         let gas_counter: Gas = Gas::default();
 
         // Snapshot of effects before execution, so in case of error
         // only nonce update can be returned.
         let effects_snapshot = tc.borrow().effect();
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
-        } else {
-            // TODO: figure out how this works with the cost model
-            // https://casperlabs.atlassian.net/browse/EE-239
-            let gas = Gas::new(args.len().into());
-            on_fail_charge!(bytesrepr::deserialize(args), gas, effects_snapshot)
-        };
+        // TODO: figure out how this works with the cost model
+        // https://casperlabs.atlassian.net/browse/EE-239
+        let gas = Gas::new(args.len().into());
+        let (args, named_args) = on_fail_charge!(deserialize_args(args), gas, effects_snapshot);
 
         let context = RuntimeContext::new(
             tc,
             &mut named_keys,
             access_rights,
             args.clone(),
+            named_args,
             authorized_keys,
             &account,
             base_key,
@@ -147,6 +155,8 @@ impl Executor {
             gas_counter,
             FN_STORE_ID_INITIAL,
             Rc::new(RefCell::new(address_generator)),
+            Rc::new(RefCell::new(random_generator)),
+            Rc::new(RefCell::new(Vec::new())),
             protocol_version,
             correlation_id,
             phase,
@@ -156,6 +166,7 @@ impl Executor {
         let mut runtime = Runtime::new(
             self.config,
             system_contract_cache,
+            ModuleCache::clone(&self.module_cache),
             memory,
             parity_module,
             context,
@@ -208,7 +219,7 @@ impl Executor {
         }
 
         on_fail_charge!(
-            instance.invoke_export("call", &[], &mut runtime),
+            instance.invoke_export("call", &mut runtime),
             runtime.context().gas_counter(),
             effects_snapshot
         );
@@ -259,24 +270,25 @@ impl Executor {
             let address_generator = AddressGenerator::new(&deploy_hash, phase);
             Rc::new(RefCell::new(address_generator))
         };
+        let random_generator = {
+            let random_generator = RandomGenerator::new(&deploy_hash, phase, blocktime);
+            Rc::new(RefCell::new(random_generator))
+        };
         let gas_counter = Gas::default(); // maybe const?
 
         // Snapshot of effects before execution, so in case of error only nonce update
         // can be returned.
         let effects_snapshot = state.borrow().effect();
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
-        } else {
-            let gas = Gas::new(args.len().into());
-            on_fail_charge!(bytesrepr::deserialize(args), gas, effects_snapshot)
-        };
+        let gas = Gas::new(args.len().into());
+        let (args, named_args) = on_fail_charge!(deserialize_args(args), gas, effects_snapshot);
 
         let context = RuntimeContext::new(
             state,
             &mut named_keys,
             access_rights,
             args.clone(),
+            named_args,
             authorization_keys,
             &account,
             base_key,
@@ -286,18 +298,24 @@ impl Executor {
             gas_counter,
             FN_STORE_ID_INITIAL,
             address_generator,
+            random_generator,
+            Rc::new(RefCell::new(Vec::new())),
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
         );
 
-        let (instance, memory) =
-            on_fail_charge!(instance_and_memory(parity_module.clone(), protocol_version));
+        let (instance, memory) = on_fail_charge!(instance_and_memory(
+            parity_module.clone(),
+            protocol_version,
+            self.config.wasm_backend()
+        ));
 
         let mut runtime = Runtime::new(
             self.config,
             system_contract_cache,
+            ModuleCache::clone(&self.module_cache),
             memory,
             parity_module,
             context,
@@ -326,7 +344,7 @@ impl Executor {
             }
         }
 
-        let error = match instance.invoke_export("call", &[], &mut runtime) {
+        let error = match instance.invoke_export("call", &mut runtime) {
             Err(error) => error,
             Ok(_) => {
                 return ExecutionResult::Success {
@@ -381,7 +399,7 @@ impl Executor {
         phase: Phase,
         protocol_data: ProtocolData,
         system_contract_cache: SystemContractCache,
-    ) -> Result<(ModuleRef, Runtime<'a, R>), Error>
+    ) -> Result<(Box<dyn WasmInstance>, Runtime<'a, R>), Error>
     where
         R: StateReader<Key, StoredValue>,
         R::Error: Into<Error>,
@@ -395,12 +413,12 @@ impl Executor {
                 extract_access_rights_from_keys(keys)
             };
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
-        } else {
-            bytesrepr::deserialize(args)?
-        };
+        let (args, named_args) = deserialize_args(args)?;
 
+        let random_generator = {
+            let random_generator = RandomGenerator::new(&deploy_hash, phase, blocktime);
+            Rc::new(RefCell::new(random_generator))
+        };
         let gas_counter = Gas::default();
 
         let runtime_context = RuntimeContext::new(
@@ -408,6 +426,7 @@ impl Executor {
             keys,
             access_rights,
             args,
+            named_args,
             authorization_keys,
             account,
             base_key,
@@ -417,17 +436,21 @@ impl Executor {
             gas_counter,
             FN_STORE_ID_INITIAL,
             address_generator,
+            random_generator,
+            Rc::new(RefCell::new(Vec::new())),
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
         );
 
-        let (instance, memory) = instance_and_memory(module.clone(), protocol_version)?;
+        let (instance, memory) =
+            instance_and_memory(module.clone(), protocol_version, self.config.wasm_backend())?;
 
         let runtime = Runtime::new(
             self.config,
             system_contract_cache,
+            ModuleCache::clone(&self.module_cache),
             memory,
             module,
             runtime_context,
@@ -479,7 +502,7 @@ impl Executor {
             system_contract_cache,
         )?;
 
-        let error: wasmi::Error = match instance.invoke_export("call", &[], &mut runtime) {
+        let error: wasmi::Error = match instance.invoke_export("call", &mut runtime) {
             Err(error) => error,
             Ok(_) => {
                 // This duplicates the behavior of sub_call, but is admittedly rather questionable.
@@ -2,6 +2,7 @@ mod address_generator;
 mod error;
 #[macro_use]
 mod executor;
+mod random_generator;
 #[cfg(test)]
 mod tests;
 
@@ -9,6 +10,7 @@ pub use self::{
     address_generator::{AddressGenerator, AddressGeneratorBuilder},
     error::Error,
     executor::Executor,
+    random_generator::RandomGenerator,
 };
 
 pub const MINT_NAME: &str = "mint";
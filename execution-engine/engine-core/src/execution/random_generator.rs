@@ -0,0 +1,99 @@
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+use types::{BlockTime, Phase};
+
+const SEED_LENGTH: usize = 32;
+
+/// Mixed into the seed so the generated bytes differ from those of an
+/// [`AddressGenerator`](super::AddressGenerator) seeded with the same deploy hash and phase.
+const SEED_DOMAIN: &[u8] = b"random_bytes";
+
+/// A [`RandomGenerator`] generates the bytes returned to contracts by the `random_bytes` host
+/// function.
+///
+/// It is seeded from the deploy hash, [`Phase`] and [`BlockTime`], so every validator executing a
+/// given deploy in a given block generates the same bytes.  It is kept apart from the
+/// [`AddressGenerator`](super::AddressGenerator) so that calling `random_bytes` doesn't change the
+/// addresses of [`URef`](types::URef)s created afterwards.
+pub struct RandomGenerator(ChaChaRng);
+
+impl RandomGenerator {
+    /// Creates a [`RandomGenerator`] from a 32-byte hash digest, [`Phase`] and [`BlockTime`].
+    pub fn new(hash: &[u8], phase: Phase, blocktime: BlockTime) -> RandomGenerator {
+        let blocktime: u64 = blocktime.into();
+
+        let mut seed: [u8; SEED_LENGTH] = [0u8; SEED_LENGTH];
+        let mut hasher = VarBlake2b::new(SEED_LENGTH).unwrap();
+        hasher.input(SEED_DOMAIN);
+        hasher.input(hash);
+        hasher.input(&[phase as u8]);
+        hasher.input(&blocktime.to_le_bytes());
+        hasher.variable_result(|hash| seed.clone_from_slice(hash));
+        RandomGenerator(ChaChaRng::from_seed(seed))
+    }
+
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{BlockTime, Phase};
+
+    use super::RandomGenerator;
+    use crate::execution::AddressGenerator;
+
+    const DEPLOY_HASH_1: [u8; 32] = [1u8; 32];
+    const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
+    const BLOCKTIME_1: u64 = 1;
+    const BLOCKTIME_2: u64 = 2;
+
+    fn random_bytes(hash: &[u8], phase: Phase, blocktime: u64) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        RandomGenerator::new(hash, phase, BlockTime::new(blocktime)).fill_bytes(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn should_generate_same_bytes_for_same_seed() {
+        assert_eq!(
+            random_bytes(&DEPLOY_HASH_1, Phase::Session, BLOCKTIME_1),
+            random_bytes(&DEPLOY_HASH_1, Phase::Session, BLOCKTIME_1)
+        );
+    }
+
+    #[test]
+    fn should_generate_different_bytes_for_different_seeds() {
+        let bytes = random_bytes(&DEPLOY_HASH_1, Phase::Session, BLOCKTIME_1);
+        assert_ne!(
+            bytes,
+            random_bytes(&DEPLOY_HASH_2, Phase::Session, BLOCKTIME_1),
+            "different deploy hash should have different output"
+        );
+        assert_ne!(
+            bytes,
+            random_bytes(&DEPLOY_HASH_1, Phase::Payment, BLOCKTIME_1),
+            "different phase should have different output"
+        );
+        assert_ne!(
+            bytes,
+            random_bytes(&DEPLOY_HASH_1, Phase::Session, BLOCKTIME_2),
+            "different block time should have different output"
+        );
+    }
+
+    #[test]
+    fn should_not_generate_same_bytes_as_address_generator() {
+        let mut address_generator = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        assert_ne!(
+            random_bytes(&DEPLOY_HASH_1, Phase::Session, BLOCKTIME_1),
+            address_generator.create_address()
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use wasmi::{Error as InterpreterError, Signature};
+
+use super::v1_function_index::FunctionIndex;
+
+/// This trait describes the host functions and memory which a contract may import, so that they
+/// can be provided by any wasm backend.
+pub trait HostResolver {
+    /// Returns the signature and index of the host function exported under `field_name`.
+    fn resolve_host_function(
+        &self,
+        field_name: &str,
+    ) -> Result<(Signature, FunctionIndex), InterpreterError>;
+
+    /// Checks that a memory of `initial` pages, growing up to `maximum` pages, may be imported
+    /// under `field_name`.
+    fn check_memory(
+        &self,
+        field_name: &str,
+        initial: u32,
+        maximum: Option<u32>,
+    ) -> Result<(), InterpreterError>;
+}
@@ -1,4 +1,5 @@
 pub mod error;
+pub mod host_resolver;
 pub mod memory_resolver;
 pub mod v1_function_index;
 mod v1_resolver;
@@ -8,14 +9,14 @@ use wasmi::ModuleImportResolver;
 use types::ProtocolVersion;
 
 use self::error::ResolverError;
-use crate::resolvers::memory_resolver::MemoryResolver;
+use crate::resolvers::{host_resolver::HostResolver, memory_resolver::MemoryResolver};
 
 /// Creates a module resolver for given protocol version.
 ///
 /// * `protocol_version` Version of the protocol. Can't be lower than 1.
 pub fn create_module_resolver(
     protocol_version: ProtocolVersion,
-) -> Result<impl ModuleImportResolver + MemoryResolver, ResolverError> {
+) -> Result<impl ModuleImportResolver + MemoryResolver + HostResolver, ResolverError> {
     // TODO: revisit how protocol_version check here is meant to combine with upgrade
     if protocol_version >= ProtocolVersion::V1_0_0 {
         return Ok(v1_resolver::RuntimeModuleImportResolver::default());
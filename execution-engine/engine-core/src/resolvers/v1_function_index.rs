@@ -43,6 +43,18 @@ pub enum FunctionIndex {
     GetMainPurseIndex,
     GetArgSizeFuncIndex,
     ReadHostBufferIndex,
+    RemoveFuncIndex,
+    EmitEventFuncIndex,
+    TryCallContractFuncIndex,
+    StoreContractFuncIndex,
+    StoreContractAtHashFuncIndex,
+    CallEntryPointFuncIndex,
+    GetNamedArgSizeFuncIndex,
+    GetNamedArgFuncIndex,
+    Blake2bFuncIndex,
+    Ed25519VerifyFuncIndex,
+    Secp256k1VerifyFuncIndex,
+    RandomBytesFuncIndex,
 }
 
 impl Into<usize> for FunctionIndex {
@@ -6,7 +6,8 @@ use wasmi::{
 };
 
 use super::{
-    error::ResolverError, memory_resolver::MemoryResolver, v1_function_index::FunctionIndex,
+    error::ResolverError, host_resolver::HostResolver, memory_resolver::MemoryResolver,
+    v1_function_index::FunctionIndex,
 };
 
 pub struct RuntimeModuleImportResolver {
@@ -33,160 +34,207 @@ impl MemoryResolver for RuntimeModuleImportResolver {
     }
 }
 
-impl ModuleImportResolver for RuntimeModuleImportResolver {
-    fn resolve_func(
+impl HostResolver for RuntimeModuleImportResolver {
+    fn resolve_host_function(
         &self,
         field_name: &str,
-        _signature: &Signature,
-    ) -> Result<FuncRef, InterpreterError> {
-        let func_ref = match field_name {
-            "read_value" => FuncInstance::alloc_host(
+    ) -> Result<(Signature, FunctionIndex), InterpreterError> {
+        let host_function = match field_name {
+            "read_value" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::ReadFuncIndex.into(),
+                FunctionIndex::ReadFuncIndex,
             ),
-            "read_value_local" => FuncInstance::alloc_host(
+            "read_value_local" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::ReadLocalFuncIndex.into(),
+                FunctionIndex::ReadLocalFuncIndex,
             ),
-            "load_named_keys" => FuncInstance::alloc_host(
+            "load_named_keys" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::LoadNamedKeysFuncIndex.into(),
+                FunctionIndex::LoadNamedKeysFuncIndex,
             ),
-            "write" => FuncInstance::alloc_host(
+            "write" => (
                 Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::WriteFuncIndex.into(),
+                FunctionIndex::WriteFuncIndex,
             ),
-            "write_local" => FuncInstance::alloc_host(
+            "write_local" => (
                 Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::WriteLocalFuncIndex.into(),
+                FunctionIndex::WriteLocalFuncIndex,
             ),
-            "add" => FuncInstance::alloc_host(
+            "add" => (
                 Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::AddFuncIndex.into(),
+                FunctionIndex::AddFuncIndex,
             ),
-            "add_local" => FuncInstance::alloc_host(
+            "add_local" => (
                 Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::AddLocalFuncIndex.into(),
+                FunctionIndex::AddLocalFuncIndex,
             ),
-            "new_uref" => FuncInstance::alloc_host(
+            "new_uref" => (
                 Signature::new(&[ValueType::I32; 3][..], None),
-                FunctionIndex::NewFuncIndex.into(),
+                FunctionIndex::NewFuncIndex,
             ),
-            "get_arg_size" => FuncInstance::alloc_host(
+            "get_arg_size" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::GetArgSizeFuncIndex.into(),
+                FunctionIndex::GetArgSizeFuncIndex,
             ),
-            "get_arg" => FuncInstance::alloc_host(
+            "get_arg" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::GetArgFuncIndex.into(),
+                FunctionIndex::GetArgFuncIndex,
             ),
-            "ret" => FuncInstance::alloc_host(
+            "ret" => (
                 Signature::new(&[ValueType::I32; 2][..], None),
-                FunctionIndex::RetFuncIndex.into(),
+                FunctionIndex::RetFuncIndex,
             ),
-            "call_contract" => FuncInstance::alloc_host(
+            "call_contract" => (
                 Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
-                FunctionIndex::CallContractFuncIndex.into(),
+                FunctionIndex::CallContractFuncIndex,
             ),
-            "get_key" => FuncInstance::alloc_host(
+            "get_key" => (
                 Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
-                FunctionIndex::GetKeyFuncIndex.into(),
+                FunctionIndex::GetKeyFuncIndex,
             ),
-            "has_key" => FuncInstance::alloc_host(
+            "has_key" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::HasKeyFuncIndex.into(),
+                FunctionIndex::HasKeyFuncIndex,
             ),
-            "put_key" => FuncInstance::alloc_host(
+            "put_key" => (
                 Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::PutKeyFuncIndex.into(),
+                FunctionIndex::PutKeyFuncIndex,
             ),
-            "gas" => FuncInstance::alloc_host(
+            "gas" => (
                 Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GasFuncIndex.into(),
+                FunctionIndex::GasFuncIndex,
             ),
-            "store_function" => FuncInstance::alloc_host(
+            "store_function" => (
                 Signature::new(&[ValueType::I32; 5][..], None),
-                FunctionIndex::StoreFnIndex.into(),
+                FunctionIndex::StoreFnIndex,
             ),
-            "store_function_at_hash" => FuncInstance::alloc_host(
+            "store_function_at_hash" => (
                 Signature::new(&[ValueType::I32; 5][..], None),
-                FunctionIndex::StoreFnAtHashIndex.into(),
+                FunctionIndex::StoreFnAtHashIndex,
             ),
-            "is_valid_uref" => FuncInstance::alloc_host(
+            "is_valid_uref" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::IsValidURefFnIndex.into(),
+                FunctionIndex::IsValidURefFnIndex,
             ),
-            "revert" => FuncInstance::alloc_host(
+            "revert" => (
                 Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::RevertFuncIndex.into(),
+                FunctionIndex::RevertFuncIndex,
             ),
-            "add_associated_key" => FuncInstance::alloc_host(
+            "add_associated_key" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::AddAssociatedKeyFuncIndex.into(),
+                FunctionIndex::AddAssociatedKeyFuncIndex,
             ),
-            "remove_associated_key" => FuncInstance::alloc_host(
+            "remove_associated_key" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::RemoveAssociatedKeyFuncIndex.into(),
+                FunctionIndex::RemoveAssociatedKeyFuncIndex,
             ),
-            "update_associated_key" => FuncInstance::alloc_host(
+            "update_associated_key" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::UpdateAssociatedKeyFuncIndex.into(),
+                FunctionIndex::UpdateAssociatedKeyFuncIndex,
             ),
-            "set_action_threshold" => FuncInstance::alloc_host(
+            "set_action_threshold" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::SetActionThresholdFuncIndex.into(),
+                FunctionIndex::SetActionThresholdFuncIndex,
             ),
-            "remove_key" => FuncInstance::alloc_host(
+            "remove_key" => (
                 Signature::new(&[ValueType::I32; 2][..], None),
-                FunctionIndex::RemoveKeyFuncIndex.into(),
+                FunctionIndex::RemoveKeyFuncIndex,
             ),
-            "get_caller" => FuncInstance::alloc_host(
+            "get_caller" => (
                 Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
-                FunctionIndex::GetCallerIndex.into(),
+                FunctionIndex::GetCallerIndex,
             ),
-            "get_blocktime" => FuncInstance::alloc_host(
+            "get_blocktime" => (
                 Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GetBlocktimeIndex.into(),
+                FunctionIndex::GetBlocktimeIndex,
             ),
-            "create_purse" => FuncInstance::alloc_host(
+            "create_purse" => (
                 Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::CreatePurseIndex.into(),
+                FunctionIndex::CreatePurseIndex,
             ),
-            "transfer_to_account" => FuncInstance::alloc_host(
+            "transfer_to_account" => (
                 Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
-                FunctionIndex::TransferToAccountIndex.into(),
+                FunctionIndex::TransferToAccountIndex,
             ),
-            "transfer_from_purse_to_account" => FuncInstance::alloc_host(
+            "transfer_from_purse_to_account" => (
                 Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
-                FunctionIndex::TransferFromPurseToAccountIndex.into(),
+                FunctionIndex::TransferFromPurseToAccountIndex,
             ),
-            "transfer_from_purse_to_purse" => FuncInstance::alloc_host(
+            "transfer_from_purse_to_purse" => (
                 Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
-                FunctionIndex::TransferFromPurseToPurseIndex.into(),
+                FunctionIndex::TransferFromPurseToPurseIndex,
             ),
-            "get_balance" => FuncInstance::alloc_host(
+            "get_balance" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::GetBalanceIndex.into(),
+                FunctionIndex::GetBalanceIndex,
             ),
-            "get_phase" => FuncInstance::alloc_host(
+            "get_phase" => (
                 Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GetPhaseIndex.into(),
+                FunctionIndex::GetPhaseIndex,
             ),
-            "upgrade_contract_at_uref" => FuncInstance::alloc_host(
+            "upgrade_contract_at_uref" => (
                 Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
-                FunctionIndex::UpgradeContractAtURefIndex.into(),
+                FunctionIndex::UpgradeContractAtURefIndex,
             ),
-            "get_system_contract" => FuncInstance::alloc_host(
+            "get_system_contract" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::GetSystemContractIndex.into(),
+                FunctionIndex::GetSystemContractIndex,
             ),
-            "get_main_purse" => FuncInstance::alloc_host(
+            "get_main_purse" => (
                 Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GetMainPurseIndex.into(),
+                FunctionIndex::GetMainPurseIndex,
             ),
-            "read_host_buffer" => FuncInstance::alloc_host(
+            "read_host_buffer" => (
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::ReadHostBufferIndex.into(),
+                FunctionIndex::ReadHostBufferIndex,
+            ),
+            "remove" => (
+                Signature::new(&[ValueType::I32; 2][..], None),
+                FunctionIndex::RemoveFuncIndex,
+            ),
+            "emit_event" => (
+                Signature::new(&[ValueType::I32; 4][..], None),
+                FunctionIndex::EmitEventFuncIndex,
+            ),
+            "try_call_contract" => (
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
+                FunctionIndex::TryCallContractFuncIndex,
+            ),
+            "store_contract" => (
+                Signature::new(&[ValueType::I32; 5][..], None),
+                FunctionIndex::StoreContractFuncIndex,
+            ),
+            "store_contract_at_hash" => (
+                Signature::new(&[ValueType::I32; 5][..], None),
+                FunctionIndex::StoreContractAtHashFuncIndex,
+            ),
+            "call_entry_point" => (
+                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32)),
+                FunctionIndex::CallEntryPointFuncIndex,
+            ),
+            "get_named_arg_size" => (
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::GetNamedArgSizeFuncIndex,
+            ),
+            "get_named_arg" => (
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::GetNamedArgFuncIndex,
+            ),
+            "blake2b" => (
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::Blake2bFuncIndex,
+            ),
+            "ed25519_verify" => (
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::Ed25519VerifyFuncIndex,
+            ),
+            "secp256k1_verify" => (
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::Secp256k1VerifyFuncIndex,
+            ),
+            "random_bytes" => (
+                Signature::new(&[ValueType::I32; 2][..], None),
+                FunctionIndex::RandomBytesFuncIndex,
             ),
             _ => {
                 return Err(InterpreterError::Function(format!(
@@ -195,7 +243,38 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 )));
             }
         };
-        Ok(func_ref)
+        Ok(host_function)
+    }
+
+    fn check_memory(
+        &self,
+        field_name: &str,
+        initial: u32,
+        maximum: Option<u32>,
+    ) -> Result<(), InterpreterError> {
+        if field_name != "memory" {
+            return Err(InterpreterError::Instantiation(
+                "Memory imported under unknown name".to_owned(),
+            ));
+        }
+        let effective_max = maximum.unwrap_or(self.max_memory + 1);
+        if initial > self.max_memory || effective_max > self.max_memory {
+            return Err(InterpreterError::Instantiation(
+                "Module requested too much memory".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl ModuleImportResolver for RuntimeModuleImportResolver {
+    fn resolve_func(
+        &self,
+        field_name: &str,
+        _signature: &Signature,
+    ) -> Result<FuncRef, InterpreterError> {
+        let (signature, function_index) = self.resolve_host_function(field_name)?;
+        Ok(FuncInstance::alloc_host(signature, function_index.into()))
     }
 
     fn resolve_memory(
@@ -203,25 +282,13 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
         field_name: &str,
         descriptor: &MemoryDescriptor,
     ) -> Result<MemoryRef, InterpreterError> {
-        if field_name == "memory" {
-            let effective_max = descriptor.maximum().unwrap_or(self.max_memory + 1);
-            if descriptor.initial() > self.max_memory || effective_max > self.max_memory {
-                Err(InterpreterError::Instantiation(
-                    "Module requested too much memory".to_owned(),
-                ))
-            } else {
-                // Note: each "page" is 64 KiB
-                let mem = MemoryInstance::alloc(
-                    Pages(descriptor.initial() as usize),
-                    descriptor.maximum().map(|x| Pages(x as usize)),
-                )?;
-                *self.memory.borrow_mut() = Some(mem.clone());
-                Ok(mem)
-            }
-        } else {
-            Err(InterpreterError::Instantiation(
-                "Memory imported under unknown name".to_owned(),
-            ))
-        }
+        self.check_memory(field_name, descriptor.initial(), descriptor.maximum())?;
+        // Note: each "page" is 64 KiB
+        let mem = MemoryInstance::alloc(
+            Pages(descriptor.initial() as usize),
+            descriptor.maximum().map(|x| Pages(x as usize)),
+        )?;
+        *self.memory.borrow_mut() = Some(mem.clone());
+        Ok(mem)
     }
 }
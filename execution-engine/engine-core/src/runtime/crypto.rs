@@ -0,0 +1,118 @@
+//! Signature verification backing the `ed25519_verify` and `secp256k1_verify` host functions.
+
+use lazy_static::lazy_static;
+use secp256k1::{Message, Secp256k1, VerifyOnly};
+
+lazy_static! {
+    static ref SECP256K1_VERIFIER: Secp256k1<VerifyOnly> = Secp256k1::verification_only();
+}
+
+/// Returns `true` if `signature` is a valid Ed25519 signature of `message` by `public_key`.
+///
+/// Malformed public keys or signatures are treated as invalid rather than as errors, so contracts
+/// can't distinguish between the two.
+pub(super) fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let public_key = match ed25519_dalek::PublicKey::from_bytes(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match ed25519_dalek::Signature::from_bytes(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    public_key.verify(message, &signature).is_ok()
+}
+
+/// Returns `true` if `signature` is a valid compact secp256k1 signature of the 32-byte `digest` by
+/// `public_key`, which may be compressed or uncompressed.
+///
+/// Malformed public keys, digests or signatures are treated as invalid rather than as errors.
+pub(super) fn secp256k1_verify(public_key: &[u8], digest: &[u8], signature: &[u8]) -> bool {
+    let public_key = match secp256k1::PublicKey::from_slice(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let message = match Message::from_slice(digest) {
+        Ok(message) => message,
+        Err(_) => return false,
+    };
+    let signature = match secp256k1::Signature::from_compact(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    SECP256K1_VERIFIER
+        .verify(&message, &signature, &public_key)
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::ExpandedSecretKey;
+    use secp256k1::{Message, Secp256k1};
+
+    use super::{ed25519_verify, secp256k1_verify};
+
+    const MESSAGE: &[u8] = b"hello world";
+    const DIGEST: [u8; 32] = [7u8; 32];
+
+    fn ed25519_signed() -> (Vec<u8>, Vec<u8>) {
+        let secret_key = ed25519_dalek::SecretKey::from_bytes(&[1u8; 32]).unwrap();
+        let public_key = ed25519_dalek::PublicKey::from(&secret_key);
+        let signature = ExpandedSecretKey::from(&secret_key).sign(MESSAGE, &public_key);
+        (
+            public_key.to_bytes().to_vec(),
+            signature.to_bytes().to_vec(),
+        )
+    }
+
+    fn secp256k1_signed() -> (Vec<u8>, Vec<u8>) {
+        let secp = Secp256k1::new();
+        let secret_key = secp256k1::SecretKey::from_slice(&[1u8; 32]).unwrap();
+        let public_key = secp256k1::PublicKey::from_secret_key(&secp, &secret_key);
+        let message = Message::from_slice(&DIGEST).unwrap();
+        let signature = secp.sign(&message, &secret_key);
+        (
+            public_key.serialize().to_vec(),
+            signature.serialize_compact().to_vec(),
+        )
+    }
+
+    #[test]
+    fn should_verify_ed25519_signature() {
+        let (public_key, signature) = ed25519_signed();
+        assert!(ed25519_verify(&public_key, MESSAGE, &signature));
+    }
+
+    #[test]
+    fn should_not_verify_ed25519_signature_of_other_message() {
+        let (public_key, signature) = ed25519_signed();
+        assert!(!ed25519_verify(&public_key, b"goodbye world", &signature));
+    }
+
+    #[test]
+    fn should_not_verify_malformed_ed25519_inputs() {
+        let (public_key, signature) = ed25519_signed();
+        assert!(!ed25519_verify(&public_key[1..], MESSAGE, &signature));
+        assert!(!ed25519_verify(&public_key, MESSAGE, &signature[1..]));
+    }
+
+    #[test]
+    fn should_verify_secp256k1_signature() {
+        let (public_key, signature) = secp256k1_signed();
+        assert!(secp256k1_verify(&public_key, &DIGEST, &signature));
+    }
+
+    #[test]
+    fn should_not_verify_secp256k1_signature_of_other_digest() {
+        let (public_key, signature) = secp256k1_signed();
+        assert!(!secp256k1_verify(&public_key, &[8u8; 32], &signature));
+    }
+
+    #[test]
+    fn should_not_verify_malformed_secp256k1_inputs() {
+        let (public_key, signature) = secp256k1_signed();
+        assert!(!secp256k1_verify(&public_key[1..], &DIGEST, &signature));
+        assert!(!secp256k1_verify(&public_key, &DIGEST[1..], &signature));
+        assert!(!secp256k1_verify(&public_key, &DIGEST, &signature[1..]));
+    }
+}
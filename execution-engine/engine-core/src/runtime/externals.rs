@@ -26,12 +26,14 @@ where
         args: RuntimeArgs,
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
+        let host_function_costs = *self.context.protocol_data().host_function_costs();
         match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
                 // args(1) = size of key in Wasm memory
                 // args(2) = pointer to output size (output param)
                 let (key_ptr, key_size, output_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.read_value, &[key_size])?;
                 let ret = self.read(key_ptr, key_size, output_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -41,6 +43,7 @@ where
                 // args(1) = size of key in Wasm memory
                 // args(2) = pointer to output size (output param)
                 let (key_ptr, key_size, output_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.read_value_local, &[key_size])?;
                 let ret = self.read_local(key_ptr, key_size, output_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -49,6 +52,7 @@ where
                 // args(0) = pointer to amount of keys (output)
                 // args(1) = pointer to amount of serialized bytes (output)
                 let (total_keys_ptr, result_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.load_named_keys, &[])?;
                 let ret = self.load_named_keys(total_keys_ptr, result_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -59,6 +63,10 @@ where
                 // args(2) = pointer to value
                 // args(3) = size of value
                 let (key_ptr, key_size, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.write,
+                    &[key_size, value_size],
+                )?;
                 self.write(key_ptr, key_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -69,6 +77,10 @@ where
                 // args(2) = pointer to value
                 // args(3) = size of value
                 let (key_bytes_ptr, key_bytes_size, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.write_local,
+                    &[key_bytes_size, value_size],
+                )?;
                 self.write_local(key_bytes_ptr, key_bytes_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -79,6 +91,7 @@ where
                 // args(2) = pointer to value
                 // args(3) = size of value
                 let (key_ptr, key_size, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.add, &[key_size, value_size])?;
                 self.add(key_ptr, key_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -89,6 +102,10 @@ where
                 // args(2) = pointer to value
                 // args(3) = size of value
                 let (key_bytes_ptr, key_bytes_size, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.add_local,
+                    &[key_bytes_size, value_size],
+                )?;
                 self.add_local(key_bytes_ptr, key_bytes_size, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -98,6 +115,7 @@ where
                 // args(1) = pointer to initial value
                 // args(2) = size of initial value
                 let (key_ptr, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.new_uref, &[value_size])?;
                 self.new_uref(key_ptr, value_ptr, value_size)?;
                 Ok(None)
             }
@@ -106,6 +124,7 @@ where
                 // args(0) = index of host runtime arg to load
                 // args(1) = pointer to a argument size (output)
                 let (index, size_ptr): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_arg_size, &[])?;
                 let ret = self.get_arg_size(index as usize, size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -115,6 +134,7 @@ where
                 // args(1) = pointer to destination in Wasm memory
                 // args(2) = size of destination pointer memory
                 let (index, dest_ptr, dest_size): (u32, _, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_arg, &[dest_size])?;
                 let ret = self.get_arg(index as usize, dest_ptr, dest_size as usize)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -123,6 +143,7 @@ where
                 // args(0) = pointer to value
                 // args(1) = size of value
                 let (value_ptr, value_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.ret, &[value_size])?;
 
                 Err(self.ret(value_ptr, value_size as usize))
             }
@@ -135,6 +156,10 @@ where
                 // args(4) = pointer to result size (output)
                 let (key_ptr, key_size, args_ptr, args_size, result_size_ptr): (_, _, _, u32, _) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_contract,
+                    &[key_size, args_size],
+                )?;
 
                 let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
                 let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
@@ -157,6 +182,7 @@ where
                     u32,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_key, &[name_size])?;
                 let ret = self.load_key(
                     name_ptr,
                     name_size,
@@ -171,6 +197,7 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 let (name_ptr, name_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.has_key, &[name_size])?;
                 let result = self.has_key(name_ptr, name_size)?;
                 Ok(Some(RuntimeValue::I32(result)))
             }
@@ -181,6 +208,10 @@ where
                 // args(2) = pointer to key in Wasm memory
                 // args(3) = size of key
                 let (name_ptr, name_size, key_ptr, key_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.put_key,
+                    &[name_size, key_size],
+                )?;
                 self.put_key(name_ptr, name_size, key_ptr, key_size)?;
                 Ok(None)
             }
@@ -189,6 +220,7 @@ where
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
                 let (name_ptr, name_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.remove_key, &[name_size])?;
                 self.remove_key(name_ptr, name_size)?;
                 Ok(None)
             }
@@ -196,6 +228,7 @@ where
             FunctionIndex::GetCallerIndex => {
                 // args(0) = pointer where a size of serialized bytes will be stored
                 let output_size = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_caller, &[])?;
                 let ret = self.get_caller(output_size)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -203,6 +236,7 @@ where
             FunctionIndex::GetBlocktimeIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_blocktime, &[])?;
                 self.get_blocktime(dest_ptr)?;
                 Ok(None)
             }
@@ -222,6 +256,10 @@ where
                 // args(4) = pointer to a Wasm memory where we will save
                 //           uref address of the new function
                 let (name_ptr, name_size, urefs_ptr, urefs_size, hash_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.store_function,
+                    &[name_size, urefs_size],
+                )?;
                 let _uref_type: u32 = urefs_size;
                 let fn_bytes = self.get_function_by_name(name_ptr, name_size)?;
                 let uref_bytes = self
@@ -229,7 +267,7 @@ where
                     .get(urefs_ptr, urefs_size as usize)
                     .map_err(Error::Interpreter)?;
                 let urefs = bytesrepr::deserialize(uref_bytes).map_err(Error::BytesRepr)?;
-                let contract_hash = self.store_function(fn_bytes, urefs)?;
+                let contract_hash = self.store_function(fn_bytes, Vec::new(), urefs)?;
                 self.function_address(contract_hash, hash_ptr)?;
                 Ok(None)
             }
@@ -243,6 +281,10 @@ where
                 // args(4) = pointer to a Wasm memory where we will save
                 //           hash of the new function
                 let (name_ptr, name_size, urefs_ptr, urefs_size, hash_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.store_function_at_hash,
+                    &[name_size, urefs_size],
+                )?;
                 let _uref_type: u32 = urefs_size;
                 let fn_bytes = self.get_function_by_name(name_ptr, name_size)?;
                 let uref_bytes = self
@@ -250,7 +292,7 @@ where
                     .get(urefs_ptr, urefs_size as usize)
                     .map_err(Error::Interpreter)?;
                 let urefs = bytesrepr::deserialize(uref_bytes).map_err(Error::BytesRepr)?;
-                let contract_hash = self.store_function_at_hash(fn_bytes, urefs)?;
+                let contract_hash = self.store_function_at_hash(fn_bytes, Vec::new(), urefs)?;
                 self.function_address(contract_hash, hash_ptr)?;
                 Ok(None)
             }
@@ -259,6 +301,7 @@ where
                 // args(0) = pointer to value to validate
                 // args(1) = size of value
                 let (uref_ptr, uref_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.is_valid_uref, &[uref_size])?;
 
                 Ok(Some(RuntimeValue::I32(i32::from(
                     self.is_valid_uref(uref_ptr, uref_size)?,
@@ -268,6 +311,7 @@ where
             FunctionIndex::RevertFuncIndex => {
                 // args(0) = status u32
                 let status = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.revert, &[])?;
 
                 Err(self.revert(status))
             }
@@ -278,6 +322,10 @@ where
                 // args(2) = weight of the key
                 let (public_key_ptr, public_key_size, weight_value): (u32, u32, u8) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.add_associated_key,
+                    &[public_key_size],
+                )?;
                 let value = self.add_associated_key(
                     public_key_ptr,
                     public_key_size as usize,
@@ -290,6 +338,10 @@ where
                 // args(0) = pointer to array of bytes of a public key
                 // args(1) = size of a public key
                 let (public_key_ptr, public_key_size): (_, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.remove_associated_key,
+                    &[public_key_size],
+                )?;
                 let value = self.remove_associated_key(public_key_ptr, public_key_size as usize)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -300,6 +352,10 @@ where
                 // args(2) = weight of the key
                 let (public_key_ptr, public_key_size, weight_value): (u32, u32, u8) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.update_associated_key,
+                    &[public_key_size],
+                )?;
                 let value = self.update_associated_key(
                     public_key_ptr,
                     public_key_size as usize,
@@ -312,6 +368,7 @@ where
                 // args(0) = action type
                 // args(1) = new threshold
                 let (action_type_value, threshold_value): (u32, u8) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.set_action_threshold, &[])?;
                 let value = self.set_action_threshold(action_type_value, threshold_value)?;
                 Ok(Some(RuntimeValue::I32(value)))
             }
@@ -320,6 +377,7 @@ where
                 // args(0) = pointer to array for return value
                 // args(1) = length of array for return value
                 let (dest_ptr, dest_size): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.create_purse, &[])?;
                 let purse = self.create_purse()?;
                 let purse_bytes = purse.into_bytes().map_err(Error::BytesRepr)?;
                 assert_eq!(dest_size, purse_bytes.len() as u32);
@@ -336,6 +394,10 @@ where
                 // args(3) = length of array of bytes of an amount
                 let (key_ptr, key_size, amount_ptr, amount_size): (u32, u32, u32, u32) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_to_account,
+                    &[key_size, amount_size],
+                )?;
                 let public_key: PublicKey = {
                     let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
                     bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
@@ -363,6 +425,10 @@ where
                     u32,
                     u32,
                 ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_from_purse_to_account,
+                    &[source_size, key_size, amount_size],
+                )?;
 
                 let source_purse = {
                     let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
@@ -389,6 +455,10 @@ where
                 // args(5) = length of array of bytes in Wasm memory of an amount
                 let (source_ptr, source_size, target_ptr, target_size, amount_ptr, amount_size) =
                     Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.transfer_from_purse_to_purse,
+                    &[source_size, target_size, amount_size],
+                )?;
                 let ret = self.transfer_from_purse_to_purse(
                     source_ptr,
                     source_size,
@@ -405,6 +475,7 @@ where
                 // args(1) = length of purse
                 // args(2) = pointer to output size (output)
                 let (ptr, ptr_size, output_size_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_balance, &[ptr_size])?;
                 let ret = self.get_balance_host_buffer(ptr, ptr_size as usize, output_size_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -412,6 +483,7 @@ where
             FunctionIndex::GetPhaseIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_phase, &[])?;
                 self.get_phase(dest_ptr)?;
                 Ok(None)
             }
@@ -422,6 +494,10 @@ where
                 // args(2) = pointer to key in Wasm memory
                 // args(3) = size of key
                 let (name_ptr, name_size, key_ptr, key_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.upgrade_contract_at_uref,
+                    &[name_size, key_size],
+                )?;
                 let ret = self.upgrade_contract_at_uref(name_ptr, name_size, key_ptr, key_size)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -431,6 +507,7 @@ where
                 // args(1) = dest pointer for storing serialized result
                 // args(2) = dest pointer size
                 let (system_contract_index, dest_ptr, dest_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_system_contract, &[])?;
                 let ret = self.get_system_contract(system_contract_index, dest_ptr, dest_size)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -438,6 +515,7 @@ where
             FunctionIndex::GetMainPurseIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_main_purse, &[])?;
                 self.get_main_purse(dest_ptr)?;
                 Ok(None)
             }
@@ -445,9 +523,251 @@ where
             FunctionIndex::ReadHostBufferIndex => {
                 // args(0) = pointer to Wasm memory where to write size.
                 let (dest_ptr, dest_size, bytes_written_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.read_host_buffer,
+                    &[dest_size],
+                )?;
                 let ret = self.read_host_buffer(dest_ptr, dest_size as usize, bytes_written_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
+
+            FunctionIndex::RemoveFuncIndex => {
+                // args(0) = pointer to key in Wasm memory
+                // args(1) = size of key
+                let (key_ptr, key_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.remove, &[key_size])?;
+                self.remove(key_ptr, key_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::EmitEventFuncIndex => {
+                // args(0) = pointer to event name in Wasm memory
+                // args(1) = size of event name
+                // args(2) = pointer to event value in Wasm memory
+                // args(3) = size of event value
+                let (name_ptr, name_size, value_ptr, value_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_event,
+                    &[name_size, value_size],
+                )?;
+                self.emit_event(name_ptr, name_size, value_ptr, value_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::TryCallContractFuncIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to function arguments in Wasm memory
+                // args(3) = size of arguments
+                // args(4) = pointer to result size (output)
+                let (key_ptr, key_size, args_ptr, args_size, result_size_ptr): (_, _, _, u32, _) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.try_call_contract,
+                    &[key_size, args_size],
+                )?;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+
+                let ret =
+                    self.try_call_contract_host_buffer(key_contract, args_bytes, result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::StoreContractFuncIndex => {
+                // args(0) = pointer to serialized entry points in Wasm memory
+                // args(1) = size of the entry points
+                // args(2) = pointer to additional unforgable names
+                //           to be saved with the function bodies
+                // args(3) = size of the additional unforgable names
+                // args(4) = pointer to a Wasm memory where we will save
+                //           uref address of the new contract
+                let (names_ptr, names_size, urefs_ptr, urefs_size, hash_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.store_contract,
+                    &[names_size, urefs_size],
+                )?;
+                let (fn_bytes, entry_points) =
+                    self.get_functions_by_entry_points(names_ptr, names_size)?;
+                let uref_bytes = self
+                    .memory
+                    .get(urefs_ptr, urefs_size as usize)
+                    .map_err(Error::Interpreter)?;
+                let urefs = bytesrepr::deserialize(uref_bytes).map_err(Error::BytesRepr)?;
+                let contract_hash = self.store_function(fn_bytes, entry_points, urefs)?;
+                self.function_address(contract_hash, hash_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::StoreContractAtHashFuncIndex => {
+                // args(0) = pointer to serialized entry points in Wasm memory
+                // args(1) = size of the entry points
+                // args(2) = pointer to additional unforgable names
+                //           to be saved with the function bodies
+                // args(3) = size of the additional unforgable names
+                // args(4) = pointer to a Wasm memory where we will save
+                //           hash of the new contract
+                let (names_ptr, names_size, urefs_ptr, urefs_size, hash_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.store_contract_at_hash,
+                    &[names_size, urefs_size],
+                )?;
+                let (fn_bytes, entry_points) =
+                    self.get_functions_by_entry_points(names_ptr, names_size)?;
+                let uref_bytes = self
+                    .memory
+                    .get(urefs_ptr, urefs_size as usize)
+                    .map_err(Error::Interpreter)?;
+                let urefs = bytesrepr::deserialize(uref_bytes).map_err(Error::BytesRepr)?;
+                let contract_hash = self.store_function_at_hash(fn_bytes, entry_points, urefs)?;
+                self.function_address(contract_hash, hash_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::CallEntryPointFuncIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to entry point name in Wasm memory
+                // args(3) = size of entry point name
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to result size (output)
+                let (
+                    key_ptr,
+                    key_size,
+                    entry_point_ptr,
+                    entry_point_size,
+                    args_ptr,
+                    args_size,
+                    result_size_ptr,
+                ): (_, _, _, _, _, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_entry_point,
+                    &[key_size, entry_point_size, args_size],
+                )?;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let entry_point = self.string_from_mem(entry_point_ptr, entry_point_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+
+                let ret = self.call_entry_point_host_buffer(
+                    key_contract,
+                    entry_point,
+                    args_bytes,
+                    result_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetNamedArgSizeFuncIndex => {
+                // args(0) = pointer to arg name in Wasm memory
+                // args(1) = size of arg name
+                // args(2) = pointer to a argument size (output)
+                let (name_ptr, name_size, size_ptr): (_, u32, _) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.get_named_arg_size,
+                    &[name_size],
+                )?;
+                let ret = self.get_named_arg_size(name_ptr, name_size, size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetNamedArgFuncIndex => {
+                // args(0) = pointer to arg name in Wasm memory
+                // args(1) = size of arg name
+                // args(2) = pointer to destination in Wasm memory
+                // args(3) = size of destination pointer memory
+                let (name_ptr, name_size, dest_ptr, dest_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.get_named_arg,
+                    &[name_size, dest_size],
+                )?;
+                let ret = self.get_named_arg(name_ptr, name_size, dest_ptr, dest_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Blake2bFuncIndex => {
+                // args(0) = pointer to input bytes in Wasm memory
+                // args(1) = size of input bytes
+                // args(2) = pointer to destination in Wasm memory
+                // args(3) = size of destination pointer memory
+                let (input_ptr, input_size, dest_ptr, dest_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.blake2b, &[input_size])?;
+                let ret = self.blake2b(input_ptr, input_size, dest_ptr, dest_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Ed25519VerifyFuncIndex => {
+                // args(0) = pointer to public key bytes in Wasm memory
+                // args(1) = size of public key bytes
+                // args(2) = pointer to message bytes in Wasm memory
+                // args(3) = size of message bytes
+                // args(4) = pointer to signature bytes in Wasm memory
+                // args(5) = size of signature bytes
+                let (
+                    public_key_ptr,
+                    public_key_size,
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.ed25519_verify,
+                    &[public_key_size, message_size, signature_size],
+                )?;
+
+                Ok(Some(RuntimeValue::I32(i32::from(self.ed25519_verify(
+                    public_key_ptr,
+                    public_key_size,
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                )?))))
+            }
+
+            FunctionIndex::Secp256k1VerifyFuncIndex => {
+                // args(0) = pointer to public key bytes in Wasm memory
+                // args(1) = size of public key bytes
+                // args(2) = pointer to 32-byte message digest in Wasm memory
+                // args(3) = size of message digest
+                // args(4) = pointer to compact signature bytes in Wasm memory
+                // args(5) = size of signature bytes
+                let (
+                    public_key_ptr,
+                    public_key_size,
+                    digest_ptr,
+                    digest_size,
+                    signature_ptr,
+                    signature_size,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.secp256k1_verify,
+                    &[public_key_size, digest_size, signature_size],
+                )?;
+
+                Ok(Some(RuntimeValue::I32(i32::from(self.secp256k1_verify(
+                    public_key_ptr,
+                    public_key_size,
+                    digest_ptr,
+                    digest_size,
+                    signature_ptr,
+                    signature_size,
+                )?))))
+            }
+
+            FunctionIndex::RandomBytesFuncIndex => {
+                // args(0) = pointer to destination in Wasm memory
+                // args(1) = number of bytes to write
+                let (dest_ptr, dest_size) = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.random_bytes, &[dest_size])?;
+                self.random_bytes(dest_ptr, dest_size)?;
+                Ok(None)
+            }
         }
     }
 }
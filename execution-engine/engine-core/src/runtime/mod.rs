@@ -1,4 +1,5 @@
 mod args;
+mod crypto;
 mod externals;
 mod mint_internal;
 mod proof_of_stake_internal;
@@ -9,39 +10,54 @@ use std::{
     collections::{BTreeMap, HashMap, HashSet},
     convert::TryFrom,
     iter::IntoIterator,
+    rc::Rc,
 };
 
 use itertools::Itertools;
 use parity_wasm::elements::Module;
-use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
+use wasmi::{Trap, TrapKind};
 
 use ::mint::Mint;
 use contract::args_parser::ArgsParser;
-use engine_shared::{account::Account, contract::Contract, gas::Gas, stored_value::StoredValue};
+use engine_shared::{
+    account::Account,
+    contract::{Contract, DEFAULT_ENTRY_POINT},
+    gas::Gas,
+    host_function_costs::HostFunctionCost,
+    newtypes::Blake2bHash,
+    stored_value::StoredValue,
+};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use proof_of_stake::ProofOfStake;
 use standard_payment::StandardPayment;
 use types::{
     account::{ActionType, PublicKey, Weight},
+    api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    system_contract_errors,
+    deserialize_args, system_contract_errors,
     system_contract_errors::mint,
-    AccessRights, ApiError, CLType, CLTyped, CLValue, Key, ProtocolVersion, SystemContractType,
-    TransferResult, TransferredTo, URef, U128, U256, U512,
+    AccessRights, ApiError, CLType, CLTyped, CLValue, EntryPoint, Key, Parameter, ProtocolVersion,
+    RuntimeArgs, SystemContractType, TransferResult, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH,
+    U128, U256, U512,
 };
 
 use crate::{
-    engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
+    engine_state::{
+        module_cache::{ModuleCache, ModuleCacheKey},
+        system_contract_cache::SystemContractCache,
+        EngineConfig,
+    },
     execution::{Error, MINT_NAME, POS_NAME},
-    resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
     runtime_context::RuntimeContext,
+    wasm_backend::{WasmBackend, WasmBackendKind, WasmInstance, WasmMemory},
     Address,
 };
 
 pub struct Runtime<'a, R> {
     system_contract_cache: SystemContractCache,
+    module_cache: ModuleCache,
     config: EngineConfig,
-    memory: MemoryRef,
+    memory: Rc<dyn WasmMemory>,
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
@@ -63,21 +79,72 @@ pub fn rename_export_to_call(module: &mut Module, name: String) {
     main_export.push_str("call");
 }
 
+/// Instantiates `parity_module` on `wasm_backend`, returning the instance and its memory.
 pub fn instance_and_memory(
     parity_module: Module,
     protocol_version: ProtocolVersion,
-) -> Result<(ModuleRef, MemoryRef), Error> {
-    let module = wasmi::Module::from_parity_wasm_module(parity_module)?;
-    let resolver = create_module_resolver(protocol_version)?;
-    let mut imports = ImportsBuilder::new();
-    imports.push_resolver("env", &resolver);
-    let not_started_module = ModuleInstance::new(&module, &imports)?;
-    if not_started_module.has_start() {
-        return Err(Error::UnsupportedWasmStart);
-    }
-    let instance = not_started_module.not_started_instance().clone();
-    let memory = resolver.memory_ref()?;
-    Ok((instance, memory))
+    wasm_backend: WasmBackendKind,
+) -> Result<(Box<dyn WasmInstance>, Rc<dyn WasmMemory>), Error> {
+    wasm_backend.instantiate(parity_module, protocol_version)
+}
+
+/// Checks that `contract` can be called at `entry_point` with `args`.
+///
+/// Contracts which declare no entry points can only be called at `call`, and accept any `args`.
+/// Otherwise the types of `args` must match those declared for the entry point, where an arg
+/// declared as `CLType::Any` accepts a value of any type.  If the args were passed by name,
+/// they're matched to the declared args by name rather than by position.
+pub fn validate_entry_point_args(
+    contract: &Contract,
+    entry_point: &str,
+    args: &[CLValue],
+    named_args: &RuntimeArgs,
+) -> Result<(), Error> {
+    if contract.entry_points().is_empty() {
+        if entry_point == DEFAULT_ENTRY_POINT {
+            return Ok(());
+        }
+        return Err(Error::NoSuchEntryPoint(entry_point.to_string()));
+    }
+
+    let declared = contract
+        .entry_point(entry_point)
+        .ok_or_else(|| Error::NoSuchEntryPoint(entry_point.to_string()))?;
+
+    let is_valid_type = |parameter: &Parameter, arg: &CLValue| -> bool {
+        *parameter.cl_type() == CLType::Any || parameter.cl_type() == arg.cl_type()
+    };
+
+    // Args passed by position must match the declared args in number and order.  Args passed by
+    // name are matched by name only, so callers may pass args the entry point doesn't declare.
+    let is_valid = if named_args.is_empty() {
+        declared.args().len() == args.len()
+            && declared
+                .args()
+                .iter()
+                .zip(args)
+                .all(|(parameter, arg)| is_valid_type(parameter, arg))
+    } else {
+        declared.args().iter().all(|parameter| {
+            named_args
+                .get(parameter.name())
+                .map_or(false, |arg| is_valid_type(parameter, arg))
+        })
+    };
+
+    if !is_valid {
+        return Err(Error::InvalidArguments {
+            entry_point: entry_point.to_string(),
+            expected: declared
+                .args()
+                .iter()
+                .map(|parameter| parameter.cl_type().clone())
+                .collect(),
+            actual: args.iter().map(|arg| arg.cl_type().clone()).collect(),
+        });
+    }
+
+    Ok(())
 }
 
 /// Turns `key` into a `([u8; 32], AccessRights)` tuple.
@@ -1347,13 +1414,15 @@ where
     pub fn new(
         config: EngineConfig,
         system_contract_cache: SystemContractCache,
-        memory: MemoryRef,
+        module_cache: ModuleCache,
+        memory: Rc<dyn WasmMemory>,
         module: Module,
         context: RuntimeContext<'a, R>,
     ) -> Self {
         Runtime {
             config,
             system_contract_cache,
+            module_cache,
             memory,
             module,
             host_buffer: None,
@@ -1361,8 +1430,8 @@ where
         }
     }
 
-    pub fn memory(&self) -> &MemoryRef {
-        &self.memory
+    pub fn memory(&self) -> &dyn WasmMemory {
+        &*self.memory
     }
 
     pub fn module(&self) -> &Module {
@@ -1403,6 +1472,17 @@ where
         }
     }
 
+    /// Charges for a call to a host function, given the sizes of the arguments it was passed in
+    /// Wasm memory.
+    fn charge_host_function_call(
+        &mut self,
+        host_function: &HostFunctionCost,
+        arg_sizes: &[u32],
+    ) -> Result<(), Trap> {
+        let bytes_count = arg_sizes.iter().map(|&size| u64::from(size)).sum();
+        self.gas(host_function.calculate_gas(bytes_count))
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
         self.memory.get(ptr, size).map_err(Into::into)
     }
@@ -1430,29 +1510,55 @@ where
 
     fn get_function_by_name(&mut self, name_ptr: u32, name_size: u32) -> Result<Vec<u8>, Trap> {
         let name = self.string_from_mem(name_ptr, name_size)?;
+        let mut module = self.module_with_exports(&[name.clone()])?;
+        rename_export_to_call(&mut module, name);
+        parity_wasm::serialize(module).map_err(|e| Error::ParityWasm(e).into())
+    }
 
-        let has_name: bool = self
-            .module
-            .export_section()
-            .and_then(|export_section| {
-                export_section
-                    .entries()
-                    .iter()
-                    .find(|export_entry| export_entry.field() == name)
-            })
-            .is_some();
-
-        if has_name {
-            let mut module = self.module.clone();
-            // We only want the function exported under `name` to be callable; `optimize` removes
-            // all code that is not reachable from the exports listed in the second argument.
-            pwasm_utils::optimize(&mut module, vec![&name]).unwrap();
-            rename_export_to_call(&mut module, name);
-
-            parity_wasm::serialize(module).map_err(|e| Error::ParityWasm(e).into())
-        } else {
-            Err(Error::FunctionNotFound(name).into())
+    /// Reads a list of entry points from the Wasm memory, and returns the serialized module
+    /// exporting only the functions they name.
+    fn get_functions_by_entry_points(
+        &mut self,
+        entry_points_ptr: u32,
+        entry_points_size: u32,
+    ) -> Result<(Vec<u8>, Vec<EntryPoint>), Trap> {
+        let bytes = self.bytes_from_mem(entry_points_ptr, entry_points_size as usize)?;
+        let entry_points: Vec<EntryPoint> =
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?;
+        let names: Vec<String> = entry_points
+            .iter()
+            .map(|entry_point| entry_point.name().to_string())
+            .collect();
+        let module = self.module_with_exports(&names)?;
+        let module_bytes = parity_wasm::serialize(module).map_err(Error::ParityWasm)?;
+        Ok((module_bytes, entry_points))
+    }
+
+    /// Returns a copy of the current module stripped of everything not reachable from the
+    /// functions exported under `names`.
+    fn module_with_exports(&self, names: &[String]) -> Result<Module, Error> {
+        for name in names {
+            let has_name: bool = self
+                .module
+                .export_section()
+                .and_then(|export_section| {
+                    export_section
+                        .entries()
+                        .iter()
+                        .find(|export_entry| export_entry.field() == name)
+                })
+                .is_some();
+
+            if !has_name {
+                return Err(Error::FunctionNotFound(name.clone()));
+            }
         }
+
+        let mut module = self.module.clone();
+        // We only want the functions exported under `names` to be callable; `optimize` removes
+        // all code that is not reachable from the exports listed in the second argument.
+        pwasm_utils::optimize(&mut module, names.iter().map(String::as_str).collect()).unwrap();
+        Ok(module)
     }
 
     fn is_valid_uref(&mut self, uref_ptr: u32, uref_size: u32) -> Result<bool, Trap> {
@@ -1494,16 +1600,115 @@ where
             return Ok(Err(ApiError::OutOfMemory));
         }
 
-        if let Err(e) = self
-            .memory
-            .set(output_ptr, &arg.inner_bytes()[..output_size])
-        {
+        if let Err(e) = self.memory.set(output_ptr, arg.inner_bytes()) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn get_named_arg_size(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+
+        let arg_size = match self.context.named_args().get(&name) {
+            Some(arg) if arg.inner_bytes().len() > u32::max_value() as usize => {
+                return Ok(Err(ApiError::OutOfMemory))
+            }
+            None => return Ok(Err(ApiError::MissingArgument)),
+            Some(arg) => arg.inner_bytes().len() as u32,
+        };
+
+        let arg_size_bytes = arg_size.to_le_bytes(); // Wasm is little-endian
+
+        if let Err(e) = self.memory.set(size_ptr, &arg_size_bytes) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn get_named_arg(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+
+        let arg = match self.context.named_args().get(&name) {
+            Some(arg) => arg,
+            None => return Ok(Err(ApiError::MissingArgument)),
+        };
+
+        if arg.inner_bytes().len() > output_size {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        if let Err(e) = self.memory.set(output_ptr, arg.inner_bytes()) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the BLAKE2b hash of the given bytes into the Wasm memory.
+    fn blake2b(
+        &mut self,
+        input_ptr: u32,
+        input_size: u32,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if output_size < BLAKE2B_DIGEST_LENGTH {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+
+        let input = self.bytes_from_mem(input_ptr, input_size as usize)?;
+        let digest = Blake2bHash::new(&input);
+
+        if let Err(e) = self.memory.set(output_ptr, &digest.value()) {
             return Err(Error::Interpreter(e).into());
         }
 
         Ok(Ok(()))
     }
 
+    fn ed25519_verify(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: u32,
+        message_ptr: u32,
+        message_size: u32,
+        signature_ptr: u32,
+        signature_size: u32,
+    ) -> Result<bool, Trap> {
+        let public_key = self.bytes_from_mem(public_key_ptr, public_key_size as usize)?;
+        let message = self.bytes_from_mem(message_ptr, message_size as usize)?;
+        let signature = self.bytes_from_mem(signature_ptr, signature_size as usize)?;
+        Ok(crypto::ed25519_verify(&public_key, &message, &signature))
+    }
+
+    fn secp256k1_verify(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: u32,
+        digest_ptr: u32,
+        digest_size: u32,
+        signature_ptr: u32,
+        signature_size: u32,
+    ) -> Result<bool, Trap> {
+        let public_key = self.bytes_from_mem(public_key_ptr, public_key_size as usize)?;
+        let digest = self.bytes_from_mem(digest_ptr, digest_size as usize)?;
+        let signature = self.bytes_from_mem(signature_ptr, signature_size as usize)?;
+        Ok(crypto::secp256k1_verify(&public_key, &digest, &signature))
+    }
+
     /// Load the uref known by the given name into the Wasm memory
     fn load_key(
         &mut self,
@@ -1626,6 +1831,27 @@ where
             .map_err(|e| Error::Interpreter(e).into())
     }
 
+    /// Writes `dest_size` bytes from the deploy's deterministic random generator into the Wasm
+    /// memory.
+    fn random_bytes(&mut self, dest_ptr: u32, dest_size: u32) -> Result<(), Trap> {
+        // Check the destination before allocating for it, so a contract can't make the host
+        // allocate more than its own memory holds.
+        let memory_size = self.memory.size();
+        let dest_end = u64::from(dest_ptr) + u64::from(dest_size);
+        if dest_end > memory_size as u64 {
+            return Err(Error::Interpreter(wasmi::Error::Memory(format!(
+                "trying to access region [{}..{}] in memory [0..{}]",
+                dest_ptr, dest_end, memory_size
+            )))
+            .into());
+        }
+        let mut bytes = vec![0u8; dest_size as usize];
+        self.context.random_bytes(&mut bytes);
+        self.memory
+            .set(dest_ptr, &bytes)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
     /// Return some bytes from the memory and terminate the current `sub_call`. Note that the return
     /// type is `Trap`, indicating that this function will always kill the current Wasm instance.
     fn ret(&mut self, value_ptr: u32, value_size: usize) -> Trap {
@@ -1710,6 +1936,8 @@ where
         let gas_counter = self.context.gas_counter();
         let fn_store_id = self.context.fn_store_id();
         let address_generator = self.context.address_generator();
+        let random_generator = self.context.random_generator();
+        let events = self.context.events();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1719,6 +1947,7 @@ where
             &mut named_keys,
             access_rights,
             args.to_owned(),
+            RuntimeArgs::new(),
             authorization_keys,
             account,
             base_key,
@@ -1728,6 +1957,8 @@ where
             gas_counter,
             fn_store_id,
             address_generator,
+            random_generator,
+            events,
             protocol_version,
             correlation_id,
             phase,
@@ -1784,6 +2015,9 @@ where
         const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
         const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
         const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+        const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+        const METHOD_SLASH: &str = "slash";
+        const METHOD_UNBOND_PAYOUT: &str = "unbond_payout";
 
         let state = self.context.state();
         let access_rights = {
@@ -1802,6 +2036,8 @@ where
         let gas_counter = self.context.gas_counter();
         let fn_store_id = self.context.fn_store_id();
         let address_generator = self.context.address_generator();
+        let random_generator = self.context.random_generator();
+        let events = self.context.events();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1811,6 +2047,7 @@ where
             &mut named_keys,
             access_rights,
             args.to_owned(),
+            RuntimeArgs::new(),
             authorization_keys,
             account,
             base_key,
@@ -1820,6 +2057,8 @@ where
             gas_counter,
             fn_store_id,
             address_generator,
+            random_generator,
+            events,
             protocol_version,
             correlation_id,
             phase,
@@ -1829,6 +2068,7 @@ where
         let mut runtime = Runtime::new(
             self.config,
             SystemContractCache::clone(&self.system_contract_cache),
+            ModuleCache::clone(&self.module_cache),
             self.memory.clone(),
             self.module.clone(),
             runtime_context,
@@ -1886,6 +2126,23 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_DISTRIBUTE_REWARDS => {
+                let rewards: Vec<(PublicKey, U512)> = Self::get_argument(&args, 1)?;
+                runtime
+                    .distribute_rewards(rewards)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_SLASH => {
+                let slashes: Vec<(PublicKey, U512)> = Self::get_argument(&args, 1)?;
+                runtime.slash(slashes).map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_UNBOND_PAYOUT => {
+                let era_height: u64 = Self::get_argument(&args, 1)?;
+                runtime.unbond_payout(era_height).map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1905,6 +2162,17 @@ where
 
     /// Calls contract living under a `key`, with supplied `args`.
     pub fn call_contract(&mut self, key: Key, args_bytes: Vec<u8>) -> Result<CLValue, Error> {
+        self.call_entry_point(key, DEFAULT_ENTRY_POINT, args_bytes)
+    }
+
+    /// Calls the function exported as `entry_point` by the contract living under a `key`, with
+    /// supplied `args`.
+    pub fn call_entry_point(
+        &mut self,
+        key: Key,
+        entry_point: &str,
+        args_bytes: Vec<u8>,
+    ) -> Result<CLValue, Error> {
         let contract = match self.context.read_gs(&key)? {
             Some(StoredValue::Contract(contract)) => contract,
             Some(_) => {
@@ -1926,7 +2194,8 @@ where
             });
         }
 
-        let args: Vec<CLValue> = bytesrepr::deserialize(args_bytes)?;
+        let (args, named_args) = deserialize_args(args_bytes)?;
+        validate_entry_point_args(&contract, entry_point, &args, &named_args)?;
 
         let mut extra_urefs = vec![];
         // A loop is needed to be able to use the '?' operator
@@ -1966,12 +2235,23 @@ where
 
         let module = match maybe_module {
             Some(module) => module,
-            None => parity_wasm::deserialize_buffer(contract.bytes())?,
+            None => {
+                let key = ModuleCacheKey::new(
+                    contract.bytes(),
+                    self.context.protocol_data().wasm_costs(),
+                    current_version,
+                )?;
+                self.module_cache
+                    .get_or_insert_with(self.context.correlation_id(), key, || {
+                        parity_wasm::deserialize_buffer(contract.bytes()).map_err(Error::from)
+                    })?
+            }
         };
 
         let mut named_keys = contract.take_named_keys();
 
-        let (instance, memory) = instance_and_memory(module.clone(), contract_version)?;
+        let (instance, memory) =
+            instance_and_memory(module.clone(), contract_version, self.config.wasm_backend())?;
 
         let access_rights = {
             let mut keys: Vec<Key> = named_keys.values().cloned().collect();
@@ -1983,6 +2263,8 @@ where
 
         let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
+        let module_cache = ModuleCache::clone(&self.module_cache);
+
         let config = self.config;
 
         let host_buffer = None;
@@ -1992,6 +2274,7 @@ where
             &mut named_keys,
             access_rights,
             args,
+            named_args,
             self.context.authorization_keys().clone(),
             &self.context.account(),
             key,
@@ -2001,6 +2284,8 @@ where
             self.context.gas_counter(),
             self.context.fn_store_id(),
             self.context.address_generator(),
+            self.context.random_generator(),
+            self.context.events(),
             contract_version,
             self.context.correlation_id(),
             self.context.phase(),
@@ -2009,6 +2294,7 @@ where
 
         let mut runtime = Runtime {
             system_contract_cache,
+            module_cache,
             config,
             memory,
             module,
@@ -2016,7 +2302,7 @@ where
             context,
         };
 
-        let result = instance.invoke_export("call", &[], &mut runtime);
+        let result = instance.invoke_export(entry_point, &mut runtime);
 
         // The `runtime`'s context was initialized with our counter from before the call and any gas
         // charged by the sub-call was added to its counter - so let's copy the correct value of the
@@ -2074,6 +2360,68 @@ where
         }
 
         let result = self.call_contract(key, args_bytes)?;
+        self.write_call_result(result, result_size_ptr)
+    }
+
+    fn call_entry_point_host_buffer(
+        &mut self,
+        key: Key,
+        entry_point: String,
+        args_bytes: Vec<u8>,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let result = self.call_entry_point(key, &entry_point, args_bytes)?;
+        self.write_call_result(result, result_size_ptr)
+    }
+
+    /// Calls the contract at `key` as `call_contract_host_buffer` does, except that a revert of
+    /// the callee is returned to the caller rather than ending execution.
+    ///
+    /// The writes and events of a reverted callee are discarded, but the gas it used is still
+    /// charged.
+    fn try_call_contract_host_buffer(
+        &mut self,
+        key: Key,
+        args_bytes: Vec<u8>,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let state = self.context.state();
+        let events = self.context.events();
+        let snapshot = state.borrow().snapshot();
+        let events_count = events.borrow().len();
+
+        match self.call_contract(key, args_bytes) {
+            Ok(result) => self.write_call_result(result, result_size_ptr),
+            Err(Error::Revert(status)) => {
+                state.borrow_mut().restore(snapshot);
+                events.borrow_mut().truncate(events_count);
+                // A status of 0 would read as success, so it's reported as unhandled instead
+                let error = api_error::result_from(status as i32)
+                    .err()
+                    .unwrap_or(ApiError::Unhandled);
+                Ok(Err(error))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Writes the value returned by a called contract to the host buffer, and its size to
+    /// `result_size_ptr`.
+    fn write_call_result(
+        &mut self,
+        result: CLValue,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
         let result_size = result.inner_bytes().len() as u32; // considered to be safe
 
         // leave the host buffer set to `None` if there's nothing to write there
@@ -2131,9 +2479,15 @@ where
     fn store_function(
         &mut self,
         fn_bytes: Vec<u8>,
+        entry_points: Vec<EntryPoint>,
         named_keys: BTreeMap<String, Key>,
     ) -> Result<[u8; 32], Error> {
-        let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version());
+        let contract = Contract::with_entry_points(
+            fn_bytes,
+            entry_points,
+            named_keys,
+            self.context.protocol_version(),
+        );
         let contract_addr = self
             .context
             .store_function(StoredValue::Contract(contract))?;
@@ -2146,9 +2500,15 @@ where
     fn store_function_at_hash(
         &mut self,
         fn_bytes: Vec<u8>,
+        entry_points: Vec<EntryPoint>,
         named_keys: BTreeMap<String, Key>,
     ) -> Result<[u8; 32], Error> {
-        let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version());
+        let contract = Contract::with_entry_points(
+            fn_bytes,
+            entry_points,
+            named_keys,
+            self.context.protocol_version(),
+        );
         let new_hash = self
             .context
             .store_function_at_hash(StoredValue::Contract(contract))?;
@@ -2188,6 +2548,26 @@ where
             .map_err(Into::into)
     }
 
+    /// Removes the value under `key` from GlobalState
+    fn remove(&mut self, key_ptr: u32, key_size: u32) -> Result<(), Trap> {
+        let key = self.key_from_mem(key_ptr, key_size)?;
+        self.context.delete_gs(key).map_err(Into::into)
+    }
+
+    /// Records an event named by the string at `name_ptr` carrying the `CLValue` at `value_ptr`.
+    fn emit_event(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<(), Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        let value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context.emit_event(name, value);
+        Ok(())
+    }
+
     /// Writes `value` under a key derived from `key` in the "local cluster" of
     /// GlobalState
     fn write_local(
@@ -1,22 +1,77 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
     fmt::Write,
 };
 
 use engine_shared::stored_value::StoredValue;
 use engine_storage::global_state::StateReader;
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    EraProvider, MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes,
+    StakesProvider,
 };
 use types::{
     account::PublicKey, bytesrepr::ToBytes, system_contract_errors::pos::Error, ApiError,
     BlockTime, CLValue, Key, Phase, TransferredTo, URef, U512,
 };
 
-use crate::{execution, runtime::Runtime};
+use crate::{
+    engine_state::genesis::{POS_BONDING_DELAY, POS_UNBONDING_DELAY},
+    execution,
+    runtime::Runtime,
+};
 
 const BONDING_KEY: u8 = 1;
 const UNBONDING_KEY: u8 = 2;
+const ERA_HEIGHT_KEY: u8 = 3;
+
+// TODO: Update EraProvider to better handle errors
+impl<'a, R> EraProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_era_height(&mut self) -> u64 {
+        let key = ERA_HEIGHT_KEY.to_bytes().expect("should serialize");
+        match self.context.read_ls(&key) {
+            Ok(Some(cl_value)) => cl_value.into_t().expect("should convert"),
+            _ => 0,
+        }
+    }
+
+    fn write_era_height(&mut self, era_height: u64) -> Result<(), Error> {
+        let key = ERA_HEIGHT_KEY
+            .to_bytes()
+            .map_err(|_| Error::EraHeightWriteFailed)?;
+        let value = CLValue::from_t(era_height).map_err(|_| Error::EraHeightWriteFailed)?;
+        self.context
+            .write_ls(&key, value)
+            .map_err(|_| Error::EraHeightWriteFailed)
+    }
+
+    fn read_bonding_delay(&mut self) -> Result<u64, Error> {
+        read_delay(self, POS_BONDING_DELAY).ok_or(Error::BondingDelayNotFound)
+    }
+
+    fn read_unbonding_delay(&mut self) -> Result<u64, Error> {
+        read_delay(self, POS_UNBONDING_DELAY).ok_or(Error::UnbondingDelayNotFound)
+    }
+}
+
+fn read_delay<R>(runtime: &mut Runtime<R>, name: &str) -> Option<u64>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let key = match runtime.context.named_keys_get(name) {
+        Some(key @ Key::URef(_)) => *key,
+        _ => return None,
+    };
+    match runtime.context.read_gs(&key) {
+        Ok(Some(StoredValue::CLValue(cl_value))) => cl_value.into_t().ok(),
+        _ => None,
+    }
+}
 
 // TODO: Update MintProvider to better handle errors
 impl<'a, R> MintProvider for Runtime<'a, R>
@@ -143,14 +198,10 @@ where
             let hex_key = split_name
                 .next()
                 .ok_or(Error::StakesKeyDeserializationFailed)?;
-            if hex_key.len() != 64 {
-                return Err(Error::StakesKeyDeserializationFailed);
-            }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+            let key_bytes =
+                base16::decode(hex_key).map_err(|_| Error::StakesKeyDeserializationFailed)?;
+            let pub_key = PublicKey::try_from(key_bytes.as_slice())
                 .map_err(|_| Error::StakesKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            let pub_key = PublicKey::ed25519_from(key_bytes);
             let balance = split_name
                 .next()
                 .and_then(|b| U512::from_dec_str(b).ok())
@@ -169,9 +220,9 @@ where
             .0
             .iter()
             .map(|(pub_key, balance)| {
-                let key_bytes = pub_key.value();
-                let mut hex_key = String::with_capacity(64);
-                for byte in &key_bytes[..32] {
+                let key_bytes = pub_key.as_bytes();
+                let mut hex_key = String::with_capacity(2 * key_bytes.len());
+                for byte in key_bytes {
                     write!(hex_key, "{:02x}", byte).expect("Writing to a string cannot fail");
                 }
                 let mut uref = String::new();
@@ -22,13 +22,16 @@ use types::{
         UpdateKeyFailure, Weight,
     },
     bytesrepr::{self, ToBytes},
-    AccessRights, BlockTime, CLType, CLValue, Key, Phase, ProtocolVersion, URef,
+    AccessRights, BlockTime, CLType, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
     KEY_LOCAL_SEED_LENGTH,
 };
 
 use crate::{
-    engine_state::{execution_effect::ExecutionEffect, SYSTEM_ACCOUNT_ADDR},
-    execution::{AddressGenerator, Error},
+    engine_state::{
+        execution_effect::{Event, ExecutionEffect},
+        utils, SYSTEM_ACCOUNT_ADDR,
+    },
+    execution::{AddressGenerator, Error, RandomGenerator},
     tracking_copy::{AddResult, TrackingCopy},
     Address,
 };
@@ -60,6 +63,8 @@ pub struct RuntimeContext<'a, R> {
     // Original account for read only tasks taken before execution
     account: &'a Account,
     args: Vec<CLValue>,
+    // The same args as `args`, by name, if they were passed as `RuntimeArgs`
+    named_args: RuntimeArgs,
     authorization_keys: BTreeSet<PublicKey>,
     // Key pointing to the entity we are currently running
     //(could point at an account or contract in the global state)
@@ -70,6 +75,10 @@ pub struct RuntimeContext<'a, R> {
     gas_counter: Gas,
     fn_store_id: u32,
     address_generator: Rc<RefCell<AddressGenerator>>,
+    // Backs `random_bytes`, shared with the contexts of called contracts
+    random_generator: Rc<RefCell<RandomGenerator>>,
+    // Events emitted so far by the deploy, shared with the contexts of called contracts
+    events: Rc<RefCell<Vec<Event>>>,
     protocol_version: ProtocolVersion,
     correlation_id: CorrelationId,
     phase: Phase,
@@ -87,6 +96,7 @@ where
         named_keys: &'a mut BTreeMap<String, Key>,
         access_rights: HashMap<Address, HashSet<AccessRights>>,
         args: Vec<CLValue>,
+        named_args: RuntimeArgs,
         authorization_keys: BTreeSet<PublicKey>,
         account: &'a Account,
         base_key: Key,
@@ -96,6 +106,8 @@ where
         gas_counter: Gas,
         fn_store_id: u32,
         address_generator: Rc<RefCell<AddressGenerator>>,
+        random_generator: Rc<RefCell<RandomGenerator>>,
+        events: Rc<RefCell<Vec<Event>>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         phase: Phase,
@@ -106,6 +118,7 @@ where
             named_keys,
             access_rights,
             args,
+            named_args,
             account,
             authorization_keys,
             blocktime,
@@ -115,6 +128,8 @@ where
             gas_counter,
             fn_store_id,
             address_generator,
+            random_generator,
+            events,
             protocol_version,
             correlation_id,
             phase,
@@ -227,10 +242,32 @@ where
         &self.args
     }
 
+    pub fn named_args(&self) -> &RuntimeArgs {
+        &self.named_args
+    }
+
     pub fn address_generator(&self) -> Rc<RefCell<AddressGenerator>> {
         Rc::clone(&self.address_generator)
     }
 
+    pub fn random_generator(&self) -> Rc<RefCell<RandomGenerator>> {
+        Rc::clone(&self.random_generator)
+    }
+
+    /// Fills `dest` with bytes from the deploy's deterministic random generator.
+    pub fn random_bytes(&mut self, dest: &mut [u8]) {
+        self.random_generator.borrow_mut().fill_bytes(dest)
+    }
+
+    pub fn events(&self) -> Rc<RefCell<Vec<Event>>> {
+        Rc::clone(&self.events)
+    }
+
+    /// Records an event, to be returned along with the effect of the deploy.
+    pub fn emit_event(&mut self, name: String, value: CLValue) {
+        self.events.borrow_mut().push(Event::new(name, value));
+    }
+
     pub fn state(&self) -> Rc<RefCell<TrackingCopy<R>>> {
         Rc::clone(&self.state)
     }
@@ -257,7 +294,7 @@ where
 
     pub fn seed(&self) -> [u8; KEY_LOCAL_SEED_LENGTH] {
         match self.base_key {
-            Key::Account(PublicKey::Ed25519(bytes)) => bytes.value(),
+            Key::Account(public_key) => utils::public_key_id(public_key),
             Key::Hash(bytes) => bytes,
             Key::URef(uref) => uref.addr(),
             Key::Local { seed, .. } => seed,
@@ -401,6 +438,13 @@ where
         Ok(())
     }
 
+    pub fn delete_gs(&mut self, key: Key) -> Result<(), Error> {
+        self.validate_writeable(&key)?;
+        self.validate_key(&key)?;
+        self.state.borrow_mut().delete(key);
+        Ok(())
+    }
+
     pub fn read_account(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         if let Key::Account(_) = key {
             self.validate_key(key)?;
@@ -459,7 +503,9 @@ where
     }
 
     pub fn effect(&self) -> ExecutionEffect {
-        self.state.borrow_mut().effect()
+        let mut effect = self.state.borrow_mut().effect();
+        effect.events = self.events.borrow().clone();
+        effect
     }
 
     /// Validates whether keys used in the `value` are not forged.
@@ -24,13 +24,16 @@ use types::{
     account::{
         ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure, Weight,
     },
-    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, URef, KEY_LOCAL_SEED_LENGTH,
+    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
+    KEY_LOCAL_SEED_LENGTH,
 };
 
 use super::{attenuate_uref_for_account, Address, Error, RuntimeContext};
 use crate::{
-    engine_state::SYSTEM_ACCOUNT_ADDR, execution::AddressGenerator,
-    runtime::extract_access_rights_from_keys, tracking_copy::TrackingCopy,
+    engine_state::SYSTEM_ACCOUNT_ADDR,
+    execution::{AddressGenerator, RandomGenerator},
+    runtime::extract_access_rights_from_keys,
+    tracking_copy::TrackingCopy,
 };
 
 const DEPLOY_HASH: [u8; 32] = [1u8; 32];
@@ -118,6 +121,7 @@ fn mock_runtime_context<'a>(
         named_keys,
         access_rights,
         Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from([0; 32])]),
         &account,
         base_key,
@@ -127,6 +131,12 @@ fn mock_runtime_context<'a>(
         Gas::default(),
         0,
         Rc::new(RefCell::new(address_generator)),
+        Rc::new(RefCell::new(RandomGenerator::new(
+            &DEPLOY_HASH,
+            PHASE,
+            BlockTime::new(0),
+        ))),
+        Rc::new(RefCell::new(Vec::new())),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         Phase::Session,
@@ -449,6 +459,7 @@ fn contract_key_addable_valid() {
         &mut uref_map,
         access_rights,
         Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         contract_key,
@@ -458,6 +469,12 @@ fn contract_key_addable_valid() {
         Gas::default(),
         0,
         Rc::new(RefCell::new(address_generator)),
+        Rc::new(RefCell::new(RandomGenerator::new(
+            &DEPLOY_HASH,
+            PHASE,
+            BlockTime::new(0),
+        ))),
+        Rc::new(RefCell::new(Vec::new())),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -511,6 +528,7 @@ fn contract_key_addable_invalid() {
         &mut uref_map,
         access_rights,
         Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         other_contract_key,
@@ -520,6 +538,12 @@ fn contract_key_addable_invalid() {
         Gas::default(),
         0,
         Rc::new(RefCell::new(address_generator)),
+        Rc::new(RefCell::new(RandomGenerator::new(
+            &DEPLOY_HASH,
+            PHASE,
+            BlockTime::new(0),
+        ))),
+        Rc::new(RefCell::new(Vec::new())),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -6,6 +6,7 @@ pub trait Meter<K, V> {
 pub mod heap_meter {
     use crate::tracking_copy::byte_size::ByteSize;
 
+    #[derive(Clone)]
     pub struct HeapSize;
 
     impl<K: ByteSize, V: ByteSize> super::Meter<K, V> for HeapSize {
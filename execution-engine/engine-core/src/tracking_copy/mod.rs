@@ -5,7 +5,7 @@ pub(self) mod meter;
 mod tests;
 
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
     convert::From,
     iter,
 };
@@ -19,7 +19,7 @@ use engine_shared::{
     transform::{self, Transform},
     TypeMismatch,
 };
-use engine_storage::global_state::StateReader;
+use engine_storage::{global_state::StateReader, trie::merkle_proof::TrieMerkleProof};
 use types::{bytesrepr, CLType, CLValueError, Key};
 
 use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
@@ -95,11 +95,13 @@ impl Query {
 /// Keeps track of already accessed keys.
 /// We deliberately separate cached Reads from cached mutations
 /// because we want to invalidate Reads' cache so it doesn't grow too fast.
+#[derive(Clone)]
 pub struct TrackingCopyCache<M> {
     max_cache_size: usize,
     current_cache_size: usize,
     reads_cached: LinkedHashMap<Key, StoredValue>,
     muts_cached: HashMap<Key, StoredValue>,
+    deletes_cached: HashSet<Key>,
     meter: M,
 }
 
@@ -114,6 +116,7 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
             current_cache_size: 0,
             reads_cached: LinkedHashMap::new(),
             muts_cached: HashMap::new(),
+            deletes_cached: HashSet::new(),
             meter,
         }
     }
@@ -136,9 +139,24 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
 
     /// Inserts `key` and `value` pair to Write/Add cache.
     pub fn insert_write(&mut self, key: Key, value: StoredValue) {
+        self.deletes_cached.remove(&key);
         self.muts_cached.insert(key, value);
     }
 
+    /// Marks `key` as deleted, evicting any value cached for it.
+    pub fn insert_delete(&mut self, key: Key) {
+        if let Some(value) = self.reads_cached.remove(&key) {
+            self.current_cache_size -= Meter::measure(&self.meter, &key, &value);
+        }
+        self.muts_cached.remove(&key);
+        self.deletes_cached.insert(key);
+    }
+
+    /// Returns `true` if `key` has been deleted.
+    pub fn is_deleted(&self, key: &Key) -> bool {
+        self.deletes_cached.contains(key)
+    }
+
     /// Gets value from `key` in the cache.
     pub fn get(&mut self, key: &Key) -> Option<&StoredValue> {
         if let Some(value) = self.muts_cached.get(&key) {
@@ -154,6 +172,15 @@ pub struct TrackingCopy<R> {
     cache: TrackingCopyCache<HeapSize>,
     ops: AdditiveMap<Key, Op>,
     fns: AdditiveMap<Key, Transform>,
+    discarded_reads: BTreeSet<Key>,
+}
+
+/// The cached values and effect of a [`TrackingCopy`] at some point, which it can be reset to with
+/// [`TrackingCopy::restore`].
+pub struct TrackingCopySnapshot {
+    cache: TrackingCopyCache<HeapSize>,
+    ops: AdditiveMap<Key, Op>,
+    fns: AdditiveMap<Key, Transform>,
 }
 
 #[derive(Debug)]
@@ -186,6 +213,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                                                                  * limit? */
             ops: AdditiveMap::new(),
             fns: AdditiveMap::new(),
+            discarded_reads: BTreeSet::new(),
         }
     }
 
@@ -200,20 +228,50 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
     /// allows isolating a specific set of changes (those in the new
     /// `TrackingCopy`) from existing changes. Note that mutations to state
     /// caused by new changes (i.e. writes and adds) only impact the new
-    /// `TrackingCopy`, not this one. Note that currently there is no `join` /
-    /// `merge` function to bring changes from a fork back to the main
-    /// `TrackingCopy`. this means the current usage requires repeated
-    /// forking, however we recognize this is sub-optimal and will revisit
-    /// in the future.
+    /// `TrackingCopy`, not this one. The changes made in a fork can be brought
+    /// back to this `TrackingCopy` by passing the fork's `effect()` to
+    /// `apply_effect`.
     pub fn fork(&self) -> TrackingCopy<&TrackingCopy<R>> {
         TrackingCopy::new(self)
     }
 
+    /// Takes a snapshot of `self`, so that any changes made after it can be discarded.
+    ///
+    /// Unlike a fork, the snapshot leaves the type of the tracking copy unchanged, which allows
+    /// code generic over the reader to run on it.
+    pub fn snapshot(&self) -> TrackingCopySnapshot {
+        TrackingCopySnapshot {
+            cache: self.cache.clone(),
+            ops: self.ops.clone(),
+            fns: self.fns.clone(),
+        }
+    }
+
+    /// Discards all changes made since `snapshot` was taken.
+    ///
+    /// The keys those changes may have read are kept as the discarded reads of the effect, since
+    /// whatever comes after the changes may have been decided by them.  Only keys which were just
+    /// added to are left out.
+    pub fn restore(&mut self, snapshot: TrackingCopySnapshot) {
+        let discarded_reads = self
+            .ops
+            .iter()
+            .filter(|(key, op)| **op != Op::Add && snapshot.ops.get(*key) != Some(*op))
+            .map(|(key, _)| *key);
+        self.discarded_reads.extend(discarded_reads);
+        self.cache = snapshot.cache;
+        self.ops = snapshot.ops;
+        self.fns = snapshot.fns;
+    }
+
     pub fn get(
         &mut self,
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, R::Error> {
+        if self.cache.is_deleted(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.get(key) {
             return Ok(Some(value.to_owned()));
         }
@@ -247,6 +305,15 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         self.fns.insert_add(normalized_key, Transform::Write(value));
     }
 
+    /// Removes the value under `key` from global state.  Deleting a key which has no value is not
+    /// an error.
+    pub fn delete(&mut self, key: Key) {
+        let normalized_key = key.normalize();
+        self.cache.insert_delete(normalized_key);
+        self.ops.insert_add(normalized_key, Op::Write);
+        self.fns.insert_add(normalized_key, Transform::Delete);
+    }
+
     /// Ok(None) represents missing key to which we want to "add" some value.
     /// Ok(Some(unit)) represents successful operation.
     /// Err(error) is reserved for unexpected errors when accessing global
@@ -324,8 +391,69 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         }
     }
 
+    /// Applies the transforms of `effect` on top of this `TrackingCopy`, as if the operations
+    /// which produced them had been run against it directly. Subsequent reads (including those
+    /// made through forks) observe the resulting values, and the effect is folded into the one
+    /// returned by `effect()`.
+    ///
+    /// If any of the transforms can't be applied, none of them are.
+    pub fn apply_effect(
+        &mut self,
+        correlation_id: CorrelationId,
+        effect: ExecutionEffect,
+    ) -> Result<AddResult, R::Error> {
+        let mut new_values = Vec::new();
+        for (key, transform) in effect.transforms.iter() {
+            let normalized_key = key.normalize();
+            let new_value = match transform {
+                // A read doesn't change the value, so there's nothing to cache.
+                Transform::Identity => continue,
+                Transform::Write(value) => Some(value.clone()),
+                Transform::Delete => None,
+                transform => {
+                    let current_value = match self.get(correlation_id, &normalized_key)? {
+                        None => return Ok(AddResult::KeyNotFound(normalized_key)),
+                        Some(current_value) => current_value,
+                    };
+                    match transform.clone().apply(current_value) {
+                        Ok(new_value) => Some(new_value),
+                        Err(transform::Error::TypeMismatch(type_mismatch)) => {
+                            return Ok(AddResult::TypeMismatch(type_mismatch))
+                        }
+                        Err(transform::Error::Serialization(error)) => {
+                            return Ok(AddResult::Serialization(error))
+                        }
+                    }
+                }
+            };
+            new_values.push((normalized_key, new_value));
+        }
+
+        for (key, maybe_value) in new_values {
+            match maybe_value {
+                Some(value) => self.cache.insert_write(key, value),
+                None => self.cache.insert_delete(key),
+            }
+        }
+        let ExecutionEffect {
+            ops,
+            transforms,
+            discarded_reads,
+            ..
+        } = effect;
+        self.discarded_reads.extend(discarded_reads);
+        for (key, transform) in transforms {
+            let op = ops.get(&key).copied().unwrap_or_default();
+            self.ops.insert_add(key.normalize(), op);
+            self.fns.insert_add(key.normalize(), transform);
+        }
+        Ok(AddResult::Success)
+    }
+
     pub fn effect(&self) -> ExecutionEffect {
-        ExecutionEffect::new(self.ops.clone(), self.fns.clone())
+        let mut effect = ExecutionEffect::new(self.ops.clone(), self.fns.clone());
+        effect.discarded_reads = self.discarded_reads.clone();
+        effect
     }
 
     /// Calling `query()` avoids calling into `self.cache`, so this will not return any values
@@ -341,60 +469,97 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         base_key: Key,
         path: &[String],
     ) -> Result<TrackingCopyQueryResult, R::Error> {
-        let mut query = Query::new(base_key, path);
-        loop {
-            if !query.visited_keys.insert(query.current_key) {
-                return Ok(query.into_circular_ref_result());
-            }
-            let stored_value = match self.reader.read(correlation_id, &query.current_key)? {
-                None => {
-                    return Ok(query.into_not_found_result("Failed to find base key"));
-                }
-                Some(stored_value) => stored_value,
-            };
+        run_query(base_key, path, |key| self.reader.read(correlation_id, key))
+    }
+
+    /// Like `query()`, but also returns a proof for every key visited by the query, in order.  The
+    /// last proof is either a proof of the inclusion of the queried value, or a proof of the
+    /// exclusion of the key which couldn't be found.
+    pub fn query_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        base_key: Key,
+        path: &[String],
+    ) -> Result<
+        (
+            TrackingCopyQueryResult,
+            Vec<TrieMerkleProof<Key, StoredValue>>,
+        ),
+        R::Error,
+    > {
+        let mut proofs = Vec::new();
+        let result = run_query(base_key, path, |key| {
+            let proof = self.reader.read_with_proof(correlation_id, key)?;
+            let value = proof.value().cloned();
+            proofs.push(proof);
+            Ok(value)
+        })?;
+        Ok((result, proofs))
+    }
+}
 
-            if query.unvisited_names.is_empty() {
-                return Ok(TrackingCopyQueryResult::Success(stored_value));
+/// Follows `path` from `base_key`, reading each visited key with `read`.
+fn run_query<E, F>(
+    base_key: Key,
+    path: &[String],
+    mut read: F,
+) -> Result<TrackingCopyQueryResult, E>
+where
+    F: FnMut(&Key) -> Result<Option<StoredValue>, E>,
+{
+    let mut query = Query::new(base_key, path);
+    loop {
+        if !query.visited_keys.insert(query.current_key) {
+            return Ok(query.into_circular_ref_result());
+        }
+        let stored_value = match read(&query.current_key)? {
+            None => {
+                return Ok(query.into_not_found_result("Failed to find base key"));
             }
+            Some(stored_value) => stored_value,
+        };
 
-            match stored_value {
-                StoredValue::Account(account) => {
-                    let name = query.next_name();
-                    if let Some(key) = account.named_keys().get(name) {
-                        query.current_key = key.normalize();
-                    } else {
-                        let msg_prefix = format!("Name {} not found in Account", name);
-                        return Ok(query.into_not_found_result(&msg_prefix));
-                    }
-                }
+        if query.unvisited_names.is_empty() {
+            return Ok(TrackingCopyQueryResult::Success(stored_value));
+        }
 
-                StoredValue::Contract(contract) => {
-                    let name = query.next_name();
-                    if let Some(key) = contract.named_keys().get(name) {
-                        query.current_key = key.normalize();
-                    } else {
-                        let msg_prefix = format!("Name {} not found in Contract", name);
-                        return Ok(query.into_not_found_result(&msg_prefix));
-                    }
+        match stored_value {
+            StoredValue::Account(account) => {
+                let name = query.next_name();
+                if let Some(key) = account.named_keys().get(name) {
+                    query.current_key = key.normalize();
+                } else {
+                    let msg_prefix = format!("Name {} not found in Account", name);
+                    return Ok(query.into_not_found_result(&msg_prefix));
                 }
+            }
 
-                StoredValue::CLValue(cl_value) if cl_value.cl_type() == &CLType::Key => {
-                    if let Ok(key) = cl_value.into_t::<Key>() {
-                        query.current_key = key.normalize();
-                    } else {
-                        return Ok(query.into_not_found_result("Failed to parse CLValue as Key"));
-                    }
+            StoredValue::Contract(contract) => {
+                let name = query.next_name();
+                if let Some(key) = contract.named_keys().get(name) {
+                    query.current_key = key.normalize();
+                } else {
+                    let msg_prefix = format!("Name {} not found in Contract", name);
+                    return Ok(query.into_not_found_result(&msg_prefix));
                 }
+            }
 
-                StoredValue::CLValue(cl_value) => {
-                    let msg_prefix = format!(
-                        "Query cannot continue as {:?} is not an account, contract nor key to \
-                        such.  Value found",
-                        cl_value
-                    );
-                    return Ok(query.into_not_found_result(&msg_prefix));
+            StoredValue::CLValue(cl_value) if cl_value.cl_type() == &CLType::Key => {
+                if let Ok(key) = cl_value.into_t::<Key>() {
+                    query.current_key = key.normalize();
+                } else {
+                    return Ok(query.into_not_found_result("Failed to parse CLValue as Key"));
                 }
             }
+
+            StoredValue::CLValue(cl_value) => {
+                let msg_prefix = format!(
+                    "Query cannot continue as {:?} is not an account, contract nor key to \
+                    such.  Value found",
+                    cl_value
+                );
+                return Ok(query.into_not_found_result(&msg_prefix));
+            }
         }
     }
 }
@@ -412,6 +577,9 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, Self::Error> {
+        if self.cache.is_deleted(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.muts_cached.get(key) {
             return Ok(Some(value.to_owned()));
         }
@@ -421,4 +589,14 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
             Ok(None)
         }
     }
+
+    /// Proofs are made against the state the tracking copy was created from, so they don't reflect
+    /// any cached effects.
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<TrieMerkleProof<Key, StoredValue>, Self::Error> {
+        self.reader.read_with_proof(correlation_id, key)
+    }
 }
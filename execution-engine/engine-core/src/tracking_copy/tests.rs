@@ -20,7 +20,7 @@ use types::{
 use super::{
     meter::count_meter::Count, AddResult, TrackingCopy, TrackingCopyCache, TrackingCopyQueryResult,
 };
-use crate::engine_state::op::Op;
+use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
 
 struct CountingDb {
     count: Rc<Cell<i32>>,
@@ -58,6 +58,16 @@ impl StateReader<Key, StoredValue> for CountingDb {
         self.count.set(count + 1);
         Ok(Some(value))
     }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<TrieMerkleProof<Key, StoredValue>, Self::Error> {
+        // the mocked state is a single leaf under its own root
+        let value = self.read(correlation_id, key)?.unwrap();
+        Ok(TrieMerkleProof::new(*key, vec![Trie::leaf(*key, value)]))
+    }
 }
 
 #[test]
@@ -285,6 +295,173 @@ fn tracking_copy_aw() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Write));
 }
 
+#[test]
+fn tracking_copy_delete() {
+    let correlation_id = CorrelationId::new();
+    let counter = Rc::new(Cell::new(0));
+    let db = CountingDb::new(Rc::clone(&counter));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    // reading then deleting should hide the value from subsequent reads without hitting the db
+    let _ = tc.read(correlation_id, &k);
+    tc.delete(k);
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), None);
+    assert_eq!(counter.get(), 1);
+    assert_eq!(tc.fns.len(), 1);
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Delete));
+    assert_eq!(tc.ops.len(), 1);
+    assert_eq!(tc.ops.get(&k), Some(&Op::Write));
+
+    // the deletion is visible through a fork
+    {
+        let mut fork = tc.fork();
+        assert_eq!(fork.read(correlation_id, &k).unwrap(), None);
+    }
+
+    // adding to a deleted key should fail
+    let value = StoredValue::CLValue(CLValue::from_t(3_i32).unwrap());
+    assert_matches!(
+        tc.add(correlation_id, k, value.clone()),
+        Ok(AddResult::KeyNotFound(_))
+    );
+
+    // writing after deleting restores the value
+    tc.write(k, value.clone());
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(value.clone()));
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Write(value)));
+    assert_eq!(tc.ops.get(&k), Some(&Op::Write));
+}
+
+#[test]
+fn tracking_copy_restore_snapshot() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([1u8; 32]);
+    let k2 = Key::Hash([2u8; 32]);
+
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+    let two = StoredValue::CLValue(CLValue::from_t(2_i32).unwrap());
+    tc.write(k1, one.clone());
+
+    let snapshot = tc.snapshot();
+    let effect = tc.effect();
+
+    // changes made after the snapshot
+    tc.write(k1, two.clone());
+    tc.write(k2, two);
+    tc.delete(k2);
+
+    tc.restore(snapshot);
+
+    assert_eq!(tc.effect().ops, effect.ops);
+    assert_eq!(tc.effect().transforms, effect.transforms);
+    // the discarded changes to k2 may have depended on its value
+    assert_eq!(tc.effect().discarded_reads, iter::once(k2).collect());
+    assert_eq!(tc.read(correlation_id, &k1).unwrap(), Some(one.clone()));
+    // k2 is read from the underlying db again
+    assert_eq!(tc.read(correlation_id, &k2).unwrap(), Some(one));
+}
+
+#[test]
+fn tracking_copy_restore_snapshot_keeps_discarded_reads() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([1u8; 32]);
+    let k2 = Key::Hash([2u8; 32]);
+    let k3 = Key::Hash([3u8; 32]);
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+
+    tc.read(correlation_id, &k3).unwrap();
+    let snapshot = tc.snapshot();
+    tc.read(correlation_id, &k1).unwrap();
+    tc.add(correlation_id, k2, one).unwrap();
+    tc.read(correlation_id, &k3).unwrap();
+    tc.restore(snapshot);
+
+    // only adding to k2 didn't depend on its value, and k3 was already read before
+    assert_eq!(tc.effect().discarded_reads, iter::once(k1).collect());
+    assert_eq!(tc.effect().ops.get(&k1), None);
+    assert_eq!(tc.effect().ops.get(&k3), Some(&Op::Read));
+
+    // the discarded reads outlive later snapshots
+    let snapshot = tc.snapshot();
+    tc.restore(snapshot);
+    assert_eq!(tc.effect().discarded_reads, iter::once(k1).collect());
+}
+
+#[test]
+fn tracking_copy_apply_effect_of_fork() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k1 = Key::Hash([1u8; 32]);
+    let k2 = Key::Hash([2u8; 32]);
+
+    let write_value = StoredValue::CLValue(CLValue::from_t(7_i32).unwrap());
+    let _ = tc.add(
+        correlation_id,
+        k1,
+        StoredValue::CLValue(CLValue::from_t(2_i32).unwrap()),
+    );
+
+    let effect = {
+        let mut fork = tc.fork();
+        let _ = fork.add(
+            correlation_id,
+            k1,
+            StoredValue::CLValue(CLValue::from_t(3_i32).unwrap()),
+        );
+        fork.write(k2, write_value.clone());
+        fork.effect()
+    };
+    assert_matches!(
+        tc.apply_effect(correlation_id, effect),
+        Ok(AddResult::Success)
+    );
+
+    // the effect of the fork is combined with the existing one
+    assert_eq!(tc.fns.len(), 2);
+    assert_eq!(tc.fns.get(&k1), Some(&Transform::AddInt32(5)));
+    assert_eq!(
+        tc.fns.get(&k2),
+        Some(&Transform::Write(write_value.clone()))
+    );
+    assert_eq!(tc.ops.get(&k1), Some(&Op::Add));
+    assert_eq!(tc.ops.get(&k2), Some(&Op::Write));
+
+    // and the resulting values are visible to reads
+    assert_eq!(
+        tc.read(correlation_id, &k1).unwrap(),
+        Some(StoredValue::CLValue(CLValue::from_t(6_i32).unwrap()))
+    );
+    assert_eq!(tc.read(correlation_id, &k2).unwrap(), Some(write_value));
+}
+
+#[test]
+fn tracking_copy_apply_effect_type_mismatch() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(1_i32).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    let mut effect = ExecutionEffect::default();
+    effect.ops.insert_add(k, Op::Add);
+    effect
+        .transforms
+        .insert_add(k, Transform::AddKeys(BTreeMap::new()));
+
+    assert_matches!(
+        tc.apply_effect(correlation_id, effect),
+        Ok(AddResult::TypeMismatch(_))
+    );
+    // nothing is recorded for the failed transform
+    assert!(tc.fns.is_empty());
+    assert!(tc.ops.is_empty());
+}
+
 proptest! {
     #[test]
     fn query_empty_path(k in key_arb(), missing_key in key_arb(), v in stored_value_arb()) {
@@ -430,6 +607,56 @@ proptest! {
             panic!("Query failed when it should not have!");
         }
     }
+
+    #[test]
+    fn query_path_with_proof(
+        k in key_arb(), // key state is stored at
+        v in stored_value_arb(), // value in account state
+        name in "\\PC*", // human-readable name for state
+        missing_name in "\\PC*",
+        pk in public_key_arb(), // account public key
+        address in public_key_arb(), // address for account key
+    ) {
+        let correlation_id = CorrelationId::new();
+        let named_keys = iter::once((name.clone(), k)).collect();
+        let purse = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
+        let associated_keys = AssociatedKeys::new(pk, Weight::new(1));
+        let account = Account::new(
+            pk,
+            named_keys,
+            purse,
+            associated_keys,
+            Default::default(),
+        );
+        let account_key = Key::Account(address);
+
+        let (gs, root_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[(k, v.to_owned()), (account_key, StoredValue::Account(account))],
+        ).unwrap();
+        let view = gs.checkout(root_hash).unwrap().unwrap();
+        let tc = TrackingCopy::new(view);
+        let path = vec!(name.clone());
+        let (result, proofs) = tc.query_with_proof(correlation_id, account_key, &path).unwrap();
+        if let TrackingCopyQueryResult::Success(result) = result {
+            assert_eq!(v, result);
+        } else {
+            panic!("Query failed when it should not have!");
+        }
+        // one proof for the account and one for the value
+        assert_eq!(proofs.len(), 2);
+        for proof in &proofs {
+            assert!(proof.verify(&root_hash).unwrap().is_some());
+        }
+        assert_eq!(proofs[1].verify(&root_hash), Ok(Some(&v)));
+
+        if missing_name != name {
+            let (result, proofs) =
+                tc.query_with_proof(correlation_id, account_key, &[missing_name]).unwrap();
+            assert_matches!(result, TrackingCopyQueryResult::ValueNotFound(_));
+            assert_eq!(proofs.len(), 1);
+        }
+    }
 }
 
 #[test]
@@ -0,0 +1,90 @@
+//! The wasm backends which contracts can be run on.
+//!
+//! The rest of the engine only reaches a backend through the traits here, so values, traps and
+//! errors are exchanged as their `wasmi` types whichever backend runs the contract.  Gas and stack
+//! height are metered by code injected into the contract when it is preprocessed, so a contract
+//! costs the same on every backend.
+
+mod wasmi_backend;
+#[cfg(feature = "wasmtime-backend")]
+mod wasmtime_backend;
+
+use std::rc::Rc;
+
+use parity_wasm::elements::Module;
+use wasmi::{Externals, RuntimeValue};
+
+use types::ProtocolVersion;
+
+use crate::execution::Error;
+
+pub use self::wasmi_backend::WasmiBackend;
+#[cfg(feature = "wasmtime-backend")]
+pub use self::wasmtime_backend::WasmtimeBackend;
+
+/// The linear memory of an instantiated contract.
+pub trait WasmMemory {
+    /// Returns a copy of `size` bytes of memory, starting at `offset`.
+    fn get(&self, offset: u32, size: usize) -> Result<Vec<u8>, wasmi::Error>;
+
+    /// Copies `value` into memory, starting at `offset`.
+    fn set(&self, offset: u32, value: &[u8]) -> Result<(), wasmi::Error>;
+
+    /// Returns the current size of memory in bytes.
+    fn size(&self) -> usize;
+}
+
+/// An instantiated contract.
+pub trait WasmInstance {
+    /// Calls the function exported under `name`, which takes no arguments, dispatching the host
+    /// functions it calls to `externals`.
+    fn invoke_export(
+        &self,
+        name: &str,
+        externals: &mut dyn Externals,
+    ) -> Result<Option<RuntimeValue>, wasmi::Error>;
+}
+
+/// A way of instantiating contracts.
+pub trait WasmBackend {
+    /// Instantiates `module` against the host functions of `protocol_version`, returning the
+    /// instance and the memory it imported.
+    ///
+    /// Modules with a start function are rejected, as it would run before the host functions can
+    /// be dispatched.
+    fn instantiate(
+        &self,
+        module: Module,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(Box<dyn WasmInstance>, Rc<dyn WasmMemory>), Error>;
+}
+
+/// The backend chosen to run contracts, which is [`WasmiBackend`] by default.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WasmBackendKind {
+    /// Interprets contracts with `wasmi`.
+    Wasmi,
+    /// Compiles contracts to native code with `wasmtime`.
+    #[cfg(feature = "wasmtime-backend")]
+    Wasmtime,
+}
+
+impl Default for WasmBackendKind {
+    fn default() -> Self {
+        WasmBackendKind::Wasmi
+    }
+}
+
+impl WasmBackend for WasmBackendKind {
+    fn instantiate(
+        &self,
+        module: Module,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(Box<dyn WasmInstance>, Rc<dyn WasmMemory>), Error> {
+        match self {
+            WasmBackendKind::Wasmi => WasmiBackend.instantiate(module, protocol_version),
+            #[cfg(feature = "wasmtime-backend")]
+            WasmBackendKind::Wasmtime => WasmtimeBackend.instantiate(module, protocol_version),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use parity_wasm::elements::Module;
+use wasmi::{
+    memory_units::Bytes, Externals, ImportsBuilder, MemoryInstance, MemoryRef, ModuleInstance,
+    ModuleRef, RuntimeArgs, RuntimeValue, Trap,
+};
+
+use types::ProtocolVersion;
+
+use super::{WasmBackend, WasmInstance, WasmMemory};
+use crate::{
+    execution::Error,
+    resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
+};
+
+/// Interprets contracts with `wasmi`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WasmiBackend;
+
+impl WasmBackend for WasmiBackend {
+    fn instantiate(
+        &self,
+        parity_module: Module,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(Box<dyn WasmInstance>, Rc<dyn WasmMemory>), Error> {
+        let module = wasmi::Module::from_parity_wasm_module(parity_module)?;
+        let resolver = create_module_resolver(protocol_version)?;
+        let mut imports = ImportsBuilder::new();
+        imports.push_resolver("env", &resolver);
+        let not_started_module = ModuleInstance::new(&module, &imports)?;
+        if not_started_module.has_start() {
+            return Err(Error::UnsupportedWasmStart);
+        }
+        let instance = not_started_module.not_started_instance().clone();
+        let memory = resolver.memory_ref()?;
+        Ok((Box::new(instance), Rc::new(memory)))
+    }
+}
+
+impl WasmInstance for ModuleRef {
+    fn invoke_export(
+        &self,
+        name: &str,
+        externals: &mut dyn Externals,
+    ) -> Result<Option<RuntimeValue>, wasmi::Error> {
+        ModuleInstance::invoke_export(self, name, &[], &mut DynExternals(externals))
+    }
+}
+
+impl WasmMemory for MemoryRef {
+    fn get(&self, offset: u32, size: usize) -> Result<Vec<u8>, wasmi::Error> {
+        MemoryInstance::get(self, offset, size)
+    }
+
+    fn set(&self, offset: u32, value: &[u8]) -> Result<(), wasmi::Error> {
+        MemoryInstance::set(self, offset, value)
+    }
+
+    fn size(&self) -> usize {
+        Bytes::from(MemoryInstance::current_size(self)).0
+    }
+}
+
+/// Lets `wasmi`, which takes the externals by type, dispatch to a trait object.
+struct DynExternals<'a, 'b>(&'a mut (dyn Externals + 'b));
+
+impl<'a, 'b> Externals for DynExternals<'a, 'b> {
+    fn invoke_index(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        self.0.invoke_index(index, args)
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    mem,
+    ops::Range,
+    rc::Rc,
+    sync::Mutex,
+};
+
+use lazy_static::lazy_static;
+use linked_hash_map::LinkedHashMap;
+use parity_wasm::elements::Module;
+use wasmi::{
+    nan_preserving_float::{F32, F64},
+    Externals, RuntimeArgs, RuntimeValue, Signature, TrapKind, ValueType,
+};
+use wasmtime::{
+    Config, Engine, Extern, ExternType, Func, FuncType, Instance, Memory, Store, Trap, Val, ValType,
+};
+
+use engine_shared::newtypes::Blake2bHash;
+
+use types::ProtocolVersion;
+
+use super::{WasmBackend, WasmInstance, WasmMemory};
+use crate::{
+    execution::Error,
+    resolvers::{create_module_resolver, error::ResolverError, host_resolver::HostResolver},
+};
+
+const HOST_MODULE: &str = "env";
+const HOST_TRAP_MESSAGE: &str = "host function trapped";
+/// The number of compiled modules kept for reuse.
+const COMPILED_MODULE_CACHE_CAPACITY: usize = 256;
+
+lazy_static! {
+    static ref ENGINE: Engine = new_engine();
+    /// Modules compiled with `ENGINE`, by the hash of their wasm bytes, so that a contract called
+    /// again, including from a nested call or on another thread, isn't recompiled.
+    static ref COMPILED_MODULES: Mutex<LinkedHashMap<Blake2bHash, wasmtime::Module>> =
+        Mutex::new(LinkedHashMap::new());
+}
+
+/// Returns an engine whose float operations return canonical NaNs.  Preprocessing rejects float
+/// opcodes, but any which got through would otherwise return NaNs whose bits depend on the host.
+fn new_engine() -> Engine {
+    let mut config = Config::new();
+    config.cranelift_nan_canonicalization(true);
+    Engine::new(&config)
+}
+
+/// Returns the module compiled from `wasm_bytes`, compiling it only if it isn't cached yet.
+fn compiled_module(wasm_bytes: &[u8]) -> Result<wasmtime::Module, Error> {
+    let hash = Blake2bHash::new(wasm_bytes);
+    if let Some(module) = COMPILED_MODULES.lock().unwrap().get_refresh(&hash) {
+        return Ok(module.clone());
+    }
+    // Compiled without holding the lock, so that other threads can use the cache meanwhile
+    let module = wasmtime::Module::new(&ENGINE, wasm_bytes)
+        .map_err(|error| instantiation_error(error.to_string()))?;
+    let mut compiled_modules = COMPILED_MODULES.lock().unwrap();
+    compiled_modules.insert(hash, module.clone());
+    if compiled_modules.len() > COMPILED_MODULE_CACHE_CAPACITY {
+        compiled_modules.pop_front();
+    }
+    Ok(module)
+}
+
+/// Compiles contracts to native code with `wasmtime`, keeping the compiled modules for reuse.
+///
+/// Each contract is instantiated in a store of its own, which is freed along with the instance and
+/// its memory once the contract has run.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WasmtimeBackend;
+
+impl WasmBackend for WasmtimeBackend {
+    fn instantiate(
+        &self,
+        parity_module: Module,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(Box<dyn WasmInstance>, Rc<dyn WasmMemory>), Error> {
+        if parity_module.start_section().is_some() {
+            return Err(Error::UnsupportedWasmStart);
+        }
+        let resolver = create_module_resolver(protocol_version)?;
+        let wasm_bytes = parity_wasm::serialize(parity_module)?;
+
+        instantiate(&wasm_bytes, &resolver)
+    }
+}
+
+fn instantiate(
+    wasm_bytes: &[u8],
+    resolver: &dyn HostResolver,
+) -> Result<(Box<dyn WasmInstance>, Rc<dyn WasmMemory>), Error> {
+    let module = compiled_module(wasm_bytes)?;
+    let store = Store::new(&ENGINE);
+
+    let dispatcher = Rc::new(Dispatcher::default());
+    let mut imports = Vec::new();
+    let mut maybe_memory = None;
+    for import in module.imports() {
+        if import.module() != HOST_MODULE {
+            return Err(instantiation_error(format!(
+                "Module {} not found",
+                import.module()
+            )));
+        }
+        match import.ty() {
+            ExternType::Func(_) => {
+                let (signature, function_index) = resolver.resolve_host_function(import.name())?;
+                let index: usize = function_index.into();
+                let dispatcher = Rc::clone(&dispatcher);
+                let func = Func::new(&store, func_type(&signature), move |_, params, results| {
+                    dispatcher.dispatch(index, params, results)
+                });
+                imports.push(Extern::from(func));
+            }
+            ExternType::Memory(memory_type) => {
+                let limits = memory_type.limits();
+                resolver.check_memory(import.name(), limits.min(), limits.max())?;
+                let memory = Memory::new(&store, memory_type.clone());
+                maybe_memory = Some(memory.clone());
+                imports.push(Extern::from(memory));
+            }
+            _ => {
+                return Err(instantiation_error(format!(
+                    "host module doesn't export {}",
+                    import.name()
+                )));
+            }
+        }
+    }
+
+    let instance = Instance::new(&store, &module, &imports)
+        .map_err(|error| instantiation_error(error.to_string()))?;
+    let memory = maybe_memory.ok_or(ResolverError::NoImportedMemory)?;
+    Ok((
+        Box::new(WasmtimeInstance {
+            instance,
+            dispatcher,
+        }),
+        Rc::new(WasmtimeMemory(memory)),
+    ))
+}
+
+struct WasmtimeInstance {
+    instance: Instance,
+    dispatcher: Rc<Dispatcher>,
+}
+
+impl WasmInstance for WasmtimeInstance {
+    fn invoke_export(
+        &self,
+        name: &str,
+        externals: &mut dyn Externals,
+    ) -> Result<Option<RuntimeValue>, wasmi::Error> {
+        let func = self
+            .instance
+            .get_export(name)
+            .and_then(Extern::into_func)
+            .ok_or_else(|| {
+                wasmi::Error::Function(format!("Module doesn't have export {}", name))
+            })?;
+
+        let result = {
+            let _guard = self.dispatcher.enter(externals);
+            func.call(&[])
+        };
+
+        match result {
+            Ok(values) => match values.first() {
+                Some(value) => runtime_value(value)
+                    .map(Some)
+                    .ok_or_else(|| wasmi::Error::Function("Unsupported return type".to_string())),
+                None => Ok(None),
+            },
+            Err(trap) => Err(self.dispatcher.interpreter_error(&trap)),
+        }
+    }
+}
+
+/// Passes calls to host functions on to the externals of the current call into the instance.
+#[derive(Default)]
+struct Dispatcher {
+    externals: Cell<Option<*mut (dyn Externals + 'static)>>,
+    /// The trap raised by the last host function which failed, which `wasmtime` can't carry.
+    host_trap: RefCell<Option<wasmi::Trap>>,
+}
+
+impl Dispatcher {
+    /// Makes host functions dispatch to `externals` until the returned guard is dropped.
+    fn enter<'a>(&'a self, externals: &'a mut dyn Externals) -> DispatchGuard<'a> {
+        let externals: *mut (dyn Externals + 'a) = externals;
+        // SAFETY: this only erases the lifetime of the pointer, so that it can be stored in
+        // `self.externals`; its address and vtable are unchanged.  The pointer is only
+        // dereferenced by `dispatch` while it is stored, and the returned guard, which keeps
+        // `externals` mutably borrowed for `'a`, puts the previous pointer back when dropped.  The
+        // guard is private to this module and only kept in a local of `invoke_export`, which never
+        // leaks it, so the pointer is gone before the borrow ends.
+        let externals: *mut (dyn Externals + 'static) = unsafe { mem::transmute(externals) };
+        let previous = self.externals.replace(Some(externals));
+        DispatchGuard {
+            dispatcher: self,
+            previous,
+            _externals: PhantomData,
+        }
+    }
+
+    fn dispatch(&self, index: usize, params: &[Val], results: &mut [Val]) -> Result<(), Trap> {
+        let externals = self
+            .externals
+            .get()
+            .ok_or_else(|| Trap::new("host function called outside of an export"))?;
+        let args = params
+            .iter()
+            .map(runtime_value)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Trap::new("unsupported argument type"))?;
+
+        // SAFETY: the pointer was set by `enter` and, as the guard it returned is still alive, the
+        // borrow it came from is too.  The caller of `invoke_export` doesn't use the externals
+        // while the export runs, so this is the only reference to them.
+        let externals = unsafe { &mut *externals };
+        match externals.invoke_index(index, RuntimeArgs::from(&args[..])) {
+            Ok(Some(value)) => {
+                if let Some(result) = results.first_mut() {
+                    *result = val(value);
+                }
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(trap) => {
+                *self.host_trap.borrow_mut() = Some(trap);
+                Err(Trap::new(HOST_TRAP_MESSAGE))
+            }
+        }
+    }
+
+    /// Returns the error `wasmi` would have returned for `trap`.
+    fn interpreter_error(&self, trap: &Trap) -> wasmi::Error {
+        if let Some(host_trap) = self.host_trap.borrow_mut().take() {
+            return wasmi::Error::Trap(host_trap);
+        }
+        match trap_kind(trap.message()) {
+            Some(trap_kind) => wasmi::Error::Trap(wasmi::Trap::new(trap_kind)),
+            None => wasmi::Error::Function(trap.message().to_string()),
+        }
+    }
+}
+
+struct DispatchGuard<'a> {
+    dispatcher: &'a Dispatcher,
+    previous: Option<*mut (dyn Externals + 'static)>,
+    _externals: PhantomData<&'a mut dyn Externals>,
+}
+
+impl<'a> Drop for DispatchGuard<'a> {
+    fn drop(&mut self) {
+        self.dispatcher.externals.set(self.previous);
+    }
+}
+
+struct WasmtimeMemory(Memory);
+
+impl WasmMemory for WasmtimeMemory {
+    fn get(&self, offset: u32, size: usize) -> Result<Vec<u8>, wasmi::Error> {
+        // SAFETY: the memory is only used from this thread, and the slice is dropped before any
+        // wasm can run and grow it.
+        let data = unsafe { self.0.data_unchecked() };
+        let range = checked_range(offset, size, data.len())?;
+        Ok(data[range].to_vec())
+    }
+
+    fn set(&self, offset: u32, value: &[u8]) -> Result<(), wasmi::Error> {
+        // SAFETY: as for `get`, and no other slice of the memory is alive while this one is.
+        let data = unsafe { self.0.data_unchecked_mut() };
+        let range = checked_range(offset, value.len(), data.len())?;
+        data[range].copy_from_slice(value);
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        self.0.data_size()
+    }
+}
+
+fn checked_range(
+    offset: u32,
+    size: usize,
+    memory_size: usize,
+) -> Result<Range<usize>, wasmi::Error> {
+    let start = offset as usize;
+    match start.checked_add(size) {
+        Some(end) if end <= memory_size => Ok(start..end),
+        _ => Err(wasmi::Error::Memory(format!(
+            "trying to access region [{}..{}] in memory [0..{}]",
+            start,
+            start.saturating_add(size),
+            memory_size
+        ))),
+    }
+}
+
+fn instantiation_error(message: String) -> Error {
+    Error::Interpreter(wasmi::Error::Instantiation(message))
+}
+
+fn func_type(signature: &Signature) -> FuncType {
+    let params = signature
+        .params()
+        .iter()
+        .map(|value_type| val_type(*value_type));
+    let results = signature.return_type().map(val_type);
+    FuncType::new(params.collect(), results.into_iter().collect())
+}
+
+fn val_type(value_type: ValueType) -> ValType {
+    match value_type {
+        ValueType::I32 => ValType::I32,
+        ValueType::I64 => ValType::I64,
+        ValueType::F32 => ValType::F32,
+        ValueType::F64 => ValType::F64,
+    }
+}
+
+fn runtime_value(value: &Val) -> Option<RuntimeValue> {
+    match value {
+        Val::I32(value) => Some(RuntimeValue::I32(*value)),
+        Val::I64(value) => Some(RuntimeValue::I64(*value)),
+        Val::F32(bits) => Some(RuntimeValue::F32(F32::from_bits(*bits))),
+        Val::F64(bits) => Some(RuntimeValue::F64(F64::from_bits(*bits))),
+        _ => None,
+    }
+}
+
+fn val(value: RuntimeValue) -> Val {
+    match value {
+        RuntimeValue::I32(value) => Val::I32(value),
+        RuntimeValue::I64(value) => Val::I64(value),
+        RuntimeValue::F32(value) => Val::F32(value.to_bits()),
+        RuntimeValue::F64(value) => Val::F64(value.to_bits()),
+    }
+}
+
+/// Returns the kind of the trap `wasmi` raises for the same fault as a `wasmtime` trap.
+fn trap_kind(message: &str) -> Option<TrapKind> {
+    let trap_kind = if message.contains("unreachable") {
+        TrapKind::Unreachable
+    } else if message.contains("out of bounds memory access") {
+        TrapKind::MemoryAccessOutOfBounds
+    } else if message.contains("undefined element") {
+        TrapKind::ElemUninitialized
+    } else if message.contains("out of bounds table access") {
+        TrapKind::TableAccessOutOfBounds
+    } else if message.contains("integer divide by zero") {
+        TrapKind::DivisionByZero
+    } else if message.contains("integer overflow")
+        || message.contains("invalid conversion to integer")
+    {
+        TrapKind::InvalidConversionToInt
+    } else if message.contains("indirect call type mismatch") {
+        TrapKind::UnexpectedSignature
+    } else if message.contains("call stack exhausted") {
+        TrapKind::StackOverflow
+    } else {
+        return None;
+    };
+    Some(trap_kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_range, trap_kind};
+
+    #[test]
+    fn should_check_memory_range() {
+        assert_eq!(checked_range(2, 3, 5).unwrap(), 2..5);
+        assert!(checked_range(3, 3, 5).is_err());
+        assert!(checked_range(u32::max_value(), usize::max_value(), 5).is_err());
+    }
+
+    #[test]
+    fn should_map_wasm_trap_messages() {
+        assert!(trap_kind("wasm trap: unreachable, source location: @0012").is_some());
+        assert!(trap_kind("wasm trap: call stack exhausted").is_some());
+        assert!(trap_kind(super::HOST_TRAP_MESSAGE).is_none());
+    }
+}
@@ -0,0 +1,165 @@
+//! This executable prunes the global state held in the data directory of a stopped Execution
+//! Engine Server.  Every trie which is unreachable from the given state root hashes is deleted.
+
+use std::{convert::TryFrom, fs, path::PathBuf, process, str::FromStr};
+
+use clap::{crate_version, App, Arg, ArgMatches};
+use dirs::home_dir;
+
+use engine_shared::{newtypes::Blake2bHash, os::get_page_size, stored_value::StoredValue};
+use engine_storage::{
+    transaction_source::lmdb::LmdbEnvironment,
+    trie_store::{
+        lmdb::LmdbTrieStore,
+        pruning::{self, PruneResult},
+    },
+};
+use types::Key;
+
+const APP_NAME: &str = "CasperLabs Global State Pruner";
+const ABOUT: &str = "Deletes every trie in global state which is unreachable from the given state \
+                     root hashes.  The Execution Engine Server must not be running.";
+
+// data-dir / lmdb
+const ARG_DATA_DIR: &str = "data-dir";
+const ARG_DATA_DIR_SHORT: &str = "d";
+const ARG_DATA_DIR_VALUE: &str = "DIR";
+const ARG_DATA_DIR_HELP: &str = "Sets the data directory";
+const DEFAULT_DATA_DIR_RELATIVE: &str = ".casperlabs";
+const GLOBAL_STATE_DIR: &str = "global_state";
+const GET_HOME_DIR_EXPECT: &str = "Could not get home directory";
+const LMDB_ENVIRONMENT_EXPECT: &str = "Could not open LmdbEnvironment";
+const LMDB_TRIE_STORE_EXPECT: &str = "Could not open LmdbTrieStore";
+
+// pages / lmdb
+const ARG_PAGES: &str = "pages";
+const ARG_PAGES_SHORT: &str = "p";
+const ARG_PAGES_VALUE: &str = "NUM";
+const ARG_PAGES_HELP: &str = "Sets the max number of pages to use for lmdb's mmap";
+const GET_PAGES_EXPECT: &str = "Could not parse pages argument";
+// 750 GiB = 805306368000 bytes
+// page size on x86_64 linux = 4096 bytes
+// 805306368000 / 4096 = 196608000
+const DEFAULT_PAGES: usize = 196_608_000;
+
+// state roots
+const ARG_STATE_ROOTS: &str = "state-roots";
+const ARG_STATE_ROOTS_VALUE: &str = "HASH";
+const ARG_STATE_ROOTS_HELP: &str = "Hex-encoded state root hashes to retain";
+const ARG_STATE_ROOTS_EXPECT: &str = "state roots required";
+
+fn main() {
+    let arg_matches = get_args();
+
+    let data_dir = get_data_dir(&arg_matches);
+    let map_size = get_map_size(&arg_matches);
+    let state_roots = get_state_roots(&arg_matches);
+
+    let environment = LmdbEnvironment::new(&data_dir, map_size).expect(LMDB_ENVIRONMENT_EXPECT);
+    let trie_store = LmdbTrieStore::open(&environment, None).expect(LMDB_TRIE_STORE_EXPECT);
+
+    match pruning::prune::<Key, StoredValue>(&environment, &trie_store, &state_roots) {
+        Ok(PruneResult::Success { retained, pruned }) => {
+            println!("retained {} tries, pruned {} tries", retained, pruned)
+        }
+        Ok(PruneResult::TrieNotFound(hash)) => {
+            eprintln!("trie not found: {}; nothing was pruned", hash);
+            process::exit(1)
+        }
+        Err(error) => {
+            eprintln!("failed to prune global state: {}", error);
+            process::exit(1)
+        }
+    }
+}
+
+/// Gets command line arguments
+fn get_args() -> ArgMatches<'static> {
+    App::new(APP_NAME)
+        .version(crate_version!())
+        .about(ABOUT)
+        .arg(
+            Arg::with_name(ARG_DATA_DIR)
+                .short(ARG_DATA_DIR_SHORT)
+                .long(ARG_DATA_DIR)
+                .value_name(ARG_DATA_DIR_VALUE)
+                .help(ARG_DATA_DIR_HELP)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ARG_PAGES)
+                .short(ARG_PAGES_SHORT)
+                .long(ARG_PAGES)
+                .value_name(ARG_PAGES_VALUE)
+                .help(ARG_PAGES_HELP)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ARG_STATE_ROOTS)
+                .required(true)
+                .multiple(true)
+                .value_name(ARG_STATE_ROOTS_VALUE)
+                .help(ARG_STATE_ROOTS_HELP)
+                .validator(|value| parse_hash(&value).map(|_| ()))
+                .index(1),
+        )
+        .get_matches()
+}
+
+/// Gets value of data-dir argument
+fn get_data_dir(arg_matches: &ArgMatches) -> PathBuf {
+    let mut buf = arg_matches.value_of(ARG_DATA_DIR).map_or(
+        {
+            let mut dir = home_dir().expect(GET_HOME_DIR_EXPECT);
+            dir.push(DEFAULT_DATA_DIR_RELATIVE);
+            dir
+        },
+        PathBuf::from,
+    );
+    buf.push(GLOBAL_STATE_DIR);
+    if !fs::metadata(&buf)
+        .map(|metadata| metadata.is_dir())
+        .unwrap_or(false)
+    {
+        eprintln!("global state directory not found: {:?}", buf);
+        process::exit(1)
+    }
+    buf
+}
+
+///  Parses pages argument and returns map size
+fn get_map_size(arg_matches: &ArgMatches) -> usize {
+    let page_size = get_page_size().unwrap();
+    let pages = arg_matches
+        .value_of(ARG_PAGES)
+        .map_or(Ok(DEFAULT_PAGES), usize::from_str)
+        .expect(GET_PAGES_EXPECT);
+    page_size * pages
+}
+
+/// Gets the state root hashes to retain
+fn get_state_roots(arg_matches: &ArgMatches) -> Vec<Blake2bHash> {
+    arg_matches
+        .values_of(ARG_STATE_ROOTS)
+        .expect(ARG_STATE_ROOTS_EXPECT)
+        .map(|value| parse_hash(value).expect("should have been validated"))
+        .collect()
+}
+
+/// Parses a hex-encoded [`Blake2bHash`]
+fn parse_hash(value: &str) -> Result<Blake2bHash, String> {
+    let invalid = || format!("invalid state root hash: {}", value);
+    if value.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    let bytes = (0..value.len())
+        .step_by(2)
+        .map(|index| {
+            value
+                .get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(invalid)?;
+    Blake2bHash::try_from(bytes.as_slice()).map_err(|_| invalid())
+}
@@ -0,0 +1,73 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::bid_state::BidStateRequest;
+use proof_of_stake::Stakes;
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{
+    ipc::{self, BidState, BidState_Bid},
+    mappings::MappingError,
+};
+
+impl TryFrom<ipc::BidStateRequest> for BidStateRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_bid_state_request: ipc::BidStateRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = pb_bid_state_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("parent_state_hash".to_string()))?;
+
+        let protocol_version = pb_bid_state_request.take_protocol_version().into();
+
+        Ok(BidStateRequest::new(parent_state_hash, protocol_version))
+    }
+}
+
+impl From<(PublicKey, U512)> for BidState_Bid {
+    fn from((key, amount): (PublicKey, U512)) -> Self {
+        let mut pb_bid = BidState_Bid::new();
+        pb_bid.set_id(key.as_bytes().to_vec());
+        pb_bid.set_value(amount.into());
+        pb_bid
+    }
+}
+
+impl TryFrom<BidState_Bid> for (PublicKey, U512) {
+    type Error = MappingError;
+
+    fn try_from(mut pb_bid: BidState_Bid) -> Result<Self, Self::Error> {
+        let public_key = PublicKey::try_from(pb_bid.get_id())
+            .map_err(|_| MappingError::invalid_public_key_length(pb_bid.id.len()))?;
+
+        let value = pb_bid.take_value().try_into()?;
+
+        Ok((public_key, value))
+    }
+}
+
+impl From<Stakes> for BidState {
+    fn from(stakes: Stakes) -> Self {
+        let mut pb_bid_state = BidState::new();
+        let bids = stakes.0.into_iter().map(Into::into).collect();
+        pb_bid_state.set_bids(bids);
+        pb_bid_state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            test_utils::protobuf_round_trip::<(PublicKey, U512), BidState_Bid>((public_key, u512));
+        }
+    }
+}
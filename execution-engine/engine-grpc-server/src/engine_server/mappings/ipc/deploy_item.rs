@@ -12,7 +12,7 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
     type Error = MappingError;
 
     fn try_from(mut pb_deploy_item: ipc::DeployItem) -> Result<Self, Self::Error> {
-        let address = PublicKey::ed25519_try_from(pb_deploy_item.get_address())
+        let address = PublicKey::try_from(pb_deploy_item.get_address())
             .map_err(|_| MappingError::invalid_public_key_length(pb_deploy_item.address.len()))?;
 
         let session = pb_deploy_item
@@ -33,7 +33,7 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
             .get_authorization_keys()
             .iter()
             .map(|raw: &Vec<u8>| {
-                PublicKey::ed25519_try_from(raw.as_slice())
+                PublicKey::try_from(raw.as_slice())
                     .map_err(|_| MappingError::invalid_public_key_length(raw.len()))
             })
             .collect::<Result<BTreeSet<PublicKey>, Self::Error>>()?;
@@ -71,3 +71,50 @@ impl From<DeployItem> for ipc::DeployItem {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use engine_core::engine_state::executable_deploy_item::ExecutableDeployItem;
+
+    use super::*;
+
+    fn deploy_item(address: PublicKey, authorization_keys: BTreeSet<PublicKey>) -> DeployItem {
+        let executable_deploy_item = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![1, 2, 3],
+            args: vec![],
+        };
+        DeployItem::new(
+            address,
+            executable_deploy_item.clone(),
+            executable_deploy_item,
+            1,
+            authorization_keys,
+            [42; 32],
+        )
+    }
+
+    #[test]
+    fn round_trip_with_secp256k1_keys() {
+        let ed25519 = PublicKey::ed25519_from([1; 32]);
+        let secp256k1 = PublicKey::secp256k1_from([2; 33]);
+        let original = deploy_item(secp256k1, vec![ed25519, secp256k1].into_iter().collect());
+
+        let parsed = DeployItem::try_from(ipc::DeployItem::from(original.clone()))
+            .expect("should parse deploy item");
+        assert!(parsed == original);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_public_key_length() {
+        let mut pb_deploy_item = ipc::DeployItem::from(deploy_item(
+            PublicKey::ed25519_from([1; 32]),
+            BTreeSet::new(),
+        ));
+        pb_deploy_item.set_address(vec![1; 34]);
+
+        match DeployItem::try_from(pb_deploy_item) {
+            Err(MappingError::InvalidPublicKeyLength { actual: 34 }) => {}
+            _ => panic!("should fail with invalid public key length"),
+        }
+    }
+}
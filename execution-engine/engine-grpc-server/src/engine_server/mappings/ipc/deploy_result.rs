@@ -31,6 +31,8 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             error @ EngineStateError::InvalidHashLength { .. }
             | error @ EngineStateError::InvalidPublicKeyLength { .. }
             | error @ EngineStateError::InvalidProtocolVersion { .. }
+            | error @ EngineStateError::GasPriceTooLow { .. }
+            | error @ EngineStateError::BlockGasLimitReached { .. }
             | error @ EngineStateError::InvalidUpgradeConfig
             | error @ EngineStateError::WasmPreprocessing(_)
             | error @ EngineStateError::WasmSerialization(_)
@@ -152,6 +154,8 @@ mod detail {
                 .mut_exec_error()
                 .set_message(msg),
         }
+        let pb_events = effect.events.iter().cloned().map(Into::into).collect();
+        pb_execution_result.set_events(pb_events);
         pb_execution_result.set_effects(effect.into());
         pb_execution_result.set_cost(cost.value().into());
 
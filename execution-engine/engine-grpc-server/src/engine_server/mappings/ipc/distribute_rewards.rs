@@ -0,0 +1,82 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::distribute_rewards::DistributeRewardsRequest;
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{
+    ipc::{self, DistributeRewardsRequest_ValidatorReward},
+    mappings::MappingError,
+};
+
+impl TryFrom<ipc::DistributeRewardsRequest> for DistributeRewardsRequest {
+    type Error = MappingError;
+
+    fn try_from(
+        mut pb_distribute_rewards_request: ipc::DistributeRewardsRequest,
+    ) -> Result<Self, Self::Error> {
+        let parent_state_hash = pb_distribute_rewards_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("parent_state_hash".to_string()))?;
+
+        let protocol_version = pb_distribute_rewards_request.take_protocol_version().into();
+
+        let rewards = pb_distribute_rewards_request
+            .take_rewards()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<(PublicKey, U512)>, MappingError>>()?;
+
+        Ok(DistributeRewardsRequest::new(
+            parent_state_hash,
+            protocol_version,
+            rewards,
+        ))
+    }
+}
+
+impl From<(PublicKey, U512)> for DistributeRewardsRequest_ValidatorReward {
+    fn from((key, amount): (PublicKey, U512)) -> Self {
+        let mut pb_validator_reward = DistributeRewardsRequest_ValidatorReward::new();
+        pb_validator_reward.set_validator_id(key.as_bytes().to_vec());
+        pb_validator_reward.set_value(amount.into());
+        pb_validator_reward
+    }
+}
+
+impl TryFrom<DistributeRewardsRequest_ValidatorReward> for (PublicKey, U512) {
+    type Error = MappingError;
+
+    fn try_from(
+        mut pb_validator_reward: DistributeRewardsRequest_ValidatorReward,
+    ) -> Result<Self, Self::Error> {
+        let public_key =
+            PublicKey::try_from(pb_validator_reward.get_validator_id()).map_err(|_| {
+                MappingError::invalid_public_key_length(pb_validator_reward.validator_id.len())
+            })?;
+
+        let value = pb_validator_reward.take_value().try_into()?;
+
+        Ok((public_key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            test_utils::protobuf_round_trip::<
+                (PublicKey, U512),
+                DistributeRewardsRequest_ValidatorReward,
+            >((public_key, u512));
+        }
+    }
+}
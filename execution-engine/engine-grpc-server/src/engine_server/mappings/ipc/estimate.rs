@@ -0,0 +1,30 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::estimate::EstimateRequest;
+use types::BlockTime;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::EstimateRequest> for EstimateRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_estimate_request: ipc::EstimateRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = pb_estimate_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("parent_state_hash".to_string()))?;
+
+        let block_time = BlockTime::new(pb_estimate_request.get_block_time());
+
+        let deploy_item = pb_estimate_request.take_deploy().try_into()?;
+
+        let protocol_version = pb_estimate_request.take_protocol_version().into();
+
+        Ok(EstimateRequest::new(
+            parent_state_hash,
+            block_time,
+            deploy_item,
+            protocol_version,
+        ))
+    }
+}
@@ -1,8 +1,14 @@
-use engine_core::engine_state::{execution_effect::ExecutionEffect, op::Op};
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::{
+    execution_effect::{Event, ExecutionEffect},
+    op::Op,
+};
 use types::Key;
 
 use crate::engine_server::{
     ipc::{self, AddOp, NoOp, OpEntry, ReadOp, WriteOp},
+    mappings::ParsingError,
     transforms::TransformEntry as ProbufTransformEntry,
 };
 
@@ -40,3 +46,41 @@ impl From<ExecutionEffect> for ipc::ExecutionEffect {
         pb_execution_effect
     }
 }
+
+impl From<Event> for ipc::Event {
+    fn from(event: Event) -> ipc::Event {
+        let (name, value) = event.destructure();
+
+        let mut pb_event = ipc::Event::new();
+        pb_event.set_name(name);
+        pb_event.set_value(value.into());
+
+        pb_event
+    }
+}
+
+impl TryFrom<ipc::Event> for Event {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_event: ipc::Event) -> Result<Self, Self::Error> {
+        let value = pb_event.take_value().try_into()?;
+        Ok(Event::new(pb_event.take_name(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn event_round_trip(name in "\\PC*", cl_value in gens::cl_value_arb()) {
+            test_utils::protobuf_round_trip::<Event, ipc::Event>(Event::new(name, cl_value));
+        }
+    }
+}
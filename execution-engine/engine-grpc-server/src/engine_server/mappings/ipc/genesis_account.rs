@@ -24,7 +24,7 @@ impl TryFrom<ChainSpec_GenesisAccount> for GenesisAccount {
     fn try_from(mut pb_genesis_account: ChainSpec_GenesisAccount) -> Result<Self, Self::Error> {
         // TODO: our TryFromSliceForPublicKeyError should convey length info
         let public_key =
-            PublicKey::ed25519_try_from(pb_genesis_account.get_public_key()).map_err(|_| {
+            PublicKey::try_from(pb_genesis_account.get_public_key()).map_err(|_| {
                 MappingError::invalid_public_key_length(pb_genesis_account.public_key.len())
             })?;
         let balance = pb_genesis_account
@@ -41,6 +41,8 @@ impl TryFrom<ChainSpec_GenesisAccount> for GenesisAccount {
 
 #[cfg(test)]
 mod tests {
+    use types::U512;
+
     use super::*;
     use crate::engine_server::mappings::test_utils;
 
@@ -51,4 +53,16 @@ mod tests {
             genesis_account,
         );
     }
+
+    #[test]
+    fn round_trip_with_secp256k1_key() {
+        let genesis_account = GenesisAccount::new(
+            PublicKey::secp256k1_from([3; 33]),
+            Motes::new(U512::from(100)),
+            Motes::new(U512::from(10)),
+        );
+        test_utils::protobuf_round_trip::<GenesisAccount, ChainSpec_GenesisAccount>(
+            genesis_account,
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use std::convert::{TryFrom, TryInto};
 
 use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
+use engine_storage::protocol_data::{DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_MAX_PAYMENT};
 
 use crate::engine_server::{
     ipc::{ChainSpec_GenesisAccount, ChainSpec_GenesisConfig},
@@ -29,9 +30,18 @@ impl From<GenesisConfig> for ChainSpec_GenesisConfig {
                 .collect::<Vec<ChainSpec_GenesisAccount>>();
             pb_genesis_config.set_accounts(accounts.into());
         }
-        pb_genesis_config
-            .mut_costs()
-            .set_wasm(genesis_config.wasm_costs().into());
+        {
+            let pb_costs = pb_genesis_config.mut_costs();
+            pb_costs.set_wasm(genesis_config.wasm_costs().into());
+            pb_costs.set_host_function_costs(genesis_config.host_function_costs().into());
+        }
+        pb_genesis_config.set_max_payment(genesis_config.max_payment());
+        pb_genesis_config.set_block_gas_limit(genesis_config.block_gas_limit());
+        {
+            let pb_pos_config = pb_genesis_config.mut_pos_config();
+            pb_pos_config.set_bonding_delay(genesis_config.bonding_delay());
+            pb_pos_config.set_unbonding_delay(genesis_config.unbonding_delay());
+        }
         pb_genesis_config
     }
 }
@@ -48,7 +58,28 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             .into_iter()
             .map(TryInto::try_into)
             .collect::<Result<Vec<GenesisAccount>, Self::Error>>()?;
-        let wasm_costs = pb_genesis_config.take_costs().take_wasm().into();
+        // Unset costs would otherwise map to zero, leaving every opcode or host function free.
+        let mut pb_costs = pb_genesis_config.take_costs();
+        if !pb_costs.has_wasm() {
+            return Err(MappingError::MissingField("costs.wasm".to_string()));
+        }
+        if !pb_costs.has_host_function_costs() {
+            return Err(MappingError::MissingField(
+                "costs.host_function_costs".to_string(),
+            ));
+        }
+        let wasm_costs = pb_costs.take_wasm().into();
+        let host_function_costs = pb_costs.take_host_function_costs().into();
+        let max_payment = match pb_genesis_config.get_max_payment() {
+            0 => DEFAULT_MAX_PAYMENT,
+            max_payment => max_payment,
+        };
+        let block_gas_limit = match pb_genesis_config.get_block_gas_limit() {
+            0 => DEFAULT_BLOCK_GAS_LIMIT,
+            block_gas_limit => block_gas_limit,
+        };
+        let bonding_delay = pb_genesis_config.get_pos_config().get_bonding_delay();
+        let unbonding_delay = pb_genesis_config.get_pos_config().get_unbonding_delay();
         let mint_initializer_bytes = pb_genesis_config.mint_installer;
         let proof_of_stake_initializer_bytes = pb_genesis_config.pos_installer;
         let standard_payment_installer_bytes = pb_genesis_config.standard_payment_installer;
@@ -61,6 +92,11 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            max_payment,
+            block_gas_limit,
+            bonding_delay,
+            unbonding_delay,
         ))
     }
 }
@@ -75,4 +111,27 @@ mod tests {
         let genesis_config = rand::random();
         test_utils::protobuf_round_trip::<GenesisConfig, ChainSpec_GenesisConfig>(genesis_config);
     }
+
+    #[test]
+    fn should_reject_genesis_config_without_host_function_costs() {
+        let genesis_config: GenesisConfig = rand::random();
+        let mut pb_genesis_config = ChainSpec_GenesisConfig::from(genesis_config);
+        pb_genesis_config.mut_costs().clear_host_function_costs();
+
+        let error = GenesisConfig::try_from(pb_genesis_config).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Missing field: costs.host_function_costs"
+        );
+    }
+
+    #[test]
+    fn should_reject_genesis_config_without_wasm_costs() {
+        let genesis_config: GenesisConfig = rand::random();
+        let mut pb_genesis_config = ChainSpec_GenesisConfig::from(genesis_config);
+        pb_genesis_config.mut_costs().clear_wasm();
+
+        let error = GenesisConfig::try_from(pb_genesis_config).unwrap_err();
+        assert_eq!(error.to_string(), "Missing field: costs.wasm");
+    }
 }
@@ -0,0 +1,170 @@
+use engine_shared::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
+use crate::engine_server::ipc::{
+    ChainSpec_CostTable_HostFunction, ChainSpec_CostTable_HostFunctionCosts,
+};
+
+impl From<HostFunctionCost> for ChainSpec_CostTable_HostFunction {
+    fn from(host_function_cost: HostFunctionCost) -> Self {
+        let mut pb_host_function = ChainSpec_CostTable_HostFunction::new();
+        pb_host_function.set_fixed(host_function_cost.fixed);
+        pb_host_function.set_per_byte(host_function_cost.per_byte);
+        pb_host_function
+    }
+}
+
+impl From<ChainSpec_CostTable_HostFunction> for HostFunctionCost {
+    fn from(pb_host_function: ChainSpec_CostTable_HostFunction) -> Self {
+        HostFunctionCost::new(pb_host_function.fixed, pb_host_function.per_byte)
+    }
+}
+
+impl From<HostFunctionCosts> for ChainSpec_CostTable_HostFunctionCosts {
+    fn from(host_function_costs: HostFunctionCosts) -> Self {
+        let mut pb_host_function_costs = ChainSpec_CostTable_HostFunctionCosts::new();
+        pb_host_function_costs.set_read_value(host_function_costs.read_value.into());
+        pb_host_function_costs.set_read_value_local(host_function_costs.read_value_local.into());
+        pb_host_function_costs.set_load_named_keys(host_function_costs.load_named_keys.into());
+        pb_host_function_costs.set_write(host_function_costs.write.into());
+        pb_host_function_costs.set_write_local(host_function_costs.write_local.into());
+        pb_host_function_costs.set_add(host_function_costs.add.into());
+        pb_host_function_costs.set_add_local(host_function_costs.add_local.into());
+        pb_host_function_costs.set_new_uref(host_function_costs.new_uref.into());
+        pb_host_function_costs.set_get_arg_size(host_function_costs.get_arg_size.into());
+        pb_host_function_costs.set_get_arg(host_function_costs.get_arg.into());
+        pb_host_function_costs.set_ret(host_function_costs.ret.into());
+        pb_host_function_costs.set_call_contract(host_function_costs.call_contract.into());
+        pb_host_function_costs.set_get_key(host_function_costs.get_key.into());
+        pb_host_function_costs.set_has_key(host_function_costs.has_key.into());
+        pb_host_function_costs.set_put_key(host_function_costs.put_key.into());
+        pb_host_function_costs.set_remove_key(host_function_costs.remove_key.into());
+        pb_host_function_costs.set_get_caller(host_function_costs.get_caller.into());
+        pb_host_function_costs.set_get_blocktime(host_function_costs.get_blocktime.into());
+        pb_host_function_costs.set_store_function(host_function_costs.store_function.into());
+        pb_host_function_costs
+            .set_store_function_at_hash(host_function_costs.store_function_at_hash.into());
+        pb_host_function_costs.set_is_valid_uref(host_function_costs.is_valid_uref.into());
+        pb_host_function_costs.set_revert(host_function_costs.revert.into());
+        pb_host_function_costs
+            .set_add_associated_key(host_function_costs.add_associated_key.into());
+        pb_host_function_costs
+            .set_remove_associated_key(host_function_costs.remove_associated_key.into());
+        pb_host_function_costs
+            .set_update_associated_key(host_function_costs.update_associated_key.into());
+        pb_host_function_costs
+            .set_set_action_threshold(host_function_costs.set_action_threshold.into());
+        pb_host_function_costs.set_create_purse(host_function_costs.create_purse.into());
+        pb_host_function_costs
+            .set_transfer_to_account(host_function_costs.transfer_to_account.into());
+        pb_host_function_costs.set_transfer_from_purse_to_account(
+            host_function_costs.transfer_from_purse_to_account.into(),
+        );
+        pb_host_function_costs.set_transfer_from_purse_to_purse(
+            host_function_costs.transfer_from_purse_to_purse.into(),
+        );
+        pb_host_function_costs.set_get_balance(host_function_costs.get_balance.into());
+        pb_host_function_costs.set_get_phase(host_function_costs.get_phase.into());
+        pb_host_function_costs
+            .set_upgrade_contract_at_uref(host_function_costs.upgrade_contract_at_uref.into());
+        pb_host_function_costs
+            .set_get_system_contract(host_function_costs.get_system_contract.into());
+        pb_host_function_costs.set_get_main_purse(host_function_costs.get_main_purse.into());
+        pb_host_function_costs.set_read_host_buffer(host_function_costs.read_host_buffer.into());
+        pb_host_function_costs.set_remove(host_function_costs.remove.into());
+        pb_host_function_costs.set_emit_event(host_function_costs.emit_event.into());
+        pb_host_function_costs.set_try_call_contract(host_function_costs.try_call_contract.into());
+        pb_host_function_costs.set_store_contract(host_function_costs.store_contract.into());
+        pb_host_function_costs
+            .set_store_contract_at_hash(host_function_costs.store_contract_at_hash.into());
+        pb_host_function_costs.set_call_entry_point(host_function_costs.call_entry_point.into());
+        pb_host_function_costs
+            .set_get_named_arg_size(host_function_costs.get_named_arg_size.into());
+        pb_host_function_costs.set_get_named_arg(host_function_costs.get_named_arg.into());
+        pb_host_function_costs.set_blake2b(host_function_costs.blake2b.into());
+        pb_host_function_costs.set_ed25519_verify(host_function_costs.ed25519_verify.into());
+        pb_host_function_costs.set_secp256k1_verify(host_function_costs.secp256k1_verify.into());
+        pb_host_function_costs.set_random_bytes(host_function_costs.random_bytes.into());
+        pb_host_function_costs
+    }
+}
+
+impl From<ChainSpec_CostTable_HostFunctionCosts> for HostFunctionCosts {
+    fn from(mut pb_host_function_costs: ChainSpec_CostTable_HostFunctionCosts) -> Self {
+        HostFunctionCosts {
+            read_value: pb_host_function_costs.take_read_value().into(),
+            read_value_local: pb_host_function_costs.take_read_value_local().into(),
+            load_named_keys: pb_host_function_costs.take_load_named_keys().into(),
+            write: pb_host_function_costs.take_write().into(),
+            write_local: pb_host_function_costs.take_write_local().into(),
+            add: pb_host_function_costs.take_add().into(),
+            add_local: pb_host_function_costs.take_add_local().into(),
+            new_uref: pb_host_function_costs.take_new_uref().into(),
+            get_arg_size: pb_host_function_costs.take_get_arg_size().into(),
+            get_arg: pb_host_function_costs.take_get_arg().into(),
+            ret: pb_host_function_costs.take_ret().into(),
+            call_contract: pb_host_function_costs.take_call_contract().into(),
+            get_key: pb_host_function_costs.take_get_key().into(),
+            has_key: pb_host_function_costs.take_has_key().into(),
+            put_key: pb_host_function_costs.take_put_key().into(),
+            remove_key: pb_host_function_costs.take_remove_key().into(),
+            get_caller: pb_host_function_costs.take_get_caller().into(),
+            get_blocktime: pb_host_function_costs.take_get_blocktime().into(),
+            store_function: pb_host_function_costs.take_store_function().into(),
+            store_function_at_hash: pb_host_function_costs.take_store_function_at_hash().into(),
+            is_valid_uref: pb_host_function_costs.take_is_valid_uref().into(),
+            revert: pb_host_function_costs.take_revert().into(),
+            add_associated_key: pb_host_function_costs.take_add_associated_key().into(),
+            remove_associated_key: pb_host_function_costs.take_remove_associated_key().into(),
+            update_associated_key: pb_host_function_costs.take_update_associated_key().into(),
+            set_action_threshold: pb_host_function_costs.take_set_action_threshold().into(),
+            create_purse: pb_host_function_costs.take_create_purse().into(),
+            transfer_to_account: pb_host_function_costs.take_transfer_to_account().into(),
+            transfer_from_purse_to_account: pb_host_function_costs
+                .take_transfer_from_purse_to_account()
+                .into(),
+            transfer_from_purse_to_purse: pb_host_function_costs
+                .take_transfer_from_purse_to_purse()
+                .into(),
+            get_balance: pb_host_function_costs.take_get_balance().into(),
+            get_phase: pb_host_function_costs.take_get_phase().into(),
+            upgrade_contract_at_uref: pb_host_function_costs
+                .take_upgrade_contract_at_uref()
+                .into(),
+            get_system_contract: pb_host_function_costs.take_get_system_contract().into(),
+            get_main_purse: pb_host_function_costs.take_get_main_purse().into(),
+            read_host_buffer: pb_host_function_costs.take_read_host_buffer().into(),
+            remove: pb_host_function_costs.take_remove().into(),
+            emit_event: pb_host_function_costs.take_emit_event().into(),
+            try_call_contract: pb_host_function_costs.take_try_call_contract().into(),
+            store_contract: pb_host_function_costs.take_store_contract().into(),
+            store_contract_at_hash: pb_host_function_costs.take_store_contract_at_hash().into(),
+            call_entry_point: pb_host_function_costs.take_call_entry_point().into(),
+            get_named_arg_size: pb_host_function_costs.take_get_named_arg_size().into(),
+            get_named_arg: pb_host_function_costs.take_get_named_arg().into(),
+            blake2b: pb_host_function_costs.take_blake2b().into(),
+            ed25519_verify: pb_host_function_costs.take_ed25519_verify().into(),
+            secp256k1_verify: pb_host_function_costs.take_secp256k1_verify().into(),
+            random_bytes: pb_host_function_costs.take_random_bytes().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use engine_shared::host_function_costs::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(host_function_costs in gens::host_function_costs_arb()) {
+            test_utils::protobuf_round_trip::<
+                HostFunctionCosts,
+                ChainSpec_CostTable_HostFunctionCosts,
+            >(host_function_costs);
+        }
+    }
+}
@@ -1,14 +1,21 @@
 //! Functions for converting between CasperLabs types and their Protobuf equivalents which are
 //! defined in protobuf/io/casperlabs/ipc/ipc.proto
 
+mod bid_state;
 mod bond;
 mod deploy_item;
 mod deploy_result;
+mod distribute_rewards;
+mod estimate;
 mod executable_deploy_item;
 mod execute_request;
 mod execution_effect;
 mod genesis_account;
 mod genesis_config;
+mod host_function_costs;
 mod query_request;
+mod slash;
+mod system_operation;
+mod unbond_payout;
 mod upgrade_request;
 mod wasm_costs;
@@ -0,0 +1,78 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::slash::SlashRequest;
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{
+    ipc::{self, SlashRequest_ValidatorSlash},
+    mappings::MappingError,
+};
+
+impl TryFrom<ipc::SlashRequest> for SlashRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_slash_request: ipc::SlashRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = pb_slash_request
+            .get_parent_state_hash()
+            .try_into()
+            .map_err(|_| MappingError::InvalidStateHash("parent_state_hash".to_string()))?;
+
+        let protocol_version = pb_slash_request.take_protocol_version().into();
+
+        let slashes = pb_slash_request
+            .take_slashes()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<(PublicKey, U512)>, MappingError>>()?;
+
+        Ok(SlashRequest::new(
+            parent_state_hash,
+            protocol_version,
+            slashes,
+        ))
+    }
+}
+
+impl From<(PublicKey, U512)> for SlashRequest_ValidatorSlash {
+    fn from((key, amount): (PublicKey, U512)) -> Self {
+        let mut pb_validator_slash = SlashRequest_ValidatorSlash::new();
+        pb_validator_slash.set_validator_id(key.as_bytes().to_vec());
+        pb_validator_slash.set_value(amount.into());
+        pb_validator_slash
+    }
+}
+
+impl TryFrom<SlashRequest_ValidatorSlash> for (PublicKey, U512) {
+    type Error = MappingError;
+
+    fn try_from(mut pb_validator_slash: SlashRequest_ValidatorSlash) -> Result<Self, Self::Error> {
+        let public_key =
+            PublicKey::try_from(pb_validator_slash.get_validator_id()).map_err(|_| {
+                MappingError::invalid_public_key_length(pb_validator_slash.validator_id.len())
+            })?;
+
+        let value = pb_validator_slash.take_value().try_into()?;
+
+        Ok((public_key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(public_key in gens::public_key_arb(), u512 in gens::u512_arb()) {
+            test_utils::protobuf_round_trip::<
+                (PublicKey, U512),
+                SlashRequest_ValidatorSlash,
+            >((public_key, u512));
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use engine_core::engine_state::system_operation::SystemOperationError;
+
+use crate::engine_server::ipc;
+
+impl From<SystemOperationError> for ipc::SystemOperationError {
+    fn from(error: SystemOperationError) -> Self {
+        let mut pb_error = ipc::SystemOperationError::new();
+        match error {
+            SystemOperationError::InvalidProtocolVersion(protocol_version) => pb_error
+                .mut_invalid_protocol_version()
+                .set_protocol_version(protocol_version.into()),
+            SystemOperationError::InsufficientRewards => {
+                pb_error.mut_insufficient_rewards();
+            }
+            SystemOperationError::NotBonded => {
+                pb_error.mut_not_bonded();
+            }
+            SystemOperationError::EraWentBackwards => {
+                pb_error.mut_era_went_backwards();
+            }
+            SystemOperationError::TransferFailed => {
+                pb_error.mut_transfer_failed();
+            }
+            SystemOperationError::Exec(error) => pb_error
+                .mut_execution_error()
+                .set_message(error.to_string()),
+            error @ SystemOperationError::KeyNotFound(_)
+            | error @ SystemOperationError::TypeMismatch(_)
+            | error @ SystemOperationError::Serialization(_) => {
+                pb_error.mut_commit_error().set_message(error.to_string())
+            }
+        }
+        pb_error
+    }
+}
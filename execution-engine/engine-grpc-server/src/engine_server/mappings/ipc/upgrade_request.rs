@@ -30,10 +30,52 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
                 (bytes, args)
             };
 
-        let wasm_costs = if !upgrade_point.has_new_costs() {
+        // A cost table in an upgrade has to change at least one of the costs; the ones it leaves
+        // out keep their current values.
+        if upgrade_point.has_new_costs()
+            && !upgrade_point.get_new_costs().has_wasm()
+            && !upgrade_point.get_new_costs().has_host_function_costs()
+        {
+            return Err(MappingError::MissingField("new_costs".to_string()));
+        }
+        let wasm_costs =
+            if !upgrade_point.has_new_costs() || !upgrade_point.get_new_costs().has_wasm() {
+                None
+            } else {
+                Some(upgrade_point.mut_new_costs().take_wasm().into())
+            };
+        let host_function_costs = if !upgrade_point.has_new_costs()
+            || !upgrade_point.get_new_costs().has_host_function_costs()
+        {
             None
         } else {
-            Some(upgrade_point.mut_new_costs().take_wasm().into())
+            Some(
+                upgrade_point
+                    .mut_new_costs()
+                    .take_host_function_costs()
+                    .into(),
+            )
+        };
+        let min_gas_price = match upgrade_point.get_new_min_gas_price() {
+            0 => None,
+            min_gas_price => Some(min_gas_price),
+        };
+        let max_payment = match upgrade_point.get_new_max_payment() {
+            0 => None,
+            max_payment => Some(max_payment),
+        };
+        let block_gas_limit = match upgrade_point.get_new_block_gas_limit() {
+            0 => None,
+            block_gas_limit => Some(block_gas_limit),
+        };
+        let (bonding_delay, unbonding_delay) = if !upgrade_point.has_new_pos_config() {
+            (None, None)
+        } else {
+            let pos_config = upgrade_point.get_new_pos_config();
+            (
+                Some(pos_config.get_bonding_delay()),
+                Some(pos_config.get_unbonding_delay()),
+            )
         };
         let activation_point = if !upgrade_point.has_activation_point() {
             None
@@ -48,6 +90,12 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            max_payment,
+            block_gas_limit,
+            bonding_delay,
+            unbonding_delay,
             activation_point,
         ))
     }
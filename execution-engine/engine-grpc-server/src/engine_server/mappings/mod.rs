@@ -5,13 +5,13 @@ mod state;
 mod transforms;
 
 use std::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     fmt::{self, Display, Formatter},
     string::ToString,
 };
 
 use engine_core::{engine_state, DEPLOY_HASH_LENGTH};
-use types::account::ED25519_LENGTH;
+use types::account::{PublicKey, ED25519_LENGTH, SECP256K1_LENGTH};
 
 pub use transforms::TransformMap;
 
@@ -23,6 +23,20 @@ pub(crate) fn vec_to_array(input: Vec<u8>, input_name: &str) -> Result<[u8; 32],
         .map_err(|_| format!("{} must be 32 bytes.", input_name).into())
 }
 
+/// Try to convert a `Vec<u8>` to a `PublicKey`, using its length to tell the key types apart.
+pub(crate) fn vec_to_public_key(
+    input: Vec<u8>,
+    input_name: &str,
+) -> Result<PublicKey, ParsingError> {
+    PublicKey::try_from(input.as_slice()).map_err(|_| {
+        format!(
+            "{} must be {} or {} bytes.",
+            input_name, ED25519_LENGTH, SECP256K1_LENGTH
+        )
+        .into()
+    })
+}
+
 /// Try to convert a `Vec<u8>` to a 64-byte array.
 pub(crate) fn vec_to_array64(input: Vec<u8>, input_name: &str) -> Result<[u8; 64], ParsingError> {
     if input.len() != 64 {
@@ -36,18 +50,18 @@ pub(crate) fn vec_to_array64(input: Vec<u8>, input_name: &str) -> Result<[u8; 64
 #[derive(Debug)]
 pub enum MappingError {
     InvalidStateHashLength { expected: usize, actual: usize },
-    InvalidPublicKeyLength { expected: usize, actual: usize },
+    InvalidPublicKeyLength { actual: usize },
     InvalidDeployHashLength { expected: usize, actual: usize },
     Parsing(ParsingError),
     InvalidStateHash(String),
     MissingPayload,
+    MissingField(String),
     TryFromSlice,
 }
 
 impl MappingError {
     pub fn invalid_public_key_length(actual: usize) -> Self {
-        let expected = ED25519_LENGTH;
-        MappingError::InvalidPublicKeyLength { expected, actual }
+        MappingError::InvalidPublicKeyLength { actual }
     }
 
     pub fn invalid_deploy_hash_length(actual: usize) -> Self {
@@ -82,10 +96,10 @@ impl Display for MappingError {
                 "Invalid hash length: expected {}, actual {}",
                 expected, actual
             ),
-            MappingError::InvalidPublicKeyLength { expected, actual } => write!(
+            MappingError::InvalidPublicKeyLength { actual } => write!(
                 f,
-                "Invalid public key length: expected {}, actual {}",
-                expected, actual
+                "Invalid public key length: expected {} or {}, actual {}",
+                ED25519_LENGTH, SECP256K1_LENGTH, actual
             ),
             MappingError::InvalidDeployHashLength { expected, actual } => write!(
                 f,
@@ -95,6 +109,7 @@ impl Display for MappingError {
             MappingError::Parsing(ParsingError(message)) => write!(f, "Parsing error: {}", message),
             MappingError::InvalidStateHash(message) => write!(f, "Invalid hash: {}", message),
             MappingError::MissingPayload => write!(f, "Missing payload"),
+            MappingError::MissingField(field) => write!(f, "Missing field: {}", field),
             MappingError::TryFromSlice => write!(f, "Unable to convert from slice"),
         }
     }
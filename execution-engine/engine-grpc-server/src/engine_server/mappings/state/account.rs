@@ -46,7 +46,7 @@ impl TryFrom<state::Account> for Account {
 
     fn try_from(pb_account: state::Account) -> Result<Self, Self::Error> {
         let public_key =
-            mappings::vec_to_array(pb_account.public_key, "Protobuf Account::PublicKey")?;
+            mappings::vec_to_public_key(pb_account.public_key, "Protobuf Account::PublicKey")?;
 
         let named_keys: NamedKeyMap = pb_account.named_keys.into_vec().try_into()?;
 
@@ -92,7 +92,7 @@ impl TryFrom<state::Account> for Account {
         };
 
         let account = Account::new(
-            PublicKey::ed25519_from(public_key),
+            public_key,
             named_keys.into_inner(),
             main_purse,
             associated_keys,
@@ -115,10 +115,10 @@ impl TryFrom<Account_AssociatedKey> for (PublicKey, Weight) {
     type Error = ParsingError;
 
     fn try_from(pb_associated_key: Account_AssociatedKey) -> Result<Self, Self::Error> {
-        let public_key = PublicKey::ed25519_from(mappings::vec_to_array(
+        let public_key = mappings::vec_to_public_key(
             pb_associated_key.public_key,
             "Protobuf Account::AssociatedKey",
-        )?);
+        )?;
 
         let weight = weight_from(pb_associated_key.weight, "Protobuf AssociatedKey::Weight")?;
 
@@ -1,6 +1,7 @@
 use std::convert::{TryFrom, TryInto};
 
 use engine_shared::contract::Contract;
+use types::EntryPoint;
 
 use super::NamedKeyMap;
 use crate::engine_server::{
@@ -10,10 +11,13 @@ use crate::engine_server::{
 
 impl From<Contract> for state::Contract {
     fn from(contract: Contract) -> Self {
-        let (bytes, named_keys, protocol_version) = contract.destructure();
+        let (bytes, entry_points, named_keys, protocol_version) = contract.destructure();
         let mut pb_contract = state::Contract::new();
         let named_keys: Vec<NamedKey> = NamedKeyMap::new(named_keys).into();
         pb_contract.set_body(bytes);
+        let entry_points: Vec<state::EntryPoint> =
+            entry_points.into_iter().map(Into::into).collect();
+        pb_contract.set_entry_points(entry_points.into());
         pb_contract.set_named_keys(named_keys.into());
         pb_contract.set_protocol_version(protocol_version.into());
         pb_contract
@@ -26,7 +30,17 @@ impl TryFrom<state::Contract> for Contract {
     fn try_from(mut pb_contract: state::Contract) -> Result<Self, Self::Error> {
         let named_keys: NamedKeyMap = pb_contract.take_named_keys().into_vec().try_into()?;
         let protocol_version = pb_contract.take_protocol_version().into();
-        let contract = Contract::new(pb_contract.body, named_keys.into_inner(), protocol_version);
+        let entry_points = pb_contract
+            .take_entry_points()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<EntryPoint>, ParsingError>>()?;
+        let contract = Contract::with_entry_points(
+            pb_contract.body,
+            entry_points,
+            named_keys.into_inner(),
+            protocol_version,
+        );
         Ok(contract)
     }
 }
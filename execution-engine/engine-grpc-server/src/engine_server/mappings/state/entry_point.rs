@@ -0,0 +1,66 @@
+use std::convert::{TryFrom, TryInto};
+
+use types::{EntryPoint, Parameter};
+
+use crate::engine_server::{mappings::ParsingError, state};
+
+impl From<Parameter> for state::EntryPoint_Parameter {
+    fn from(parameter: Parameter) -> Self {
+        let mut pb_parameter = state::EntryPoint_Parameter::new();
+        pb_parameter.set_name(parameter.name().to_string());
+        pb_parameter.set_cl_type(parameter.cl_type().clone().into());
+        pb_parameter
+    }
+}
+
+impl TryFrom<state::EntryPoint_Parameter> for Parameter {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_parameter: state::EntryPoint_Parameter) -> Result<Self, Self::Error> {
+        let cl_type = pb_parameter.take_cl_type().try_into()?;
+        Ok(Parameter::new(pb_parameter.name, cl_type))
+    }
+}
+
+impl From<EntryPoint> for state::EntryPoint {
+    fn from(entry_point: EntryPoint) -> Self {
+        let mut pb_entry_point = state::EntryPoint::new();
+        pb_entry_point.set_name(entry_point.name().to_string());
+        let args: Vec<state::EntryPoint_Parameter> =
+            entry_point.args().iter().cloned().map(Into::into).collect();
+        pb_entry_point.set_args(args.into());
+        pb_entry_point.set_ret(entry_point.ret().clone().into());
+        pb_entry_point
+    }
+}
+
+impl TryFrom<state::EntryPoint> for EntryPoint {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_entry_point: state::EntryPoint) -> Result<Self, Self::Error> {
+        let args = pb_entry_point
+            .take_args()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Parameter>, ParsingError>>()?;
+        let ret = pb_entry_point.take_ret().try_into()?;
+        Ok(EntryPoint::new(pb_entry_point.name, args, ret))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(entry_point in gens::entry_point_arb()) {
+            test_utils::protobuf_round_trip::<EntryPoint, state::EntryPoint>(entry_point);
+        }
+    }
+}
@@ -1,8 +1,6 @@
 use std::convert::{TryFrom, TryInto};
 
-use types::{
-    account::PublicKey, Key, BLAKE2B_DIGEST_LENGTH, KEY_LOCAL_LENGTH, KEY_LOCAL_SEED_LENGTH,
-};
+use types::{Key, BLAKE2B_DIGEST_LENGTH, KEY_LOCAL_LENGTH, KEY_LOCAL_SEED_LENGTH};
 
 use crate::engine_server::{
     mappings::{self, ParsingError},
@@ -49,8 +47,9 @@ impl TryFrom<state::Key> for Key {
 
         let key = match pb_key {
             Key_oneof_value::address(pb_account) => {
-                let account = mappings::vec_to_array(pb_account.account, "Protobuf Key::Account")?;
-                Key::Account(PublicKey::ed25519_from(account))
+                let account =
+                    mappings::vec_to_public_key(pb_account.account, "Protobuf Key::Account")?;
+                Key::Account(account)
             }
             Key_oneof_value::hash(pb_hash) => {
                 let hash = mappings::vec_to_array(pb_hash.hash, "Protobuf Key::Hash")?;
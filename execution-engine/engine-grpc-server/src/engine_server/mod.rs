@@ -30,9 +30,13 @@ use grpc::{Error as GrpcError, RequestOptions, ServerBuilder, SingleResponse};
 use log::{info, warn, Level};
 
 use engine_core::engine_state::{
+    bid_state::{BidStateRequest as EngineBidStateRequest, BidStateResult},
+    estimate::{EstimateRequest as EngineEstimateRequest, EstimateResult},
     execute_request::ExecuteRequest,
+    execution_result::ExecutionResult,
     genesis::{GenesisConfig, GenesisResult},
     query::{QueryRequest, QueryResult},
+    system_operation::SystemOperationResult,
     upgrade::{UpgradeConfig, UpgradeResult},
     EngineState, Error as EngineError,
 };
@@ -46,27 +50,37 @@ use types::{bytesrepr::ToBytes, ProtocolVersion};
 use self::{
     ipc::{
         BidStateRequest, BidStateResponse, ChainSpec_GenesisConfig, CommitRequest, CommitResponse,
-        DistributeRewardsRequest, DistributeRewardsResponse, ExecuteResponse, GenesisResponse,
-        QueryResponse, SlashRequest, SlashResponse, UnbondPayoutRequest, UnbondPayoutResponse,
-        UpgradeRequest, UpgradeResponse,
+        DistributeRewardsRequest, DistributeRewardsResponse, EstimateRequest, EstimateResponse,
+        ExecuteResponse, GenesisResponse, QueryResponse, QueryWithProofResponse, SlashRequest,
+        SlashResponse, UnbondPayoutRequest, UnbondPayoutResponse, UpgradeRequest, UpgradeResponse,
     },
     ipc_grpc::{ExecutionEngineService, ExecutionEngineServiceServer},
-    mappings::{ParsingError, TransformMap},
+    mappings::{MappingError, ParsingError, TransformMap},
 };
 
 const METRIC_DURATION_COMMIT: &str = "commit_duration";
 const METRIC_DURATION_EXEC: &str = "exec_duration";
 const METRIC_DURATION_QUERY: &str = "query_duration";
+const METRIC_DURATION_QUERY_WITH_PROOF: &str = "query_with_proof_duration";
 const METRIC_DURATION_GENESIS: &str = "genesis_duration";
 const METRIC_DURATION_UPGRADE: &str = "upgrade_duration";
+const METRIC_DURATION_ESTIMATE: &str = "estimate_duration";
+const METRIC_DURATION_BID_STATE: &str = "bid_state_duration";
+const METRIC_DURATION_DISTRIBUTE_REWARDS: &str = "distribute_rewards_duration";
+const METRIC_DURATION_SLASH: &str = "slash_duration";
+const METRIC_DURATION_UNBOND_PAYOUT: &str = "unbond_payout_duration";
 
 const TAG_RESPONSE_COMMIT: &str = "commit_response";
 const TAG_RESPONSE_EXEC: &str = "exec_response";
 const TAG_RESPONSE_QUERY: &str = "query_response";
+const TAG_RESPONSE_QUERY_WITH_PROOF: &str = "query_with_proof_response";
 const TAG_RESPONSE_GENESIS: &str = "genesis_response";
 const TAG_RESPONSE_UPGRADE: &str = "upgrade_response";
-
-const UNIMPLEMENTED: &str = "unimplemented";
+const TAG_RESPONSE_ESTIMATE: &str = "estimate_response";
+const TAG_RESPONSE_BID_STATE: &str = "bid_state_response";
+const TAG_RESPONSE_DISTRIBUTE_REWARDS: &str = "distribute_rewards_response";
+const TAG_RESPONSE_SLASH: &str = "slash_response";
+const TAG_RESPONSE_UNBOND_PAYOUT: &str = "unbond_payout_response";
 
 const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 
@@ -77,7 +91,7 @@ const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 // (outer layer) leading to cleaner design.
 impl<S> ExecutionEngineService for EngineState<S>
 where
-    S: StateProvider,
+    S: StateProvider + Sync,
     EngineError: From<S::Error>,
     S::Error: Into<engine_core::execution::Error> + Debug,
 {
@@ -162,6 +176,100 @@ where
         SingleResponse::completed(response)
     }
 
+    fn query_with_proof(
+        &self,
+        _request_options: RequestOptions,
+        query_request: ipc::QueryRequest,
+    ) -> SingleResponse<QueryWithProofResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let request: QueryRequest = match query_request.try_into() {
+            Ok(ret) => ret,
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                warn!("{}", log_message);
+                let mut result = ipc::QueryWithProofResponse::new();
+                result.set_failure(log_message);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_QUERY_WITH_PROOF,
+                    TAG_RESPONSE_QUERY_WITH_PROOF,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(result);
+            }
+        };
+
+        let result = self.run_query_with_proof(correlation_id, request);
+
+        let response = match result {
+            Ok(query_with_proof_result) => {
+                let (query_result, proofs) = query_with_proof_result.take();
+                let mut result = ipc::QueryWithProofResponse::new();
+                match query_result {
+                    QueryResult::Success(value) => match value.to_bytes() {
+                        Ok(serialized_value) => {
+                            info!(
+                                "query with proof successful; correlation_id: {}",
+                                correlation_id
+                            );
+                            result.set_success(serialized_value);
+                        }
+                        Err(error_msg) => {
+                            let log_message =
+                                format!("Failed to serialize StoredValue: {}", error_msg);
+                            warn!("{}", log_message);
+                            result.set_failure(log_message);
+                        }
+                    },
+                    QueryResult::ValueNotFound(msg) => {
+                        info!("{}", msg);
+                        result.set_failure(msg);
+                    }
+                    QueryResult::RootNotFound => {
+                        let log_message = "Root not found";
+                        info!("{}", log_message);
+                        result.set_failure(log_message.to_string());
+                    }
+                    QueryResult::CircularReference(msg) => {
+                        warn!("{}", msg);
+                        result.set_failure(msg);
+                    }
+                }
+                match proofs
+                    .iter()
+                    .map(ToBytes::to_bytes)
+                    .collect::<Result<Vec<Vec<u8>>, _>>()
+                {
+                    Ok(serialized_proofs) => result.set_proofs(serialized_proofs.into()),
+                    Err(error_msg) => {
+                        let log_message = format!("Failed to serialize proofs: {}", error_msg);
+                        warn!("{}", log_message);
+                        result.set_failure(log_message);
+                    }
+                }
+                result
+            }
+            Err(err) => {
+                let log_message = format!("{:?}", err);
+                warn!("{}", log_message);
+                let mut result = ipc::QueryWithProofResponse::new();
+                result.set_failure(log_message);
+                result
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_QUERY_WITH_PROOF,
+            TAG_RESPONSE_QUERY_WITH_PROOF,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(response)
+    }
+
     fn execute(
         &self,
         _request_options: RequestOptions,
@@ -170,6 +278,7 @@ where
         let start = Instant::now();
         let correlation_id = CorrelationId::new();
 
+        let sequential = exec_request.get_sequential();
         let exec_request: ExecuteRequest = match exec_request.try_into() {
             Ok(ret) => ret,
             Err(err) => {
@@ -179,8 +288,20 @@ where
 
         let mut exec_response = ExecuteResponse::new();
 
-        let results = match self.run_execute(correlation_id, exec_request) {
-            Ok(results) => results,
+        let maybe_results = if sequential {
+            if self.config().parallel_execution() {
+                self.run_execute_parallel(correlation_id, exec_request)
+            } else {
+                self.run_execute_sequential(correlation_id, exec_request)
+            }
+            .map(|(results, combined_effect)| (results, Some(combined_effect)))
+        } else {
+            self.run_execute(correlation_id, exec_request)
+                .map(|results| (results, None))
+        };
+
+        let (results, maybe_combined_effect) = match maybe_results {
+            Ok(ret) => ret,
             Err(error) => {
                 info!("deploy results error: RootNotFound");
                 exec_response.mut_missing_parent().set_hash(error.to_vec());
@@ -198,6 +319,11 @@ where
         exec_response
             .mut_success()
             .set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
+        if let Some(combined_effect) = maybe_combined_effect {
+            exec_response
+                .mut_success()
+                .set_combined_effect(combined_effect.into());
+        }
         log_duration(
             correlation_id,
             METRIC_DURATION_EXEC,
@@ -445,39 +571,311 @@ where
         SingleResponse::completed(upgrade_response)
     }
 
+    fn estimate(
+        &self,
+        _request_options: RequestOptions,
+        estimate_request: EstimateRequest,
+    ) -> SingleResponse<EstimateResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash = estimate_request.get_parent_state_hash().to_vec();
+
+        let request: EngineEstimateRequest = match estimate_request.try_into() {
+            Ok(request) => request,
+            Err(error) => {
+                warn!("{}", error);
+                let mut estimate_response = EstimateResponse::new();
+                match error {
+                    MappingError::InvalidStateHash(_) => estimate_response
+                        .mut_missing_parent()
+                        .set_hash(parent_state_hash),
+                    error => estimate_response
+                        .set_failure(ExecutionResult::precondition_failure(error.into()).into()),
+                }
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_ESTIMATE,
+                    TAG_RESPONSE_ESTIMATE,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(estimate_response);
+            }
+        };
+
+        let result = self.run_estimate(correlation_id, request);
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_ESTIMATE,
+            TAG_RESPONSE_ESTIMATE,
+            start.elapsed(),
+        );
+
+        match result {
+            Ok(EstimateResult::Success {
+                payment_cost,
+                session_cost,
+                finalize_cost,
+                motes,
+            }) => {
+                info!("estimate successful; correlation_id: {}", correlation_id);
+                let mut estimate_response = EstimateResponse::new();
+                let estimate_result = estimate_response.mut_success();
+                estimate_result.set_payment_cost(payment_cost.value().into());
+                estimate_result.set_session_cost(session_cost.value().into());
+                estimate_result.set_finalize_cost(finalize_cost.value().into());
+                estimate_result.set_motes(motes.value().into());
+                SingleResponse::completed(estimate_response)
+            }
+            Ok(EstimateResult::RootNotFound) => {
+                info!("estimate error: RootNotFound");
+                let mut estimate_response = EstimateResponse::new();
+                estimate_response
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash);
+                SingleResponse::completed(estimate_response)
+            }
+            Ok(EstimateResult::Failure(execution_result)) => {
+                info!("estimate failure; correlation_id: {}", correlation_id);
+                let mut estimate_response = EstimateResponse::new();
+                estimate_response.set_failure(execution_result.into());
+                SingleResponse::completed(estimate_response)
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                SingleResponse::err(GrpcError::Panic(err_msg))
+            }
+        }
+    }
+
     fn bid_state(
         &self,
         _request_options: RequestOptions,
-        _bid_state_request: BidStateRequest,
+        bid_state_request: BidStateRequest,
     ) -> SingleResponse<BidStateResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let parent_state_hash = bid_state_request.get_parent_state_hash().to_vec();
+
+        let request: EngineBidStateRequest = match bid_state_request.try_into() {
+            Ok(request) => request,
+            Err(error) => {
+                warn!("{}", error);
+                let mut bid_state_response = BidStateResponse::new();
+                bid_state_response
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash);
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_BID_STATE,
+                    TAG_RESPONSE_BID_STATE,
+                    start.elapsed(),
+                );
+                return SingleResponse::completed(bid_state_response);
+            }
+        };
+
+        let result = self.get_bid_state(correlation_id, request);
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_BID_STATE,
+            TAG_RESPONSE_BID_STATE,
+            start.elapsed(),
+        );
+
+        match result {
+            Ok(BidStateResult::Success(stakes)) => {
+                info!("bid state successful; correlation_id: {}", correlation_id);
+                let mut bid_state_response = BidStateResponse::new();
+                bid_state_response.set_success(stakes.into());
+                SingleResponse::completed(bid_state_response)
+            }
+            Ok(BidStateResult::RootNotFound) => {
+                info!("bid state error: RootNotFound");
+                let mut bid_state_response = BidStateResponse::new();
+                bid_state_response
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash);
+                SingleResponse::completed(bid_state_response)
+            }
+            Err(error) => {
+                let err_msg = error.to_string();
+                warn!("{}", err_msg);
+                SingleResponse::err(GrpcError::Panic(err_msg))
+            }
+        }
     }
 
     fn distribute_rewards(
         &self,
         _request_options: RequestOptions,
-        _distribute_rewards_request: DistributeRewardsRequest,
+        distribute_rewards_request: DistributeRewardsRequest,
     ) -> SingleResponse<DistributeRewardsResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let parent_state_hash = distribute_rewards_request.get_parent_state_hash().to_vec();
+        run_system_operation(
+            "distribute rewards",
+            METRIC_DURATION_DISTRIBUTE_REWARDS,
+            TAG_RESPONSE_DISTRIBUTE_REWARDS,
+            parent_state_hash,
+            distribute_rewards_request,
+            |correlation_id, request| self.commit_distribute_rewards(correlation_id, request),
+        )
     }
 
     fn slash(
         &self,
         _request_options: RequestOptions,
-        _slash_request: SlashRequest,
+        slash_request: SlashRequest,
     ) -> SingleResponse<SlashResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let parent_state_hash = slash_request.get_parent_state_hash().to_vec();
+        run_system_operation(
+            "slash",
+            METRIC_DURATION_SLASH,
+            TAG_RESPONSE_SLASH,
+            parent_state_hash,
+            slash_request,
+            |correlation_id, request| self.commit_slash(correlation_id, request),
+        )
     }
 
     fn unbond_payout(
         &self,
         _request_options: RequestOptions,
-        _unbond_payout_request: UnbondPayoutRequest,
+        unbond_payout_request: UnbondPayoutRequest,
     ) -> SingleResponse<UnbondPayoutResponse> {
-        SingleResponse::err(GrpcError::Panic(UNIMPLEMENTED.to_string()))
+        let parent_state_hash = unbond_payout_request.get_parent_state_hash().to_vec();
+        run_system_operation(
+            "unbond payout",
+            METRIC_DURATION_UNBOND_PAYOUT,
+            TAG_RESPONSE_UNBOND_PAYOUT,
+            parent_state_hash,
+            unbond_payout_request,
+            |correlation_id, request| self.commit_unbond_payout(correlation_id, request),
+        )
     }
 }
 
+/// The response to a proof of stake operation which the engine runs as the system account.
+trait SystemOperationResponse: Default + Send + 'static {
+    fn set_success(&mut self, commit_result: ipc::CommitResult);
+
+    fn set_missing_parent(&mut self, root_not_found: ipc::RootNotFound);
+
+    fn set_error(&mut self, error: ipc::SystemOperationError);
+}
+
+macro_rules! impl_system_operation_response {
+    ($($response:ty),*) => {
+        $(
+            impl SystemOperationResponse for $response {
+                fn set_success(&mut self, commit_result: ipc::CommitResult) {
+                    <$response>::set_success(self, commit_result)
+                }
+
+                fn set_missing_parent(&mut self, root_not_found: ipc::RootNotFound) {
+                    <$response>::set_missing_parent(self, root_not_found)
+                }
+
+                fn set_error(&mut self, error: ipc::SystemOperationError) {
+                    <$response>::set_error(self, error)
+                }
+            }
+        )*
+    };
+}
+
+impl_system_operation_response!(
+    DistributeRewardsResponse,
+    SlashResponse,
+    UnbondPayoutResponse
+);
+
+/// Converts `pb_request` and passes it to `commit`, which runs a proof of stake operation as the
+/// system account and commits its effects.  `operation` names the operation in the logs.
+fn run_system_operation<P, R, T, F>(
+    operation: &str,
+    metric: &str,
+    tag: &str,
+    parent_state_hash: Vec<u8>,
+    pb_request: P,
+    commit: F,
+) -> SingleResponse<T>
+where
+    P: TryInto<R, Error = MappingError>,
+    T: SystemOperationResponse,
+    F: FnOnce(CorrelationId, R) -> Result<SystemOperationResult, EngineError>,
+{
+    let start = Instant::now();
+    let correlation_id = CorrelationId::new();
+
+    let mut response = T::default();
+    let result = match pb_request.try_into() {
+        Ok(request) => commit(correlation_id, request),
+        Err(error) => {
+            warn!("{}", error);
+            match error {
+                MappingError::InvalidStateHash(_) => {
+                    response.set_missing_parent(root_not_found(parent_state_hash))
+                }
+                error => {
+                    let mut pb_error = ipc::SystemOperationError::new();
+                    pb_error
+                        .mut_invalid_request()
+                        .set_message(error.to_string());
+                    response.set_error(pb_error)
+                }
+            }
+            log_duration(correlation_id, metric, tag, start.elapsed());
+            return SingleResponse::completed(response);
+        }
+    };
+
+    log_duration(correlation_id, metric, tag, start.elapsed());
+
+    match result {
+        Ok(SystemOperationResult::Success {
+            post_state_hash,
+            bonded_validators,
+        }) => {
+            info!(
+                "{} successful; new state hash is: {:?}; correlation_id: {}",
+                operation, post_state_hash, correlation_id
+            );
+            let bonds = bonded_validators.into_iter().map(Into::into).collect();
+            let mut commit_result = ipc::CommitResult::new();
+            commit_result.set_poststate_hash(post_state_hash.to_vec());
+            commit_result.set_bonded_validators(bonds);
+            response.set_success(commit_result);
+            SingleResponse::completed(response)
+        }
+        Ok(SystemOperationResult::RootNotFound) => {
+            info!("{} error: RootNotFound", operation);
+            response.set_missing_parent(root_not_found(parent_state_hash));
+            SingleResponse::completed(response)
+        }
+        Ok(SystemOperationResult::Failure(error)) => {
+            warn!("{} error: {}", operation, error);
+            response.set_error(error.into());
+            SingleResponse::completed(response)
+        }
+        Err(error) => {
+            let err_msg = error.to_string();
+            warn!("{}", err_msg);
+            SingleResponse::err(GrpcError::Panic(err_msg))
+        }
+    }
+}
+
+fn root_not_found(parent_state_hash: Vec<u8>) -> ipc::RootNotFound {
+    let mut root_not_found = ipc::RootNotFound::new();
+    root_not_found.set_hash(parent_state_hash);
+    root_not_found
+}
+
 // Helper method which returns single DeployResult that is set to be a
 // WasmError.
 pub fn new<E: ExecutionEngineService + Sync + Send + 'static>(
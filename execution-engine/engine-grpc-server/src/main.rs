@@ -12,7 +12,13 @@ use std::{
 
 use clap::{App, Arg, ArgMatches};
 use dirs::home_dir;
-use engine_core::engine_state::{EngineConfig, EngineState};
+use engine_core::{
+    engine_state::{
+        module_cache::{ModuleCache, DEFAULT_MODULE_CACHE_CAPACITY},
+        EngineConfig, EngineState,
+    },
+    wasm_backend::WasmBackendKind,
+};
 use lmdb::DatabaseFlags;
 use log::{error, info, Level, LevelFilter};
 
@@ -42,6 +48,7 @@ const ARG_DATA_DIR_VALUE: &str = "DIR";
 const ARG_DATA_DIR_HELP: &str = "Sets the data directory";
 const DEFAULT_DATA_DIR_RELATIVE: &str = ".casperlabs";
 const GLOBAL_STATE_DIR: &str = "global_state";
+const MODULE_CACHE_DIR: &str = "module_cache";
 const GET_HOME_DIR_EXPECT: &str = "Could not get home directory";
 const CREATE_DATA_DIR_EXPECT: &str = "Could not create directory";
 const LMDB_ENVIRONMENT_EXPECT: &str = "Could not create LmdbEnvironment";
@@ -107,6 +114,33 @@ const ARG_ENABLE_BONDING: &str = "enable-bonding";
 const ARG_ENABLE_BONDING_SHORT: &str = "b";
 const ARG_ENABLE_BONDING_HELP: &str = "Enable bonding";
 
+// parallel execution
+const ARG_PARALLEL_EXECUTION: &str = "parallel-execution";
+const ARG_PARALLEL_EXECUTION_HELP: &str =
+    "Run the deploys of sequential execute requests concurrently, with the same results";
+
+// module cache
+const ARG_MODULE_CACHE_CAPACITY: &str = "module-cache-capacity";
+const ARG_MODULE_CACHE_CAPACITY_VALUE: &str = "NUM";
+const ARG_MODULE_CACHE_CAPACITY_HELP: &str =
+    "Sets the max number of stored contract modules kept in memory";
+const ARG_MODULE_CACHE_CAPACITY_EXPECT: &str = "expected valid module cache capacity";
+const ARG_PERSIST_MODULE_CACHE: &str = "persist-module-cache";
+const ARG_PERSIST_MODULE_CACHE_HELP: &str =
+    "Keeps the preprocessed wasm modules of stored contracts in the data directory across restarts";
+
+// wasm backend
+const ARG_WASM_BACKEND: &str = "wasm-backend";
+const ARG_WASM_BACKEND_VALUE: &str = "BACKEND";
+const ARG_WASM_BACKEND_HELP: &str = "Sets the backend which runs contracts";
+const WASM_BACKEND_WASMI: &str = "wasmi";
+#[cfg(feature = "wasmtime-backend")]
+const WASM_BACKEND_WASMTIME: &str = "wasmtime";
+#[cfg(not(feature = "wasmtime-backend"))]
+const WASM_BACKENDS: &[&str] = &[WASM_BACKEND_WASMI];
+#[cfg(feature = "wasmtime-backend")]
+const WASM_BACKENDS: &[&str] = &[WASM_BACKEND_WASMI, WASM_BACKEND_WASMTIME];
+
 // runnable
 const SIGINT_HANDLE_EXPECT: &str = "Error setting Ctrl-C handler";
 const RUNNABLE_CHECK_INTERVAL_SECONDS: u64 = 3;
@@ -127,7 +161,7 @@ fn main() {
         Ok(_) => info!("removing old socket file"),
     };
 
-    let data_dir = get_data_dir(&arg_matches);
+    let data_dir = get_data_dir(&arg_matches, GLOBAL_STATE_DIR);
 
     let map_size = get_map_size(&arg_matches);
 
@@ -135,7 +169,16 @@ fn main() {
 
     let engine_config: EngineConfig = get_engine_config(&arg_matches);
 
-    let _server = get_grpc_server(&socket, data_dir, map_size, thread_count, engine_config);
+    let module_cache = get_module_cache(&arg_matches);
+
+    let _server = get_grpc_server(
+        &socket,
+        data_dir,
+        map_size,
+        thread_count,
+        engine_config,
+        module_cache,
+    );
 
     log_listening_message(&socket);
 
@@ -239,6 +282,33 @@ fn get_args() -> ArgMatches<'static> {
                 .long(ARG_ENABLE_BONDING)
                 .help(ARG_ENABLE_BONDING_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_PARALLEL_EXECUTION)
+                .long(ARG_PARALLEL_EXECUTION)
+                .help(ARG_PARALLEL_EXECUTION_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_MODULE_CACHE_CAPACITY)
+                .long(ARG_MODULE_CACHE_CAPACITY)
+                .takes_value(true)
+                .value_name(ARG_MODULE_CACHE_CAPACITY_VALUE)
+                .help(ARG_MODULE_CACHE_CAPACITY_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_PERSIST_MODULE_CACHE)
+                .long(ARG_PERSIST_MODULE_CACHE)
+                .help(ARG_PERSIST_MODULE_CACHE_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_WASM_BACKEND)
+                .required(false)
+                .long(ARG_WASM_BACKEND)
+                .takes_value(true)
+                .possible_values(WASM_BACKENDS)
+                .default_value(WASM_BACKEND_WASMI)
+                .value_name(ARG_WASM_BACKEND_VALUE)
+                .help(ARG_WASM_BACKEND_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
                 .required(true)
@@ -266,8 +336,8 @@ fn get_socket(arg_matches: &ArgMatches) -> socket::Socket {
     socket::Socket::new(socket.to_owned())
 }
 
-/// Gets value of data-dir argument
-fn get_data_dir(arg_matches: &ArgMatches) -> PathBuf {
+/// Gets the given subdirectory of the data-dir argument, creating it if necessary
+fn get_data_dir(arg_matches: &ArgMatches, subdirectory: &str) -> PathBuf {
     let mut buf = arg_matches.value_of(ARG_DATA_DIR).map_or(
         {
             let mut dir = home_dir().expect(GET_HOME_DIR_EXPECT);
@@ -276,7 +346,7 @@ fn get_data_dir(arg_matches: &ArgMatches) -> PathBuf {
         },
         PathBuf::from,
     );
-    buf.push(GLOBAL_STATE_DIR);
+    buf.push(subdirectory);
     fs::create_dir_all(&buf).unwrap_or_else(|_| panic!("{}: {:?}", CREATE_DATA_DIR_EXPECT, buf));
     buf
 }
@@ -304,9 +374,36 @@ fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
     // feature flags go here
     let use_system_contracts = arg_matches.is_present(ARG_USE_SYSTEM_CONTRACTS);
     let enable_bonding = arg_matches.is_present(ARG_ENABLE_BONDING);
+    let parallel_execution = arg_matches.is_present(ARG_PARALLEL_EXECUTION);
+    let wasm_backend = get_wasm_backend(arg_matches);
     EngineConfig::new()
         .with_use_system_contracts(use_system_contracts)
         .with_enable_bonding(enable_bonding)
+        .with_parallel_execution(parallel_execution)
+        .with_wasm_backend(wasm_backend)
+}
+
+/// Returns the [`WasmBackendKind`] named by the wasm-backend arg.
+fn get_wasm_backend(arg_matches: &ArgMatches) -> WasmBackendKind {
+    match arg_matches.value_of(ARG_WASM_BACKEND) {
+        #[cfg(feature = "wasmtime-backend")]
+        Some(WASM_BACKEND_WASMTIME) => WasmBackendKind::Wasmtime,
+        _ => WasmBackendKind::Wasmi,
+    }
+}
+
+/// Returns a [`ModuleCache`], kept in the data directory if the persist-module-cache flag is set.
+fn get_module_cache(arg_matches: &ArgMatches) -> ModuleCache {
+    let capacity = arg_matches
+        .value_of(ARG_MODULE_CACHE_CAPACITY)
+        .map_or(Ok(DEFAULT_MODULE_CACHE_CAPACITY), usize::from_str)
+        .expect(ARG_MODULE_CACHE_CAPACITY_EXPECT);
+    let directory = if arg_matches.is_present(ARG_PERSIST_MODULE_CACHE) {
+        Some(get_data_dir(arg_matches, MODULE_CACHE_DIR))
+    } else {
+        None
+    };
+    ModuleCache::new(capacity, directory)
 }
 
 /// Builds and returns a gRPC server.
@@ -316,8 +413,9 @@ fn get_grpc_server(
     map_size: usize,
     thread_count: usize,
     engine_config: EngineConfig,
+    module_cache: ModuleCache,
 ) -> grpc::Server {
-    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+    let engine_state = get_engine_state(data_dir, map_size, engine_config, module_cache);
 
     engine_server::new(socket.as_str(), thread_count, engine_state)
         .build()
@@ -329,6 +427,7 @@ fn get_engine_state(
     data_dir: PathBuf,
     map_size: usize,
     engine_config: EngineConfig,
+    module_cache: ModuleCache,
 ) -> EngineState<LmdbGlobalState> {
     let environment = {
         let ret = LmdbEnvironment::new(&data_dir, map_size).expect(LMDB_ENVIRONMENT_EXPECT);
@@ -350,7 +449,7 @@ fn get_engine_state(
     let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
         .expect(LMDB_GLOBAL_STATE_EXPECT);
 
-    EngineState::new(global_state, engine_config)
+    EngineState::new(global_state, engine_config).with_module_cache(module_cache)
 }
 
 /// Builds and returns log settings
@@ -151,7 +151,9 @@ mod tests {
     use std::{collections::BTreeSet, iter::FromIterator};
 
     use types::{
-        account::{AddKeyFailure, PublicKey, Weight, ED25519_LENGTH, MAX_ASSOCIATED_KEYS},
+        account::{
+            AddKeyFailure, PublicKey, Weight, ED25519_LENGTH, MAX_ASSOCIATED_KEYS, SECP256K1_LENGTH,
+        },
         bytesrepr,
     };
 
@@ -215,6 +217,24 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn associated_keys_mixed_key_types() {
+        let ed25519 = PublicKey::ed25519_from([1u8; ED25519_LENGTH]);
+        let secp256k1 = PublicKey::secp256k1_from([1u8; SECP256K1_LENGTH]);
+        let mut keys = AssociatedKeys::new(ed25519, Weight::new(1));
+        assert!(keys.add_key(secp256k1, Weight::new(2)).is_ok());
+        assert_eq!(keys.get(&ed25519), Some(&Weight::new(1)));
+        assert_eq!(keys.get(&secp256k1), Some(&Weight::new(2)));
+        assert_eq!(keys.total_keys_weight(), Weight::new(3));
+        assert_eq!(
+            keys.calculate_keys_weight(&BTreeSet::from_iter(vec![secp256k1])),
+            Weight::new(2)
+        );
+        assert!(keys.remove_key(&secp256k1).is_ok());
+        assert!(keys.get(&secp256k1).is_none());
+        bytesrepr::test_serialization_roundtrip(&keys);
+    }
+
     #[test]
     fn associated_keys_calculate_keys_once() {
         let key_1 = PublicKey::ed25519_from([0; 32]);
@@ -2,24 +2,44 @@ use std::collections::BTreeMap;
 
 use types::{
     bytesrepr::{self, Error, FromBytes, ToBytes},
-    Key, ProtocolVersion,
+    EntryPoint, Key, ProtocolVersion,
 };
 
+/// The name of the function invoked when a contract is called without naming an entry point.
+pub const DEFAULT_ENTRY_POINT: &str = "call";
+
+/// Marks the entry points which follow the original layout of a serialized contract.  Contracts
+/// without entry points are serialized without it, in the original layout.
+const ENTRY_POINTS_VERSION: u8 = 1;
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Contract {
     bytes: Vec<u8>,
+    entry_points: Vec<EntryPoint>,
     named_keys: BTreeMap<String, Key>,
     protocol_version: ProtocolVersion,
 }
 
 impl Contract {
+    /// Creates a contract whose only entry point is the function exported as `call`.
     pub fn new(
         bytes: Vec<u8>,
         named_keys: BTreeMap<String, Key>,
         protocol_version: ProtocolVersion,
+    ) -> Self {
+        Contract::with_entry_points(bytes, Vec::new(), named_keys, protocol_version)
+    }
+
+    /// Creates a contract which can be called at any of the functions described by `entry_points`.
+    pub fn with_entry_points(
+        bytes: Vec<u8>,
+        entry_points: Vec<EntryPoint>,
+        named_keys: BTreeMap<String, Key>,
+        protocol_version: ProtocolVersion,
     ) -> Self {
         Contract {
             bytes,
+            entry_points,
             named_keys,
             protocol_version,
         }
@@ -37,14 +57,39 @@ impl Contract {
         &mut self.named_keys
     }
 
-    pub fn destructure(self) -> (Vec<u8>, BTreeMap<String, Key>, ProtocolVersion) {
-        (self.bytes, self.named_keys, self.protocol_version)
+    pub fn destructure(
+        self,
+    ) -> (
+        Vec<u8>,
+        Vec<EntryPoint>,
+        BTreeMap<String, Key>,
+        ProtocolVersion,
+    ) {
+        (
+            self.bytes,
+            self.entry_points,
+            self.named_keys,
+            self.protocol_version,
+        )
     }
 
     pub fn bytes(&self) -> &[u8] {
         &self.bytes
     }
 
+    /// The functions the contract can be called at.  Empty for a contract which can only be called
+    /// at `call`, and which doesn't describe the arguments it takes.
+    pub fn entry_points(&self) -> &[EntryPoint] {
+        &self.entry_points
+    }
+
+    /// Returns the entry point exported as `name`, if the contract declares one.
+    pub fn entry_point(&self, name: &str) -> Option<&EntryPoint> {
+        self.entry_points
+            .iter()
+            .find(|entry_point| entry_point.name() == name)
+    }
+
     pub fn protocol_version(&self) -> ProtocolVersion {
         self.protocol_version
     }
@@ -60,13 +105,23 @@ impl ToBytes for Contract {
         result.append(&mut self.bytes.to_bytes()?);
         result.append(&mut self.named_keys.to_bytes()?);
         result.append(&mut self.protocol_version.to_bytes()?);
+        if !self.entry_points.is_empty() {
+            result.append(&mut ENTRY_POINTS_VERSION.to_bytes()?);
+            result.append(&mut self.entry_points.to_bytes()?);
+        }
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
+        let entry_points_length = if self.entry_points.is_empty() {
+            0
+        } else {
+            ENTRY_POINTS_VERSION.serialized_length() + self.entry_points.serialized_length()
+        };
         self.bytes.serialized_length()
             + self.named_keys.serialized_length()
             + self.protocol_version.serialized_length()
+            + entry_points_length
     }
 }
 
@@ -75,13 +130,24 @@ impl FromBytes for Contract {
         let (bytes, rem1) = Vec::<u8>::from_bytes(bytes)?;
         let (named_keys, rem2) = BTreeMap::<String, Key>::from_bytes(rem1)?;
         let (protocol_version, rem3) = ProtocolVersion::from_bytes(rem2)?;
+        // Contracts stored before entry points were introduced end here
+        let (entry_points, rem4) = if rem3.is_empty() {
+            (Vec::new(), rem3)
+        } else {
+            let (version, rem) = u8::from_bytes(rem3)?;
+            if version != ENTRY_POINTS_VERSION {
+                return Err(Error::Formatting);
+            }
+            Vec::<EntryPoint>::from_bytes(rem)?
+        };
         Ok((
             Contract {
                 bytes,
+                entry_points,
                 named_keys,
                 protocol_version,
             },
-            rem3,
+            rem4,
         ))
     }
 }
@@ -89,16 +155,94 @@ impl FromBytes for Contract {
 pub mod gens {
     use proptest::{collection::vec, prelude::*};
 
-    use types::gens::{named_keys_arb, protocol_version_arb};
+    use types::gens::{entry_point_arb, named_keys_arb, protocol_version_arb};
 
     use super::Contract;
 
     pub fn contract_arb() -> impl Strategy<Value = Contract> {
         protocol_version_arb().prop_flat_map(move |protocol_version_arb| {
             named_keys_arb(20).prop_flat_map(move |urefs| {
-                vec(any::<u8>(), 1..1000)
-                    .prop_map(move |body| Contract::new(body, urefs.clone(), protocol_version_arb))
+                vec(entry_point_arb(), 0..5).prop_flat_map(move |entry_points| {
+                    let urefs = urefs.clone();
+                    vec(any::<u8>(), 1..1000).prop_map(move |body| {
+                        Contract::with_entry_points(
+                            body,
+                            entry_points.clone(),
+                            urefs.clone(),
+                            protocol_version_arb,
+                        )
+                    })
+                })
             })
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use types::{
+        bytesrepr::{self, Error, ToBytes},
+        CLType, EntryPoint, Parameter, ProtocolVersion,
+    };
+
+    use super::{Contract, ENTRY_POINTS_VERSION};
+
+    fn typed_contract() -> Contract {
+        Contract::with_entry_points(
+            vec![0, 97, 115, 109],
+            vec![EntryPoint::new(
+                "call",
+                vec![Parameter::new("value", CLType::U64)],
+                CLType::Unit,
+            )],
+            BTreeMap::new(),
+            ProtocolVersion::from_parts(1, 0, 0),
+        )
+    }
+
+    #[test]
+    fn should_serialize_contract_without_entry_points_in_original_layout() {
+        let contract = Contract::new(
+            vec![0, 97, 115, 109],
+            BTreeMap::new(),
+            ProtocolVersion::from_parts(1, 0, 0),
+        );
+        let bytes = contract.to_bytes().expect("should serialize");
+
+        let mut expected = vec![4, 0, 0, 0, 0, 97, 115, 109];
+        expected.extend_from_slice(&[0, 0, 0, 0]);
+        expected.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes, expected);
+        assert_eq!(bytesrepr::deserialize(bytes), Ok(contract));
+    }
+
+    #[test]
+    fn should_serialize_entry_points_after_original_layout() {
+        let bytes = typed_contract().to_bytes().expect("should serialize");
+
+        let mut expected = vec![4, 0, 0, 0, 0, 97, 115, 109];
+        expected.extend_from_slice(&[0, 0, 0, 0]);
+        expected.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        expected.push(ENTRY_POINTS_VERSION);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(&[4, 0, 0, 0, 99, 97, 108, 108]);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(&[5, 0, 0, 0, 118, 97, 108, 117, 101, 5]);
+        expected.push(9);
+        assert_eq!(bytes, expected);
+        assert_eq!(bytesrepr::deserialize(bytes), Ok(typed_contract()));
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_entry_points_version() {
+        let mut bytes = typed_contract().to_bytes().expect("should serialize");
+        let version_index = 24;
+        assert_eq!(bytes[version_index], ENTRY_POINTS_VERSION);
+        bytes[version_index] = ENTRY_POINTS_VERSION + 1;
+
+        let result: Result<Contract, _> = bytesrepr::deserialize(bytes);
+        assert_eq!(result, Err(Error::Formatting));
+    }
+}
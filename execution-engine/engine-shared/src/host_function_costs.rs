@@ -0,0 +1,461 @@
+use types::{
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    U512,
+};
+
+use crate::gas::Gas;
+
+pub(crate) const NUM_HOST_FUNCTIONS: usize = 48;
+pub const HOST_FUNCTION_COST_SERIALIZED_LENGTH: usize = 2 * U32_SERIALIZED_LENGTH;
+pub const HOST_FUNCTION_COSTS_SERIALIZED_LENGTH: usize =
+    U32_SERIALIZED_LENGTH + NUM_HOST_FUNCTIONS * HOST_FUNCTION_COST_SERIALIZED_LENGTH;
+
+/// The cost of a single call to a host function.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCost {
+    /// Cost charged on every call
+    pub fixed: u32,
+    /// Cost charged per byte of the arguments passed in Wasm memory
+    pub per_byte: u32,
+}
+
+impl HostFunctionCost {
+    pub fn new(fixed: u32, per_byte: u32) -> Self {
+        HostFunctionCost { fixed, per_byte }
+    }
+
+    /// Returns the cost of a call whose arguments span `bytes_count` bytes.
+    pub fn calculate_gas(&self, bytes_count: u64) -> Gas {
+        let per_byte = U512::from(self.per_byte) * U512::from(bytes_count);
+        Gas::new(U512::from(self.fixed) + per_byte)
+    }
+}
+
+impl ToBytes for HostFunctionCost {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.fixed.to_bytes()?);
+        ret.append(&mut self.per_byte.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        HOST_FUNCTION_COST_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for HostFunctionCost {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (fixed, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((HostFunctionCost { fixed, per_byte }, rem))
+    }
+}
+
+/// The costs of calling each of the host functions available to contracts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCosts {
+    /// Cost of reading a value from global state.
+    pub read_value: HostFunctionCost,
+    /// Cost of reading a value from local state.
+    pub read_value_local: HostFunctionCost,
+    /// Cost of loading the named keys of the current context.
+    pub load_named_keys: HostFunctionCost,
+    /// Cost of writing a value to global state.
+    pub write: HostFunctionCost,
+    /// Cost of writing a value to local state.
+    pub write_local: HostFunctionCost,
+    /// Cost of adding to a value in global state.
+    pub add: HostFunctionCost,
+    /// Cost of adding to a value in local state.
+    pub add_local: HostFunctionCost,
+    /// Cost of creating a new `URef`.
+    pub new_uref: HostFunctionCost,
+    /// Cost of getting the size of a runtime argument.
+    pub get_arg_size: HostFunctionCost,
+    /// Cost of getting a runtime argument.
+    pub get_arg: HostFunctionCost,
+    /// Cost of returning a value from a contract.
+    pub ret: HostFunctionCost,
+    /// Cost of calling a stored contract.
+    pub call_contract: HostFunctionCost,
+    /// Cost of getting a named key.
+    pub get_key: HostFunctionCost,
+    /// Cost of checking for a named key.
+    pub has_key: HostFunctionCost,
+    /// Cost of putting a named key.
+    pub put_key: HostFunctionCost,
+    /// Cost of removing a named key.
+    pub remove_key: HostFunctionCost,
+    /// Cost of getting the caller's public key.
+    pub get_caller: HostFunctionCost,
+    /// Cost of getting the block time.
+    pub get_blocktime: HostFunctionCost,
+    /// Cost of storing a function at a `URef`.
+    pub store_function: HostFunctionCost,
+    /// Cost of storing a function at a hash.
+    pub store_function_at_hash: HostFunctionCost,
+    /// Cost of validating a `URef`.
+    pub is_valid_uref: HostFunctionCost,
+    /// Cost of reverting execution.
+    pub revert: HostFunctionCost,
+    /// Cost of adding an associated key.
+    pub add_associated_key: HostFunctionCost,
+    /// Cost of removing an associated key.
+    pub remove_associated_key: HostFunctionCost,
+    /// Cost of updating an associated key.
+    pub update_associated_key: HostFunctionCost,
+    /// Cost of setting an action threshold.
+    pub set_action_threshold: HostFunctionCost,
+    /// Cost of creating a purse.
+    pub create_purse: HostFunctionCost,
+    /// Cost of transferring from the main purse to an account.
+    pub transfer_to_account: HostFunctionCost,
+    /// Cost of transferring from a purse to an account.
+    pub transfer_from_purse_to_account: HostFunctionCost,
+    /// Cost of transferring from a purse to a purse.
+    pub transfer_from_purse_to_purse: HostFunctionCost,
+    /// Cost of getting the balance of a purse.
+    pub get_balance: HostFunctionCost,
+    /// Cost of getting the current phase.
+    pub get_phase: HostFunctionCost,
+    /// Cost of upgrading a contract stored at a `URef`.
+    pub upgrade_contract_at_uref: HostFunctionCost,
+    /// Cost of getting a system contract's `URef`.
+    pub get_system_contract: HostFunctionCost,
+    /// Cost of getting the main purse.
+    pub get_main_purse: HostFunctionCost,
+    /// Cost of reading the host buffer.
+    pub read_host_buffer: HostFunctionCost,
+    /// Cost of removing a value from global state.
+    pub remove: HostFunctionCost,
+    /// Cost of emitting an event.
+    pub emit_event: HostFunctionCost,
+    /// Cost of calling a contract, returning its revert error to the caller.
+    pub try_call_contract: HostFunctionCost,
+    /// Cost of calling the `store_contract` host function.
+    pub store_contract: HostFunctionCost,
+    /// Cost of calling the `store_contract_at_hash` host function.
+    pub store_contract_at_hash: HostFunctionCost,
+    /// Cost of calling the `call_entry_point` host function.
+    pub call_entry_point: HostFunctionCost,
+    /// Cost of calling the `get_named_arg_size` host function.
+    pub get_named_arg_size: HostFunctionCost,
+    /// Cost of calling the `get_named_arg` host function.
+    pub get_named_arg: HostFunctionCost,
+    /// Cost of calling the `blake2b` host function.
+    pub blake2b: HostFunctionCost,
+    /// Cost of calling the `ed25519_verify` host function.
+    pub ed25519_verify: HostFunctionCost,
+    /// Cost of calling the `secp256k1_verify` host function.
+    pub secp256k1_verify: HostFunctionCost,
+    /// Cost of calling the `random_bytes` host function.
+    pub random_bytes: HostFunctionCost,
+}
+
+/// The cost table used when a chainspec doesn't specify host function costs.  No host function
+/// is free: storage writes in particular are charged per byte so that global state can't grow
+/// for free.
+impl Default for HostFunctionCosts {
+    fn default() -> Self {
+        HostFunctionCosts {
+            read_value: HostFunctionCost::new(5000, 1),
+            read_value_local: HostFunctionCost::new(5000, 1),
+            load_named_keys: HostFunctionCost::new(10000, 1),
+            write: HostFunctionCost::new(15000, 10),
+            write_local: HostFunctionCost::new(15000, 10),
+            add: HostFunctionCost::new(15000, 10),
+            add_local: HostFunctionCost::new(15000, 10),
+            new_uref: HostFunctionCost::new(15000, 10),
+            get_arg_size: HostFunctionCost::new(200, 0),
+            get_arg: HostFunctionCost::new(200, 1),
+            ret: HostFunctionCost::new(2000, 1),
+            call_contract: HostFunctionCost::new(10000, 1),
+            get_key: HostFunctionCost::new(2000, 1),
+            has_key: HostFunctionCost::new(1500, 1),
+            put_key: HostFunctionCost::new(10000, 10),
+            remove_key: HostFunctionCost::new(10000, 1),
+            get_caller: HostFunctionCost::new(500, 0),
+            get_blocktime: HostFunctionCost::new(500, 0),
+            store_function: HostFunctionCost::new(50000, 10),
+            store_function_at_hash: HostFunctionCost::new(50000, 10),
+            is_valid_uref: HostFunctionCost::new(500, 1),
+            revert: HostFunctionCost::new(500, 0),
+            add_associated_key: HostFunctionCost::new(10000, 1),
+            remove_associated_key: HostFunctionCost::new(5000, 1),
+            update_associated_key: HostFunctionCost::new(5000, 1),
+            set_action_threshold: HostFunctionCost::new(5000, 1),
+            create_purse: HostFunctionCost::new(100000, 0),
+            transfer_to_account: HostFunctionCost::new(100000, 1),
+            transfer_from_purse_to_account: HostFunctionCost::new(100000, 1),
+            transfer_from_purse_to_purse: HostFunctionCost::new(100000, 1),
+            get_balance: HostFunctionCost::new(5000, 1),
+            get_phase: HostFunctionCost::new(500, 0),
+            upgrade_contract_at_uref: HostFunctionCost::new(50000, 10),
+            get_system_contract: HostFunctionCost::new(500, 0),
+            get_main_purse: HostFunctionCost::new(500, 0),
+            read_host_buffer: HostFunctionCost::new(500, 1),
+            remove: HostFunctionCost::new(5000, 1),
+            emit_event: HostFunctionCost::new(5000, 10),
+            try_call_contract: HostFunctionCost::new(10000, 1),
+            store_contract: HostFunctionCost::new(50000, 10),
+            store_contract_at_hash: HostFunctionCost::new(50000, 10),
+            call_entry_point: HostFunctionCost::new(10000, 1),
+            get_named_arg_size: HostFunctionCost::new(200, 1),
+            get_named_arg: HostFunctionCost::new(200, 1),
+            blake2b: HostFunctionCost::new(1000, 1),
+            ed25519_verify: HostFunctionCost::new(10000, 1),
+            secp256k1_verify: HostFunctionCost::new(10000, 1),
+            random_bytes: HostFunctionCost::new(1000, 1),
+        }
+    }
+}
+
+impl ToBytes for HostFunctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut (NUM_HOST_FUNCTIONS as u32).to_bytes()?);
+        ret.append(&mut self.read_value.to_bytes()?);
+        ret.append(&mut self.read_value_local.to_bytes()?);
+        ret.append(&mut self.load_named_keys.to_bytes()?);
+        ret.append(&mut self.write.to_bytes()?);
+        ret.append(&mut self.write_local.to_bytes()?);
+        ret.append(&mut self.add.to_bytes()?);
+        ret.append(&mut self.add_local.to_bytes()?);
+        ret.append(&mut self.new_uref.to_bytes()?);
+        ret.append(&mut self.get_arg_size.to_bytes()?);
+        ret.append(&mut self.get_arg.to_bytes()?);
+        ret.append(&mut self.ret.to_bytes()?);
+        ret.append(&mut self.call_contract.to_bytes()?);
+        ret.append(&mut self.get_key.to_bytes()?);
+        ret.append(&mut self.has_key.to_bytes()?);
+        ret.append(&mut self.put_key.to_bytes()?);
+        ret.append(&mut self.remove_key.to_bytes()?);
+        ret.append(&mut self.get_caller.to_bytes()?);
+        ret.append(&mut self.get_blocktime.to_bytes()?);
+        ret.append(&mut self.store_function.to_bytes()?);
+        ret.append(&mut self.store_function_at_hash.to_bytes()?);
+        ret.append(&mut self.is_valid_uref.to_bytes()?);
+        ret.append(&mut self.revert.to_bytes()?);
+        ret.append(&mut self.add_associated_key.to_bytes()?);
+        ret.append(&mut self.remove_associated_key.to_bytes()?);
+        ret.append(&mut self.update_associated_key.to_bytes()?);
+        ret.append(&mut self.set_action_threshold.to_bytes()?);
+        ret.append(&mut self.create_purse.to_bytes()?);
+        ret.append(&mut self.transfer_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
+        ret.append(&mut self.get_balance.to_bytes()?);
+        ret.append(&mut self.get_phase.to_bytes()?);
+        ret.append(&mut self.upgrade_contract_at_uref.to_bytes()?);
+        ret.append(&mut self.get_system_contract.to_bytes()?);
+        ret.append(&mut self.get_main_purse.to_bytes()?);
+        ret.append(&mut self.read_host_buffer.to_bytes()?);
+        ret.append(&mut self.remove.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.try_call_contract.to_bytes()?);
+        ret.append(&mut self.store_contract.to_bytes()?);
+        ret.append(&mut self.store_contract_at_hash.to_bytes()?);
+        ret.append(&mut self.call_entry_point.to_bytes()?);
+        ret.append(&mut self.get_named_arg_size.to_bytes()?);
+        ret.append(&mut self.get_named_arg.to_bytes()?);
+        ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.ed25519_verify.to_bytes()?);
+        ret.append(&mut self.secp256k1_verify.to_bytes()?);
+        ret.append(&mut self.random_bytes.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        HOST_FUNCTION_COSTS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for HostFunctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (count, mut rem) = u32::from_bytes(bytes)?;
+        if count as usize > NUM_HOST_FUNCTIONS {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let mut costs = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (cost, next) = HostFunctionCost::from_bytes(rem)?;
+            costs.push(cost);
+            rem = next;
+        }
+        Ok((HostFunctionCosts::from_costs(costs), rem))
+    }
+}
+
+impl HostFunctionCosts {
+    /// Assigns `costs` to the host functions in the order they are serialized.  Costs stored
+    /// before a host function was added don't include it, so it is charged its default cost
+    /// until an upgrade sets it.
+    pub(crate) fn from_costs(costs: impl IntoIterator<Item = HostFunctionCost>) -> Self {
+        let mut costs = costs.into_iter();
+        let default = HostFunctionCosts::default();
+        HostFunctionCosts {
+            read_value: costs.next().unwrap_or(default.read_value),
+            read_value_local: costs.next().unwrap_or(default.read_value_local),
+            load_named_keys: costs.next().unwrap_or(default.load_named_keys),
+            write: costs.next().unwrap_or(default.write),
+            write_local: costs.next().unwrap_or(default.write_local),
+            add: costs.next().unwrap_or(default.add),
+            add_local: costs.next().unwrap_or(default.add_local),
+            new_uref: costs.next().unwrap_or(default.new_uref),
+            get_arg_size: costs.next().unwrap_or(default.get_arg_size),
+            get_arg: costs.next().unwrap_or(default.get_arg),
+            ret: costs.next().unwrap_or(default.ret),
+            call_contract: costs.next().unwrap_or(default.call_contract),
+            get_key: costs.next().unwrap_or(default.get_key),
+            has_key: costs.next().unwrap_or(default.has_key),
+            put_key: costs.next().unwrap_or(default.put_key),
+            remove_key: costs.next().unwrap_or(default.remove_key),
+            get_caller: costs.next().unwrap_or(default.get_caller),
+            get_blocktime: costs.next().unwrap_or(default.get_blocktime),
+            store_function: costs.next().unwrap_or(default.store_function),
+            store_function_at_hash: costs.next().unwrap_or(default.store_function_at_hash),
+            is_valid_uref: costs.next().unwrap_or(default.is_valid_uref),
+            revert: costs.next().unwrap_or(default.revert),
+            add_associated_key: costs.next().unwrap_or(default.add_associated_key),
+            remove_associated_key: costs.next().unwrap_or(default.remove_associated_key),
+            update_associated_key: costs.next().unwrap_or(default.update_associated_key),
+            set_action_threshold: costs.next().unwrap_or(default.set_action_threshold),
+            create_purse: costs.next().unwrap_or(default.create_purse),
+            transfer_to_account: costs.next().unwrap_or(default.transfer_to_account),
+            transfer_from_purse_to_account: costs
+                .next()
+                .unwrap_or(default.transfer_from_purse_to_account),
+            transfer_from_purse_to_purse: costs
+                .next()
+                .unwrap_or(default.transfer_from_purse_to_purse),
+            get_balance: costs.next().unwrap_or(default.get_balance),
+            get_phase: costs.next().unwrap_or(default.get_phase),
+            upgrade_contract_at_uref: costs.next().unwrap_or(default.upgrade_contract_at_uref),
+            get_system_contract: costs.next().unwrap_or(default.get_system_contract),
+            get_main_purse: costs.next().unwrap_or(default.get_main_purse),
+            read_host_buffer: costs.next().unwrap_or(default.read_host_buffer),
+            remove: costs.next().unwrap_or(default.remove),
+            emit_event: costs.next().unwrap_or(default.emit_event),
+            try_call_contract: costs.next().unwrap_or(default.try_call_contract),
+            store_contract: costs.next().unwrap_or(default.store_contract),
+            store_contract_at_hash: costs.next().unwrap_or(default.store_contract_at_hash),
+            call_entry_point: costs.next().unwrap_or(default.call_entry_point),
+            get_named_arg_size: costs.next().unwrap_or(default.get_named_arg_size),
+            get_named_arg: costs.next().unwrap_or(default.get_named_arg),
+            blake2b: costs.next().unwrap_or(default.blake2b),
+            ed25519_verify: costs.next().unwrap_or(default.ed25519_verify),
+            secp256k1_verify: costs.next().unwrap_or(default.secp256k1_verify),
+            random_bytes: costs.next().unwrap_or(default.random_bytes),
+        }
+    }
+}
+
+#[cfg(any(feature = "gens", test))]
+pub mod gens {
+    use proptest::{collection, num, prop_compose, strategy::Strategy};
+
+    use super::{HostFunctionCost, HostFunctionCosts, NUM_HOST_FUNCTIONS};
+
+    prop_compose! {
+        pub fn host_function_cost_arb()(
+            fixed in num::u32::ANY,
+            per_byte in num::u32::ANY,
+        ) -> HostFunctionCost {
+            HostFunctionCost { fixed, per_byte }
+        }
+    }
+
+    pub fn host_function_costs_arb() -> impl Strategy<Value = HostFunctionCosts> {
+        collection::vec(host_function_cost_arb(), NUM_HOST_FUNCTIONS)
+            .prop_map(HostFunctionCosts::from_costs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::{
+        bytesrepr::{self, ToBytes, U32_SERIALIZED_LENGTH},
+        U512,
+    };
+
+    use super::{
+        gens, HostFunctionCost, HostFunctionCosts, HOST_FUNCTION_COST_SERIALIZED_LENGTH,
+        NUM_HOST_FUNCTIONS,
+    };
+    use crate::{gas::Gas, test_utils};
+
+    #[test]
+    fn should_calculate_gas() {
+        let cost = HostFunctionCost::new(100, 3);
+        assert_eq!(cost.calculate_gas(0), Gas::new(U512::from(100)));
+        assert_eq!(cost.calculate_gas(10), Gas::new(U512::from(130)));
+    }
+
+    #[test]
+    fn should_not_overflow_when_calculating_gas() {
+        let cost = HostFunctionCost::new(u32::max_value(), u32::max_value());
+        let expected = U512::from(u32::max_value())
+            + U512::from(u32::max_value()) * U512::from(u64::max_value());
+        assert_eq!(cost.calculate_gas(u64::max_value()), Gas::new(expected));
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        bytesrepr::test_serialization_roundtrip(&test_utils::host_function_costs_mock());
+        bytesrepr::test_serialization_roundtrip(&test_utils::host_function_costs_free());
+    }
+
+    #[test]
+    fn should_use_default_costs_for_host_functions_missing_from_stored_costs() {
+        let read_value = HostFunctionCost::new(10, 1);
+        let read_value_local = HostFunctionCost::new(20, 2);
+        let mut bytes = 2u32.to_bytes().unwrap();
+        bytes.append(&mut read_value.to_bytes().unwrap());
+        bytes.append(&mut read_value_local.to_bytes().unwrap());
+
+        let host_function_costs: HostFunctionCosts = bytesrepr::deserialize(bytes).unwrap();
+        let expected = HostFunctionCosts {
+            read_value,
+            read_value_local,
+            ..Default::default()
+        };
+        assert_eq!(host_function_costs, expected);
+    }
+
+    #[test]
+    fn should_not_leave_any_host_function_free_by_default() {
+        let bytes = HostFunctionCosts::default().to_bytes().unwrap();
+        let costs = bytes[U32_SERIALIZED_LENGTH..]
+            .chunks(HOST_FUNCTION_COST_SERIALIZED_LENGTH)
+            .map(|chunk| bytesrepr::deserialize::<HostFunctionCost>(chunk.to_vec()).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(costs.len(), NUM_HOST_FUNCTIONS);
+        assert!(costs.iter().all(|cost| cost.fixed > 0));
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_host_functions() {
+        let mut bytes = (NUM_HOST_FUNCTIONS as u32 + 1).to_bytes().unwrap();
+        for _ in 0..=NUM_HOST_FUNCTIONS {
+            bytes.append(&mut HostFunctionCost::default().to_bytes().unwrap());
+        }
+
+        assert_eq!(
+            bytesrepr::deserialize::<HostFunctionCosts>(bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn should_serialize_and_deserialize_with_arbitrary_values(
+            host_function_costs in gens::host_function_costs_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&host_function_costs);
+        }
+    }
+}
@@ -1,10 +1,14 @@
 //! Some functions to use in tests.
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, iter};
 
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::{account::PublicKey, AccessRights, Key, URef};
 
-use crate::{account::Account, stored_value::StoredValue};
+use crate::{
+    account::Account,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts, NUM_HOST_FUNCTIONS},
+    stored_value::StoredValue,
+};
 
 /// Returns an account value paired with its key
 pub fn mocked_account(public_key: PublicKey) -> Vec<(Key, StoredValue)> {
@@ -42,3 +46,62 @@ pub fn wasm_costs_free() -> WasmCosts {
         opcodes_div: 1,
     }
 }
+
+pub fn host_function_costs_mock() -> HostFunctionCosts {
+    HostFunctionCosts {
+        read_value: HostFunctionCost::new(1, 1),
+        read_value_local: HostFunctionCost::new(1, 1),
+        load_named_keys: HostFunctionCost::new(1, 1),
+        write: HostFunctionCost::new(1, 1),
+        write_local: HostFunctionCost::new(1, 1),
+        add: HostFunctionCost::new(1, 1),
+        add_local: HostFunctionCost::new(1, 1),
+        new_uref: HostFunctionCost::new(1, 1),
+        get_arg_size: HostFunctionCost::new(1, 1),
+        get_arg: HostFunctionCost::new(1, 1),
+        ret: HostFunctionCost::new(1, 1),
+        call_contract: HostFunctionCost::new(1, 1),
+        get_key: HostFunctionCost::new(1, 1),
+        has_key: HostFunctionCost::new(1, 1),
+        put_key: HostFunctionCost::new(1, 1),
+        remove_key: HostFunctionCost::new(1, 1),
+        get_caller: HostFunctionCost::new(1, 1),
+        get_blocktime: HostFunctionCost::new(1, 1),
+        store_function: HostFunctionCost::new(1, 1),
+        store_function_at_hash: HostFunctionCost::new(1, 1),
+        is_valid_uref: HostFunctionCost::new(1, 1),
+        revert: HostFunctionCost::new(1, 1),
+        add_associated_key: HostFunctionCost::new(1, 1),
+        remove_associated_key: HostFunctionCost::new(1, 1),
+        update_associated_key: HostFunctionCost::new(1, 1),
+        set_action_threshold: HostFunctionCost::new(1, 1),
+        create_purse: HostFunctionCost::new(1, 1),
+        transfer_to_account: HostFunctionCost::new(1, 1),
+        transfer_from_purse_to_account: HostFunctionCost::new(1, 1),
+        transfer_from_purse_to_purse: HostFunctionCost::new(1, 1),
+        get_balance: HostFunctionCost::new(1, 1),
+        get_phase: HostFunctionCost::new(1, 1),
+        upgrade_contract_at_uref: HostFunctionCost::new(1, 1),
+        get_system_contract: HostFunctionCost::new(1, 1),
+        get_main_purse: HostFunctionCost::new(1, 1),
+        read_host_buffer: HostFunctionCost::new(1, 1),
+        remove: HostFunctionCost::new(1, 1),
+        emit_event: HostFunctionCost::new(1, 1),
+        try_call_contract: HostFunctionCost::new(1, 1),
+        store_contract: HostFunctionCost::new(1, 1),
+        store_contract_at_hash: HostFunctionCost::new(1, 1),
+        call_entry_point: HostFunctionCost::new(1, 1),
+        get_named_arg_size: HostFunctionCost::new(1, 1),
+        get_named_arg: HostFunctionCost::new(1, 1),
+        blake2b: HostFunctionCost::new(1, 1),
+        ed25519_verify: HostFunctionCost::new(1, 1),
+        secp256k1_verify: HostFunctionCost::new(1, 1),
+        random_bytes: HostFunctionCost::new(1, 1),
+    }
+}
+
+pub fn host_function_costs_free() -> HostFunctionCosts {
+    HostFunctionCosts::from_costs(
+        iter::repeat(HostFunctionCost::default()).take(NUM_HOST_FUNCTIONS),
+    )
+}
@@ -59,6 +59,8 @@ pub enum Transform {
     AddUInt256(U256),
     AddUInt512(U512),
     AddKeys(BTreeMap<String, Key>),
+    /// Removes the value from global state.  Only a `Write` can follow a `Delete` of the same key.
+    Delete,
     Failure(Error),
 }
 
@@ -160,6 +162,12 @@ impl Transform {
                     Err(TypeMismatch::new(expected, found).into())
                 }
             },
+            // A deleted key has no value to produce, so callers must handle `Delete` themselves
+            Transform::Delete => Err(TypeMismatch::new(
+                "value-producing transform".to_string(),
+                "Delete".to_string(),
+            )
+            .into()),
             Transform::Failure(error) => Err(error),
         }
     }
@@ -207,6 +215,10 @@ impl Add for Transform {
             (a @ Transform::Failure(_), _) => a,
             (_, b @ Transform::Failure(_)) => b,
             (_, b @ Transform::Write(_)) => b,
+            (_, Transform::Delete) => Transform::Delete,
+            (Transform::Delete, b) => {
+                Transform::Failure(TypeMismatch::new("Write".to_owned(), format!("{:?}", b)).into())
+            }
             (Transform::Write(v), b) => {
                 // second transform changes value being written
                 match b.apply(v) {
@@ -278,6 +290,7 @@ pub mod gens {
         prop_oneof![
             Just(Transform::Identity),
             stored_value_arb().prop_map(Transform::Write),
+            Just(Transform::Delete),
             any::<i32>().prop_map(Transform::AddInt32),
             any::<u64>().prop_map(Transform::AddUInt64),
             any::<u128>().prop_map(|u| Transform::AddUInt128(u.into())),
@@ -742,4 +755,29 @@ mod tests {
         assert_eq!(ZERO_U512, add(MAX_U512, ONE_U512));
         assert_eq!(MAX_U512 - 1, add(MAX_U512, MAX_U512));
     }
+
+    #[test]
+    fn delete_should_combine_with_other_transforms() {
+        let value = StoredValue::CLValue(CLValue::from_t(ONE_U64).expect("should create CLValue"));
+
+        assert_eq!(
+            Transform::AddUInt64(ONE_U64) + Transform::Delete,
+            Transform::Delete
+        );
+        assert_eq!(
+            Transform::Write(value.clone()) + Transform::Delete,
+            Transform::Delete
+        );
+        assert_eq!(Transform::Delete + Transform::Identity, Transform::Delete);
+        assert_eq!(
+            Transform::Delete + Transform::Write(value.clone()),
+            Transform::Write(value.clone())
+        );
+
+        match Transform::Delete + Transform::AddUInt64(ONE_U64) {
+            Transform::Failure(Error::TypeMismatch(_)) => (),
+            other => panic!("adding to a deleted key should fail, got {:?}", other),
+        }
+        assert!(Transform::Delete.apply(value).is_err());
+    }
 }
@@ -4,14 +4,24 @@ extern crate test;
 
 use test::{black_box, Bencher};
 
-use casperlabs_engine_storage::trie::{Pointer, PointerBlock, Trie};
-use engine_shared::{newtypes::Blake2bHash, stored_value::StoredValue};
+use casperlabs_engine_storage::{
+    global_state::{in_memory::InMemoryGlobalState, StateProvider},
+    trie::{Pointer, PointerBlock, Trie},
+};
+use engine_shared::{
+    additive_map::AdditiveMap,
+    newtypes::{Blake2bHash, CorrelationId},
+    stored_value::StoredValue,
+    transform::Transform,
+};
 use types::{
     account::PublicKey,
     bytesrepr::{FromBytes, ToBytes},
     CLValue, Key,
 };
 
+const COMMITTED_PAIRS_COUNT: u32 = 1000;
+
 #[bench]
 fn serialize_trie_leaf(b: &mut Bencher) {
     let leaf = Trie::Leaf {
@@ -69,3 +79,40 @@ fn deserialize_trie_node_pointer(b: &mut Bencher) {
 
     b.iter(|| u8::from_bytes(black_box(&node_bytes)))
 }
+
+fn committed_pairs() -> Vec<(Key, StoredValue)> {
+    (0..COMMITTED_PAIRS_COUNT)
+        .map(|index| {
+            let key = Key::Hash(Blake2bHash::new(&index.to_le_bytes()).value());
+            let value = StoredValue::CLValue(CLValue::from_t(index).unwrap());
+            (key, value)
+        })
+        .collect()
+}
+
+/// Writes the pairs to an empty trie one at a time.
+#[bench]
+fn write_pairs_sequentially(b: &mut Bencher) {
+    let correlation_id = CorrelationId::new();
+    let pairs = committed_pairs();
+
+    b.iter(|| InMemoryGlobalState::from_pairs(correlation_id, black_box(&pairs)).unwrap());
+}
+
+/// Writes the pairs to an empty trie as a single batch.
+#[bench]
+fn commit_pairs_as_batch(b: &mut Bencher) {
+    let correlation_id = CorrelationId::new();
+    let mut effects = AdditiveMap::new();
+    for (key, value) in committed_pairs() {
+        effects.insert(key, Transform::Write(value));
+    }
+
+    b.iter(|| {
+        let state = InMemoryGlobalState::empty().unwrap();
+        let empty_root_hash = state.empty_root_hash;
+        state
+            .commit(correlation_id, empty_root_hash, black_box(effects.clone()))
+            .unwrap()
+    });
+}
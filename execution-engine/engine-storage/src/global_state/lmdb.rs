@@ -15,10 +15,10 @@ use crate::{
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     store::Store,
     transaction_source::{lmdb::LmdbEnvironment, Transaction, TransactionSource},
-    trie::{operations::create_hashed_empty_trie, Trie},
+    trie::{merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie},
     trie_store::{
         lmdb::LmdbTrieStore,
-        operations::{read, ReadResult},
+        operations::{read, read_with_proof, ReadResult},
     },
 };
 
@@ -98,6 +98,32 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<TrieMerkleProof<Key, StoredValue>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read_with_proof::<
+            Key,
+            StoredValue,
+            lmdb::RoTransaction,
+            LmdbTrieStore,
+            Self::Error,
+        >(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            key,
+        )? {
+            Some(proof) => proof,
+            None => panic!("LmdbGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
 }
 
 impl StateProvider for LmdbGlobalState {
@@ -259,6 +285,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn proofs_from_a_checkout_verify_against_root() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            let proof = checkout.read_with_proof(correlation_id, &key).unwrap();
+            assert_eq!(Ok(Some(&value)), proof.verify(&root_hash));
+        }
+
+        let absent_key = Key::Account(PublicKey::ed25519_from([3u8; 32]));
+        let proof = checkout
+            .read_with_proof(correlation_id, &absent_key)
+            .unwrap();
+        assert_eq!(Ok(None), proof.verify(&root_hash));
+    }
+
     #[test]
     fn checkout_fails_if_unknown_hash_is_given() {
         let (state, _) = create_test_state();
@@ -297,6 +340,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn commit_deletes_keys() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs = create_test_pairs();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            tmp.insert(test_pairs[0].key, Transform::Delete);
+            tmp
+        };
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        assert_eq!(
+            None,
+            updated_checkout
+                .read(correlation_id, &test_pairs[0].key)
+                .unwrap()
+        );
+        assert_eq!(
+            Some(test_pairs[1].value.clone()),
+            updated_checkout
+                .read(correlation_id, &test_pairs[1].key)
+                .unwrap()
+        );
+
+        // The result is the same as if the deleted key had never been written
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            tmp.insert(
+                test_pairs[1].key,
+                Transform::Write(test_pairs[1].value.clone()),
+            );
+            tmp
+        };
+        let expected_hash = match state
+            .commit(correlation_id, state.empty_root(), effects)
+            .unwrap()
+        {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+        assert_eq!(expected_hash, updated_hash);
+    }
+
     #[test]
     fn commit_updates_state_and_original_state_stays_intact() {
         let correlation_id = CorrelationId::new();
@@ -16,9 +16,9 @@ use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
 use crate::{
     protocol_data::ProtocolData,
     transaction_source::{Transaction, TransactionSource},
-    trie::Trie,
+    trie::{merkle_proof::TrieMerkleProof, Trie},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
+        operations::{batch_update, read, BatchUpdateResult, ReadResult},
         TrieStore,
     },
     GAUGE_METRIC_KEY,
@@ -38,6 +38,13 @@ pub trait StateReader<K, V> {
 
     /// Returns the state value from the corresponding key
     fn read(&self, correlation_id: CorrelationId, key: &K) -> Result<Option<V>, Self::Error>;
+
+    /// Returns a proof of the inclusion or exclusion of the corresponding key
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &K,
+    ) -> Result<TrieMerkleProof<K, V>, Self::Error>;
 }
 
 #[derive(Debug)]
@@ -45,6 +52,8 @@ pub enum CommitResult {
     RootNotFound,
     Success {
         state_root: Blake2bHash,
+        /// The validator weights at `state_root`.  Global state knows nothing of PoS, so
+        /// [`commit`] leaves these empty for the engine to fill in.
         bonded_validators: HashMap<PublicKey, U512>,
     },
     KeyNotFound(Key),
@@ -129,9 +138,8 @@ where
     H: BuildHasher,
 {
     let mut txn = environment.create_read_write_txn()?;
-    let mut state_root = prestate_hash;
 
-    let maybe_root: Option<Trie<Key, StoredValue>> = store.get(&txn, &state_root)?;
+    let maybe_root: Option<Trie<Key, StoredValue>> = store.get(&txn, &prestate_hash)?;
 
     if maybe_root.is_none() {
         return Ok(CommitResult::RootNotFound);
@@ -139,17 +147,16 @@ where
 
     let start = Instant::now();
     let mut reads: i32 = 0;
-    let mut writes: i32 = 0;
+    let mut changes = Vec::with_capacity(effects.len());
 
+    // Each key has a single transform, so every current value can be read from the prestate
     for (key, transform) in effects.into_iter() {
-        let read_result = read::<_, _, _, _, E>(correlation_id, &txn, store, &state_root, &key)?;
+        if let Transform::Delete = transform {
+            changes.push((key, None));
+            continue;
+        }
 
-        log_duration(
-            correlation_id,
-            GLOBAL_STATE_COMMIT_READ_DURATION,
-            COMMIT,
-            start.elapsed(),
-        );
+        let read_result = read::<_, _, _, _, E>(correlation_id, &txn, store, &prestate_hash, &key)?;
 
         reads += 1;
 
@@ -165,25 +172,35 @@ where
             _x @ (ReadResult::RootNotFound, _) => panic!(stringify!(_x._1)),
         };
 
-        let write_result =
-            write::<_, _, _, _, E>(correlation_id, &mut txn, store, &state_root, &key, &value)?;
+        changes.push((key, Some(value)));
+    }
 
-        log_duration(
-            correlation_id,
-            GLOBAL_STATE_COMMIT_WRITE_DURATION,
-            COMMIT,
-            start.elapsed(),
-        );
+    log_duration(
+        correlation_id,
+        GLOBAL_STATE_COMMIT_READ_DURATION,
+        COMMIT,
+        start.elapsed(),
+    );
 
-        match write_result {
-            WriteResult::Written(root_hash) => {
-                state_root = root_hash;
-                writes += 1;
-            }
-            WriteResult::AlreadyExists => (),
-            _x @ WriteResult::RootNotFound => panic!(stringify!(_x)),
-        }
-    }
+    let writes = changes.len() as i32;
+
+    let state_root = match batch_update::<_, _, _, _, E>(
+        correlation_id,
+        &mut txn,
+        store,
+        &prestate_hash,
+        changes,
+    )? {
+        BatchUpdateResult::Updated(root_hash) => root_hash,
+        _x @ BatchUpdateResult::RootNotFound => panic!(stringify!(_x)),
+    };
+
+    log_duration(
+        correlation_id,
+        GLOBAL_STATE_COMMIT_WRITE_DURATION,
+        COMMIT,
+        start.elapsed(),
+    );
 
     txn.commit()?;
 
@@ -1,17 +1,41 @@
+use engine_shared::host_function_costs::{
+    HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH,
+};
 use engine_wasm_prep::wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH};
 use types::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
     AccessRights, URef, UREF_SERIALIZED_LENGTH,
 };
 
-const PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
-    WASM_COSTS_SERIALIZED_LENGTH + 3 * UREF_SERIALIZED_LENGTH;
+/// The minimum gas price of a protocol version unless an upgrade sets a different one.  It matches
+/// the fixed conversion rate from gas to motes used before deploys set their own gas price.
+pub const DEFAULT_MIN_GAS_PRICE: u64 = 10;
+/// The amount of motes, and so the payment code gas limit, of a protocol version unless an
+/// upgrade sets a different one.
+pub const DEFAULT_MAX_PAYMENT: u64 = 10_000_000;
+/// The cumulative gas limit of the deploys of a single execute request, unless an upgrade sets a
+/// different one.  The default is effectively unlimited.
+pub const DEFAULT_BLOCK_GAS_LIMIT: u64 = u64::MAX;
+
+/// The version of the encoding of the fields which follow the system contracts' URefs.  Protocol
+/// data stored before they were added ends after the URefs.
+const PROTOCOL_DATA_VERSION: u8 = 1;
+
+const PROTOCOL_DATA_SERIALIZED_LENGTH: usize = WASM_COSTS_SERIALIZED_LENGTH
+    + 3 * UREF_SERIALIZED_LENGTH
+    + U8_SERIALIZED_LENGTH
+    + HOST_FUNCTION_COSTS_SERIALIZED_LENGTH
+    + 3 * U64_SERIALIZED_LENGTH;
 const DEFAULT_UREF_ADDRESS: [u8; 32] = [0; 32];
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ProtocolData {
     wasm_costs: WasmCosts,
+    host_function_costs: HostFunctionCosts,
+    min_gas_price: u64,
+    max_payment: u64,
+    block_gas_limit: u64,
     mint: URef,
     proof_of_stake: URef,
     standard_payment: URef,
@@ -25,6 +49,10 @@ impl Default for ProtocolData {
     fn default() -> ProtocolData {
         ProtocolData {
             wasm_costs: WasmCosts::default(),
+            host_function_costs: HostFunctionCosts::default(),
+            min_gas_price: DEFAULT_MIN_GAS_PRICE,
+            max_payment: DEFAULT_MAX_PAYMENT,
+            block_gas_limit: DEFAULT_BLOCK_GAS_LIMIT,
             mint: URef::new(DEFAULT_UREF_ADDRESS, AccessRights::READ),
             proof_of_stake: URef::new(DEFAULT_UREF_ADDRESS, AccessRights::READ),
             standard_payment: URef::new(DEFAULT_UREF_ADDRESS, AccessRights::READ),
@@ -33,15 +61,25 @@ impl Default for ProtocolData {
 }
 
 impl ProtocolData {
-    /// Creates a new [`ProtocolData`] value from a given [`WasmCosts`] value.
+    /// Creates a new [`ProtocolData`] value from given [`WasmCosts`] and [`HostFunctionCosts`]
+    /// values and gas limits.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
+        min_gas_price: u64,
+        max_payment: u64,
+        block_gas_limit: u64,
         mint: URef,
         proof_of_stake: URef,
         standard_payment: URef,
     ) -> Self {
         ProtocolData {
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            max_payment,
+            block_gas_limit,
             mint,
             proof_of_stake,
             standard_payment,
@@ -64,11 +102,13 @@ impl ProtocolData {
     /// Used during `commit_genesis` before all system contracts' URefs are known.
     pub fn partial_without_standard_payment(
         wasm_costs: WasmCosts,
+        host_function_costs: HostFunctionCosts,
         mint: URef,
         proof_of_stake: URef,
     ) -> Self {
         ProtocolData {
             wasm_costs,
+            host_function_costs,
             mint,
             proof_of_stake,
             ..Default::default()
@@ -80,6 +120,27 @@ impl ProtocolData {
         &self.wasm_costs
     }
 
+    /// Gets the [`HostFunctionCosts`] value from a given [`ProtocolData`] value.
+    pub fn host_function_costs(&self) -> &HostFunctionCosts {
+        &self.host_function_costs
+    }
+
+    /// Gets the lowest gas price a deploy may offer under a given [`ProtocolData`] value.
+    pub fn min_gas_price(&self) -> u64 {
+        self.min_gas_price
+    }
+
+    /// Gets the amount of motes taken from a deployer to pay for their payment code, which at the
+    /// deploy's gas price also sets its gas limit.
+    pub fn max_payment(&self) -> u64 {
+        self.max_payment
+    }
+
+    /// Gets the cumulative gas limit of the deploys of a single execute request.
+    pub fn block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+    }
+
     pub fn mint(&self) -> URef {
         self.mint
     }
@@ -115,6 +176,11 @@ impl ToBytes for ProtocolData {
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.proof_of_stake.to_bytes()?);
         ret.append(&mut self.standard_payment.to_bytes()?);
+        ret.append(&mut PROTOCOL_DATA_VERSION.to_bytes()?);
+        ret.append(&mut self.host_function_costs.to_bytes()?);
+        ret.append(&mut self.min_gas_price.to_bytes()?);
+        ret.append(&mut self.max_payment.to_bytes()?);
+        ret.append(&mut self.block_gas_limit.to_bytes()?);
         Ok(ret)
     }
 
@@ -129,12 +195,35 @@ impl FromBytes for ProtocolData {
         let (mint, rem) = URef::from_bytes(rem)?;
         let (proof_of_stake, rem) = URef::from_bytes(rem)?;
         let (standard_payment, rem) = URef::from_bytes(rem)?;
+        let protocol_data = ProtocolData {
+            wasm_costs,
+            mint,
+            proof_of_stake,
+            standard_payment,
+            ..Default::default()
+        };
+
+        // Protocol data is always stored on its own, so unversioned data ends here.  It keeps the
+        // defaults for the fields added since, including the default host function costs.
+        if rem.is_empty() {
+            return Ok((protocol_data, rem));
+        }
+
+        let (version, rem) = u8::from_bytes(rem)?;
+        if version != PROTOCOL_DATA_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (host_function_costs, rem) = HostFunctionCosts::from_bytes(rem)?;
+        let (min_gas_price, rem) = u64::from_bytes(rem)?;
+        let (max_payment, rem) = u64::from_bytes(rem)?;
+        let (block_gas_limit, rem) = u64::from_bytes(rem)?;
         Ok((
             ProtocolData {
-                wasm_costs,
-                mint,
-                proof_of_stake,
-                standard_payment,
+                host_function_costs,
+                min_gas_price,
+                max_payment,
+                block_gas_limit,
+                ..protocol_data
             },
             rem,
         ))
@@ -143,8 +232,9 @@ impl FromBytes for ProtocolData {
 
 #[cfg(test)]
 pub(crate) mod gens {
-    use proptest::prop_compose;
+    use proptest::{prelude::any, prop_compose};
 
+    use engine_shared::host_function_costs::gens as host_function_costs_gens;
     use engine_wasm_prep::wasm_costs::gens as wasm_costs_gens;
     use types::gens;
 
@@ -153,12 +243,20 @@ pub(crate) mod gens {
     prop_compose! {
         pub fn protocol_data_arb()(
             wasm_costs in wasm_costs_gens::wasm_costs_arb(),
+            host_function_costs in host_function_costs_gens::host_function_costs_arb(),
+            min_gas_price in any::<u64>(),
+            max_payment in any::<u64>(),
+            block_gas_limit in any::<u64>(),
             mint in gens::uref_arb(),
             proof_of_stake in gens::uref_arb(),
             standard_payment in gens::uref_arb(),
         ) -> ProtocolData {
             ProtocolData {
                 wasm_costs,
+                host_function_costs,
+                min_gas_price,
+                max_payment,
+                block_gas_limit,
                 mint,
                 proof_of_stake,
                 standard_payment,
@@ -171,10 +269,17 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
+    use engine_shared::{host_function_costs::HostFunctionCosts, test_utils};
     use engine_wasm_prep::wasm_costs::WasmCosts;
-    use types::{bytesrepr, AccessRights, URef};
+    use types::{
+        bytesrepr::{self, ToBytes},
+        AccessRights, URef, UREF_SERIALIZED_LENGTH,
+    };
 
-    use super::{gens, ProtocolData};
+    use super::{
+        gens, ProtocolData, DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_MAX_PAYMENT, DEFAULT_MIN_GAS_PRICE,
+        PROTOCOL_DATA_VERSION,
+    };
 
     fn wasm_costs_mock() -> WasmCosts {
         WasmCosts {
@@ -215,6 +320,10 @@ mod tests {
             let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
             ProtocolData::new(
                 costs,
+                test_utils::host_function_costs_mock(),
+                DEFAULT_MIN_GAS_PRICE,
+                DEFAULT_MAX_PAYMENT,
+                DEFAULT_BLOCK_GAS_LIMIT,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -227,6 +336,10 @@ mod tests {
             let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
             ProtocolData::new(
                 costs,
+                test_utils::host_function_costs_free(),
+                DEFAULT_MIN_GAS_PRICE,
+                DEFAULT_MAX_PAYMENT,
+                DEFAULT_BLOCK_GAS_LIMIT,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -236,6 +349,42 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&free);
     }
 
+    #[test]
+    fn should_deserialize_unversioned_protocol_data() {
+        let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
+        let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+        let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
+        let mut bytes = wasm_costs_mock().to_bytes().unwrap();
+        bytes.append(&mut mint_reference.to_bytes().unwrap());
+        bytes.append(&mut proof_of_stake_reference.to_bytes().unwrap());
+        bytes.append(&mut standard_payment_reference.to_bytes().unwrap());
+
+        let protocol_data: ProtocolData = bytesrepr::deserialize(bytes).unwrap();
+
+        let expected = ProtocolData::new(
+            wasm_costs_mock(),
+            HostFunctionCosts::default(),
+            DEFAULT_MIN_GAS_PRICE,
+            DEFAULT_MAX_PAYMENT,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            mint_reference,
+            proof_of_stake_reference,
+            standard_payment_reference,
+        );
+        assert_eq!(protocol_data, expected);
+    }
+
+    #[test]
+    fn should_not_deserialize_unknown_version() {
+        let mut bytes = ProtocolData::default().to_bytes().unwrap();
+        let version_index = WasmCosts::default().serialized_length() + 3 * UREF_SERIALIZED_LENGTH;
+        assert_eq!(bytes[version_index], PROTOCOL_DATA_VERSION);
+        bytes[version_index] = PROTOCOL_DATA_VERSION + 1;
+
+        let result: Result<ProtocolData, _> = bytesrepr::deserialize(bytes);
+        assert_eq!(result, Err(bytesrepr::Error::Formatting));
+    }
+
     #[test]
     fn should_return_all_system_contracts() {
         let mint_reference = URef::new([197u8; 32], AccessRights::READ_ADD_WRITE);
@@ -245,6 +394,10 @@ mod tests {
             let costs = wasm_costs_mock();
             ProtocolData::new(
                 costs,
+                test_utils::host_function_costs_mock(),
+                DEFAULT_MIN_GAS_PRICE,
+                DEFAULT_MAX_PAYMENT,
+                DEFAULT_BLOCK_GAS_LIMIT,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -274,6 +427,10 @@ mod tests {
             let costs = wasm_costs_mock();
             ProtocolData::new(
                 costs,
+                test_utils::host_function_costs_mock(),
+                DEFAULT_MIN_GAS_PRICE,
+                DEFAULT_MAX_PAYMENT,
+                DEFAULT_BLOCK_GAS_LIMIT,
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
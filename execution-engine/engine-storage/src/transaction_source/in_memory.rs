@@ -105,6 +105,13 @@ impl<'a> Writable for InMemoryReadWriteTransaction<'a> {
         sub_view.insert(key.to_vec(), value.to_vec());
         Ok(())
     }
+
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<(), Self::Error> {
+        if let Some(sub_view) = self.view.get_mut(&handle) {
+            sub_view.remove(key);
+        }
+        Ok(())
+    }
 }
 
 /// An environment for the in-memory trie store.
@@ -6,7 +6,7 @@ use engine_shared::{
 };
 use types::{gens::key_arb, Key};
 
-use super::{Pointer, PointerBlock, Trie};
+use super::{merkle_proof::TrieMerkleProof, Pointer, PointerBlock, Trie};
 
 pub fn blake2b_hash_arb() -> impl Strategy<Value = Blake2bHash> {
     vec(any::<u8>(), 0..1000).prop_map(|b| Blake2bHash::new(&b))
@@ -37,3 +37,7 @@ pub fn trie_arb() -> impl Strategy<Value = Trie<Key, StoredValue>> {
             .prop_map(|(affix, pointer)| Trie::Extension { affix, pointer })
     ]
 }
+
+pub fn trie_merkle_proof_arb() -> impl Strategy<Value = TrieMerkleProof<Key, StoredValue>> {
+    (key_arb(), vec(trie_arb(), 0..4)).prop_map(|(key, steps)| TrieMerkleProof::new(key, steps))
+}
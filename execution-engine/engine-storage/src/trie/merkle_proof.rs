@@ -0,0 +1,201 @@
+//! Merkle proofs of inclusion or exclusion of a key in a Merkle Trie
+
+use failure::Fail;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::bytesrepr::{self, FromBytes, ToBytes};
+
+use crate::trie::Trie;
+
+#[derive(Debug, Fail, PartialEq, Eq)]
+pub enum TrieMerkleProofError {
+    #[fail(display = "Proof contains no steps")]
+    EmptyProof,
+
+    #[fail(display = "Proof continues past a leaf at step {}", _0)]
+    UnexpectedLeaf(usize),
+
+    #[fail(display = "Proof diverges from the path of the key at step {}", _0)]
+    PathMismatch(usize),
+
+    #[fail(display = "Proof ends before reaching the key at step {}", _0)]
+    Incomplete(usize),
+
+    #[fail(display = "Hash of step {} doesn't match its parent's pointer", _0)]
+    HashMismatch(usize),
+
+    #[fail(
+        display = "Root hash mismatch: expected {}, computed {}",
+        expected, actual
+    )]
+    RootMismatch {
+        expected: Blake2bHash,
+        actual: Blake2bHash,
+    },
+
+    #[fail(display = "{}", _0)]
+    BytesRepr(#[fail(cause)] bytesrepr::Error),
+}
+
+impl From<bytesrepr::Error> for TrieMerkleProofError {
+    fn from(error: bytesrepr::Error) -> Self {
+        TrieMerkleProofError::BytesRepr(error)
+    }
+}
+
+/// A proof that a key is present in, or absent from, the trie under a given root.
+///
+/// The steps are the trie objects visited when reading the key, ordered from the root to the
+/// deepest object.  If the key is present, the last step is its leaf.  Otherwise the last step is a
+/// leaf with another key, a node without a pointer at the next index of the key's path, or an
+/// extension whose affix diverges from the key's path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMerkleProof<K, V> {
+    key: K,
+    steps: Vec<Trie<K, V>>,
+}
+
+impl<K, V> TrieMerkleProof<K, V> {
+    pub fn new(key: K, steps: Vec<Trie<K, V>>) -> Self {
+        TrieMerkleProof { key, steps }
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn steps(&self) -> &[Trie<K, V>] {
+        &self.steps
+    }
+}
+
+impl<K: Eq, V> TrieMerkleProof<K, V> {
+    /// Returns the value of the key claimed by the proof, without verifying it.
+    pub fn value(&self) -> Option<&V> {
+        match self.steps.last() {
+            Some(Trie::Leaf { key, value }) if *key == self.key => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<K, V> TrieMerkleProof<K, V>
+where
+    K: ToBytes + Eq,
+    V: ToBytes,
+{
+    /// Checks that the steps form a path from the root to the key, and recomputes the root hash.
+    ///
+    /// Returns the root hash together with the value of the key, or `None` if the proof shows that
+    /// the key is absent.
+    pub fn compute_state_hash(&self) -> Result<(Blake2bHash, Option<&V>), TrieMerkleProofError> {
+        if self.steps.is_empty() {
+            return Err(TrieMerkleProofError::EmptyProof);
+        }
+
+        let path = self.key.to_bytes()?;
+        let hashes = self
+            .steps
+            .iter()
+            .map(|step| step.to_bytes().map(|bytes| Blake2bHash::new(&bytes)))
+            .collect::<Result<Vec<Blake2bHash>, bytesrepr::Error>>()?;
+        let last = self.steps.len() - 1;
+
+        let mut depth: usize = 0;
+        let mut value = None;
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let is_last = index == last;
+            let child_hash = if is_last {
+                None
+            } else {
+                Some(&hashes[index + 1])
+            };
+            match step {
+                Trie::Leaf {
+                    key: leaf_key,
+                    value: leaf_value,
+                } => {
+                    if !is_last {
+                        return Err(TrieMerkleProofError::UnexpectedLeaf(index));
+                    }
+                    // Keys may not match in the case of a compressed path from a Node directly to
+                    // a Leaf, which proves the absence of the key
+                    if *leaf_key == self.key {
+                        value = Some(leaf_value);
+                    }
+                }
+                Trie::Node { pointer_block } => {
+                    let path_index: usize = match path.get(depth) {
+                        Some(path_index) => (*path_index).into(),
+                        None => return Err(TrieMerkleProofError::PathMismatch(index)),
+                    };
+                    match (pointer_block[path_index], child_hash) {
+                        (None, None) => {}
+                        (None, Some(_)) => return Err(TrieMerkleProofError::PathMismatch(index)),
+                        (Some(_), None) => return Err(TrieMerkleProofError::Incomplete(index)),
+                        (Some(pointer), Some(child_hash)) => {
+                            if pointer.hash() != child_hash {
+                                return Err(TrieMerkleProofError::HashMismatch(index + 1));
+                            }
+                        }
+                    }
+                    depth += 1;
+                }
+                Trie::Extension { affix, pointer } => {
+                    let matches_path = path
+                        .get(depth..depth + affix.len())
+                        .map_or(false, |sub_path| sub_path == affix.as_slice());
+                    match (matches_path, child_hash) {
+                        (false, None) => {}
+                        (false, Some(_)) => return Err(TrieMerkleProofError::PathMismatch(index)),
+                        (true, None) => return Err(TrieMerkleProofError::Incomplete(index)),
+                        (true, Some(child_hash)) => {
+                            if pointer.hash() != child_hash {
+                                return Err(TrieMerkleProofError::HashMismatch(index + 1));
+                            }
+                        }
+                    }
+                    depth += affix.len();
+                }
+            }
+        }
+
+        Ok((hashes[0], value))
+    }
+
+    /// Verifies the proof against the given root hash.
+    ///
+    /// Returns the value of the key, or `None` if the proof shows that the key is absent.
+    pub fn verify(&self, state_hash: &Blake2bHash) -> Result<Option<&V>, TrieMerkleProofError> {
+        let (computed_state_hash, value) = self.compute_state_hash()?;
+        if computed_state_hash != *state_hash {
+            return Err(TrieMerkleProofError::RootMismatch {
+                expected: *state_hash,
+                actual: computed_state_hash,
+            });
+        }
+        Ok(value)
+    }
+}
+
+impl<K: ToBytes, V: ToBytes> ToBytes for TrieMerkleProof<K, V> {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::allocate_buffer(self)?;
+        ret.append(&mut self.key.to_bytes()?);
+        ret.append(&mut self.steps.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.key.serialized_length() + self.steps.serialized_length()
+    }
+}
+
+impl<K: FromBytes, V: FromBytes> FromBytes for TrieMerkleProof<K, V> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (key, rem) = K::from_bytes(bytes)?;
+        let (steps, rem) = Vec::<Trie<K, V>>::from_bytes(rem)?;
+        Ok((TrieMerkleProof { key, steps }, rem))
+    }
+}
@@ -75,5 +75,10 @@ mod proptests {
         fn roundtrip_trie(trie in trie_arb()) {
             bytesrepr::test_serialization_roundtrip(&trie);
         }
+
+        #[test]
+        fn roundtrip_trie_merkle_proof(proof in trie_merkle_proof_arb()) {
+            bytesrepr::test_serialization_roundtrip(&proof);
+        }
     }
 }
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests;
 
-use std::{cmp, collections::VecDeque, mem, time::Instant};
+use std::{
+    cmp,
+    collections::{HashMap, VecDeque},
+    mem,
+    time::Instant,
+};
 
 use engine_shared::{
     logging::{log_duration, log_metric},
@@ -11,7 +16,7 @@ use types::bytesrepr::{self, FromBytes, ToBytes};
 
 use crate::{
     transaction_source::{Readable, Writable},
-    trie::{self, Parents, Pointer, Trie, RADIX},
+    trie::{self, merkle_proof::TrieMerkleProof, Parents, Pointer, PointerBlock, Trie, RADIX},
     trie_store::TrieStore,
     GAUGE_METRIC_KEY,
 };
@@ -22,10 +27,16 @@ const TRIE_STORE_SCAN_DURATION: &str = "trie_store_scan_duration";
 const TRIE_STORE_SCAN_GETS: &str = "trie_store_scan_gets";
 const TRIE_STORE_WRITE_DURATION: &str = "trie_store_write_duration";
 const TRIE_STORE_WRITE_PUTS: &str = "trie_store_write_puts";
+const TRIE_STORE_DELETE_DURATION: &str = "trie_store_delete_duration";
+const TRIE_STORE_DELETE_PUTS: &str = "trie_store_delete_puts";
+const TRIE_STORE_BATCH_UPDATE_DURATION: &str = "trie_store_batch_update_duration";
+const TRIE_STORE_BATCH_UPDATE_PUTS: &str = "trie_store_batch_update_puts";
 const READ: &str = "read";
 const GET: &str = "get";
 const SCAN: &str = "scan";
 const WRITE: &str = "write";
+const DELETE: &str = "delete";
+const BATCH_UPDATE: &str = "batch_update";
 const PUT: &str = "put";
 
 #[derive(Debug, PartialEq, Eq)]
@@ -199,6 +210,39 @@ where
     }
 }
 
+/// Returns a proof of the inclusion or exclusion of the given key at a given root in a given
+/// store, or `None` if the root is not found in the store.
+pub fn read_with_proof<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<Option<TrieMerkleProof<K, V>>, E>
+where
+    K: ToBytes + FromBytes + Clone,
+    V: ToBytes + FromBytes + Clone,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let root_trie: Trie<K, V> = match store.get(txn, root)? {
+        Some(root_trie) => root_trie,
+        None => return Ok(None),
+    };
+
+    let path: Vec<u8> = key.to_bytes()?;
+
+    let TrieScan { tip, parents } =
+        scan::<K, V, T, S, E>(correlation_id, txn, store, &path, &root_trie)?;
+
+    let mut steps: Vec<Trie<K, V>> = parents.into_iter().map(|(_, parent)| parent).collect();
+    steps.push(tip);
+
+    Ok(Some(TrieMerkleProof::new(key.to_owned(), steps)))
+}
+
 struct TrieScan<K, V> {
     tip: Trie<K, V>,
     parents: Parents<K, V>,
@@ -731,6 +775,554 @@ where
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum DeleteResult {
+    Deleted(Blake2bHash),
+    DoesNotExist,
+    RootNotFound,
+}
+
+/// Removes the leaf with the given key from the trie at a given root in a given store.
+///
+/// The resulting trie is the same as if the key had never been written: a node which is left with
+/// a single child is collapsed into its parent, either by moving a remaining leaf up to the nearest
+/// node, or by turning the node into an extension and merging it with adjacent extensions.
+pub fn delete<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<DeleteResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let start = Instant::now();
+    let mut put_counter: i32 = 0;
+
+    let current_root = match store.get(txn, root)? {
+        None => return Ok(DeleteResult::RootNotFound),
+        Some(current_root) => current_root,
+    };
+
+    let path: Vec<u8> = key.to_bytes()?;
+    let TrieScan { tip, mut parents } =
+        scan::<K, V, T, S, E>(correlation_id, txn, store, &path, &current_root)?;
+
+    // Only a leaf holding the given key can be deleted
+    match tip {
+        Trie::Leaf {
+            key: ref leaf_key, ..
+        } if key == leaf_key => (),
+        _ => {
+            log_duration(
+                correlation_id,
+                TRIE_STORE_DELETE_DURATION,
+                DELETE,
+                start.elapsed(),
+            );
+            return Ok(DeleteResult::DoesNotExist);
+        }
+    }
+
+    // A leaf always has a node for its parent
+    let (leaf_index, mut pointer_block) = match parents.pop() {
+        Some((leaf_index, Trie::Node { pointer_block })) => (leaf_index, pointer_block),
+        _ => panic!("A leaf should have a node for its parent"),
+    };
+    pointer_block[<usize>::from(leaf_index)] = None;
+
+    let mut remaining_children = pointer_block[..]
+        .iter()
+        .enumerate()
+        .filter_map(|(index, maybe_pointer)| maybe_pointer.map(|pointer| (index, pointer)));
+    let sole_child = match (remaining_children.next(), remaining_children.next()) {
+        (Some(child), None) => Some(child),
+        _ => None,
+    };
+
+    let new_tip = match sole_child {
+        // The root, and any other node which still has more than one child, stays a node
+        _ if parents.is_empty() => Trie::Node { pointer_block },
+        None => Trie::Node { pointer_block },
+        // A node left with only a leaf is removed, along with any extension above it, and the
+        // leaf is moved up to the nearest node
+        Some((_, Pointer::LeafPointer(leaf_hash))) => {
+            while let Some((_, Trie::Extension { .. })) = parents.last() {
+                parents.pop();
+            }
+            match store.get(txn, &leaf_hash)? {
+                Some(leaf) => leaf,
+                None => panic!(
+                    "No trie value at key: {:?} (deleting key: {:?})",
+                    leaf_hash, key
+                ),
+            }
+        }
+        // A node left with only a node or extension becomes an extension, which absorbs any
+        // extension above or below it
+        Some((child_index, Pointer::NodePointer(child_hash))) => {
+            let mut affix = match parents.pop() {
+                Some((_, Trie::Extension { affix, .. })) => affix,
+                Some(parent) => {
+                    parents.push(parent);
+                    Vec::new()
+                }
+                None => unreachable!(),
+            };
+            affix.push(child_index as u8);
+            let pointer = match store.get(txn, &child_hash)? {
+                Some(Trie::Extension {
+                    affix: child_affix,
+                    pointer,
+                }) => {
+                    affix.extend(child_affix);
+                    pointer
+                }
+                Some(Trie::Node { .. }) => Pointer::NodePointer(child_hash),
+                Some(Trie::Leaf { .. }) => panic!("A node pointer should not point to a leaf"),
+                None => panic!(
+                    "No trie value at key: {:?} (deleting key: {:?})",
+                    child_hash, key
+                ),
+            };
+            Trie::extension(affix, pointer)
+        }
+    };
+
+    let new_elements = rehash(new_tip, parents)?;
+
+    let mut root_hash = root.to_owned();
+    for (hash, element) in new_elements.iter() {
+        put_counter += 1;
+        store.put(txn, hash, element)?;
+        root_hash = *hash;
+    }
+    log_metric(
+        correlation_id,
+        TRIE_STORE_DELETE_PUTS,
+        PUT,
+        GAUGE_METRIC_KEY,
+        f64::from(put_counter),
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_DELETE_DURATION,
+        DELETE,
+        start.elapsed(),
+    );
+    Ok(DeleteResult::Deleted(root_hash))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BatchUpdateResult {
+    Updated(Blake2bHash),
+    RootNotFound,
+}
+
+/// Applies all of `changes` to the trie at a given root in a given store.  A change of
+/// `Some(value)` writes the value under its key, and `None` deletes the key.  If there is more than
+/// one change to a key, the last one is applied.
+///
+/// The resulting trie is the same as if each change had been applied in turn with [`write`] or
+/// [`delete`].  However the changes are sorted and applied in memory, so every new trie element is
+/// hashed once, and only those which are part of the resulting trie are put in the store.
+pub fn batch_update<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    root: &Blake2bHash,
+    changes: Vec<(K, Option<V>)>,
+) -> Result<BatchUpdateResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let start = Instant::now();
+    let mut put_counter: i32 = 0;
+
+    let pointer_block = match store.get(txn, root)? {
+        None => return Ok(BatchUpdateResult::RootNotFound),
+        Some(Trie::Node { pointer_block }) => pointer_block,
+        Some(_) => panic!("The root of a trie should be a node"),
+    };
+
+    let mut changes = changes
+        .into_iter()
+        .map(|(key, value)| {
+            let path = key.to_bytes()?;
+            Ok(Change { path, key, value })
+        })
+        .collect::<Result<Vec<_>, bytesrepr::Error>>()?;
+    // The sort is stable, so of several changes to a key the last one is moved into the first
+    // one's place before the rest are removed
+    changes.sort_by(|left, right| left.path.cmp(&right.path));
+    changes.dedup_by(|later, earlier| {
+        if later.path == earlier.path {
+            mem::swap(later, earlier);
+            true
+        } else {
+            false
+        }
+    });
+
+    let (new_root, mut new_elements) = {
+        let mut batch = BatchUpdate {
+            txn: &*txn,
+            store,
+            new_elements: HashMap::new(),
+        };
+        let pointer_block = batch.update_pointer_block::<E>(0, *pointer_block, &changes)?;
+        let new_root = batch.add(Trie::Node {
+            pointer_block: Box::new(pointer_block),
+        })?;
+        (new_root, batch.new_elements)
+    };
+
+    // Only the new elements reachable from the new root are kept
+    let mut pending = vec![*new_root.hash()];
+    while let Some(hash) = pending.pop() {
+        let element = match new_elements.remove(&hash) {
+            Some(element) => element,
+            None => continue,
+        };
+        match element {
+            Trie::Leaf { .. } => (),
+            Trie::Node { ref pointer_block } => pending.extend(
+                pointer_block[..]
+                    .iter()
+                    .flatten()
+                    .map(|pointer| *pointer.hash()),
+            ),
+            Trie::Extension { ref pointer, .. } => pending.push(*pointer.hash()),
+        }
+        put_counter += 1;
+        store.put(txn, &hash, &element)?;
+    }
+    log_metric(
+        correlation_id,
+        TRIE_STORE_BATCH_UPDATE_PUTS,
+        PUT,
+        GAUGE_METRIC_KEY,
+        f64::from(put_counter),
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_BATCH_UPDATE_DURATION,
+        BATCH_UPDATE,
+        start.elapsed(),
+    );
+    Ok(BatchUpdateResult::Updated(*new_root.hash()))
+}
+
+/// A change to apply in a [`batch_update`], along with the path to its key.
+struct Change<K, V> {
+    path: Vec<u8>,
+    key: K,
+    value: Option<V>,
+}
+
+/// Returns the runs of consecutive `items` which have the same byte at `depth` of their path, along
+/// with that byte.
+fn group_by_index<X>(items: &[X], depth: usize, path: impl Fn(&X) -> &[u8]) -> Vec<(usize, &[X])> {
+    let mut groups = Vec::new();
+    let mut rest = items;
+    while let Some(first) = rest.first() {
+        let index = path(first)[depth];
+        let count = rest
+            .iter()
+            .take_while(|&item| path(item)[depth] == index)
+            .count();
+        let (group, remaining) = rest.split_at(count);
+        groups.push((index.into(), group));
+        rest = remaining;
+    }
+    groups
+}
+
+/// The state of a [`batch_update`] while the new trie is built.
+///
+/// Every part of the trie is built in the form it has in a trie holding the same leaves, which
+/// doesn't depend on the order they were written in: each node is either the root or has at least
+/// two children, a leaf hangs from the nearest node above it, and an extension only ever stands in
+/// for a chain of nodes with single children.
+struct BatchUpdate<'a, K, V, T, S> {
+    txn: &'a T,
+    store: &'a S,
+    /// All the trie elements created so far, some of which may end up not being part of the trie.
+    new_elements: HashMap<Blake2bHash, Trie<K, V>>,
+}
+
+impl<'a, K, V, T, S> BatchUpdate<'a, K, V, T, S>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+{
+    /// Returns the trie element with the given hash, whether it is new or in the store.
+    fn get<E: From<S::Error>>(&self, hash: &Blake2bHash) -> Result<Trie<K, V>, E> {
+        if let Some(trie) = self.new_elements.get(hash) {
+            return Ok(trie.clone());
+        }
+        match self.store.get(self.txn, hash)? {
+            Some(trie) => Ok(trie),
+            None => panic!("No trie value at key: {:?}", hash),
+        }
+    }
+
+    /// Hashes a new trie element, returning a pointer to it.
+    fn add(&mut self, trie: Trie<K, V>) -> Result<Pointer, bytesrepr::Error> {
+        let hash = Blake2bHash::new(&trie.to_bytes()?);
+        let pointer = match trie {
+            Trie::Leaf { .. } => Pointer::LeafPointer(hash),
+            Trie::Node { .. } | Trie::Extension { .. } => Pointer::NodePointer(hash),
+        };
+        self.new_elements.insert(hash, trie);
+        Ok(pointer)
+    }
+
+    /// Applies `changes` to the children of the node at `depth`, all of which lie below it.
+    fn update_pointer_block<E>(
+        &mut self,
+        depth: usize,
+        mut pointer_block: PointerBlock,
+        changes: &[Change<K, V>],
+    ) -> Result<PointerBlock, E>
+    where
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        for (index, group) in group_by_index(changes, depth, |change| change.path.as_slice()) {
+            pointer_block[index] =
+                self.update_child::<E>(depth + 1, pointer_block[index], group)?;
+        }
+        Ok(pointer_block)
+    }
+
+    /// Applies `changes` to the child of a node which starts at `depth`, returning the new child.
+    fn update_child<E>(
+        &mut self,
+        depth: usize,
+        child: Option<Pointer>,
+        changes: &[Change<K, V>],
+    ) -> Result<Option<Pointer>, E>
+    where
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        let pointer = match child {
+            _ if changes.is_empty() => return Ok(child),
+            None => {
+                let leaves = self.new_leaves(changes)?;
+                return Ok(self.build(depth, leaves)?);
+            }
+            Some(pointer) => pointer,
+        };
+        match self.get::<E>(pointer.hash())? {
+            Trie::Leaf { key, .. } => {
+                let path = key.to_bytes()?;
+                let mut leaves = self.new_leaves(changes)?;
+                if changes.iter().all(|change| change.path != path) {
+                    leaves.push((path, pointer));
+                    leaves.sort_by(|left, right| left.0.cmp(&right.0));
+                }
+                Ok(self.build(depth, leaves)?)
+            }
+            Trie::Node { pointer_block } => {
+                let pointer_block =
+                    self.update_pointer_block::<E>(depth, *pointer_block, changes)?;
+                self.collapse(pointer_block)
+            }
+            Trie::Extension {
+                affix,
+                pointer: node_pointer,
+            } => {
+                let node_depth = depth + affix.len();
+                // The changes are sorted, so those below the extension are all together
+                let is_inside =
+                    |change: &Change<K, V>| change.path.get(depth..node_depth) == Some(&affix[..]);
+                let start = changes
+                    .iter()
+                    .position(|change| is_inside(change))
+                    .unwrap_or_else(|| changes.len());
+                let end = start
+                    + changes[start..]
+                        .iter()
+                        .take_while(|change| is_inside(change))
+                        .count();
+                let inside = &changes[start..end];
+                // Deleting a key which isn't in the trie changes nothing
+                let outside: Vec<&Change<K, V>> = changes[..start]
+                    .iter()
+                    .chain(&changes[end..])
+                    .filter(|change| change.value.is_some())
+                    .collect();
+                if inside.is_empty() && outside.is_empty() {
+                    return Ok(Some(pointer));
+                }
+
+                let node = if inside.is_empty() {
+                    Some(node_pointer)
+                } else {
+                    let pointer_block = match self.get::<E>(node_pointer.hash())? {
+                        Trie::Node { pointer_block } => *pointer_block,
+                        _ => panic!("An extension should point to a node"),
+                    };
+                    let pointer_block =
+                        self.update_pointer_block::<E>(node_depth, pointer_block, inside)?;
+                    self.collapse::<E>(pointer_block)?
+                };
+
+                if outside.is_empty() {
+                    return self.prepend_affix(affix, node);
+                }
+
+                // The new leaves branch off within the affix, so the extension has to be split
+                let mut items = Vec::with_capacity(outside.len() + 1);
+                for change in outside {
+                    let leaf = Trie::leaf(change.key.clone(), change.value.clone().unwrap());
+                    items.push((change.path.clone(), self.add(leaf)?));
+                }
+                if let Some(node) = node {
+                    let mut node_path = changes[0].path[..depth].to_vec();
+                    node_path.extend(&affix);
+                    items.push(self.item::<E>(node_path, node)?);
+                }
+                items.sort_by(|left, right| left.0.cmp(&right.0));
+                Ok(self.build(depth, items)?)
+            }
+        }
+    }
+
+    /// Adds the leaves written by `changes`, returning them along with their paths.
+    fn new_leaves(
+        &mut self,
+        changes: &[Change<K, V>],
+    ) -> Result<Vec<(Vec<u8>, Pointer)>, bytesrepr::Error> {
+        let mut leaves = Vec::with_capacity(changes.len());
+        for change in changes {
+            if let Some(value) = &change.value {
+                let leaf = Trie::leaf(change.key.clone(), value.clone());
+                leaves.push((change.path.clone(), self.add(leaf)?));
+            }
+        }
+        Ok(leaves)
+    }
+
+    /// Returns the path and pointer which [`build`](Self::build) takes for the child of a node
+    /// which starts at the end of `path`.
+    fn item<E: From<S::Error> + From<bytesrepr::Error>>(
+        &self,
+        mut path: Vec<u8>,
+        child: Pointer,
+    ) -> Result<(Vec<u8>, Pointer), E> {
+        match self.get::<E>(child.hash())? {
+            Trie::Leaf { key, .. } => Ok((key.to_bytes()?, child)),
+            Trie::Node { .. } => Ok((path, child)),
+            Trie::Extension { affix, pointer } => {
+                path.extend(affix);
+                Ok((path, pointer))
+            }
+        }
+    }
+
+    /// Builds the child of a node which starts at `depth` and holds `items`, sorted by path.  An
+    /// item is either a leaf and the path to its key, or a node and the path to it.
+    fn build(
+        &mut self,
+        depth: usize,
+        mut items: Vec<(Vec<u8>, Pointer)>,
+    ) -> Result<Option<Pointer>, bytesrepr::Error> {
+        if items.len() <= 1 {
+            return match items.pop() {
+                None => Ok(None),
+                Some((path, pointer @ Pointer::NodePointer(_))) if path.len() > depth => {
+                    let extension = Trie::extension(path[depth..].to_vec(), pointer);
+                    Ok(Some(self.add(extension)?))
+                }
+                Some((_, pointer)) => Ok(Some(pointer)),
+            };
+        }
+
+        // The items are sorted, so the path they all share is the one the first and last share
+        let shared_path = common_prefix(&items[0].0, &items[items.len() - 1].0);
+        let node_depth = shared_path.len();
+        let mut pointer_block = PointerBlock::new();
+        for (index, group) in group_by_index(&items, node_depth, |item| item.0.as_slice()) {
+            pointer_block[index] = self.build(node_depth + 1, group.to_vec())?;
+        }
+        let node = self.add(Trie::Node {
+            pointer_block: Box::new(pointer_block),
+        })?;
+        if node_depth == depth {
+            Ok(Some(node))
+        } else {
+            let extension = Trie::extension(shared_path[depth..].to_vec(), node);
+            Ok(Some(self.add(extension)?))
+        }
+    }
+
+    /// Returns what replaces a node other than the root with the given children: nothing if it
+    /// has none, and its child moved up if it has only one.
+    fn collapse<E>(&mut self, pointer_block: PointerBlock) -> Result<Option<Pointer>, E>
+    where
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        let children: Vec<(usize, Pointer)> = pointer_block[..]
+            .iter()
+            .enumerate()
+            .filter_map(|(index, maybe_pointer)| maybe_pointer.map(|pointer| (index, pointer)))
+            .take(2)
+            .collect();
+        match children.as_slice() {
+            [] => Ok(None),
+            [(_, leaf @ Pointer::LeafPointer(_))] => Ok(Some(*leaf)),
+            [(index, child)] => self.prepend_affix(vec![*index as u8], Some(*child)),
+            _ => Ok(Some(self.add(Trie::Node {
+                pointer_block: Box::new(pointer_block),
+            })?)),
+        }
+    }
+
+    /// Returns what replaces `child` when it is moved up by `affix`: a leaf stays as it is, while a
+    /// node gets an extension above it, or the affix is added to the front of an extension.
+    fn prepend_affix<E>(
+        &mut self,
+        mut affix: Vec<u8>,
+        child: Option<Pointer>,
+    ) -> Result<Option<Pointer>, E>
+    where
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        let child = match child {
+            None => return Ok(None),
+            Some(leaf @ Pointer::LeafPointer(_)) => return Ok(Some(leaf)),
+            Some(child) => child,
+        };
+        let extension = match self.get::<E>(child.hash())? {
+            Trie::Node { .. } => Trie::extension(affix, child),
+            Trie::Extension {
+                affix: child_affix,
+                pointer,
+            } => {
+                affix.extend(child_affix);
+                Trie::extension(affix, pointer)
+            }
+            Trie::Leaf { .. } => panic!("A node pointer should not point to a leaf"),
+        };
+        Ok(Some(self.add(extension)?))
+    }
+}
+
 enum KeysIteratorState<K, V, S: TrieStore<K, V>> {
     /// Iterate normally
     Ok,
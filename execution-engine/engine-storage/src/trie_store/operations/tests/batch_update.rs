@@ -0,0 +1,259 @@
+use proptest::{
+    array,
+    collection::vec,
+    option,
+    prelude::{any, prop_oneof, proptest, Strategy},
+};
+
+use super::*;
+use crate::trie_store::operations::{batch_update, delete, BatchUpdateResult, DeleteResult};
+
+type TestChange = (TestKey, Option<TestValue>);
+
+/// Applies `changes` to the trie at `root` one at a time, returning the resulting root.
+fn update_sequentially<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    changes: &[TestChange],
+) -> Result<Blake2bHash, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut txn = environment.create_read_write_txn()?;
+    let mut root = *root;
+    for (key, maybe_value) in changes {
+        match maybe_value {
+            Some(value) => {
+                match write::<_, _, _, _, E>(correlation_id, &mut txn, store, &root, key, value)? {
+                    WriteResult::Written(root_hash) => root = root_hash,
+                    WriteResult::AlreadyExists => (),
+                    WriteResult::RootNotFound => panic!("write could not find root"),
+                }
+            }
+            None => match delete::<_, _, _, _, E>(correlation_id, &mut txn, store, &root, key)? {
+                DeleteResult::Deleted(root_hash) => root = root_hash,
+                DeleteResult::DoesNotExist => (),
+                DeleteResult::RootNotFound => panic!("delete could not find root"),
+            },
+        }
+    }
+    txn.commit()?;
+    Ok(root)
+}
+
+/// Applies `changes` to the trie at `root` as a batch, checks that the result holds the last value
+/// given to each key, and that it is the trie which results from applying the changes one at a
+/// time.
+fn batch_update_matches_sequential_updates<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    changes: &[TestChange],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut txn = environment.create_read_write_txn()?;
+    let batch_root = match batch_update::<_, _, _, _, E>(
+        correlation_id,
+        &mut txn,
+        store,
+        root,
+        changes.to_vec(),
+    )? {
+        BatchUpdateResult::Updated(root_hash) => root_hash,
+        BatchUpdateResult::RootNotFound => panic!("batch update could not find root"),
+    };
+    txn.commit()?;
+
+    // Checked before the sequential updates put their own trie elements in the store
+    let txn = environment.create_read_txn()?;
+    for (index, (key, maybe_value)) in changes.iter().enumerate() {
+        if changes[index + 1..].iter().any(|(other, _)| other == key) {
+            continue;
+        }
+        let expected = match maybe_value {
+            Some(value) => ReadResult::Found(*value),
+            None => ReadResult::NotFound,
+        };
+        let actual = read::<_, _, _, _, E>(correlation_id, &txn, store, &batch_root, key)?;
+        assert_eq!(actual, expected);
+    }
+    txn.commit()?;
+
+    let sequential_root =
+        update_sequentially::<_, _, E>(correlation_id, environment, store, root, changes)?;
+    assert_eq!(batch_root, sequential_root);
+    Ok(())
+}
+
+/// Changes which update, delete and add to the leaves of the test tries.
+fn test_changes() -> Vec<TestChange> {
+    let updated = TEST_LEAVES_UPDATED.iter().step_by(2);
+    let deleted = TEST_LEAVES.iter().skip(1).step_by(2);
+    let added = TEST_LEAVES_ADJACENTS
+        .iter()
+        .chain(&TEST_LEAVES_NON_COLLIDING);
+    updated
+        .chain(added)
+        .map(|leaf| match leaf {
+            Trie::Leaf { key, value } => (*key, Some(*value)),
+            _ => panic!("should be a leaf"),
+        })
+        .chain(deleted.map(|leaf| (*leaf.key().expect("should be a leaf"), None)))
+        .collect()
+}
+
+#[test]
+fn lmdb_batch_update_to_n_leaf_trie_matches_sequential_updates() {
+    let correlation_id = CorrelationId::new();
+    let changes = test_changes();
+
+    for generator in &TEST_TRIE_GENERATORS {
+        let (root_hash, tries) = generator().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+
+        batch_update_matches_sequential_updates::<_, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &changes,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_batch_update_to_n_leaf_trie_matches_sequential_updates() {
+    let correlation_id = CorrelationId::new();
+    let changes = test_changes();
+
+    for generator in &TEST_TRIE_GENERATORS {
+        let (root_hash, tries) = generator().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+
+        batch_update_matches_sequential_updates::<_, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &changes,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_batch_delete_of_absent_key_keeps_root() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[TEST_TRIE_GENERATORS_LENGTH - 1]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let absent_key = TEST_LEAVES_ADJACENTS[0].key().unwrap();
+
+    let mut txn = context.environment.create_read_write_txn().unwrap();
+    let result = batch_update::<_, _, _, _, in_memory::Error>(
+        correlation_id,
+        &mut txn,
+        &context.store,
+        &root_hash,
+        vec![(*absent_key, None)],
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    assert_eq!(result, BatchUpdateResult::Updated(root_hash));
+}
+
+fn lmdb_batch_update_succeeds(pairs: &[(TestKey, TestValue)], changes: &[TestChange]) {
+    let correlation_id = CorrelationId::new();
+    let (empty_root, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+    let root_hash = write_pairs::<_, _, _, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &empty_root,
+        pairs,
+    )
+    .unwrap()
+    .last()
+    .cloned()
+    .unwrap_or(empty_root);
+
+    batch_update_matches_sequential_updates::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        changes,
+    )
+    .unwrap();
+}
+
+fn in_memory_batch_update_succeeds(pairs: &[(TestKey, TestValue)], changes: &[TestChange]) {
+    let correlation_id = CorrelationId::new();
+    let (empty_root, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let root_hash = write_pairs::<_, _, _, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &empty_root,
+        pairs,
+    )
+    .unwrap()
+    .last()
+    .cloned()
+    .unwrap_or(empty_root);
+
+    batch_update_matches_sequential_updates::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        changes,
+    )
+    .unwrap();
+}
+
+/// Keys from a two letter alphabet share long prefixes and collide often, so that changes hit
+/// existing leaves and extensions get split and merged.
+fn test_key_arb() -> impl Strategy<Value = TestKey> {
+    prop_oneof![array::uniform7(0u8..2), array::uniform7(any::<u8>())].prop_map(TestKey)
+}
+
+fn test_value_arb() -> impl Strategy<Value = TestValue> {
+    array::uniform6(any::<u8>()).prop_map(TestValue)
+}
+
+fn test_change_arb() -> impl Strategy<Value = TestChange> {
+    (test_key_arb(), option::of(test_value_arb()))
+}
+
+proptest! {
+    #[test]
+    fn prop_in_memory_batch_update_matches_sequential_updates(
+        pairs in vec((test_key_arb(), test_value_arb()), 0..50),
+        changes in vec(test_change_arb(), 0..50),
+    ) {
+        in_memory_batch_update_succeeds(&pairs, &changes);
+    }
+
+    #[test]
+    fn prop_lmdb_batch_update_matches_sequential_updates(
+        pairs in vec((test_key_arb(), test_value_arb()), 0..50),
+        changes in vec(test_change_arb(), 0..50),
+    ) {
+        lmdb_batch_update_succeeds(&pairs, &changes);
+    }
+}
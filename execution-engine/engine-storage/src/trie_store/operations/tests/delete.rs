@@ -0,0 +1,200 @@
+use super::*;
+use crate::trie_store::operations::{delete, DeleteResult};
+
+fn delete_key<'a, K, V, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<DeleteResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut txn = environment.create_read_write_txn()?;
+    let result = delete::<K, V, _, _, E>(correlation_id, &mut txn, store, root, key)?;
+    txn.commit()?;
+    Ok(result)
+}
+
+/// Deletes each of the leaves of an n-leaf trie in turn, checking that the resulting root is the
+/// same as the root of a trie to which only the remaining leaves were written.
+fn deletes_from_n_leaf_trie_had_expected_results<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    empty_root: &Blake2bHash,
+    leaves: &[TestTrie],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    for (index, leaf) in leaves.iter().enumerate() {
+        let key = leaf.key().expect("should be a leaf");
+
+        let remaining: Vec<TestTrie> = leaves
+            .iter()
+            .enumerate()
+            .filter(|(other_index, _)| *other_index != index)
+            .map(|(_, other)| other.to_owned())
+            .collect();
+
+        let expected_root = write_leaves::<_, _, _, _, E>(
+            correlation_id,
+            environment,
+            store,
+            empty_root,
+            &remaining,
+        )?
+        .into_iter()
+        .fold(*empty_root, |root, result| match result {
+            WriteResult::Written(root_hash) => root_hash,
+            _ => root,
+        });
+
+        let deleted_root =
+            match delete_key::<_, _, _, _, E>(correlation_id, environment, store, root, key)? {
+                DeleteResult::Deleted(root_hash) => root_hash,
+                other => panic!("unexpected delete result: {:?}", other),
+            };
+
+        assert_eq!(deleted_root, expected_root);
+
+        check_leaves::<_, _, _, _, E>(
+            correlation_id,
+            environment,
+            store,
+            &deleted_root,
+            &remaining,
+            &[leaf.to_owned()],
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn lmdb_deletes_from_n_leaf_trie_had_expected_results() {
+    for num_leaves in 1..TEST_TRIE_GENERATORS_LENGTH {
+        let correlation_id = CorrelationId::new();
+        let (empty_root, empty_tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+        let (root_hash, tries) = TEST_TRIE_GENERATORS[num_leaves]().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        context.update(&empty_tries).unwrap();
+
+        deletes_from_n_leaf_trie_had_expected_results::<_, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &empty_root,
+            &TEST_LEAVES[..num_leaves],
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_deletes_from_n_leaf_trie_had_expected_results() {
+    for num_leaves in 1..TEST_TRIE_GENERATORS_LENGTH {
+        let correlation_id = CorrelationId::new();
+        let (empty_root, empty_tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+        let (root_hash, tries) = TEST_TRIE_GENERATORS[num_leaves]().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        context.update(&empty_tries).unwrap();
+
+        deletes_from_n_leaf_trie_had_expected_results::<_, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &empty_root,
+            &TEST_LEAVES[..num_leaves],
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn lmdb_delete_of_missing_key_does_not_exist() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[3]().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    for leaf in &TEST_LEAVES[3..] {
+        let key = leaf.key().unwrap();
+        let result = delete_key::<_, _, _, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            key,
+        )
+        .unwrap();
+        assert_eq!(result, DeleteResult::DoesNotExist);
+    }
+}
+
+#[test]
+fn in_memory_delete_of_missing_key_does_not_exist() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[3]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    for leaf in &TEST_LEAVES[3..] {
+        let key = leaf.key().unwrap();
+        let result = delete_key::<_, _, _, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            key,
+        )
+        .unwrap();
+        assert_eq!(result, DeleteResult::DoesNotExist);
+    }
+}
+
+#[test]
+fn lmdb_delete_from_missing_root_is_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let context = LmdbTestContext::new(EMPTY_HASHED_TEST_TRIES).unwrap();
+    let root_hash = Blake2bHash::new(&[1u8; 32]);
+    let key = TEST_LEAVES[0].key().unwrap();
+
+    let result = delete_key::<TestKey, TestValue, _, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        key,
+    )
+    .unwrap();
+    assert_eq!(result, DeleteResult::RootNotFound);
+}
+
+#[test]
+fn in_memory_delete_from_missing_root_is_root_not_found() {
+    let correlation_id = CorrelationId::new();
+    let context = InMemoryTestContext::new(EMPTY_HASHED_TEST_TRIES).unwrap();
+    let root_hash = Blake2bHash::new(&[1u8; 32]);
+    let key = TEST_LEAVES[0].key().unwrap();
+
+    let result = delete_key::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        key,
+    )
+    .unwrap();
+    assert_eq!(result, DeleteResult::RootNotFound);
+}
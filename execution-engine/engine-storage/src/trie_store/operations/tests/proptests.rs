@@ -7,6 +7,7 @@ use proptest::{
 };
 
 use super::*;
+use crate::trie_store::operations::DeleteResult;
 
 const DEFAULT_MIN_LENGTH: usize = 0;
 
@@ -76,6 +77,75 @@ fn in_memory_roundtrip_succeeds(pairs: &[(TestKey, TestValue)]) -> bool {
     .unwrap()
 }
 
+fn lmdb_write_then_delete_all_returns_to_empty_root(pairs: &[(TestKey, TestValue)]) -> bool {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    write_then_delete_all_returns_to_empty_root::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        pairs,
+    )
+    .unwrap()
+}
+
+fn in_memory_write_then_delete_all_returns_to_empty_root(pairs: &[(TestKey, TestValue)]) -> bool {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    write_then_delete_all_returns_to_empty_root::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        pairs,
+    )
+    .unwrap()
+}
+
+fn write_then_delete_all_returns_to_empty_root<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root: &Blake2bHash,
+    pairs: &[(TestKey, TestValue)],
+) -> Result<bool, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut root_hash =
+        write_pairs::<_, _, _, _, E>(correlation_id, environment, store, empty_root, pairs)?
+            .last()
+            .cloned()
+            .unwrap_or(*empty_root);
+
+    let mut txn = environment.create_read_write_txn()?;
+    for (key, _) in pairs {
+        match operations::delete::<TestKey, TestValue, _, _, E>(
+            correlation_id,
+            &mut txn,
+            store,
+            &root_hash,
+            key,
+        )? {
+            DeleteResult::Deleted(new_root_hash) => root_hash = new_root_hash,
+            // Keys may be repeated in `pairs`
+            DeleteResult::DoesNotExist => (),
+            DeleteResult::RootNotFound => return Ok(false),
+        }
+    }
+    txn.commit()?;
+
+    Ok(root_hash == *empty_root)
+}
+
 fn test_key_arb() -> impl Strategy<Value = TestKey> {
     array::uniform7(any::<u8>()).prop_map(TestKey)
 }
@@ -94,4 +164,14 @@ proptest! {
     fn prop_lmdb_roundtrip_succeeds(inputs in vec((test_key_arb(), test_value_arb()), get_range())) {
         assert!(lmdb_roundtrip_succeeds(&inputs));
     }
+
+    #[test]
+    fn prop_in_memory_write_then_delete_all_returns_to_empty_root(inputs in vec((test_key_arb(), test_value_arb()), get_range())) {
+        assert!(in_memory_write_then_delete_all_returns_to_empty_root(&inputs));
+    }
+
+    #[test]
+    fn prop_lmdb_write_then_delete_all_returns_to_empty_root(inputs in vec((test_key_arb(), test_value_arb()), get_range())) {
+        assert!(lmdb_write_then_delete_all_returns_to_empty_root(&inputs));
+    }
 }
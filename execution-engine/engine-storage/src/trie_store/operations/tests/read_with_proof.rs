@@ -0,0 +1,212 @@
+//! This module contains tests for [`read_with_proof`] and for verifying the returned proofs with
+//! [`TrieMerkleProof::verify`].
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie::merkle_proof::{TrieMerkleProof, TrieMerkleProofError},
+    trie_store::operations::read_with_proof,
+};
+
+fn check_proofs<'a, K, V, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    present: &[Trie<K, V>],
+    absent: &[Trie<K, V>],
+) -> Result<(), E>
+where
+    K: ToBytes + FromBytes + Eq + std::fmt::Debug + Clone,
+    V: ToBytes + FromBytes + Eq + std::fmt::Debug + Clone,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let txn: R::ReadTransaction = environment.create_read_txn()?;
+
+    for (leaf, is_present) in present
+        .iter()
+        .map(|leaf| (leaf, true))
+        .chain(absent.iter().map(|leaf| (leaf, false)))
+    {
+        if let Trie::Leaf { key, value } = leaf {
+            let proof: TrieMerkleProof<K, V> =
+                read_with_proof::<_, _, _, _, E>(correlation_id, &txn, store, root, key)?
+                    .expect("root should exist");
+            let expected = if is_present { Some(value) } else { None };
+            assert_eq!(proof.verify(root), Ok(expected));
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn in_memory_proofs_from_n_leaf_partial_trie_verify() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        check_proofs::<_, _, _, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn lmdb_proofs_from_n_leaf_partial_trie_verify() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        check_proofs::<_, _, _, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_proofs_of_adjacent_keys_verify_absence() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    check_proofs::<_, _, _, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+        &TEST_LEAVES,
+        &TEST_LEAVES_ADJACENTS,
+    )
+    .unwrap();
+}
+
+#[test]
+fn read_with_proof_from_unknown_root_returns_none() {
+    let correlation_id = CorrelationId::new();
+    let (_, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let unknown_root = Blake2bHash::new(b"unknown root");
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let result = read_with_proof::<TestKey, TestValue, _, _, in_memory::Error>(
+        correlation_id,
+        &txn,
+        &context.store,
+        &unknown_root,
+        TEST_LEAVES[0].key().unwrap(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    assert_eq!(result, None);
+}
+
+fn read_proof(key: &TestKey) -> (Blake2bHash, TrieMerkleProof<TestKey, TestValue>) {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = create_6_leaf_trie().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    let txn = context.environment.create_read_txn().unwrap();
+    let proof = read_with_proof::<_, _, _, _, in_memory::Error>(
+        correlation_id,
+        &txn,
+        &context.store,
+        &root_hash,
+        key,
+    )
+    .unwrap()
+    .expect("root should exist");
+    txn.commit().unwrap();
+
+    (root_hash, proof)
+}
+
+#[test]
+fn proof_should_not_verify_against_other_root() {
+    let key = TEST_LEAVES[0].key().unwrap();
+    let (root_hash, proof) = read_proof(key);
+    let other_root = Blake2bHash::new(b"other root");
+
+    assert_eq!(
+        proof.verify(&other_root),
+        Err(TrieMerkleProofError::RootMismatch {
+            expected: other_root,
+            actual: root_hash,
+        })
+    );
+}
+
+#[test]
+fn proof_should_not_verify_with_tampered_leaf() {
+    let key = TEST_LEAVES[0].key().unwrap();
+    let (root_hash, proof) = read_proof(key);
+
+    let mut steps = proof.steps().to_vec();
+    let last = steps.len() - 1;
+    steps[last] = Trie::leaf(*key, TestValue(*b"forged"));
+    let tampered = TrieMerkleProof::new(*key, steps);
+
+    assert_eq!(
+        tampered.verify(&root_hash),
+        Err(TrieMerkleProofError::HashMismatch(last))
+    );
+}
+
+#[test]
+fn proof_should_not_verify_for_other_key() {
+    let key = TEST_LEAVES[0].key().unwrap();
+    let (root_hash, proof) = read_proof(key);
+
+    // a proof of key 0 ends at its leaf, so it can't be passed off as an absence proof of a key
+    // which shares its path only partially
+    let other_key = TEST_LEAVES[4].key().unwrap();
+    let forged = TrieMerkleProof::new(*other_key, proof.steps().to_vec());
+
+    assert!(forged.verify(&root_hash).is_err());
+}
+
+#[test]
+fn truncated_proof_should_not_verify() {
+    let key = TEST_LEAVES[0].key().unwrap();
+    let (root_hash, proof) = read_proof(key);
+
+    let mut steps = proof.steps().to_vec();
+    steps.pop();
+    let truncated = TrieMerkleProof::new(*key, steps);
+
+    assert!(match truncated.verify(&root_hash) {
+        Err(TrieMerkleProofError::Incomplete(_)) => true,
+        _ => false,
+    });
+
+    let empty = TrieMerkleProof::<TestKey, TestValue>::new(*key, Vec::new());
+    assert_eq!(
+        empty.verify(&root_hash),
+        Err(TrieMerkleProofError::EmptyProof)
+    );
+}
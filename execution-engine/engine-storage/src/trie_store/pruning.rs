@@ -0,0 +1,319 @@
+//! Pruning of an LMDB-backed trie store.
+//!
+//! Every commit writes new tries to the store and nothing is ever removed from it.  Pruning keeps a
+//! given set of retained state roots and deletes every trie which none of them can reach, using a
+//! mark-and-sweep over the [`Pointer`]s of the stored tries.
+//!
+//! Pruning runs in a single read-write transaction, so readers never observe a partially pruned
+//! store, and other writers are blocked until it completes.
+
+use std::collections::HashSet;
+
+use lmdb::Cursor;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::bytesrepr::{FromBytes, ToBytes};
+
+use crate::{
+    error,
+    store::Store,
+    transaction_source::{lmdb::LmdbEnvironment, Readable, Transaction, TransactionSource},
+    trie::{Pointer, Trie},
+    trie_store::{lmdb::LmdbTrieStore, TrieStore},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PruneResult {
+    /// A retained root, or a trie reachable from one, is missing from the store.  Nothing was
+    /// deleted.
+    TrieNotFound(Blake2bHash),
+    Success {
+        /// The number of tries reachable from the retained roots.
+        retained: usize,
+        /// The number of tries which were deleted.
+        pruned: usize,
+    },
+}
+
+/// Returns the serialized hashes of all tries reachable from the given roots, or the hash of the
+/// first trie found to be missing from the store.
+fn mark<K, V, T, S, E>(
+    txn: &T,
+    store: &S,
+    retained_roots: &[Blake2bHash],
+) -> Result<Result<HashSet<Vec<u8>>, Blake2bHash>, E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut reachable: HashSet<Vec<u8>> = HashSet::new();
+    let mut to_visit: Vec<Pointer> = retained_roots
+        .iter()
+        .map(|root| Pointer::NodePointer(*root))
+        .collect();
+
+    while let Some(pointer) = to_visit.pop() {
+        if !reachable.insert(pointer.hash().to_bytes()?) {
+            continue;
+        }
+        // Leaves have no children, so there is no need to read them
+        if let Pointer::LeafPointer(_) = pointer {
+            continue;
+        }
+        let trie: Trie<K, V> = match store.get(txn, pointer.hash())? {
+            Some(trie) => trie,
+            None => return Ok(Err(*pointer.hash())),
+        };
+        match trie {
+            Trie::Leaf { .. } => {}
+            Trie::Node { pointer_block } => {
+                to_visit.extend(pointer_block[..].iter().filter_map(|pointer| *pointer))
+            }
+            Trie::Extension { pointer, .. } => to_visit.push(pointer),
+        }
+    }
+
+    Ok(Ok(reachable))
+}
+
+/// Deletes every trie in `store` which is not reachable from one of `retained_roots`.
+///
+/// `K` and `V` are the key and value types of the tries in the store.
+pub fn prune<K, V>(
+    environment: &LmdbEnvironment,
+    store: &LmdbTrieStore,
+    retained_roots: &[Blake2bHash],
+) -> Result<PruneResult, error::Error>
+where
+    K: FromBytes,
+    V: FromBytes,
+{
+    let mut txn = environment.create_read_write_txn()?;
+
+    let reachable = match mark::<K, V, _, _, error::Error>(&txn, store, retained_roots)? {
+        Ok(reachable) => reachable,
+        Err(missing_hash) => return Ok(PruneResult::TrieNotFound(missing_hash)),
+    };
+
+    let unreachable: Vec<Vec<u8>> = {
+        let handle = <LmdbTrieStore as Store<Blake2bHash, Trie<K, V>>>::handle(store);
+        let mut cursor = lmdb::Transaction::open_ro_cursor(&txn, handle)?;
+        cursor
+            .iter_start()
+            .map(|(key, _)| key)
+            .filter(|key| !reachable.contains(*key))
+            .map(<[u8]>::to_vec)
+            .collect()
+    };
+
+    for key in &unreachable {
+        let key = Blake2bHash::from_bytes(key)?.0;
+        Store::<Blake2bHash, Trie<K, V>>::delete(store, &mut txn, &key)?;
+    }
+
+    txn.commit()?;
+
+    Ok(PruneResult::Success {
+        retained: reachable.len(),
+        pruned: unreachable.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use lmdb::DatabaseFlags;
+    use tempfile::{tempdir, TempDir};
+
+    use super::*;
+    use crate::{
+        trie::operations::create_hashed_empty_trie,
+        trie_store::operations::{self, ReadResult, WriteResult},
+        TEST_MAP_SIZE,
+    };
+    use engine_shared::newtypes::CorrelationId;
+
+    type TestKey = Vec<u8>;
+    type TestValue = Vec<u8>;
+
+    const TEST_PAIRS: [([u8; 3], &[u8]); 4] = [
+        ([0, 0, 0], b"value0"),
+        ([0, 0, 1], b"value1"),
+        ([1, 0, 0], b"value2"),
+        ([0, 0, 0], b"value3"),
+    ];
+
+    struct TestContext {
+        _temp_dir: TempDir,
+        environment: LmdbEnvironment,
+        store: LmdbTrieStore,
+        /// The root after each write, starting with the empty root.
+        roots: Vec<Blake2bHash>,
+    }
+
+    impl TestContext {
+        fn new() -> Self {
+            let correlation_id = CorrelationId::new();
+            let _temp_dir = tempdir().unwrap();
+            let environment =
+                LmdbEnvironment::new(&_temp_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap();
+            let store = LmdbTrieStore::new(&environment, None, DatabaseFlags::empty()).unwrap();
+
+            let mut roots = Vec::new();
+            {
+                let mut txn = environment.create_read_write_txn().unwrap();
+                let (empty_root_hash, empty_root) =
+                    create_hashed_empty_trie::<TestKey, TestValue>().unwrap();
+                store.put(&mut txn, &empty_root_hash, &empty_root).unwrap();
+                roots.push(empty_root_hash);
+
+                for (key, value) in TEST_PAIRS.iter() {
+                    let current_root = *roots.last().unwrap();
+                    match operations::write::<_, _, _, _, error::Error>(
+                        correlation_id,
+                        &mut txn,
+                        &store,
+                        &current_root,
+                        &key.to_vec(),
+                        &value.to_vec(),
+                    )
+                    .unwrap()
+                    {
+                        WriteResult::Written(root_hash) => roots.push(root_hash),
+                        _ => panic!("should write"),
+                    }
+                }
+                txn.commit().unwrap();
+            }
+
+            TestContext {
+                _temp_dir,
+                environment,
+                store,
+                roots,
+            }
+        }
+
+        fn stored_count(&self) -> usize {
+            let txn = self.environment.create_read_txn().unwrap();
+            let handle = <LmdbTrieStore as Store<Blake2bHash, Trie<TestKey, TestValue>>>::handle(
+                &self.store,
+            );
+            let mut cursor = lmdb::Transaction::open_ro_cursor(&txn, handle).unwrap();
+            cursor.iter_start().count()
+        }
+
+        fn has_root(&self, root: &Blake2bHash) -> bool {
+            let txn = self.environment.create_read_txn().unwrap();
+            let maybe_trie: Option<Trie<TestKey, TestValue>> = self.store.get(&txn, root).unwrap();
+            maybe_trie.is_some()
+        }
+
+        fn read(&self, root: &Blake2bHash, key: &[u8]) -> ReadResult<TestValue> {
+            let txn = self.environment.create_read_txn().unwrap();
+            operations::read::<_, _, _, _, error::Error>(
+                CorrelationId::new(),
+                &txn,
+                &self.store,
+                root,
+                &key.to_vec(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[test]
+    fn should_keep_everything_reachable_from_all_roots() {
+        let context = TestContext::new();
+        let stored_count = context.stored_count();
+
+        let result =
+            prune::<TestKey, TestValue>(&context.environment, &context.store, &context.roots)
+                .unwrap();
+
+        assert_eq!(
+            result,
+            PruneResult::Success {
+                retained: stored_count,
+                pruned: 0
+            }
+        );
+        assert_eq!(context.stored_count(), stored_count);
+    }
+
+    #[test]
+    fn should_prune_tries_unreachable_from_latest_root() {
+        let context = TestContext::new();
+        let stored_count = context.stored_count();
+        let latest_root = *context.roots.last().unwrap();
+
+        let result =
+            prune::<TestKey, TestValue>(&context.environment, &context.store, &[latest_root])
+                .unwrap();
+
+        let (retained, pruned) = match result {
+            PruneResult::Success { retained, pruned } => (retained, pruned),
+            other => panic!("unexpected result: {:?}", other),
+        };
+        assert!(pruned > 0);
+        assert_eq!(retained + pruned, stored_count);
+        assert_eq!(context.stored_count(), retained);
+
+        // older roots are gone
+        for root in &context.roots[..context.roots.len() - 1] {
+            assert!(!context.has_root(root));
+        }
+
+        // every value is still readable from the latest root
+        assert_eq!(
+            context.read(&latest_root, &[0, 0, 0]),
+            ReadResult::Found(b"value3".to_vec())
+        );
+        assert_eq!(
+            context.read(&latest_root, &[0, 0, 1]),
+            ReadResult::Found(b"value1".to_vec())
+        );
+        assert_eq!(
+            context.read(&latest_root, &[1, 0, 0]),
+            ReadResult::Found(b"value2".to_vec())
+        );
+    }
+
+    #[test]
+    fn should_not_prune_anything_if_root_is_missing() {
+        let context = TestContext::new();
+        let stored_count = context.stored_count();
+        let missing_root = Blake2bHash::new(b"missing");
+
+        let result = prune::<TestKey, TestValue>(
+            &context.environment,
+            &context.store,
+            &[*context.roots.last().unwrap(), missing_root],
+        )
+        .unwrap();
+
+        assert_eq!(result, PruneResult::TrieNotFound(missing_root));
+        assert_eq!(context.stored_count(), stored_count);
+    }
+
+    #[test]
+    fn should_prune_everything_without_retained_roots() {
+        let context = TestContext::new();
+        let stored_count = context.stored_count();
+
+        let result =
+            prune::<TestKey, TestValue>(&context.environment, &context.store, &[]).unwrap();
+
+        assert_eq!(
+            result,
+            PruneResult::Success {
+                retained: 0,
+                pruned: stored_count
+            }
+        );
+        assert_eq!(context.stored_count(), 0);
+    }
+}
@@ -5,9 +5,9 @@ use engine_core::{
     engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
     DeployHash,
 };
-use types::{account::PublicKey, bytesrepr::ToBytes, URef};
+use types::{account::PublicKey, URef};
 
-use crate::internal::utils;
+use crate::internal::{utils, DEFAULT_GAS_PRICE};
 
 #[derive(Default)]
 struct DeployItemData {
@@ -174,17 +174,14 @@ impl DeployItemBuilder {
     }
 
     fn serialize_args(args: impl ArgsParser) -> Vec<u8> {
-        args.parse()
-            .expect("should convert to `Vec<CLValue>`")
-            .into_bytes()
-            .expect("should serialize args")
+        args.serialize().expect("should serialize args")
     }
 }
 
 impl Default for DeployItemBuilder {
     fn default() -> Self {
         let mut deploy_item: DeployItemData = Default::default();
-        deploy_item.gas_price = 1;
+        deploy_item.gas_price = DEFAULT_GAS_PRICE;
         DeployItemBuilder { deploy_item }
     }
 }
@@ -6,20 +6,25 @@ use engine_core::{
         executable_deploy_item::ExecutableDeployItem, execution_effect::ExecutionEffect,
         EngineConfig, EngineState,
     },
-    execution::{self, AddressGenerator},
+    execution::{self, AddressGenerator, RandomGenerator},
     runtime::{self, Runtime},
     runtime_context::RuntimeContext,
 };
 use engine_grpc_server::engine_server::ipc_grpc::ExecutionEngineService;
 use engine_shared::{gas::Gas, newtypes::CorrelationId};
-use engine_storage::{global_state::StateProvider, protocol_data::ProtocolData};
+use engine_storage::{
+    global_state::StateProvider,
+    protocol_data::{
+        ProtocolData, DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_MAX_PAYMENT, DEFAULT_MIN_GAS_PRICE,
+    },
+};
 use engine_wasm_prep::Preprocessor;
 use types::{
-    account::PublicKey, bytesrepr::FromBytes, BlockTime, CLTyped, CLValue, Key, Phase,
+    account::PublicKey, bytesrepr::FromBytes, deserialize_args, BlockTime, CLTyped, Key, Phase,
     ProtocolVersion, URef, U512,
 };
 
-use crate::internal::{utils, WasmTestBuilder, DEFAULT_WASM_COSTS};
+use crate::internal::{utils, WasmTestBuilder, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_WASM_COSTS};
 
 const INIT_FN_STORE_ID: u32 = 0;
 
@@ -62,12 +67,20 @@ where
         let address_generator = AddressGenerator::new(&deploy_hash, phase);
         Rc::new(RefCell::new(address_generator))
     };
+    let random_generator = {
+        let random_generator =
+            RandomGenerator::new(&deploy_hash, phase, BlockTime::new(block_time));
+        Rc::new(RefCell::new(random_generator))
+    };
     let gas_counter = Gas::default();
     let fn_store_id = INIT_FN_STORE_ID;
     let gas_limit = Gas::new(U512::from(std::u64::MAX));
     let protocol_version = ProtocolVersion::V1_0_0;
     let correlation_id = CorrelationId::new();
-    let arguments: Vec<CLValue> = args.parse().expect("should be able to serialize args");
+    let (arguments, named_arguments) = {
+        let args_bytes = args.serialize().expect("should be able to serialize args");
+        deserialize_args(args_bytes).expect("should be able to deserialize args")
+    };
     let base_key = Key::Account(address);
 
     let account = builder.get_account(address).expect("should find account");
@@ -85,7 +98,16 @@ where
         let mint = builder.get_mint_contract_uref();
         let pos = builder.get_mint_contract_uref();
         let standard_payment = builder.get_standard_payment_contract_uref();
-        ProtocolData::new(*DEFAULT_WASM_COSTS, mint, pos, standard_payment)
+        ProtocolData::new(
+            *DEFAULT_WASM_COSTS,
+            *DEFAULT_HOST_FUNCTION_COSTS,
+            DEFAULT_MIN_GAS_PRICE,
+            DEFAULT_MAX_PAYMENT,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            mint,
+            pos,
+            standard_payment,
+        )
     };
 
     let context = RuntimeContext::new(
@@ -93,6 +115,7 @@ where
         &mut named_keys,
         access_rights,
         arguments,
+        named_arguments,
         BTreeSet::new(),
         &account,
         base_key,
@@ -102,6 +125,8 @@ where
         gas_counter,
         fn_store_id,
         address_generator,
+        random_generator,
+        Rc::new(RefCell::new(Vec::new())),
         protocol_version,
         correlation_id,
         phase,
@@ -129,12 +154,23 @@ where
         )
         .expect("should get wasm module");
 
-    let (instance, memory) = runtime::instance_and_memory(parity_module.clone(), protocol_version)
-        .expect("should be able to make wasm instance from module");
-
-    let mut runtime = Runtime::new(config, Default::default(), memory, parity_module, context);
+    let (instance, memory) = runtime::instance_and_memory(
+        parity_module.clone(),
+        protocol_version,
+        config.wasm_backend(),
+    )
+    .expect("should be able to make wasm instance from module");
+
+    let mut runtime = Runtime::new(
+        config,
+        Default::default(),
+        Default::default(),
+        memory,
+        parity_module,
+        context,
+    );
 
-    match instance.invoke_export("call", &[], &mut runtime) {
+    match instance.invoke_export("call", &mut runtime) {
         Ok(_) => None,
         Err(e) => {
             if let Some(host_error) = e.as_host_error() {
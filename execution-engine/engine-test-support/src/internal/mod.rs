@@ -10,7 +10,8 @@ use lazy_static::lazy_static;
 use num_traits::identities::Zero;
 
 use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
-use engine_shared::{motes::Motes, test_utils};
+use engine_shared::{host_function_costs::HostFunctionCosts, motes::Motes, test_utils};
+pub use engine_storage::protocol_data::{DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_MAX_PAYMENT};
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::{account::PublicKey, ProtocolVersion, U512};
 
@@ -31,6 +32,9 @@ pub const STANDARD_PAYMENT_CONTRACT: &str = "standard_payment.wasm";
 pub const DEFAULT_CHAIN_NAME: &str = "gerald";
 pub const DEFAULT_GENESIS_TIMESTAMP: u64 = 0;
 pub const DEFAULT_BLOCK_TIME: u64 = 0;
+pub const DEFAULT_BONDING_DELAY: u64 = 0;
+pub const DEFAULT_UNBONDING_DELAY: u64 = 0;
+pub const DEFAULT_GAS_PRICE: u64 = 10;
 pub const MOCKED_ACCOUNT_ADDRESS: PublicKey = PublicKey::ed25519_from([48u8; 32]);
 
 pub const DEFAULT_ACCOUNT_KEY: PublicKey = DEFAULT_ACCOUNT_ADDR;
@@ -49,6 +53,8 @@ lazy_static! {
     pub static ref DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
     pub static ref DEFAULT_PAYMENT: U512 = 100_000_000.into();
     pub static ref DEFAULT_WASM_COSTS: WasmCosts = test_utils::wasm_costs_mock();
+    pub static ref DEFAULT_HOST_FUNCTION_COSTS: HostFunctionCosts =
+        test_utils::host_function_costs_mock();
     pub static ref DEFAULT_GENESIS_CONFIG: GenesisConfig = {
         let mint_installer_bytes;
         let pos_installer_bytes;
@@ -73,6 +79,11 @@ lazy_static! {
             standard_payment_installer_bytes,
             DEFAULT_ACCOUNTS.clone(),
             *DEFAULT_WASM_COSTS,
+            *DEFAULT_HOST_FUNCTION_COSTS,
+            DEFAULT_MAX_PAYMENT,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            DEFAULT_BONDING_DELAY,
+            DEFAULT_UNBONDING_DELAY,
         )
     };
 }
@@ -1,10 +1,12 @@
 use engine_grpc_server::engine_server::{
     ipc::{
-        ChainSpec_ActivationPoint, ChainSpec_CostTable_WasmCosts, ChainSpec_UpgradePoint,
+        ChainSpec_ActivationPoint, ChainSpec_CostTable, ChainSpec_CostTable_HostFunctionCosts,
+        ChainSpec_CostTable_WasmCosts, ChainSpec_ProofOfStakeConfig, ChainSpec_UpgradePoint,
         DeployCode, UpgradeRequest,
     },
     state,
 };
+use engine_shared::host_function_costs::HostFunctionCosts;
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::ProtocolVersion;
 
@@ -14,6 +16,11 @@ pub struct UpgradeRequestBuilder {
     new_protocol_version: state::ProtocolVersion,
     upgrade_installer: DeployCode,
     new_costs: Option<ChainSpec_CostTable_WasmCosts>,
+    new_host_function_costs: Option<ChainSpec_CostTable_HostFunctionCosts>,
+    new_min_gas_price: u64,
+    new_max_payment: u64,
+    new_block_gas_limit: u64,
+    new_pos_config: Option<ChainSpec_ProofOfStakeConfig>,
     activation_point: ChainSpec_ActivationPoint,
 }
 
@@ -58,6 +65,34 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_host_function_costs(mut self, host_function_costs: HostFunctionCosts) -> Self {
+        self.new_host_function_costs = Some(host_function_costs.into());
+        self
+    }
+
+    pub fn with_new_min_gas_price(mut self, min_gas_price: u64) -> Self {
+        self.new_min_gas_price = min_gas_price;
+        self
+    }
+
+    pub fn with_new_max_payment(mut self, max_payment: u64) -> Self {
+        self.new_max_payment = max_payment;
+        self
+    }
+
+    pub fn with_new_block_gas_limit(mut self, block_gas_limit: u64) -> Self {
+        self.new_block_gas_limit = block_gas_limit;
+        self
+    }
+
+    pub fn with_new_pos_config(mut self, bonding_delay: u64, unbonding_delay: u64) -> Self {
+        let mut new_pos_config = ChainSpec_ProofOfStakeConfig::new();
+        new_pos_config.set_bonding_delay(bonding_delay);
+        new_pos_config.set_unbonding_delay(unbonding_delay);
+        self.new_pos_config = Some(new_pos_config);
+        self
+    }
+
     pub fn with_activation_point(mut self, rank: u64) -> Self {
         self.activation_point = {
             let mut ret = ChainSpec_ActivationPoint::new();
@@ -70,14 +105,21 @@ impl UpgradeRequestBuilder {
     pub fn build(self) -> UpgradeRequest {
         let mut upgrade_point = ChainSpec_UpgradePoint::new();
         upgrade_point.set_activation_point(self.activation_point);
-        match self.new_costs {
-            None => {}
-            Some(new_costs) => {
-                let mut cost_table =
-                    engine_grpc_server::engine_server::ipc::ChainSpec_CostTable::new();
+        if self.new_costs.is_some() || self.new_host_function_costs.is_some() {
+            let mut cost_table = ChainSpec_CostTable::new();
+            if let Some(new_costs) = self.new_costs {
                 cost_table.set_wasm(new_costs);
-                upgrade_point.set_new_costs(cost_table);
             }
+            if let Some(new_host_function_costs) = self.new_host_function_costs {
+                cost_table.set_host_function_costs(new_host_function_costs);
+            }
+            upgrade_point.set_new_costs(cost_table);
+        }
+        upgrade_point.set_new_min_gas_price(self.new_min_gas_price);
+        upgrade_point.set_new_max_payment(self.new_max_payment);
+        upgrade_point.set_new_block_gas_limit(self.new_block_gas_limit);
+        if let Some(new_pos_config) = self.new_pos_config {
+            upgrade_point.set_new_pos_config(new_pos_config);
         }
         upgrade_point.set_protocol_version(self.new_protocol_version);
         upgrade_point.set_upgrade_installer(self.upgrade_installer);
@@ -97,6 +139,11 @@ impl Default for UpgradeRequestBuilder {
             new_protocol_version: Default::default(),
             upgrade_installer: Default::default(),
             new_costs: None,
+            new_host_function_costs: None,
+            new_min_gas_price: 0,
+            new_max_payment: 0,
+            new_block_gas_limit: 0,
+            new_pos_config: None,
             activation_point: Default::default(),
         }
     }
@@ -7,6 +7,7 @@ use std::{
 use lazy_static::lazy_static;
 
 use engine_core::engine_state::{
+    execution_effect::Event,
     execution_result::ExecutionResult,
     genesis::{GenesisAccount, GenesisConfig},
 };
@@ -17,8 +18,10 @@ use engine_shared::{
 use types::Key;
 
 use crate::internal::{
-    DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_TIMESTAMP, DEFAULT_PROTOCOL_VERSION, DEFAULT_WASM_COSTS,
-    MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_BONDING_DELAY, DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_TIMESTAMP,
+    DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MAX_PAYMENT, DEFAULT_PROTOCOL_VERSION,
+    DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT,
+    STANDARD_PAYMENT_INSTALL_CONTRACT,
 };
 
 lazy_static! {
@@ -109,6 +112,7 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
     let standard_payment_installer_bytes = read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT);
     let protocol_version = *DEFAULT_PROTOCOL_VERSION;
     let wasm_costs = *DEFAULT_WASM_COSTS;
+    let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
     GenesisConfig::new(
         name,
         timestamp,
@@ -118,6 +122,11 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
         standard_payment_installer_bytes,
         accounts,
         wasm_costs,
+        host_function_costs,
+        DEFAULT_MAX_PAYMENT,
+        DEFAULT_BLOCK_GAS_LIMIT,
+        DEFAULT_BONDING_DELAY,
+        DEFAULT_UNBONDING_DELAY,
     )
 }
 
@@ -130,6 +139,15 @@ pub fn get_exec_costs<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
         .collect()
 }
 
+pub fn get_exec_events<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
+    exec_response: I,
+) -> Vec<Vec<Event>> {
+    exec_response
+        .into_iter()
+        .map(|res| res.as_ref().effect().events.clone())
+        .collect()
+}
+
 pub fn get_success_result(response: &[Rc<ExecutionResult>]) -> &ExecutionResult {
     &*response.get(0).expect("should have a result")
 }
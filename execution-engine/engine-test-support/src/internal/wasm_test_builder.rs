@@ -14,15 +14,18 @@ use log::LevelFilter;
 
 use engine_core::{
     engine_state::{
-        execute_request::ExecuteRequest, execution_result::ExecutionResult, genesis::GenesisConfig,
-        EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
+        deploy_item::DeployItem, execute_request::ExecuteRequest, execution_effect::Event,
+        execution_result::ExecutionResult, genesis::GenesisConfig, EngineConfig, EngineState,
+        SYSTEM_ACCOUNT_ADDR,
     },
     execution,
 };
 use engine_grpc_server::engine_server::{
     ipc::{
-        CommitRequest, CommitResponse, GenesisResponse, QueryRequest, UpgradeRequest,
-        UpgradeResponse,
+        BidStateRequest, BidStateResponse, CommitRequest, CommitResponse, CommitResult,
+        DistributeRewardsRequest, DistributeRewardsResponse, EstimateRequest, EstimateResponse,
+        GenesisResponse, QueryRequest, SlashRequest, SlashResponse, UnbondPayoutRequest,
+        UnbondPayoutResponse, UpgradeRequest, UpgradeResponse,
     },
     ipc_grpc::ExecutionEngineService,
     mappings::{MappingError, TransformMap},
@@ -43,6 +46,7 @@ use engine_storage::{
     global_state::{in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, StateProvider},
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     transaction_source::lmdb::LmdbEnvironment,
+    trie::merkle_proof::TrieMerkleProof,
     trie_store::lmdb::LmdbTrieStore,
 };
 use types::{
@@ -51,7 +55,7 @@ use types::{
     CLValue, Key, URef, U512,
 };
 
-use crate::internal::utils;
+use crate::internal::{utils, DEFAULT_PROTOCOL_VERSION};
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
 ///
@@ -99,28 +103,10 @@ impl<S> WasmTestBuilder<S> {
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
-        Self::initialize_logging();
         let engine_config = EngineConfig::new()
             .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
             .with_enable_bonding(cfg!(feature = "enable-bonding"));
-
-        let global_state = InMemoryGlobalState::empty().expect("should create global state");
-        let engine_state = EngineState::new(global_state, engine_config);
-
-        WasmTestBuilder {
-            engine_state: Rc::new(engine_state),
-            exec_responses: Vec::new(),
-            upgrade_responses: Vec::new(),
-            genesis_hash: None,
-            post_state_hash: None,
-            transforms: Vec::new(),
-            bonded_validators: Vec::new(),
-            genesis_account: None,
-            genesis_transforms: None,
-            mint_contract_uref: None,
-            pos_contract_uref: None,
-            standard_payment_uref: None,
-        }
+        Self::new_with_config(engine_config)
     }
 }
 
@@ -171,6 +157,27 @@ impl InMemoryWasmTestBuilder {
             ..Default::default()
         }
     }
+
+    pub fn new_with_config(engine_config: EngineConfig) -> Self {
+        Self::initialize_logging();
+        let global_state = InMemoryGlobalState::empty().expect("should create global state");
+        let engine_state = EngineState::new(global_state, engine_config);
+
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            exec_responses: Vec::new(),
+            upgrade_responses: Vec::new(),
+            genesis_hash: None,
+            post_state_hash: None,
+            transforms: Vec::new(),
+            bonded_validators: Vec::new(),
+            genesis_account: None,
+            genesis_transforms: None,
+            mint_contract_uref: None,
+            pos_contract_uref: None,
+            standard_payment_uref: None,
+        }
+    }
 }
 
 impl LmdbWasmTestBuilder {
@@ -383,6 +390,83 @@ where
         bytesrepr::deserialize(query_response.take_success()).map_err(|err| format!("{}", err))
     }
 
+    /// Queries the state like `query()`, and also returns the proofs of every key
+    /// visited by the query.
+    #[allow(clippy::type_complexity)]
+    pub fn query_with_proof(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        base_key: Key,
+        path: &[&str],
+    ) -> (
+        Result<StoredValue, String>,
+        Vec<TrieMerkleProof<Key, StoredValue>>,
+    ) {
+        let post_state = maybe_post_state
+            .or_else(|| self.post_state_hash.clone())
+            .expect("builder must have a post-state hash");
+
+        let path_vec: Vec<String> = path.iter().map(|s| String::from(*s)).collect();
+
+        let query_request = create_query_request(post_state, base_key, path_vec);
+
+        let mut query_response = self
+            .engine_state
+            .query_with_proof(RequestOptions::new(), query_request)
+            .wait_drop_metadata()
+            .expect("should get query with proof response");
+
+        let proofs = query_response
+            .take_proofs()
+            .into_iter()
+            .map(|proof| bytesrepr::deserialize(proof).expect("should deserialize proof"))
+            .collect();
+
+        let result = if query_response.has_failure() {
+            Err(query_response.take_failure())
+        } else {
+            bytesrepr::deserialize(query_response.take_success()).map_err(|err| format!("{}", err))
+        };
+
+        (result, proofs)
+    }
+
+    /// Requests the validator bids from the PoS contract at the given post-state hash, or at the
+    /// latest cached post-state hash if `None` is supplied.
+    pub fn get_bid_state(&self, maybe_post_state: Option<Vec<u8>>) -> BidStateResponse {
+        let post_state = maybe_post_state
+            .or_else(|| self.post_state_hash.clone())
+            .expect("builder must have a post-state hash");
+
+        let mut bid_state_request = BidStateRequest::new();
+        bid_state_request.set_parent_state_hash(post_state);
+        bid_state_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        self.engine_state
+            .bid_state(RequestOptions::new(), bid_state_request)
+            .wait_drop_metadata()
+            .expect("should get bid state response")
+    }
+
+    /// Requests a gas estimate for `deploy_item` at the latest cached post-state hash.  Nothing is
+    /// cached, as an estimate has no effects to commit.
+    pub fn estimate(&self, deploy_item: DeployItem) -> EstimateResponse {
+        let post_state = self
+            .post_state_hash
+            .clone()
+            .expect("builder must have a post-state hash");
+
+        let mut estimate_request = EstimateRequest::new();
+        estimate_request.set_parent_state_hash(post_state);
+        estimate_request.set_deploy(deploy_item.into());
+        estimate_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        self.engine_state
+            .estimate(RequestOptions::new(), estimate_request)
+            .wait_drop_metadata()
+            .expect("should get estimate response")
+    }
+
     pub fn exec(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
         let exec_request = {
             let hash = self
@@ -410,6 +494,53 @@ where
         self
     }
 
+    /// Runs the deploys of `exec_request` one after another on the latest post-state hash, and
+    /// caches their combined effect so that a following `commit` applies all of them.
+    pub fn exec_sequential(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
+        let exec_request = {
+            let hash = self
+                .post_state_hash
+                .clone()
+                .expect("expected post_state_hash");
+            exec_request.parent_state_hash =
+                hash.as_slice().try_into().expect("expected a valid hash");
+            exec_request
+        };
+        let (execution_results, combined_effect) = self
+            .engine_state
+            .run_execute_sequential(CorrelationId::new(), exec_request)
+            .expect("should execute deploys sequentially");
+        self.transforms.push(combined_effect.transforms);
+        self.exec_responses
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self
+    }
+
+    /// Like `exec_sequential()`, but runs the deploys concurrently, re-running any of them which
+    /// conflict with the deploys before them.
+    pub fn exec_parallel(&mut self, mut exec_request: ExecuteRequest) -> &mut Self
+    where
+        S: Sync,
+    {
+        let exec_request = {
+            let hash = self
+                .post_state_hash
+                .clone()
+                .expect("expected post_state_hash");
+            exec_request.parent_state_hash =
+                hash.as_slice().try_into().expect("expected a valid hash");
+            exec_request
+        };
+        let (execution_results, combined_effect) = self
+            .engine_state
+            .run_execute_parallel(CorrelationId::new(), exec_request)
+            .expect("should execute deploys in parallel");
+        self.transforms.push(combined_effect.transforms);
+        self.exec_responses
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
@@ -464,6 +595,133 @@ where
         self
     }
 
+    /// Sends a raw distribute rewards request on top of the given pre-state hash.
+    ///
+    /// Can be used where a failure response is expected.
+    pub fn distribute_rewards_response(
+        &self,
+        prestate_hash: Vec<u8>,
+        rewards: Vec<(PublicKey, U512)>,
+    ) -> DistributeRewardsResponse {
+        let mut distribute_rewards_request = DistributeRewardsRequest::new();
+        distribute_rewards_request.set_parent_state_hash(prestate_hash);
+        distribute_rewards_request.set_rewards(rewards.into_iter().map(Into::into).collect());
+        distribute_rewards_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        self.engine_state
+            .distribute_rewards(RequestOptions::new(), distribute_rewards_request)
+            .wait_drop_metadata()
+            .expect("should get distribute rewards response")
+    }
+
+    /// Distributes rewards on top of the latest post-state hash, expects a successful response,
+    /// and overwrites existing cached post state hash with a new one.
+    pub fn distribute_rewards(&mut self, rewards: Vec<(PublicKey, U512)>) -> &mut Self {
+        let prestate_hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+
+        let mut distribute_rewards_response =
+            self.distribute_rewards_response(prestate_hash, rewards);
+        if !distribute_rewards_response.has_success() {
+            panic!(
+                "Expected distribute rewards success but received a failure instead: {:?}",
+                distribute_rewards_response
+            );
+        }
+        self.record_commit_success(distribute_rewards_response.take_success())
+    }
+
+    /// Sends a raw slash request on top of the given pre-state hash.
+    ///
+    /// Can be used where a failure response is expected.
+    pub fn slash_response(
+        &self,
+        prestate_hash: Vec<u8>,
+        slashes: Vec<(PublicKey, U512)>,
+    ) -> SlashResponse {
+        let mut slash_request = SlashRequest::new();
+        slash_request.set_parent_state_hash(prestate_hash);
+        slash_request.set_slashes(slashes.into_iter().map(Into::into).collect());
+        slash_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        self.engine_state
+            .slash(RequestOptions::new(), slash_request)
+            .wait_drop_metadata()
+            .expect("should get slash response")
+    }
+
+    /// Slashes validators on top of the latest post-state hash, expects a successful response, and
+    /// overwrites existing cached post state hash with a new one.
+    pub fn slash(&mut self, slashes: Vec<(PublicKey, U512)>) -> &mut Self {
+        let prestate_hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+
+        let mut slash_response = self.slash_response(prestate_hash, slashes);
+        if !slash_response.has_success() {
+            panic!(
+                "Expected slash success but received a failure instead: {:?}",
+                slash_response
+            );
+        }
+        self.record_commit_success(slash_response.take_success())
+    }
+
+    /// Sends a raw unbond payout request for the given era height on top of the given pre-state
+    /// hash.
+    ///
+    /// Can be used where a failure response is expected.
+    pub fn unbond_payout_response(
+        &self,
+        prestate_hash: Vec<u8>,
+        era_height: u64,
+    ) -> UnbondPayoutResponse {
+        let mut unbond_payout_request = UnbondPayoutRequest::new();
+        unbond_payout_request.set_parent_state_hash(prestate_hash);
+        unbond_payout_request.set_era_height(era_height);
+        unbond_payout_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+
+        self.engine_state
+            .unbond_payout(RequestOptions::new(), unbond_payout_request)
+            .wait_drop_metadata()
+            .expect("should get unbond payout response")
+    }
+
+    /// Pays out due unbonds at the given era height on top of the latest post-state hash, expects a
+    /// successful response, and overwrites existing cached post state hash with a new one.
+    pub fn unbond_payout(&mut self, era_height: u64) -> &mut Self {
+        let prestate_hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+
+        let mut unbond_payout_response = self.unbond_payout_response(prestate_hash, era_height);
+        if !unbond_payout_response.has_success() {
+            panic!(
+                "Expected unbond payout success but received a failure instead: {:?}",
+                unbond_payout_response
+            );
+        }
+        self.record_commit_success(unbond_payout_response.take_success())
+    }
+
+    /// Caches the post state hash and bonded validators of a system operation which was committed
+    /// successfully.
+    fn record_commit_success(&mut self, mut commit_success: CommitResult) -> &mut Self {
+        self.post_state_hash = Some(commit_success.take_poststate_hash().to_vec());
+        let bonded_validators = commit_success
+            .take_bonded_validators()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
+            .unwrap();
+        self.bonded_validators.push(bonded_validators);
+        self
+    }
+
     pub fn upgrade_with_upgrade_request(
         &mut self,
         upgrade_request: &mut UpgradeRequest,
@@ -649,6 +907,14 @@ where
         utils::get_exec_costs(exec_response)
     }
 
+    /// Returns the events emitted by each deploy of the `index`th exec request.
+    pub fn exec_events(&self, index: usize) -> Vec<Vec<Event>> {
+        let exec_response = self
+            .get_exec_response(index)
+            .expect("should have exec response");
+        utils::get_exec_events(exec_response)
+    }
+
     pub fn exec_error_message(&self, index: usize) -> Option<String> {
         let response = self.get_exec_response(index)?;
         Some(utils::get_error_message(response))
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+
+use engine_core::{engine_state::EngineConfig, wasm_backend::WasmBackendKind};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_COUNTER_CALL: &str = "counter_call.wasm";
+const CONTRACT_COUNTER_DEFINE: &str = "counter_define.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const TARGET_ADDR: PublicKey = PublicKey::ed25519_from([127; 32]);
+
+fn bootstrap(wasm_backend: WasmBackendKind) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_wasm_backend(wasm_backend);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_COUNTER_DEFINE, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+/// Measures a session transfer, and a session which makes two calls into a stored contract, on
+/// `wasm_backend`.  Nothing is committed, so each iteration runs against the same state.
+fn exec_on_backend(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    wasm_backend: WasmBackendKind,
+) {
+    let mut builder = bootstrap(wasm_backend);
+
+    group.bench_function(format!("transfer_to_account/{}", name), |b| {
+        b.iter(|| {
+            let exec_request = ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_TRANSFER_TO_ACCOUNT,
+                (TARGET_ADDR, U512::one()),
+            )
+            .build();
+            builder.exec(exec_request).expect_success();
+        })
+    });
+
+    group.bench_function(format!("stored_contract_calls/{}", name), |b| {
+        b.iter(|| {
+            let exec_request =
+                ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_COUNTER_CALL, ())
+                    .build();
+            builder.exec(exec_request).expect_success();
+        })
+    });
+}
+
+pub fn wasm_backend_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wasm_backend");
+
+    // Same settings as the transfer benchmark, to keep the total time down.
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    exec_on_backend(&mut group, "wasmi", WasmBackendKind::Wasmi);
+    exec_on_backend(&mut group, "wasmtime", WasmBackendKind::Wasmtime);
+
+    group.finish();
+}
+
+criterion_group!(benches, wasm_backend_bench);
+criterion_main!(benches);
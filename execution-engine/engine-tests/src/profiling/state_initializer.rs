@@ -10,8 +10,10 @@ use engine_core::engine_state::{engine_config::EngineConfig, genesis::GenesisCon
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_ACCOUNTS,
-        DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_TIMESTAMP, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
-        DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_CONTRACT,
+        DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_BONDING_DELAY, DEFAULT_CHAIN_NAME,
+        DEFAULT_GENESIS_TIMESTAMP, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MAX_PAYMENT,
+        DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_COSTS,
+        MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_CONTRACT,
         STANDARD_PAYMENT_INSTALL_CONTRACT,
     },
     DEFAULT_ACCOUNT_ADDR,
@@ -77,6 +79,11 @@ fn main() {
         standard_payment_installer_bytes,
         DEFAULT_ACCOUNTS.clone(),
         *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        DEFAULT_MAX_PAYMENT,
+        DEFAULT_BLOCK_GAS_LIMIT,
+        DEFAULT_BONDING_DELAY,
+        DEFAULT_UNBONDING_DELAY,
     );
 
     let post_state_hash = builder
@@ -0,0 +1,86 @@
+use ed25519_dalek::ExpandedSecretKey;
+use secp256k1::{Message, Secp256k1};
+
+use engine_shared::newtypes::Blake2bHash;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, RuntimeArgs};
+
+const CONTRACT_CRYPTO: &str = "crypto.wasm";
+const ARG_MESSAGE: &str = "message";
+const ARG_DIGEST: &str = "digest";
+const ARG_ED25519_PUBLIC_KEY: &str = "ed25519_public_key";
+const ARG_ED25519_SIGNATURE: &str = "ed25519_signature";
+const ARG_SECP256K1_PUBLIC_KEY: &str = "secp256k1_public_key";
+const ARG_SECP256K1_SIGNATURE: &str = "secp256k1_signature";
+const ARG_EXPECT_VALID: &str = "expect_valid";
+const MESSAGE: &[u8] = b"hello world";
+const OTHER_MESSAGE: &[u8] = b"goodbye world";
+
+/// Returns the args for the crypto contract, where the message is always `MESSAGE` but both
+/// signatures are over `signed_message`.
+fn crypto_args(signed_message: &[u8], expect_valid: bool) -> RuntimeArgs {
+    let digest = Blake2bHash::new(MESSAGE).value();
+
+    let ed25519_secret_key = ed25519_dalek::SecretKey::from_bytes(&[1u8; 32]).unwrap();
+    let ed25519_public_key = ed25519_dalek::PublicKey::from(&ed25519_secret_key);
+    let ed25519_signature =
+        ExpandedSecretKey::from(&ed25519_secret_key).sign(signed_message, &ed25519_public_key);
+
+    let secp = Secp256k1::new();
+    let secp256k1_secret_key = secp256k1::SecretKey::from_slice(&[1u8; 32]).unwrap();
+    let secp256k1_public_key = secp256k1::PublicKey::from_secret_key(&secp, &secp256k1_secret_key);
+    let signed_digest = Blake2bHash::new(signed_message).value();
+    let secp256k1_signature = secp.sign(
+        &Message::from_slice(&signed_digest).unwrap(),
+        &secp256k1_secret_key,
+    );
+
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_MESSAGE, MESSAGE.to_vec()).unwrap();
+    args.insert(ARG_DIGEST, digest).unwrap();
+    args.insert(
+        ARG_ED25519_PUBLIC_KEY,
+        PublicKey::ed25519_from(ed25519_public_key.to_bytes()),
+    )
+    .unwrap();
+    args.insert(ARG_ED25519_SIGNATURE, ed25519_signature.to_bytes())
+        .unwrap();
+    args.insert(
+        ARG_SECP256K1_PUBLIC_KEY,
+        PublicKey::secp256k1_from(secp256k1_public_key.serialize()),
+    )
+    .unwrap();
+    args.insert(
+        ARG_SECP256K1_SIGNATURE,
+        secp256k1_signature.serialize_compact(),
+    )
+    .unwrap();
+    args.insert(ARG_EXPECT_VALID, expect_valid).unwrap();
+    args
+}
+
+fn run_crypto(args: RuntimeArgs) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_CRYPTO, args).build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_hash_and_verify_valid_signatures() {
+    run_crypto(crypto_args(MESSAGE, true));
+}
+
+#[ignore]
+#[test]
+fn should_not_verify_signatures_of_other_message() {
+    run_crypto(crypto_args(OTHER_MESSAGE, false));
+}
@@ -0,0 +1,53 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::CLValue;
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const VALUE: u64 = 42;
+
+#[ignore]
+#[test]
+fn should_return_emitted_events_in_order() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_EMIT_EVENT, (VALUE, false))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let events = builder.exec_events(0).pop().expect("should have a deploy");
+    let events: Vec<(&str, &CLValue)> = events
+        .iter()
+        .map(|event| (event.name(), event.value()))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            ("first", &CLValue::from_t(VALUE).unwrap()),
+            ("second", &CLValue::from_t(VALUE + 1).unwrap()),
+        ]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_drop_events_when_session_reverts() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_EMIT_EVENT, (VALUE, true))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    assert!(builder.is_error());
+    assert_eq!(builder.exec_events(0), vec![vec![]]);
+}
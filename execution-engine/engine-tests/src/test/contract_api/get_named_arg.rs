@@ -0,0 +1,102 @@
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, RuntimeArgs, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetNamedArgContractError {
+    MissingMessage = 0,
+    InvalidMessage,
+    InvalidAmount,
+}
+
+const CONTRACT_GET_NAMED_ARG: &str = "get_named_arg.wasm";
+const ARG_MESSAGE: &str = "message";
+const ARG_AMOUNT: &str = "amount";
+const MESSAGE_VALUE: &str = "Hello, world!";
+const AMOUNT_VALUE: u64 = 42;
+
+/// Calls get_named_arg contract and returns Ok(()) in case no error, or String which is the error
+/// message returned by the engine
+fn call_get_named_arg(args: RuntimeArgs) -> Result<(), String> {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_NAMED_ARG, args).build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    let error_message = utils::get_error_message(response);
+
+    Err(error_message)
+}
+
+fn expected_revert(error: GetNamedArgContractError) -> String {
+    format!("Revert({})", u32::from(ApiError::User(error as u16)))
+}
+
+#[ignore]
+#[test]
+fn should_use_passed_named_arguments() {
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_MESSAGE, String::from(MESSAGE_VALUE))
+        .expect("should insert arg");
+    args.insert(ARG_AMOUNT, U512::from(AMOUNT_VALUE))
+        .expect("should insert arg");
+
+    call_get_named_arg(args).expect("should successfully call get_named_arg with valid args");
+}
+
+#[ignore]
+#[test]
+fn should_allow_missing_optional_named_argument() {
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_MESSAGE, String::from(MESSAGE_VALUE))
+        .expect("should insert arg");
+
+    call_get_named_arg(args).expect("should successfully call get_named_arg without amount");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_named_argument() {
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_AMOUNT, U512::from(AMOUNT_VALUE))
+        .expect("should insert arg");
+
+    assert!(call_get_named_arg(args)
+        .expect_err("should fail")
+        .contains(&expected_revert(GetNamedArgContractError::MissingMessage)));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_named_argument() {
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_MESSAGE, U512::from(AMOUNT_VALUE))
+        .expect("should insert arg");
+    assert!(call_get_named_arg(args)
+        .expect_err("should fail")
+        .contains(&expected_revert(GetNamedArgContractError::InvalidMessage)));
+
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_MESSAGE, String::from(MESSAGE_VALUE))
+        .expect("should insert arg");
+    args.insert(ARG_AMOUNT, String::from("this is expected to be U512"))
+        .expect("should insert arg");
+    assert!(call_get_named_arg(args)
+        .expect_err("should fail")
+        .contains(&expected_revert(GetNamedArgContractError::InvalidAmount)));
+}
@@ -0,0 +1,127 @@
+use contract::args_parser::ArgsParser;
+use engine_core::engine_state::genesis::GenesisConfig;
+use engine_shared::{
+    gas::Gas,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    test_utils,
+};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_BONDING_DELAY, DEFAULT_CHAIN_NAME,
+        DEFAULT_GENESIS_TIMESTAMP, DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MAX_PAYMENT,
+        DEFAULT_PROTOCOL_VERSION, DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_COSTS,
+        MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, U512};
+
+const CONTRACT_GET_ARG: &str = "get_arg.wasm";
+const CONTRACT_GET_CALLER: &str = "get_caller.wasm";
+const ARG0_VALUE: &str = "Hello, world!";
+const ARG1_VALUE: u64 = 42;
+
+fn genesis_config_with_host_function_costs(
+    host_function_costs: HostFunctionCosts,
+) -> GenesisConfig {
+    GenesisConfig::new(
+        DEFAULT_CHAIN_NAME.to_string(),
+        DEFAULT_GENESIS_TIMESTAMP,
+        *DEFAULT_PROTOCOL_VERSION,
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_COSTS,
+        host_function_costs,
+        DEFAULT_MAX_PAYMENT,
+        DEFAULT_BLOCK_GAS_LIMIT,
+        DEFAULT_BONDING_DELAY,
+        DEFAULT_UNBONDING_DELAY,
+    )
+}
+
+fn session_cost(
+    host_function_costs: HostFunctionCosts,
+    session_file: &str,
+    session_args: impl ArgsParser,
+) -> Gas {
+    let genesis_config = genesis_config_with_host_function_costs(host_function_costs);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, session_file, session_args).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    builder.exec_costs(0)[0]
+}
+
+fn get_caller_cost(host_function_costs: HostFunctionCosts) -> Gas {
+    session_cost(
+        host_function_costs,
+        CONTRACT_GET_CALLER,
+        (DEFAULT_ACCOUNT_ADDR,),
+    )
+}
+
+fn get_arg_cost(host_function_costs: HostFunctionCosts) -> Gas {
+    session_cost(
+        host_function_costs,
+        CONTRACT_GET_ARG,
+        (String::from(ARG0_VALUE), U512::from(ARG1_VALUE)),
+    )
+}
+
+#[ignore]
+#[test]
+fn should_charge_fixed_cost_of_host_function() {
+    const FIXED_COST: u32 = 1_000;
+
+    let base_cost = get_caller_cost(*DEFAULT_HOST_FUNCTION_COSTS);
+    let expensive_cost = get_caller_cost(HostFunctionCosts {
+        get_caller: HostFunctionCost::new(
+            DEFAULT_HOST_FUNCTION_COSTS.get_caller.fixed + FIXED_COST,
+            DEFAULT_HOST_FUNCTION_COSTS.get_caller.per_byte,
+        ),
+        ..*DEFAULT_HOST_FUNCTION_COSTS
+    });
+
+    // the session code calls `get_caller` at least once
+    assert!(expensive_cost >= base_cost + Gas::new(FIXED_COST.into()));
+}
+
+#[ignore]
+#[test]
+fn should_charge_per_byte_cost_of_host_function() {
+    const PER_BYTE_COST: u32 = 1_000;
+
+    let base_cost = get_arg_cost(*DEFAULT_HOST_FUNCTION_COSTS);
+    let expensive_cost = get_arg_cost(HostFunctionCosts {
+        get_arg: HostFunctionCost::new(
+            DEFAULT_HOST_FUNCTION_COSTS.get_arg.fixed,
+            DEFAULT_HOST_FUNCTION_COSTS.get_arg.per_byte + PER_BYTE_COST,
+        ),
+        ..*DEFAULT_HOST_FUNCTION_COSTS
+    });
+
+    // the session code reads both of its args in full with `get_arg`
+    let args_size =
+        String::from(ARG0_VALUE).serialized_length() + U512::from(ARG1_VALUE).serialized_length();
+    let per_byte_cost = Gas::new(U512::from(PER_BYTE_COST) * U512::from(args_size));
+    assert!(expensive_cost >= base_cost + per_byte_cost);
+}
+
+#[ignore]
+#[test]
+fn should_charge_nothing_for_free_host_functions() {
+    let free_cost = get_caller_cost(test_utils::host_function_costs_free());
+    let base_cost = get_caller_cost(*DEFAULT_HOST_FUNCTION_COSTS);
+
+    assert!(free_cost < base_cost);
+}
@@ -1,13 +1,19 @@
 mod account;
 mod create_purse;
+mod crypto;
+mod emit_event;
 mod get_arg;
 mod get_blocktime;
 mod get_caller;
+mod get_named_arg;
 mod get_phase;
+mod host_function_costs;
 mod list_named_keys;
 mod local_state;
 mod main_purse;
 mod mint_purse;
+mod named_entry_points;
+mod random_bytes;
 mod revert;
 mod subcall;
 mod transfer;
@@ -15,3 +21,4 @@ mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod try_call_contract;
@@ -0,0 +1,87 @@
+use engine_core::execution;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::CLType;
+
+const CONTRACT_NAMED_ENTRY_POINTS: &str = "named_entry_points.wasm";
+
+#[ignore]
+#[test]
+fn should_call_each_named_entry_point() {
+    for entry_point in &["add_one", "double"] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_NAMED_ENTRY_POINTS,
+            (entry_point.to_string(), false),
+        )
+        .build();
+
+        InMemoryWasmTestBuilder::default()
+            .run_genesis(&DEFAULT_GENESIS_CONFIG)
+            .exec(exec_request)
+            .expect_success()
+            .commit();
+    }
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_unknown_entry_point() {
+    for entry_point in &["missing", "call"] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_NAMED_ENTRY_POINTS,
+            (entry_point.to_string(), false),
+        )
+        .build();
+
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&DEFAULT_GENESIS_CONFIG)
+            .exec(exec_request)
+            .commit();
+
+        let error_message = builder
+            .exec_error_message(0)
+            .expect("should have exec error");
+        let expected_error = execution::Error::NoSuchEntryPoint(entry_point.to_string());
+        assert!(
+            error_message.contains(&format!("{:?}", expected_error)),
+            "unexpected error: {}",
+            error_message
+        );
+    }
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_entry_point_with_invalid_args() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_NAMED_ENTRY_POINTS,
+        ("add_one".to_string(), true),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    let error_message = builder
+        .exec_error_message(0)
+        .expect("should have exec error");
+    let expected_error = execution::Error::InvalidArguments {
+        entry_point: "add_one".to_string(),
+        expected: vec![CLType::U64],
+        actual: vec![CLType::U32],
+    };
+    assert!(
+        error_message.contains(&format!("{:?}", expected_error)),
+        "unexpected error: {}",
+        error_message
+    );
+}
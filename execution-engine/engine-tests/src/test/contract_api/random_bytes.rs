@@ -0,0 +1,104 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const CONTRACT_RANDOM_BYTES: &str = "random_bytes.wasm";
+const CONTRACT_RANDOM_BYTES_OUT_OF_BOUNDS: &str = "random_bytes_out_of_bounds.wasm";
+const RANDOM_BYTES_1_KEY: &str = "random_bytes_1";
+const RANDOM_BYTES_2_KEY: &str = "random_bytes_2";
+const DEPLOY_HASH_1: [u8; 32] = [1; 32];
+const DEPLOY_HASH_2: [u8; 32] = [2; 32];
+const BLOCK_TIME_1: u64 = 10;
+const BLOCK_TIME_2: u64 = 20;
+
+/// Runs the random-bytes contract in a fresh builder and returns the two byte arrays it stored.
+fn run_random_bytes(deploy_hash: [u8; 32], block_time: u64) -> ([u8; 32], [u8; 32]) {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_RANDOM_BYTES, ())
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash(deploy_hash)
+        .build();
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(deploy)
+        .with_block_time(block_time)
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let query_random_bytes = |name: &str| -> [u8; 32] {
+        builder
+            .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[name])
+            .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+            .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+            .expect("should parse value into a [u8; 32]")
+    };
+
+    (
+        query_random_bytes(RANDOM_BYTES_1_KEY),
+        query_random_bytes(RANDOM_BYTES_2_KEY),
+    )
+}
+
+#[ignore]
+#[test]
+fn should_return_same_random_bytes_for_same_deploy_and_block_time() {
+    assert_eq!(
+        run_random_bytes(DEPLOY_HASH_1, BLOCK_TIME_1),
+        run_random_bytes(DEPLOY_HASH_1, BLOCK_TIME_1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_for_different_deploy_hash() {
+    assert_ne!(
+        run_random_bytes(DEPLOY_HASH_1, BLOCK_TIME_1),
+        run_random_bytes(DEPLOY_HASH_2, BLOCK_TIME_1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_for_different_block_time() {
+    assert_ne!(
+        run_random_bytes(DEPLOY_HASH_1, BLOCK_TIME_1),
+        run_random_bytes(DEPLOY_HASH_1, BLOCK_TIME_2)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_when_random_bytes_destination_is_out_of_bounds() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RANDOM_BYTES_OUT_OF_BOUNDS,
+        (),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    assert!(builder.is_error(), "should fail out of bounds");
+    let error_message = builder
+        .exec_error_message(0)
+        .expect("should have exec error");
+    assert!(error_message.contains("trying to access region"));
+}
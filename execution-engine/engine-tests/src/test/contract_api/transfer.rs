@@ -1,11 +1,10 @@
 use lazy_static::lazy_static;
 
-use engine_core::engine_state::CONV_RATE;
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GAS_PRICE,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
@@ -67,8 +66,8 @@ fn should_transfer_to_account() {
 
     let genesis_balance = builder.get_purse_balance(default_account_purse);
 
-    let gas_cost =
-        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+    let gas_cost = Motes::from_gas(builder.exec_costs(0)[0], DEFAULT_GAS_PRICE)
+        .expect("should convert gas to motes");
 
     assert_eq!(
         genesis_balance,
@@ -122,7 +121,7 @@ fn should_transfer_from_account_to_account() {
 
     let genesis_balance = builder.get_purse_balance(default_account_purse);
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_1_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_1_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     assert_eq!(
@@ -164,7 +163,7 @@ fn should_transfer_from_account_to_account() {
 
     let account_1_balance = builder.get_purse_balance(account_1_purse);
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_2_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_2_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     assert_eq!(
@@ -223,8 +222,8 @@ fn should_transfer_to_existing_account() {
 
     let genesis_balance = builder.get_purse_balance(default_account_purse);
 
-    let gas_cost =
-        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+    let gas_cost = Motes::from_gas(builder.exec_costs(0)[0], DEFAULT_GAS_PRICE)
+        .expect("should convert gas to motes");
 
     assert_eq!(
         genesis_balance,
@@ -257,8 +256,8 @@ fn should_transfer_to_existing_account() {
 
     let account_1_balance = builder.get_purse_balance(account_1_purse);
 
-    let gas_cost =
-        Motes::from_gas(builder.exec_costs(1)[0], CONV_RATE).expect("should convert gas to motes");
+    let gas_cost = Motes::from_gas(builder.exec_costs(1)[0], DEFAULT_GAS_PRICE)
+        .expect("should convert gas to motes");
 
     assert_eq!(
         account_1_balance,
@@ -1,9 +1,8 @@
-use engine_core::engine_state::CONV_RATE;
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
-        DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_ACCOUNT_KEY, DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
@@ -49,7 +48,7 @@ fn should_transfer_to_account_stored() {
         .clone();
     let mut result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_alpha = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let modified_balance_alpha: U512 = builder.get_purse_balance(default_account.main_purse());
 
@@ -85,7 +84,7 @@ fn should_transfer_to_account_stored() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_bravo = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let tally =
         motes_alpha.value() + motes_bravo.value() + transferred_amount + modified_balance_bravo;
@@ -0,0 +1,60 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use std::convert::TryFrom;
+
+use types::{CLValue, Key};
+
+const CONTRACT_TRY_CALL_CONTRACT: &str = "try_call_contract.wasm";
+const VALUE_KEY: &str = "value";
+
+fn run_try_call_contract(should_revert: bool) -> (u64, Vec<String>) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRY_CALL_CONTRACT,
+        (should_revert,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let value: u64 = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[VALUE_KEY])
+        .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+        .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+        .expect("should parse value into a u64");
+
+    let events = builder
+        .exec_events(0)
+        .pop()
+        .expect("should have a deploy")
+        .into_iter()
+        .map(|event| event.name().to_string())
+        .collect();
+
+    (value, events)
+}
+
+#[ignore]
+#[test]
+fn should_keep_effects_of_successful_try_call_contract() {
+    let (value, events) = run_try_call_contract(false);
+
+    assert_eq!(value, 1);
+    assert_eq!(events, vec!["callee".to_string(), "caller".to_string()]);
+}
+
+#[ignore]
+#[test]
+fn should_discard_effects_of_reverted_try_call_contract() {
+    let (value, events) = run_try_call_contract(true);
+
+    assert_eq!(value, 0, "write from reverted sub-call should be discarded");
+    assert_eq!(events, vec!["caller".to_string()]);
+}
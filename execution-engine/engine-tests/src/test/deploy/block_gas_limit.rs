@@ -0,0 +1,128 @@
+use engine_core::engine_state::upgrade::ActivationPoint;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+// Low enough to be used up by the first deploy of a request
+const NEW_BLOCK_GAS_LIMIT: u64 = 1;
+
+fn upgrade_block_gas_limit(builder: &mut InMemoryWasmTestBuilder) -> ProtocolVersion {
+    let sem_ver = ProtocolVersion::V1_0_0.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(ProtocolVersion::V1_0_0)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_block_gas_limit(NEW_BLOCK_GAS_LIMIT)
+        .build();
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    new_protocol_version
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_once_block_gas_limit_reached() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let new_protocol_version = upgrade_block_gas_limit(&mut builder);
+
+    let exec_request = {
+        let mut exec_request_builder =
+            ExecuteRequestBuilder::new().with_protocol_version(new_protocol_version);
+        for deploy_hash in &[[1; 32], [2; 32], [3; 32]] {
+            let deploy = DeployItemBuilder::new()
+                .with_address(DEFAULT_ACCOUNT_ADDR)
+                .with_session_code(DO_NOTHING_WASM, ())
+                .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+                .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+                .with_deploy_hash(*deploy_hash)
+                .build();
+            exec_request_builder = exec_request_builder.push_deploy(deploy);
+        }
+        exec_request_builder.build()
+    };
+
+    builder.exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert_eq!(response.len(), 3);
+    assert!(response[0].is_success());
+
+    let expected_failure = format!("Block gas limit reached: {}", NEW_BLOCK_GAS_LIMIT);
+    assert_eq!(
+        utils::get_precondition_failure(&response[1..2]),
+        expected_failure
+    );
+    assert_eq!(
+        utils::get_precondition_failure(&response[2..]),
+        expected_failure
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_run_later_deploys_of_sequence_once_block_gas_limit_reached() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let new_protocol_version = upgrade_block_gas_limit(&mut builder);
+
+    let exec_request = {
+        let create_account = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(
+                TRANSFER_PURSE_TO_ACCOUNT_WASM,
+                (ACCOUNT_1_ADDR, U512::from(100_000_000)),
+            )
+            .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+            .with_deploy_hash([1; 32])
+            .build();
+        let do_nothing = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, ())
+            .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+            .with_deploy_hash([2; 32])
+            .build();
+
+        ExecuteRequestBuilder::new()
+            .with_protocol_version(new_protocol_version)
+            .push_deploy(create_account)
+            .push_deploy(do_nothing)
+            .build()
+    };
+
+    builder.exec_sequential(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert!(response[0].is_success());
+    assert_eq!(
+        utils::get_precondition_failure(&response[1..]),
+        format!("Block gas limit reached: {}", NEW_BLOCK_GAS_LIMIT)
+    );
+}
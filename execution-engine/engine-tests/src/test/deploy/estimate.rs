@@ -0,0 +1,131 @@
+use std::convert::TryInto;
+
+use engine_core::engine_state::deploy_item::DeployItem;
+use engine_grpc_server::engine_server::ipc::EstimateResult;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const TRANSFER_AMOUNT: u64 = 100_000_000;
+
+fn do_nothing_deploy(authorization_key: PublicKey) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(DO_NOTHING_WASM, ())
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[authorization_key])
+        .with_deploy_hash([1; 32])
+        .build()
+}
+
+fn estimate_values(estimate_result: &EstimateResult) -> (U512, U512, U512, U512) {
+    let payment_cost = estimate_result
+        .get_payment_cost()
+        .clone()
+        .try_into()
+        .expect("should convert payment cost");
+    let session_cost = estimate_result
+        .get_session_cost()
+        .clone()
+        .try_into()
+        .expect("should convert session cost");
+    let finalize_cost = estimate_result
+        .get_finalize_cost()
+        .clone()
+        .try_into()
+        .expect("should convert finalize cost");
+    let motes = estimate_result
+        .get_motes()
+        .clone()
+        .try_into()
+        .expect("should convert motes");
+    (payment_cost, session_cost, finalize_cost, motes)
+}
+
+#[ignore]
+#[test]
+fn should_estimate_session_cost_deterministically() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let mut estimate_response = builder.estimate(do_nothing_deploy(DEFAULT_ACCOUNT_KEY));
+    assert!(estimate_response.has_success(), "expected success");
+    let estimate_result = estimate_response.take_success();
+
+    let (payment_cost, session_cost, finalize_cost, motes) = estimate_values(&estimate_result);
+    assert!(!payment_cost.is_zero(), "payment should use gas");
+    assert!(!session_cost.is_zero(), "session should use gas");
+    assert!(!finalize_cost.is_zero(), "finalization should use gas");
+    assert_eq!(
+        motes,
+        (payment_cost + session_cost) * U512::from(DEFAULT_GAS_PRICE)
+    );
+
+    let mut second_response = builder.estimate(do_nothing_deploy(DEFAULT_ACCOUNT_KEY));
+    assert_eq!(second_response.take_success(), estimate_result);
+
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(do_nothing_deploy(DEFAULT_ACCOUNT_KEY))
+        .build();
+    builder.exec(exec_request).expect_success();
+
+    // The executed deploy is charged for exactly the estimated payment and session
+    let exec_cost = builder.exec_costs(0)[0];
+    assert_eq!(exec_cost.value(), payment_cost + session_cost);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_funds_when_estimating() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let initial_balance = builder.get_purse_balance(default_account.main_purse());
+
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+        )
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([1; 32])
+        .build();
+
+    let estimate_response = builder.estimate(deploy);
+    assert!(estimate_response.has_success(), "expected success");
+
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+    assert_eq!(
+        builder.get_purse_balance(default_account.main_purse()),
+        initial_balance
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_failure_when_estimating_unauthorized_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let mut estimate_response = builder.estimate(do_nothing_deploy(ACCOUNT_1_ADDR));
+    assert!(estimate_response.has_failure(), "expected failure");
+
+    let deploy_result = estimate_response.take_failure();
+    assert_eq!(
+        deploy_result.get_precondition_failure().get_message(),
+        "Authorization failure: not authorized."
+    );
+}
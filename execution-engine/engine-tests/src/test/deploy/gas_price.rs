@@ -0,0 +1,146 @@
+use engine_core::engine_state::{execute_request::ExecuteRequest, upgrade::ActivationPoint};
+use engine_shared::motes::Motes;
+use engine_storage::protocol_data::DEFAULT_MIN_GAS_PRICE;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{ProtocolVersion, U512};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+fn do_nothing_deploy_request(gas_price: u64, protocol_version: ProtocolVersion) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(DO_NOTHING_WASM, ())
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_gas_price(gas_price)
+        .with_deploy_hash([1; 32])
+        .build();
+
+    ExecuteRequestBuilder::new()
+        .push_deploy(deploy)
+        .with_protocol_version(protocol_version)
+        .build()
+}
+
+#[ignore]
+#[test]
+fn should_charge_gas_cost_times_gas_price() {
+    for gas_price in &[DEFAULT_MIN_GAS_PRICE, 25, 100] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+
+        builder
+            .run_genesis(&DEFAULT_GENESIS_CONFIG)
+            .exec(do_nothing_deploy_request(
+                *gas_price,
+                ProtocolVersion::V1_0_0,
+            ))
+            .expect_success()
+            .commit();
+
+        let default_account = builder
+            .get_account(DEFAULT_ACCOUNT_ADDR)
+            .expect("should get genesis account");
+        let modified_balance: U512 = builder.get_purse_balance(default_account.main_purse());
+        let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
+
+        let gas = builder.exec_costs(0)[0];
+        let motes = Motes::from_gas(gas, *gas_price).expect("should have motes");
+
+        assert_eq!(
+            initial_balance - modified_balance,
+            motes.value(),
+            "deployer should be charged gas cost times gas price"
+        );
+    }
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_when_gas_price_below_default_minimum() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(do_nothing_deploy_request(
+            DEFAULT_MIN_GAS_PRICE - 1,
+            ProtocolVersion::V1_0_0,
+        ));
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+
+    assert_eq!(
+        precondition_failure,
+        format!(
+            "Gas price too low: minimum {}, actual {}",
+            DEFAULT_MIN_GAS_PRICE,
+            DEFAULT_MIN_GAS_PRICE - 1
+        )
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_when_gas_price_below_minimum() {
+    const NEW_MIN_GAS_PRICE: u64 = 5;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = ProtocolVersion::V1_0_0.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(ProtocolVersion::V1_0_0)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_min_gas_price(NEW_MIN_GAS_PRICE)
+        .build();
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    builder.exec(do_nothing_deploy_request(
+        NEW_MIN_GAS_PRICE - 1,
+        new_protocol_version,
+    ));
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+
+    assert_eq!(
+        precondition_failure,
+        format!(
+            "Gas price too low: minimum {}, actual {}",
+            NEW_MIN_GAS_PRICE,
+            NEW_MIN_GAS_PRICE - 1
+        )
+    );
+
+    builder
+        .exec(do_nothing_deploy_request(
+            NEW_MIN_GAS_PRICE,
+            new_protocol_version,
+        ))
+        .expect_success();
+}
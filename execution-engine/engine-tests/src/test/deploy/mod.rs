@@ -1,3 +1,11 @@
+mod block_gas_limit;
+mod estimate;
+mod gas_price;
 mod non_standard_payment;
+mod parallel_execution;
 mod preconditions;
+mod sequential_execution;
+mod stored_contract_args;
 mod stored_contracts;
+#[cfg(feature = "wasmtime-backend")]
+mod wasm_backends;
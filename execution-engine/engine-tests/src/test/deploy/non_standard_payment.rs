@@ -1,11 +1,10 @@
 use std::convert::TryFrom;
 
-use engine_core::engine_state::CONV_RATE;
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
-        DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_ACCOUNT_KEY, DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -138,7 +137,7 @@ fn should_charge_non_main_purse() {
 
     let result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let expected_resting_balance = account_1_purse_funding_amount - motes.value();
 
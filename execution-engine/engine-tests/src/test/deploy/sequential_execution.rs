@@ -0,0 +1,124 @@
+use engine_core::engine_state::execute_request::ExecuteRequest;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_2_AMOUNT: u64 = 1;
+
+/// Builds a request where the default account creates account 1, and account 1 then creates
+/// account 2, i.e. the second deploy depends on the first one.
+fn dependent_deploys_request() -> ExecuteRequest {
+    let account_1_amount = *DEFAULT_PAYMENT * 10;
+
+    let deploy_1 = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            (ACCOUNT_1_ADDR, account_1_amount),
+        )
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1; 32])
+        .build();
+
+    let deploy_2 = DeployItemBuilder::new()
+        .with_address(ACCOUNT_1_ADDR)
+        .with_session_code(
+            TRANSFER_PURSE_TO_ACCOUNT_WASM,
+            (ACCOUNT_2_ADDR, U512::from(ACCOUNT_2_AMOUNT)),
+        )
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[ACCOUNT_1_ADDR])
+        .with_deploy_hash([2; 32])
+        .build();
+
+    ExecuteRequestBuilder::new()
+        .push_deploy(deploy_1)
+        .push_deploy(deploy_2)
+        .build()
+}
+
+#[ignore]
+#[test]
+fn should_run_dependent_deploys_sequentially() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_sequential(dependent_deploys_request());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert_eq!(response.len(), 2);
+    for result in response.iter() {
+        assert!(result.is_success(), "{:?}", result);
+    }
+
+    // committing the combined effect applies both deploys
+    builder.commit();
+
+    builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+    let account_2 = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should have account 2");
+    assert_eq!(
+        builder.get_purse_balance(account_2.main_purse()),
+        U512::from(ACCOUNT_2_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_see_earlier_deploys_when_not_sequential() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(dependent_deploys_request());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert!(response[0].is_success());
+
+    // account 1 doesn't exist at the parent state hash
+    let precondition_failure = utils::get_precondition_failure(&response[1..]);
+    assert_eq!(
+        precondition_failure,
+        "Authorization failure: not authorized."
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_each_deploy_for_unknown_protocol_version() {
+    let unknown_protocol_version = ProtocolVersion::from_parts(255, 0, 0);
+    let mut exec_request = dependent_deploys_request();
+    exec_request.protocol_version = unknown_protocol_version;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_sequential(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert_eq!(response.len(), 2);
+    for result in response.iter() {
+        assert!(result.has_precondition_failure(), "{:?}", result);
+        assert_eq!(
+            result.error().expect("should have an error").to_string(),
+            format!("Invalid protocol version: {}", unknown_protocol_version)
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use contract::args_parser::ArgsParser;
+use engine_core::{engine_state::execute_request::ExecuteRequest, execution};
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLType, EntryPoint, Key, Parameter, RuntimeArgs};
+
+const CONTRACT_TYPED_CALL: &str = "typed_call.wasm";
+const TYPED_CALL_KEY: &str = "typed_call";
+const ARG_VALUE: &str = "value";
+const ARG_MEMO: &str = "memo";
+
+fn install_typed_call() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_TYPED_CALL, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn call_typed_call_request(args: impl ArgsParser) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_stored_session_named_key(TYPED_CALL_KEY, args)
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .with_deploy_hash([2; 32])
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+#[ignore]
+#[test]
+fn should_query_entry_points_of_stored_contract() {
+    let builder = install_typed_call();
+
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[TYPED_CALL_KEY])
+        .expect("should query stored contract");
+    let contract = stored_value.as_contract().expect("should be a contract");
+
+    assert_eq!(
+        contract.entry_points(),
+        &[EntryPoint::new(
+            "call",
+            vec![Parameter::new("value", CLType::U64)],
+            CLType::Unit,
+        )]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_run_stored_contract_with_valid_args() {
+    let mut builder = install_typed_call();
+
+    builder
+        .exec(call_typed_call_request((42u64,)))
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_reject_stored_contract_deploy_with_invalid_args() {
+    let mut builder = install_typed_call();
+
+    builder
+        .exec(call_typed_call_request((String::from("42"),)))
+        .commit();
+
+    let error_message = builder
+        .exec_error_message(1)
+        .expect("should have exec error");
+    let expected_error = execution::Error::InvalidArguments {
+        entry_point: "call".to_string(),
+        expected: vec![CLType::U64],
+        actual: vec![CLType::String],
+    };
+    assert!(
+        error_message.contains(&format!("{:?}", expected_error)),
+        "unexpected error: {}",
+        error_message
+    );
+}
+
+#[ignore]
+#[test]
+fn should_run_stored_contract_with_valid_named_args() {
+    let mut builder = install_typed_call();
+
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_VALUE, 42u64).expect("should insert arg");
+
+    builder
+        .exec(call_typed_call_request(args))
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_run_stored_contract_with_undeclared_named_args() {
+    let mut builder = install_typed_call();
+
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_VALUE, 42u64).expect("should insert arg");
+    args.insert(ARG_MEMO, String::from("hello"))
+        .expect("should insert arg");
+
+    builder
+        .exec(call_typed_call_request(args))
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_reject_stored_contract_deploy_with_misnamed_args() {
+    let mut builder = install_typed_call();
+
+    let mut args = RuntimeArgs::new();
+    args.insert("amount", 42u64).expect("should insert arg");
+
+    builder.exec(call_typed_call_request(args)).commit();
+
+    let error_message = builder
+        .exec_error_message(1)
+        .expect("should have exec error");
+    let expected_error = execution::Error::InvalidArguments {
+        entry_point: "call".to_string(),
+        expected: vec![CLType::U64],
+        actual: vec![CLType::U64],
+    };
+    assert!(
+        error_message.contains(&format!("{:?}", expected_error)),
+        "unexpected error: {}",
+        error_message
+    );
+}
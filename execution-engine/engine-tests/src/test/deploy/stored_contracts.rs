@@ -1,12 +1,12 @@
 use std::collections::BTreeMap;
 
-use engine_core::engine_state::{upgrade::ActivationPoint, CONV_RATE};
+use engine_core::engine_state::upgrade::ActivationPoint;
 use engine_grpc_server::engine_server::ipc::DeployCode;
 use engine_shared::{motes::Motes, stored_value::StoredValue, transform::Transform};
 use engine_test_support::{
     internal::{
         utils, AdditiveMapDiff, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
-        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG,
+        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
@@ -99,7 +99,7 @@ fn should_exec_non_stored_code() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
     let tally = motes.value() + U512::from(transferred_amount) + modified_balance;
 
     assert_eq!(
@@ -142,7 +142,7 @@ fn should_exec_stored_code_by_hash() {
         .clone();
     let mut result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_alpha = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let modified_balance_alpha: U512 = builder.get_purse_balance(default_account.main_purse());
 
@@ -181,7 +181,7 @@ fn should_exec_stored_code_by_hash() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_bravo = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let tally = motes_alpha.value()
         + motes_bravo.value()
@@ -230,7 +230,7 @@ fn should_exec_stored_code_by_named_hash() {
 
     let mut result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_alpha = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let default_account = builder
         .get_account(DEFAULT_ACCOUNT_ADDR)
@@ -276,7 +276,7 @@ fn should_exec_stored_code_by_named_hash() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_bravo = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let tally = motes_alpha.value()
         + motes_bravo.value()
@@ -336,7 +336,7 @@ fn should_exec_stored_code_by_named_uref() {
 
     let mut result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_alpha = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let default_account = builder
         .get_account(DEFAULT_ACCOUNT_ADDR)
@@ -379,7 +379,7 @@ fn should_exec_stored_code_by_named_uref() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_bravo = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let tally = motes_alpha.value()
         + motes_bravo.value()
@@ -428,7 +428,7 @@ fn should_exec_payment_and_session_stored_code() {
 
     let mut result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_alpha = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_alpha = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     // next store transfer contract
     let exec_request_store_transfer = {
@@ -459,7 +459,7 @@ fn should_exec_payment_and_session_stored_code() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_bravo = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_bravo = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let account_1_public_key = ACCOUNT_1_ADDR;
     let transferred_amount = 1;
@@ -494,7 +494,7 @@ fn should_exec_payment_and_session_stored_code() {
 
     result = utils::get_success_result(&response);
     let gas = result.cost();
-    let motes_charlie = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes_charlie = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let default_account = builder
         .get_account(DEFAULT_ACCOUNT_ADDR)
@@ -1,9 +1,10 @@
 use std::{convert::TryFrom, rc::Rc};
 
-use engine_core::engine_state::{execution_result::ExecutionResult, CONV_RATE};
+use engine_core::engine_state::execution_result::ExecutionResult;
 use engine_shared::motes::Motes;
 use engine_test_support::internal::{
-    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder as TestBuilder, DEFAULT_GENESIS_CONFIG,
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder as TestBuilder, DEFAULT_GAS_PRICE,
+    DEFAULT_GENESIS_CONFIG,
 };
 use types::{account::PublicKey, bytesrepr::FromBytes, CLTyped, CLValue, Key, U512};
 
@@ -350,7 +351,7 @@ fn get_cost(response: &[Rc<ExecutionResult>]) -> U512 {
         utils::get_exec_costs(response)
             .into_iter()
             .fold(Default::default(), |i, acc| i + acc),
-        CONV_RATE,
+        DEFAULT_GAS_PRICE,
     )
     .expect("should convert");
     motes.value()
@@ -1,6 +1,7 @@
 mod contract_api;
 mod deploy;
 mod examples;
+mod query_with_proof;
 mod regression;
 mod system_contracts;
 mod upgrade;
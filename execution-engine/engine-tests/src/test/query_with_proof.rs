@@ -0,0 +1,87 @@
+use std::convert::TryFrom;
+
+use engine_shared::{newtypes::Blake2bHash, stored_value::StoredValue};
+use engine_test_support::{
+    internal::{InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key};
+
+const UNKNOWN_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const MISSING_NAME: &str = "missing";
+
+fn initialize() -> (InMemoryWasmTestBuilder, Blake2bHash) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let state_hash = Blake2bHash::try_from(builder.get_post_state_hash().as_slice())
+        .expect("should have a valid post state hash");
+    (builder, state_hash)
+}
+
+#[ignore]
+#[test]
+fn should_prove_inclusion_of_queried_account() {
+    let (builder, state_hash) = initialize();
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+
+    let (result, proofs) = builder.query_with_proof(None, account_key, &[]);
+
+    let account = match result.expect("should query account") {
+        StoredValue::Account(account) => account,
+        other => panic!("expected account, got {:?}", other),
+    };
+    assert_eq!(account.public_key(), DEFAULT_ACCOUNT_ADDR);
+
+    assert_eq!(proofs.len(), 1);
+    let proof = &proofs[0];
+    assert_eq!(proof.key(), &account_key);
+    assert_eq!(
+        proof.verify(&state_hash),
+        Ok(Some(&StoredValue::Account(account)))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_prove_exclusion_of_unknown_account() {
+    let (builder, state_hash) = initialize();
+    let account_key = Key::Account(UNKNOWN_ACCOUNT_ADDR);
+
+    let (result, proofs) = builder.query_with_proof(None, account_key, &[]);
+
+    assert!(result.is_err());
+    assert_eq!(proofs.len(), 1);
+    assert_eq!(proofs[0].key(), &account_key);
+    assert_eq!(proofs[0].verify(&state_hash), Ok(None));
+}
+
+#[ignore]
+#[test]
+fn should_prove_visited_keys_of_failed_path_query() {
+    let (builder, state_hash) = initialize();
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+
+    let (result, proofs) = builder.query_with_proof(None, account_key, &[MISSING_NAME]);
+
+    // the account is proven to exist, and the proof shows it has no such named key
+    assert!(result.is_err());
+    assert_eq!(proofs.len(), 1);
+    match proofs[0].verify(&state_hash) {
+        Ok(Some(StoredValue::Account(account))) => {
+            assert!(!account.named_keys().contains_key(MISSING_NAME))
+        }
+        other => panic!("expected proof of account, got {:?}", other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_not_verify_proof_against_other_state_hash() {
+    let (builder, _) = initialize();
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+
+    let (_, proofs) = builder.query_with_proof(None, account_key, &[]);
+
+    let other_state_hash = Blake2bHash::new(b"other state");
+    assert!(proofs[0].verify(&other_state_hash).is_err());
+}
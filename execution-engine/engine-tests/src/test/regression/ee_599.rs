@@ -1,11 +1,10 @@
 use lazy_static::lazy_static;
 
-use engine_core::engine_state::CONV_RATE;
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GAS_PRICE,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -90,7 +89,7 @@ fn should_not_be_able_to_transfer_funds_with_transfer_purse_to_purse() {
         .builder()
         .get_exec_response(0)
         .expect("should have response");
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -162,7 +161,7 @@ fn should_not_be_able_to_transfer_funds_with_transfer_from_purse_to_account() {
         .get_exec_response(0)
         .expect("should have response");
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -244,7 +243,7 @@ fn should_not_be_able_to_transfer_funds_with_transfer_to_account() {
         .get_exec_response(0)
         .expect("should have response");
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     let error_msg = result_2
@@ -320,7 +319,7 @@ fn should_not_be_able_to_get_main_purse_in_invalid_context() {
         .get_exec_response(0)
         .expect("should have response");
 
-    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], CONV_RATE)
+    let gas_cost = Motes::from_gas(utils::get_exec_costs(exec_3_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     let error_msg = result_2
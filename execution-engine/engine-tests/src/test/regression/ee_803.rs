@@ -3,11 +3,12 @@ use std::rc::Rc;
 use engine_core::engine_state::{
     execution_result::ExecutionResult,
     genesis::{GenesisAccount, POS_REWARDS_PURSE},
-    CONV_RATE,
 };
 use engine_shared::motes::Motes;
 use engine_test_support::{
-    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_GAS_PRICE,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
 use types::{account::PublicKey, Key, URef, U512};
@@ -35,7 +36,7 @@ fn get_cost(response: &[Rc<ExecutionResult>]) -> U512 {
         utils::get_exec_costs(response)
             .into_iter()
             .fold(Default::default(), |i, acc| i + acc),
-        CONV_RATE,
+        DEFAULT_GAS_PRICE,
     )
     .expect("should convert");
     motes.value()
@@ -106,6 +107,8 @@ fn should_not_be_able_to_unbond_reward() {
         .expect("there should be a response")
         .to_owned();
 
+    builder.unbond_payout(0);
+
     // try to unbond, thus transferring the funds originally taken from the rewards purse to a
     // user's account
 
@@ -124,6 +127,8 @@ fn should_not_be_able_to_unbond_reward() {
         .expect("there should be a response")
         .to_owned();
 
+    builder.unbond_payout(0);
+
     let rewards_balance_post = builder.get_purse_balance(rewards_purse);
     let default_acc_balance_post = builder.get_purse_balance(default_account_purse);
 
@@ -4,8 +4,9 @@ use engine_core::engine_state::{
 };
 use engine_shared::{motes::Motes, stored_value::StoredValue};
 use engine_test_support::internal::{
-    utils, InMemoryWasmTestBuilder, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT,
-    POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    utils, InMemoryWasmTestBuilder, DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_BONDING_DELAY,
+    DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MAX_PAYMENT, DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_COSTS,
+    MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
 };
 use types::{account::PublicKey, Key, ProtocolVersion, U512};
 
@@ -54,6 +55,7 @@ fn should_run_genesis() {
     let accounts = vec![account_1, account_2];
     let protocol_version = ProtocolVersion::V1_0_0;
     let wasm_costs = *DEFAULT_WASM_COSTS;
+    let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
 
     let genesis_config = GenesisConfig::new(
         name,
@@ -64,6 +66,11 @@ fn should_run_genesis() {
         standard_payment_installer_bytes,
         accounts,
         wasm_costs,
+        host_function_costs,
+        DEFAULT_MAX_PAYMENT,
+        DEFAULT_BLOCK_GAS_LIMIT,
+        DEFAULT_BONDING_DELAY,
+        DEFAULT_UNBONDING_DELAY,
     );
 
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -106,6 +113,47 @@ fn should_run_genesis() {
     }
 }
 
+#[ignore]
+#[test]
+fn should_run_genesis_with_gas_limits() {
+    const MAX_PAYMENT: u64 = 20_000_000;
+    const BLOCK_GAS_LIMIT: u64 = 1_000_000_000;
+
+    let account_1 = GenesisAccount::new(
+        ACCOUNT_1_ADDR,
+        Motes::new(ACCOUNT_1_BALANCE.into()),
+        Motes::new(U512::zero()),
+    );
+    let genesis_config = GenesisConfig::new(
+        CHAIN_NAME.to_string(),
+        TIMESTAMP,
+        ProtocolVersion::V1_0_0,
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        vec![account_1],
+        *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        MAX_PAYMENT,
+        BLOCK_GAS_LIMIT,
+        DEFAULT_BONDING_DELAY,
+        DEFAULT_UNBONDING_DELAY,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&genesis_config);
+
+    let protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(ProtocolVersion::V1_0_0)
+        .expect("should have result")
+        .expect("should have protocol data");
+
+    assert_eq!(protocol_data.max_payment(), MAX_PAYMENT);
+    assert_eq!(protocol_data.block_gas_limit(), BLOCK_GAS_LIMIT);
+}
+
 #[cfg(feature = "use-system-contracts")]
 #[ignore]
 #[should_panic]
@@ -140,6 +188,7 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
 
         GenesisConfig::new(
             name,
@@ -150,6 +199,11 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            DEFAULT_MAX_PAYMENT,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            DEFAULT_BONDING_DELAY,
+            DEFAULT_UNBONDING_DELAY,
         )
     };
 
@@ -192,6 +246,7 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let host_function_costs = *DEFAULT_HOST_FUNCTION_COSTS;
 
         GenesisConfig::new(
             name,
@@ -202,6 +257,11 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            host_function_costs,
+            DEFAULT_MAX_PAYMENT,
+            DEFAULT_BLOCK_GAS_LIMIT,
+            DEFAULT_BONDING_DELAY,
+            DEFAULT_UNBONDING_DELAY,
         )
     };
 
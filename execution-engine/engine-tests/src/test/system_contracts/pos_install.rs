@@ -3,8 +3,8 @@ use std::collections::BTreeMap;
 use engine_core::engine_state::EngineConfig;
 use engine_test_support::{
     internal::{
-        exec_with_return, ExecuteRequestBuilder, WasmTestBuilder, DEFAULT_BLOCK_TIME,
-        DEFAULT_GENESIS_CONFIG,
+        exec_with_return, ExecuteRequestBuilder, InMemoryWasmTestBuilder, WasmTestBuilder,
+        DEFAULT_BLOCK_TIME, DEFAULT_GENESIS_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -15,13 +15,17 @@ const TRANSFER_AMOUNT: u64 = 250_000_000 + 1000;
 const SYSTEM_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
 const N_VALIDATORS: u8 = 5;
+const BONDING_DELAY: u64 = 2;
+const UNBONDING_DELAY: u64 = 3;
 
-// one named_key for each validator and three for the purses
-const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 3;
+// one named_key for each validator, three for the purses and two for the delays
+const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 5;
 
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+const POS_BONDING_DELAY: &str = "pos_bonding_delay";
+const POS_UNBONDING_DELAY: &str = "pos_unbonding_delay";
 
 #[ignore]
 #[test]
@@ -57,7 +61,12 @@ fn should_run_pos_install_contract() {
         "pos_install.wasm",
         DEFAULT_BLOCK_TIME,
         DEPLOY_HASH_2,
-        (mint_uref, genesis_validators),
+        (
+            mint_uref,
+            genesis_validators,
+            BONDING_DELAY,
+            UNBONDING_DELAY,
+        ),
         vec![mint_uref],
     )
     .expect("should run successfully");
@@ -96,6 +105,36 @@ fn should_run_pos_install_contract() {
 
     let rewards_purse_balance = builder.get_purse_balance(rewards_purse);
     assert_eq!(rewards_purse_balance, U512::zero());
+
+    // delays are stored under read-only urefs
+    assert_eq!(
+        get_delay(&builder, named_keys, POS_BONDING_DELAY),
+        BONDING_DELAY
+    );
+    assert_eq!(
+        get_delay(&builder, named_keys, POS_UNBONDING_DELAY),
+        UNBONDING_DELAY
+    );
+}
+
+fn get_delay(
+    builder: &InMemoryWasmTestBuilder,
+    named_keys: &BTreeMap<String, Key>,
+    name: &str,
+) -> u64 {
+    let uref = named_keys
+        .get(name)
+        .and_then(Key::as_uref)
+        .expect("should have delay uref");
+    assert_eq!(uref.access_rights(), AccessRights::READ);
+    let stored_value = builder
+        .query(None, Key::URef(*uref), &[])
+        .expect("should have delay value");
+    let cl_value = stored_value.as_cl_value().expect("should be CLValue");
+    cl_value
+        .clone()
+        .into_t()
+        .expect("should cast CLValue to u64")
 }
 
 fn get_purse(named_keys: &BTreeMap<String, Key>, name: &str) -> Option<URef> {
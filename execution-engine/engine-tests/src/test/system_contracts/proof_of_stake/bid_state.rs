@@ -0,0 +1,85 @@
+use std::{collections::HashMap, convert::TryInto};
+
+use num_traits::Zero;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_grpc_server::engine_server::mappings::MappingError;
+use engine_shared::motes::Motes;
+use engine_test_support::internal::{utils, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS};
+use types::{account::PublicKey, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 2000;
+const ACCOUNT_1_BOND: u64 = 1000;
+
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_2_BALANCE: u64 = 2000;
+const ACCOUNT_2_BOND: u64 = 200;
+
+#[ignore]
+#[test]
+fn should_return_bid_state_of_genesis_validators() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        let account_2 = GenesisAccount::new(
+            ACCOUNT_2_ADDR,
+            Motes::new(ACCOUNT_2_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let genesis_config = utils::create_genesis_config(accounts.clone());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let mut bid_state_response = builder.get_bid_state(None);
+    assert!(bid_state_response.has_success());
+
+    let actual = bid_state_response
+        .take_success()
+        .take_bids()
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
+        .expect("should parse bids");
+
+    let expected: HashMap<PublicKey, U512> = accounts
+        .iter()
+        .filter(|genesis_account| genesis_account.bonded_amount() > Motes::zero())
+        .map(|genesis_account| {
+            (
+                genesis_account.public_key(),
+                genesis_account.bonded_amount().value(),
+            )
+        })
+        .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[ignore]
+#[test]
+fn should_return_missing_parent_for_unknown_state_hash() {
+    let genesis_config = utils::create_genesis_config(DEFAULT_ACCOUNTS.clone());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let unknown_state_hash = vec![255u8; 32];
+    let bid_state_response = builder.get_bid_state(Some(unknown_state_hash.clone()));
+
+    assert!(bid_state_response.has_missing_parent());
+    assert_eq!(
+        bid_state_response.get_missing_parent().get_hash(),
+        unknown_state_hash.as_slice()
+    );
+}
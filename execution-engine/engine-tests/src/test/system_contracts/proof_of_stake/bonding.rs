@@ -1,11 +1,9 @@
-use engine_core::engine_state::{
-    genesis::{GenesisAccount, POS_BONDING_PURSE},
-    CONV_RATE,
-};
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE};
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_GAS_PRICE,
+        DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
@@ -196,7 +194,7 @@ fn should_run_successful_bond_and_unbond() {
     let exec_response = builder
         .get_exec_response(0)
         .expect("should have exec response");
-    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], CONV_RATE)
+    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     assert_eq!(
@@ -258,7 +256,7 @@ fn should_run_successful_bond_and_unbond() {
         builder.get_purse_balance(default_account.main_purse()),
         U512::from(
             DEFAULT_ACCOUNT_INITIAL_BALANCE
-                - Motes::from_gas(genesis_gas_cost, CONV_RATE)
+                - Motes::from_gas(genesis_gas_cost, DEFAULT_GAS_PRICE)
                     .expect("should convert")
                     .value()
                     .as_u64()
@@ -299,7 +297,7 @@ fn should_run_successful_bond_and_unbond() {
     let exec_response = builder
         .get_exec_response(0)
         .expect("should have exec response");
-    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], CONV_RATE)
+    let gas_cost_b = Motes::from_gas(utils::get_exec_costs(exec_response)[0], DEFAULT_GAS_PRICE)
         .expect("should convert");
 
     assert_eq!(
@@ -355,7 +353,7 @@ fn should_run_successful_bond_and_unbond() {
             .get_purse_balance(default_account.main_purse()),
         U512::from(
             DEFAULT_ACCOUNT_INITIAL_BALANCE
-                - Motes::from_gas(genesis_gas_cost, CONV_RATE)
+                - Motes::from_gas(genesis_gas_cost, DEFAULT_GAS_PRICE)
                     .expect("should convert")
                     .value()
                     .as_u64()
@@ -10,6 +10,7 @@ use engine_test_support::{
 use types::{account::PublicKey, U512};
 
 const CONTRACT_LOCAL_STATE: &str = "local_state.wasm";
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
 const ACCOUNT_1_BALANCE: u64 = 2000;
 const ACCOUNT_1_BOND: u64 = 1000;
@@ -18,6 +19,11 @@ const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
 const ACCOUNT_2_BALANCE: u64 = 2000;
 const ACCOUNT_2_BOND: u64 = 200;
 
+const DEFAULT_ACCOUNT_BOND: u64 = 100_000;
+const DEFAULT_ACCOUNT_UNBOND: u64 = 40_000;
+const TEST_BOND: &str = "bond";
+const TEST_UNBOND: &str = "unbond";
+
 #[ignore]
 #[test]
 fn should_return_bonded_validators() {
@@ -69,3 +75,80 @@ fn should_return_bonded_validators() {
 
     assert_eq!(actual, expected);
 }
+
+#[ignore]
+#[test]
+fn should_return_bonded_validators_changed_by_commit() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp
+    };
+
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let bond_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(DEFAULT_ACCOUNT_BOND)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let result = builder.run_genesis(&genesis_config).exec(bond_request);
+    if !cfg!(feature = "enable-bonding") && result.is_error() {
+        return;
+    }
+    // the bond is applied when the bonding queue is stepped
+    builder.expect_success().commit().unbond_payout(0);
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+
+    let expected: HashMap<PublicKey, U512> = vec![
+        (ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_BOND)),
+        (DEFAULT_ACCOUNT_ADDR, U512::from(DEFAULT_ACCOUNT_BOND)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(bonded_validators, expected);
+
+    let unbond_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNBOND),
+            Some(U512::from(DEFAULT_ACCOUNT_UNBOND)),
+        ),
+    )
+    .build();
+
+    // the stake is decreased immediately on unbonding
+    builder.exec(unbond_request).expect_success().commit();
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+
+    let expected: HashMap<PublicKey, U512> = vec![
+        (ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_BOND)),
+        (
+            DEFAULT_ACCOUNT_ADDR,
+            U512::from(DEFAULT_ACCOUNT_BOND - DEFAULT_ACCOUNT_UNBOND),
+        ),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(bonded_validators, expected);
+}
@@ -0,0 +1,174 @@
+use engine_core::engine_state::genesis::{GenesisAccount, POS_REWARDS_PURSE};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key, URef, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 2000;
+const ACCOUNT_1_BOND: u64 = 1000;
+
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_2_BALANCE: u64 = 2000;
+const ACCOUNT_2_BOND: u64 = 200;
+
+const ACCOUNT_3_ADDR: PublicKey = PublicKey::ed25519_from([3u8; 32]);
+
+/// Runs genesis with two bonded validators and executes a single deploy, so that the PoS rewards
+/// purse is funded with the cost of that deploy.
+fn initialize() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        let account_2 = GenesisAccount::new(
+            ACCOUNT_2_ADDR,
+            Motes::new(ACCOUNT_2_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        (ACCOUNT_3_ADDR, *DEFAULT_PAYMENT),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_distribute_rewards_to_validators() {
+    let mut builder = initialize();
+
+    let rewards_pre_balance = get_pos_rewards_purse_balance(&builder);
+    assert!(
+        !rewards_pre_balance.is_zero(),
+        "rewards purse should be funded by the deploy"
+    );
+    let account_1_pre_balance = get_main_purse_balance(&builder, ACCOUNT_1_ADDR);
+    let account_2_pre_balance = get_main_purse_balance(&builder, ACCOUNT_2_ADDR);
+
+    let account_1_reward = rewards_pre_balance / 2;
+    let account_2_reward = rewards_pre_balance / 4;
+
+    builder.distribute_rewards(vec![
+        (ACCOUNT_1_ADDR, account_1_reward),
+        (ACCOUNT_2_ADDR, account_2_reward),
+    ]);
+
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance + account_1_reward
+    );
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_2_ADDR),
+        account_2_pre_balance + account_2_reward
+    );
+    assert_eq!(
+        get_pos_rewards_purse_balance(&builder),
+        rewards_pre_balance - account_1_reward - account_2_reward
+    );
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_BOND))
+    );
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_2_ADDR),
+        Some(&U512::from(ACCOUNT_2_BOND))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_distribute_more_than_rewards_purse_balance() {
+    let builder = initialize();
+
+    let rewards_pre_balance = get_pos_rewards_purse_balance(&builder);
+    let account_1_pre_balance = get_main_purse_balance(&builder, ACCOUNT_1_ADDR);
+
+    let distribute_rewards_response = builder.distribute_rewards_response(
+        builder.get_post_state_hash(),
+        vec![
+            (ACCOUNT_1_ADDR, rewards_pre_balance),
+            (ACCOUNT_2_ADDR, U512::one()),
+        ],
+    );
+
+    assert!(distribute_rewards_response.has_error());
+    assert!(distribute_rewards_response
+        .get_error()
+        .has_insufficient_rewards());
+
+    // nothing was committed, so the balances at the latest post-state are unchanged
+    assert_eq!(get_pos_rewards_purse_balance(&builder), rewards_pre_balance);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_missing_parent_for_unknown_state_hash() {
+    let builder = initialize();
+
+    let unknown_state_hash = vec![255u8; 32];
+    let distribute_rewards_response = builder.distribute_rewards_response(
+        unknown_state_hash.clone(),
+        vec![(ACCOUNT_1_ADDR, U512::one())],
+    );
+
+    assert!(distribute_rewards_response.has_missing_parent());
+    assert_eq!(
+        distribute_rewards_response.get_missing_parent().get_hash(),
+        unknown_state_hash.as_slice()
+    );
+}
+
+fn get_pos_rewards_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_REWARDS_PURSE)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should find PoS rewards purse");
+    builder.get_purse_balance(purse)
+}
+
+fn get_main_purse_balance(builder: &InMemoryWasmTestBuilder, public_key: PublicKey) -> U512 {
+    let account = builder
+        .get_account(public_key)
+        .expect("should find account");
+    builder.get_purse_balance(account.main_purse())
+}
@@ -1,5 +1,9 @@
+mod bid_state;
 mod bonding;
 mod commit_validators;
+mod distribute_rewards;
 mod finalize_payment;
 mod get_payment_purse;
 mod refund_purse;
+mod slash;
+mod unbond_payout;
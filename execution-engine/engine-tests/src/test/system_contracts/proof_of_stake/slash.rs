@@ -0,0 +1,258 @@
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE, POS_REWARDS_PURSE};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, Key, URef, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const TEST_SLASH: &str = "slash";
+const TEST_UNBOND: &str = "unbond";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 1_000_000_000;
+const ACCOUNT_1_BOND: u64 = 1000;
+const ACCOUNT_1_UNBOND: u64 = 400;
+
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_2_BALANCE: u64 = 2000;
+const ACCOUNT_2_BOND: u64 = 200;
+
+const ACCOUNT_3_ADDR: PublicKey = PublicKey::ed25519_from([3u8; 32]);
+
+fn initialize() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_BOND.into()),
+        );
+        let account_2 = GenesisAccount::new(
+            ACCOUNT_2_ADDR,
+            Motes::new(ACCOUNT_2_BALANCE.into()),
+            Motes::new(ACCOUNT_2_BOND.into()),
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_slash_validator_stake() {
+    let mut builder = initialize();
+
+    let bonding_pre_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+    let rewards_pre_balance = get_pos_purse_balance(&builder, POS_REWARDS_PURSE);
+
+    let slash_amount = U512::from(300);
+    builder.slash(vec![(ACCOUNT_1_ADDR, slash_amount)]);
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_1_ADDR),
+        Some(&(U512::from(ACCOUNT_1_BOND) - slash_amount))
+    );
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_2_ADDR),
+        Some(&U512::from(ACCOUNT_2_BOND))
+    );
+
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_pre_balance - slash_amount
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_REWARDS_PURSE),
+        rewards_pre_balance + slash_amount
+    );
+}
+
+#[ignore]
+#[test]
+fn should_remove_validator_slashed_by_entire_stake() {
+    let mut builder = initialize();
+
+    let rewards_pre_balance = get_pos_purse_balance(&builder, POS_REWARDS_PURSE);
+
+    // slashing more than the stake only slashes the stake
+    builder.slash(vec![(ACCOUNT_2_ADDR, U512::from(ACCOUNT_2_BOND * 2))]);
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert!(!bonded_validators.contains_key(&ACCOUNT_2_ADDR));
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_BOND))
+    );
+
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_REWARDS_PURSE),
+        rewards_pre_balance + U512::from(ACCOUNT_2_BOND)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_slash_unbonded_account() {
+    let builder = initialize();
+
+    let slash_response = builder.slash_response(
+        builder.get_post_state_hash(),
+        vec![
+            (ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_BOND)),
+            (ACCOUNT_3_ADDR, U512::one()),
+        ],
+    );
+
+    assert!(slash_response.has_error());
+    assert!(slash_response.get_error().has_not_bonded());
+}
+
+#[ignore]
+#[test]
+fn should_return_missing_parent_for_unknown_state_hash() {
+    let builder = initialize();
+
+    let unknown_state_hash = vec![255u8; 32];
+    let slash_response = builder.slash_response(
+        unknown_state_hash.clone(),
+        vec![(ACCOUNT_1_ADDR, U512::one())],
+    );
+
+    assert!(slash_response.has_missing_parent());
+    assert_eq!(
+        slash_response.get_missing_parent().get_hash(),
+        unknown_state_hash.as_slice()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_drop_pending_unbonds_of_slashed_validator() {
+    let mut builder = initialize();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNBOND),
+            Some(U512::from(ACCOUNT_1_UNBOND)),
+        ),
+    )
+    .build();
+
+    let result = builder.exec(exec_request);
+    if !cfg!(feature = "enable-bonding") && result.is_error() {
+        return;
+    }
+    builder.expect_success().commit();
+
+    let bonding_pre_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+    let rewards_pre_balance = get_pos_purse_balance(&builder, POS_REWARDS_PURSE);
+    let account_1_pre_balance = get_main_purse_balance(&builder, ACCOUNT_1_ADDR);
+
+    // both the remaining stake and the pending unbond are slashed
+    builder.slash(vec![(ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_BOND))]);
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert!(!bonded_validators.contains_key(&ACCOUNT_1_ADDR));
+
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_pre_balance - U512::from(ACCOUNT_1_BOND)
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_REWARDS_PURSE),
+        rewards_pre_balance + U512::from(ACCOUNT_1_BOND)
+    );
+
+    // the dropped unbond is never paid out
+    builder.unbond_payout(1);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_slash_from_user_account() {
+    let mut builder = initialize();
+
+    let bonding_pre_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_SLASH),
+            vec![(ACCOUNT_1_ADDR, U512::from(ACCOUNT_1_BOND))],
+        ),
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    let error_message = builder
+        .exec_error_message(0)
+        .expect("should have an exec response");
+    let expected_error = ApiError::from(pos::Error::SystemFunctionCalledByUserAccount);
+    assert!(
+        error_message.contains(&format!("Revert({})", u32::from(expected_error))),
+        "{}",
+        error_message
+    );
+
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_BOND))
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_pre_balance
+    );
+}
+
+fn get_pos_purse_balance(builder: &InMemoryWasmTestBuilder, purse_name: &str) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(purse_name)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should find PoS purse");
+    builder.get_purse_balance(purse)
+}
+
+fn get_main_purse_balance(builder: &InMemoryWasmTestBuilder, public_key: PublicKey) -> U512 {
+    let account = builder
+        .get_account(public_key)
+        .expect("should find account");
+    builder.get_purse_balance(account.main_purse())
+}
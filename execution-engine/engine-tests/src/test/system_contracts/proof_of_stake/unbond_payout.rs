@@ -0,0 +1,304 @@
+use engine_core::engine_state::genesis::{
+    GenesisAccount, GenesisConfig, POS_BONDING_DELAY, POS_BONDING_PURSE, POS_UNBONDING_DELAY,
+};
+use engine_shared::{
+    additive_map::AdditiveMap, motes::Motes, stored_value::StoredValue, transform::Transform,
+};
+use engine_test_support::internal::{
+    utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNTS,
+    DEFAULT_BLOCK_GAS_LIMIT, DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_TIMESTAMP,
+    DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_MAX_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_WASM_COSTS,
+    MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+};
+use types::{account::PublicKey, Key, ProtocolVersion, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const TEST_UNBOND: &str = "unbond";
+
+const BONDING_DELAY: u64 = 1;
+const UNBONDING_DELAY: u64 = 2;
+
+const GENESIS_VALIDATOR_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_BALANCE: u64 = 1_000_000_000;
+const ACCOUNT_1_STAKE: u64 = 42_000;
+const ACCOUNT_1_UNBOND: u64 = 2_000;
+
+fn initialize() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let genesis_validator = GenesisAccount::new(
+            GENESIS_VALIDATOR_ADDR,
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()) * Motes::new(2.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        );
+        let account_1 = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(ACCOUNT_1_BALANCE.into()),
+            Motes::new(ACCOUNT_1_STAKE.into()),
+        );
+        tmp.push(genesis_validator);
+        tmp.push(account_1);
+        tmp
+    };
+
+    let genesis_config = GenesisConfig::new(
+        DEFAULT_CHAIN_NAME.to_string(),
+        DEFAULT_GENESIS_TIMESTAMP,
+        *DEFAULT_PROTOCOL_VERSION,
+        utils::read_wasm_file_bytes(MINT_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(POS_INSTALL_CONTRACT),
+        utils::read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT),
+        accounts,
+        *DEFAULT_WASM_COSTS,
+        *DEFAULT_HOST_FUNCTION_COSTS,
+        DEFAULT_MAX_PAYMENT,
+        DEFAULT_BLOCK_GAS_LIMIT,
+        BONDING_DELAY,
+        UNBONDING_DELAY,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_unbond_after_unbonding_delay() {
+    let mut builder = initialize();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNBOND),
+            Some(U512::from(ACCOUNT_1_UNBOND)),
+        ),
+    )
+    .build();
+
+    let result = builder.exec(exec_request);
+    if !cfg!(feature = "enable-bonding") && result.is_error() {
+        return;
+    }
+    builder.expect_success().commit();
+
+    // the stake is decreased immediately
+    let bonded_validators = builder
+        .get_bonded_validators()
+        .last()
+        .cloned()
+        .expect("should have bonded validators");
+    assert_eq!(
+        bonded_validators.get(&ACCOUNT_1_ADDR),
+        Some(&U512::from(ACCOUNT_1_STAKE - ACCOUNT_1_UNBOND))
+    );
+
+    let account_1_pre_balance = get_main_purse_balance(&builder, ACCOUNT_1_ADDR);
+
+    // the unbond is not paid out before the delay passed
+    builder.unbond_payout(UNBONDING_DELAY - 1);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance
+    );
+
+    builder.unbond_payout(UNBONDING_DELAY);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance + U512::from(ACCOUNT_1_UNBOND)
+    );
+
+    // the unbond is paid out only once
+    builder.unbond_payout(UNBONDING_DELAY + 1);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance + U512::from(ACCOUNT_1_UNBOND)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_pay_out_for_lower_era_height() {
+    let mut builder = initialize();
+
+    builder.unbond_payout(5);
+
+    let unbond_payout_response = builder.unbond_payout_response(builder.get_post_state_hash(), 4);
+
+    assert!(unbond_payout_response.has_error());
+    assert!(unbond_payout_response.get_error().has_era_went_backwards());
+}
+
+#[ignore]
+#[test]
+fn should_return_missing_parent_for_unknown_state_hash() {
+    let builder = initialize();
+
+    let unknown_state_hash = vec![255u8; 32];
+    let unbond_payout_response = builder.unbond_payout_response(unknown_state_hash.clone(), 1);
+
+    assert!(unbond_payout_response.has_missing_parent());
+    assert_eq!(
+        unbond_payout_response.get_missing_parent().get_hash(),
+        unknown_state_hash.as_slice()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_install_delays_when_upgrading_pos_contract_without_them() {
+    let mut builder = initialize_without_delays();
+
+    // a proof of stake contract which predates the delays can't pay out unbonds
+    let unbond_payout_response = builder.unbond_payout_response(builder.get_post_state_hash(), 1);
+    assert!(!unbond_payout_response.has_success());
+
+    upgrade(&mut builder, Some((BONDING_DELAY, UNBONDING_DELAY)));
+
+    assert_eq!(get_delay(&builder, POS_BONDING_DELAY), BONDING_DELAY);
+    assert_eq!(get_delay(&builder, POS_UNBONDING_DELAY), UNBONDING_DELAY);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNBOND),
+            Some(U512::from(ACCOUNT_1_UNBOND)),
+        ),
+    )
+    .build();
+
+    let result = builder.exec(exec_request);
+    if !cfg!(feature = "enable-bonding") && result.is_error() {
+        return;
+    }
+    builder.expect_success().commit();
+
+    let account_1_pre_balance = get_main_purse_balance(&builder, ACCOUNT_1_ADDR);
+
+    builder.unbond_payout(UNBONDING_DELAY - 1);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance
+    );
+
+    builder.unbond_payout(UNBONDING_DELAY);
+    assert_eq!(
+        get_main_purse_balance(&builder, ACCOUNT_1_ADDR),
+        account_1_pre_balance + U512::from(ACCOUNT_1_UNBOND)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_pay_out_unbond_immediately_after_upgrade_without_pos_config() {
+    let mut builder = initialize_without_delays();
+
+    upgrade(&mut builder, None);
+
+    assert_eq!(get_delay(&builder, POS_BONDING_DELAY), 0);
+    assert_eq!(get_delay(&builder, POS_UNBONDING_DELAY), 0);
+
+    let bonding_purse = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_BONDING_PURSE)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have bonding purse");
+    let bonding_purse_pre_balance = builder.get_purse_balance(bonding_purse);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNBOND),
+            Some(U512::from(ACCOUNT_1_UNBOND)),
+        ),
+    )
+    .build();
+
+    let result = builder.exec(exec_request);
+    if !cfg!(feature = "enable-bonding") && result.is_error() {
+        return;
+    }
+    builder.expect_success().commit();
+
+    // the unbond is paid out of the bonding purse within the deploy
+    assert_eq!(
+        builder.get_purse_balance(bonding_purse),
+        bonding_purse_pre_balance - U512::from(ACCOUNT_1_UNBOND)
+    );
+}
+
+/// Runs genesis, then drops the delays from the proof of stake contract so it looks like one
+/// installed before they existed.
+fn initialize_without_delays() -> InMemoryWasmTestBuilder {
+    let mut builder = initialize();
+
+    let pos_uref = builder.get_pos_contract_uref();
+    let mut pos_contract = builder.get_pos_contract();
+    pos_contract.named_keys_mut().remove(POS_BONDING_DELAY);
+    pos_contract.named_keys_mut().remove(POS_UNBONDING_DELAY);
+
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        Key::URef(pos_uref),
+        Transform::Write(StoredValue::Contract(pos_contract)),
+    );
+    let prestate_hash = builder.get_post_state_hash();
+    builder.commit_effects(prestate_hash, effects);
+    builder
+}
+
+fn upgrade(builder: &mut InMemoryWasmTestBuilder, pos_config: Option<(u64, u64)>) {
+    let sem_ver = DEFAULT_PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request_builder = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(1);
+    if let Some((bonding_delay, unbonding_delay)) = pos_config {
+        upgrade_request_builder =
+            upgrade_request_builder.with_new_pos_config(bonding_delay, unbonding_delay);
+    }
+    let mut upgrade_request = upgrade_request_builder.build();
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+}
+
+fn get_delay(builder: &InMemoryWasmTestBuilder, name: &str) -> u64 {
+    let uref = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(name)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have delay uref");
+    let stored_value = builder
+        .query(None, Key::URef(uref), &[])
+        .expect("should have delay value");
+    let cl_value = stored_value.as_cl_value().expect("should be CLValue");
+    cl_value
+        .clone()
+        .into_t()
+        .expect("should cast CLValue to u64")
+}
+
+fn get_main_purse_balance(builder: &InMemoryWasmTestBuilder, public_key: PublicKey) -> U512 {
+    let account = builder
+        .get_account(public_key)
+        .expect("should find account");
+    builder.get_purse_balance(account.main_purse())
+}
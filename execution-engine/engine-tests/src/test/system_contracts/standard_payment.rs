@@ -1,9 +1,10 @@
-use engine_core::engine_state::{genesis::POS_REWARDS_PURSE, CONV_RATE, MAX_PAYMENT};
+use engine_core::engine_state::genesis::POS_REWARDS_PURSE;
 use engine_shared::{motes::Motes, transform::Transform};
+use engine_storage::protocol_data::DEFAULT_MAX_PAYMENT;
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
-        DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_ACCOUNT_KEY, DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
@@ -23,7 +24,7 @@ fn should_raise_insufficient_payment_when_caller_lacks_minimum_balance() {
     let exec_request = ExecuteRequestBuilder::standard(
         DEFAULT_ACCOUNT_ADDR,
         TRANSFER_PURSE_TO_ACCOUNT_WASM,
-        (account_1_public_key, U512::from(MAX_PAYMENT - 1)),
+        (account_1_public_key, U512::from(DEFAULT_MAX_PAYMENT - 1)),
     )
     .build();
 
@@ -103,7 +104,7 @@ fn should_raise_insufficient_payment_when_payment_code_does_not_pay_enough() {
     let reward_balance = get_pos_rewards_purse_balance(&builder);
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT);
 
     assert_eq!(
         modified_balance,
@@ -167,7 +168,7 @@ fn should_raise_insufficient_payment_error_when_out_of_gas() {
         .finish();
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT);
 
     let modified_balance = builder.get_purse_balance(
         builder
@@ -237,7 +238,7 @@ fn should_forward_payment_execution_runtime_error() {
         .finish();
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT);
 
     let modified_balance = builder.get_purse_balance(
         builder
@@ -307,7 +308,7 @@ fn should_forward_payment_execution_gas_limit_error() {
         .finish();
 
     let initial_balance: U512 = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
-    let expected_reward_balance: U512 = U512::from(MAX_PAYMENT);
+    let expected_reward_balance: U512 = U512::from(DEFAULT_MAX_PAYMENT);
 
     let modified_balance = builder.get_purse_balance(
         builder
@@ -434,7 +435,7 @@ fn should_correctly_charge_when_session_code_runs_out_of_gas() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
 
     let tally = motes.value() + modified_balance;
 
@@ -497,7 +498,7 @@ fn should_correctly_charge_when_session_code_fails() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
     let tally = motes.value() + modified_balance;
 
     assert_eq!(
@@ -555,7 +556,7 @@ fn should_correctly_charge_when_session_code_succeeds() {
 
     let success_result = utils::get_success_result(&response);
     let gas = success_result.cost();
-    let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
+    let motes = Motes::from_gas(gas, DEFAULT_GAS_PRICE).expect("should have motes");
     let total = motes.value() + U512::from(transferred_amount);
     let tally = total + modified_balance;
 
@@ -1,13 +1,16 @@
 use engine_core::engine_state::{upgrade::ActivationPoint, Error};
 use engine_grpc_server::engine_server::ipc::DeployCode;
+use engine_shared::host_function_costs::{HostFunctionCost, HostFunctionCosts};
 #[cfg(feature = "use-system-contracts")]
 use engine_shared::{stored_value::StoredValue, transform::Transform};
-use engine_test_support::internal::{
-    utils, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_GENESIS_CONFIG,
-    DEFAULT_WASM_COSTS,
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_HOST_FUNCTION_COSTS, DEFAULT_PAYMENT, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
 };
-#[cfg(feature = "use-system-contracts")]
-use engine_test_support::{internal::ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::ProtocolVersion;
 #[cfg(feature = "use-system-contracts")]
@@ -20,6 +23,8 @@ const MODIFIED_SYSTEM_UPGRADER_CONTRACT_NAME: &str = "modified_system_upgrader.w
 const MODIFIED_MINT_CALLER_CONTRACT_NAME: &str = "modified_mint_caller.wasm";
 #[cfg(feature = "use-system-contracts")]
 const PAYMENT_AMOUNT: u64 = 200_000_000;
+const CONTRACT_TYPED_CALL: &str = "typed_call.wasm";
+const TYPED_CALL_KEY: &str = "typed_call";
 
 fn get_upgraded_wasm_costs() -> WasmCosts {
     WasmCosts {
@@ -36,6 +41,14 @@ fn get_upgraded_wasm_costs() -> WasmCosts {
     }
 }
 
+fn get_upgraded_host_function_costs() -> HostFunctionCosts {
+    HostFunctionCosts {
+        read_value: HostFunctionCost::new(10, 2),
+        write: HostFunctionCost::new(20, 3),
+        ..*DEFAULT_HOST_FUNCTION_COSTS
+    }
+}
+
 #[ignore]
 #[test]
 fn should_upgrade_only_protocol_version() {
@@ -73,6 +86,121 @@ fn should_upgrade_only_protocol_version() {
         *DEFAULT_WASM_COSTS, upgraded_wasm_costs,
         "upgraded costs should equal original costs"
     );
+
+    let upgraded_host_function_costs = builder
+        .get_engine_state()
+        .host_function_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have host function costs");
+
+    assert_eq!(
+        *DEFAULT_HOST_FUNCTION_COSTS, upgraded_host_function_costs,
+        "upgraded host function costs should equal original host function costs"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_only_host_function_costs_patch_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_host_function_costs = get_upgraded_host_function_costs();
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_host_function_costs(new_host_function_costs)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_host_function_costs = builder
+        .get_engine_state()
+        .host_function_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded host function costs");
+
+    assert_eq!(
+        new_host_function_costs, upgraded_host_function_costs,
+        "upgraded host function costs should equal new host function costs"
+    );
+
+    // wasm costs are kept when only host function costs are upgraded
+    let upgraded_wasm_costs = builder
+        .get_engine_state()
+        .wasm_costs(new_protocol_version)
+        .expect("should have result")
+        .expect("should have costs");
+
+    assert_eq!(
+        *DEFAULT_WASM_COSTS, upgraded_wasm_costs,
+        "upgraded costs should equal original costs"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_only_gas_limits_patch_version() {
+    const NEW_MAX_PAYMENT: u64 = 20_000_000;
+    const NEW_BLOCK_GAS_LIMIT: u64 = 1_000_000_000;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_max_payment(NEW_MAX_PAYMENT)
+            .with_new_block_gas_limit(NEW_BLOCK_GAS_LIMIT)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(new_protocol_version)
+        .expect("should have result")
+        .expect("should have protocol data");
+
+    assert_eq!(upgraded_protocol_data.max_payment(), NEW_MAX_PAYMENT);
+    assert_eq!(
+        upgraded_protocol_data.block_gas_limit(),
+        NEW_BLOCK_GAS_LIMIT
+    );
+    assert_eq!(
+        *upgraded_protocol_data.wasm_costs(),
+        *DEFAULT_WASM_COSTS,
+        "upgraded costs should equal original costs"
+    );
 }
 
 #[cfg(feature = "use-system-contracts")]
@@ -351,6 +479,73 @@ fn should_allow_only_wasm_costs_patch_version() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_not_use_modules_preprocessed_before_wasm_costs_upgrade() {
+    let call_typed_call_request = |deploy_hash: u8, protocol_version: ProtocolVersion| {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_stored_session_named_key(TYPED_CALL_KEY, (42u64,))
+            .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+            .with_deploy_hash([deploy_hash; 32])
+            .build();
+        ExecuteRequestBuilder::new()
+            .push_deploy(deploy)
+            .with_protocol_version(protocol_version)
+            .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let install_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_TYPED_CALL, ()).build();
+    builder.exec(install_request).expect_success().commit();
+
+    // caches the module of the stored contract under the original costs
+    builder
+        .exec(call_typed_call_request(2, PROTOCOL_VERSION))
+        .expect_success()
+        .commit();
+    let cost_before_upgrade = builder.exec_costs(1)[0];
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_costs(WasmCosts {
+            regular: DEFAULT_WASM_COSTS.regular * 10,
+            ..*DEFAULT_WASM_COSTS
+        })
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+    assert!(
+        builder
+            .get_upgrade_response(0)
+            .expect("should have response")
+            .has_success(),
+        "expected success"
+    );
+
+    // a module instrumented with the original costs would charge the same again
+    builder
+        .exec(call_typed_call_request(3, new_protocol_version))
+        .expect_success()
+        .commit();
+    let cost_after_upgrade = builder.exec_costs(2)[0];
+
+    assert!(
+        cost_after_upgrade > cost_before_upgrade,
+        "expected {:?} to be greater than {:?}",
+        cost_after_upgrade,
+        cost_before_upgrade
+    );
+}
+
 #[ignore]
 #[test]
 fn should_allow_only_wasm_costs_minor_version() {
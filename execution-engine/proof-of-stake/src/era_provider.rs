@@ -0,0 +1,15 @@
+use types::system_contract_errors::pos::Result;
+
+pub trait EraProvider {
+    /// Reads the height of the current era.
+    fn read_era_height(&mut self) -> u64;
+
+    /// Writes the height of the current era.
+    fn write_era_height(&mut self, era_height: u64) -> Result<()>;
+
+    /// Reads the number of eras after which a bonding request becomes effective.
+    fn read_bonding_delay(&mut self) -> Result<u64>;
+
+    /// Reads the number of eras after which an unbonding request is paid out.
+    fn read_unbonding_delay(&mut self) -> Result<u64>;
+}
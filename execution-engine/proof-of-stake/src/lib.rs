@@ -2,6 +2,7 @@
 
 extern crate alloc;
 
+mod era_provider;
 mod mint_provider;
 mod queue;
 mod queue_provider;
@@ -9,56 +10,48 @@ mod runtime_provider;
 mod stakes;
 mod stakes_provider;
 
+use alloc::vec::Vec;
 use core::marker::Sized;
 
 use types::{
     account::PublicKey,
     system_contract_errors::pos::{Error, Result},
-    AccessRights, TransferredTo, URef, U512,
+    AccessRights, URef, U512,
 };
 
 pub use crate::{
-    mint_provider::MintProvider, queue::Queue, queue_provider::QueueProvider,
-    runtime_provider::RuntimeProvider, stakes::Stakes, stakes_provider::StakesProvider,
+    era_provider::EraProvider, mint_provider::MintProvider, queue::Queue,
+    queue_provider::QueueProvider, runtime_provider::RuntimeProvider, stakes::Stakes,
+    stakes_provider::StakesProvider,
 };
 
 pub trait ProofOfStake:
-    MintProvider + QueueProvider + RuntimeProvider + StakesProvider + Sized
+    EraProvider + MintProvider + QueueProvider + RuntimeProvider + StakesProvider + Sized
 {
     fn bond(&mut self, validator: PublicKey, amount: U512, source: URef) -> Result<()> {
         if amount.is_zero() {
             return Err(Error::BondTooSmall);
         }
         let target = internal::get_bonding_purse(self)?;
-        let timestamp = self.get_block_time();
+        let era_height = self.read_era_height();
         // Transfer `amount` from the `source` purse to PoS internal purse. POS_PURSE is a constant,
         // it is the URef of the proof-of-stake contract's own purse.
         self.transfer_purse_to_purse(source, target, amount)
             .map_err(|_| Error::BondTransferFailed)?;
-        internal::bond(self, amount, validator, timestamp)?;
-
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            let _: TransferredTo = self
-                .transfer_purse_to_account(source, entry.validator, entry.amount)
-                .map_err(|_| Error::BondTransferFailed)?;
-        }
-        Ok(())
+        internal::bond(self, amount, validator, era_height)?;
+        // Without a bonding delay the bond takes effect immediately.
+        internal::apply_due(self, era_height)
     }
 
     fn unbond(&mut self, validator: PublicKey, maybe_amount: Option<U512>) -> Result<()> {
-        let pos_purse = internal::get_bonding_purse(self)?;
-        let timestamp = self.get_block_time();
-        internal::unbond(self, maybe_amount, validator, timestamp)?;
+        let era_height = self.read_era_height();
+        internal::unbond(self, maybe_amount, validator, era_height)?;
+        // Without an unbonding delay the unbond is paid out immediately.
+        internal::apply_due(self, era_height)
+    }
 
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            self.transfer_purse_to_account(pos_purse, entry.validator, entry.amount)
-                .map_err(|_| Error::UnbondTransferFailed)?;
-        }
-        Ok(())
+    fn unbond_payout(&mut self, era_height: u64) -> Result<()> {
+        internal::unbond_payout(self, era_height)
     }
 
     fn get_payment_purse(&self) -> Result<URef> {
@@ -82,6 +75,14 @@ pub trait ProofOfStake:
     fn finalize_payment(&mut self, amount_spent: U512, account: PublicKey) -> Result<()> {
         internal::finalize_payment(self, amount_spent, account)
     }
+
+    fn distribute_rewards(&mut self, rewards: Vec<(PublicKey, U512)>) -> Result<()> {
+        internal::distribute_rewards(self, rewards)
+    }
+
+    fn slash(&mut self, slashes: Vec<(PublicKey, U512)>) -> Result<()> {
+        internal::slash(self, slashes)
+    }
 }
 
 mod internal {
@@ -90,15 +91,17 @@ mod internal {
     use types::{
         account::PublicKey,
         system_contract_errors::pos::{Error, PurseLookupError, Result},
-        BlockTime, Key, Phase, URef, U512,
+        Key, Phase, URef, U512,
     };
 
     use crate::{
-        mint_provider::MintProvider, queue::QueueEntry, queue_provider::QueueProvider,
-        runtime_provider::RuntimeProvider, stakes_provider::StakesProvider,
+        era_provider::EraProvider, mint_provider::MintProvider, queue::QueueEntry,
+        queue_provider::QueueProvider, runtime_provider::RuntimeProvider,
+        stakes_provider::StakesProvider,
     };
 
-    /// Account used to run system functions (in particular `finalize_payment`).
+    /// Account used to run system functions (in particular `finalize_payment`,
+    /// `distribute_rewards`, `slash` and `unbond_payout`).
     const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
     /// The uref name where the PoS purse is stored. It contains all staked motes, and all unbonded
@@ -115,12 +118,6 @@ mod internal {
     /// corresponds to is set by the user.
     const REFUND_PURSE_KEY: &str = "pos_refund_purse";
 
-    /// The time from a bonding request until the bond becomes effective and part of the stake.
-    const BOND_DELAY: u64 = 0;
-
-    /// The time from an unbonding request until the stakes are paid out.
-    const UNBOND_DELAY: u64 = 0;
-
     /// The maximum number of pending bonding requests.
     const MAX_BOND_LEN: usize = 100;
 
@@ -133,7 +130,7 @@ mod internal {
         provider: &mut P,
         amount: U512,
         validator: PublicKey,
-        timestamp: BlockTime,
+        era_height: u64,
     ) -> Result<()> {
         let mut queue = provider.read_bonding();
         if queue.0.len() >= MAX_BOND_LEN {
@@ -147,7 +144,7 @@ mod internal {
         }
         stakes.validate_bonding(&validator, amount)?;
 
-        queue.push(validator, amount, timestamp)?;
+        queue.push(validator, amount, era_height)?;
         provider.write_bonding(queue);
         Ok(())
     }
@@ -159,7 +156,7 @@ mod internal {
         provider: &mut P,
         maybe_amount: Option<U512>,
         validator: PublicKey,
-        timestamp: BlockTime,
+        era_height: u64,
     ) -> Result<()> {
         let mut queue = provider.read_unbonding();
         if queue.0.len() >= MAX_UNBOND_LEN {
@@ -172,22 +169,31 @@ mod internal {
         // TODO: Make sure the destination is valid and the amount can be paid. The actual payment
         // will be made later, after the unbonding delay. contract_api::transfer_dry_run(POS_PURSE,
         // dest, amount)?;
-        queue.push(validator, payout, timestamp)?;
+        queue.push(validator, payout, era_height)?;
         provider.write_unbonding(queue);
         Ok(())
     }
 
-    /// Removes all due requests from the queues and applies them.
+    /// Removes all requests that are due at the given era height from the queues and applies them.
+    /// Bonding requests become due `bonding_delay` eras, and unbonding requests `unbonding_delay`
+    /// eras after the era in which they were made.
     pub fn step<P: QueueProvider + StakesProvider>(
         provider: &mut P,
-        timestamp: BlockTime,
+        era_height: u64,
+        bonding_delay: u64,
+        unbonding_delay: u64,
     ) -> Result<Vec<QueueEntry>> {
         let mut bonding_queue = provider.read_bonding();
         let mut unbonding_queue = provider.read_unbonding();
 
-        let bonds = bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(BOND_DELAY)));
-        let unbonds =
-            unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(UNBOND_DELAY)));
+        let bonds = match era_height.checked_sub(bonding_delay) {
+            Some(due_era_height) => bonding_queue.pop_due(due_era_height),
+            None => Vec::new(),
+        };
+        let unbonds = match era_height.checked_sub(unbonding_delay) {
+            Some(due_era_height) => unbonding_queue.pop_due(due_era_height),
+            None => Vec::new(),
+        };
 
         if !unbonds.is_empty() {
             provider.write_unbonding(unbonding_queue);
@@ -205,6 +211,51 @@ mod internal {
         Ok(unbonds)
     }
 
+    /// Advances the current era to `era_height`, applies all due bonding requests and pays all due
+    /// unbonding requests out of the bonding purse to the main purses of the validators' accounts.
+    pub fn unbond_payout<
+        P: EraProvider + MintProvider + QueueProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+        era_height: u64,
+    ) -> Result<()> {
+        let caller = provider.get_caller();
+        if caller != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+
+        if era_height < provider.read_era_height() {
+            return Err(Error::EraWentBackwards);
+        }
+        provider.write_era_height(era_height)?;
+
+        apply_due(provider, era_height)
+    }
+
+    /// Applies all bonding requests and pays out all unbonding requests which are due at the given
+    /// era height.
+    pub fn apply_due<
+        P: EraProvider + MintProvider + QueueProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+        era_height: u64,
+    ) -> Result<()> {
+        let bonding_delay = provider.read_bonding_delay()?;
+        let unbonding_delay = provider.read_unbonding_delay()?;
+        let unbonds = step(provider, era_height, bonding_delay, unbonding_delay)?;
+        if unbonds.is_empty() {
+            return Ok(());
+        }
+
+        let bonding_purse = get_bonding_purse(provider)?;
+        for entry in unbonds {
+            provider
+                .transfer_purse_to_account(bonding_purse, entry.validator, entry.amount)
+                .map_err(|_| Error::UnbondTransferFailed)?;
+        }
+        Ok(())
+    }
+
     /// Attempts to look up a purse from the named_keys
     fn get_purse<R: RuntimeProvider>(
         runtime_provider: &R,
@@ -320,15 +371,93 @@ mod internal {
         }
     }
 
+    /// Transfers the given rewards from the validator rewards purse to the main purses of the
+    /// validators' accounts. Nothing is transferred unless the rewards purse holds enough motes to
+    /// pay all of the rewards.
+    pub fn distribute_rewards<P: MintProvider + RuntimeProvider>(
+        provider: &mut P,
+        rewards: Vec<(PublicKey, U512)>,
+    ) -> Result<()> {
+        let caller = provider.get_caller();
+        if caller != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+
+        let rewards_purse = get_rewards_purse(provider)?;
+        let available = match provider.balance(rewards_purse) {
+            Some(balance) => balance,
+            None => return Err(Error::RewardsPurseBalanceNotFound),
+        };
+        let total = rewards
+            .iter()
+            .try_fold(U512::zero(), |total, (_, amount)| {
+                total.checked_add(*amount)
+            })
+            .ok_or(Error::InsufficientRewardsForDistribution)?;
+        if available < total {
+            return Err(Error::InsufficientRewardsForDistribution);
+        }
+
+        for (validator, amount) in rewards {
+            if amount.is_zero() {
+                continue;
+            }
+            provider
+                .transfer_purse_to_account(rewards_purse, validator, amount)
+                .map_err(|_| Error::FailedTransferToValidatorPurse)?;
+        }
+
+        Ok(())
+    }
+
+    /// Slashes the given validators. Each validator's stakes are decreased by the given amount, and
+    /// any of their pending unbonding requests are dropped. The slashed motes, including those of
+    /// the dropped unbonding requests, are moved from the bonding purse to the rewards purse.
+    pub fn slash<P: MintProvider + QueueProvider + RuntimeProvider + StakesProvider>(
+        provider: &mut P,
+        slashes: Vec<(PublicKey, U512)>,
+    ) -> Result<()> {
+        let caller = provider.get_caller();
+        if caller != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+
+        let mut stakes = provider.read()?;
+        let mut unbonding_queue = provider.read_unbonding();
+        let mut total = U512::zero();
+        for (validator, amount) in slashes {
+            let unbonds = unbonding_queue.remove_validator(&validator);
+            if unbonds.is_empty() && !stakes.0.contains_key(&validator) {
+                return Err(Error::NotBonded);
+            }
+            total = unbonds
+                .iter()
+                .fold(total, |total, entry| total.saturating_add(entry.amount))
+                .saturating_add(stakes.slash(&validator, amount));
+        }
+        provider.write(&stakes);
+        provider.write_unbonding(unbonding_queue);
+
+        if total.is_zero() {
+            return Ok(());
+        }
+
+        let bonding_purse = get_bonding_purse(provider)?;
+        let rewards_purse = get_rewards_purse(provider)?;
+        provider
+            .transfer_purse_to_purse(bonding_purse, rewards_purse, total)
+            .map_err(|_| Error::SlashTransferFailed)
+    }
+
     #[cfg(test)]
     mod tests {
         extern crate std;
 
         use std::{cell::RefCell, iter, thread_local};
 
-        use types::{account::PublicKey, system_contract_errors::pos::Result, BlockTime, U512};
+        use types::{account::PublicKey, system_contract_errors::pos::Result, U512};
 
-        use super::{bond, step, unbond, BOND_DELAY, UNBOND_DELAY};
+        use super::{bond, step, unbond};
         use crate::{
             queue::Queue, queue_provider::QueueProvider, stakes::Stakes,
             stakes_provider::StakesProvider,
@@ -337,6 +466,9 @@ mod internal {
         const KEY1: [u8; 32] = [1; 32];
         const KEY2: [u8; 32] = [2; 32];
 
+        const BOND_DELAY: u64 = 2;
+        const UNBOND_DELAY: u64 = 3;
+
         thread_local! {
             static BONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
             static UNBONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
@@ -392,28 +524,37 @@ mod internal {
                 &mut provider,
                 U512::from(500),
                 PublicKey::ed25519_from(KEY2),
-                BlockTime::new(1),
+                1,
             )
             .expect("bond validator 2");
 
             // Bonding becomes effective only after the delay.
             assert_stakes(&[(KEY1, 1_000)]);
-            step(&mut provider, BlockTime::new(BOND_DELAY)).expect("step 1");
+            step(&mut provider, BOND_DELAY, BOND_DELAY, UNBOND_DELAY).expect("step 1");
             assert_stakes(&[(KEY1, 1_000)]);
-            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 2");
+            step(&mut provider, 1 + BOND_DELAY, BOND_DELAY, UNBOND_DELAY).expect("step 2");
             assert_stakes(&[(KEY1, 1_000), (KEY2, 500)]);
 
             unbond::<Provider>(
                 &mut provider,
                 Some(U512::from(500)),
                 PublicKey::ed25519_from(KEY1),
-                BlockTime::new(2),
+                2,
             )
             .expect("partly unbond validator 1");
 
-            // Unbonding becomes effective immediately.
+            // Unbonding becomes effective immediately, but is paid out only after the delay.
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
-            step::<Provider>(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 3");
+            let unbonds =
+                step::<Provider>(&mut provider, 1 + UNBOND_DELAY, BOND_DELAY, UNBOND_DELAY)
+                    .expect("step 3");
+            assert!(unbonds.is_empty());
+            let unbonds =
+                step::<Provider>(&mut provider, 2 + UNBOND_DELAY, BOND_DELAY, UNBOND_DELAY)
+                    .expect("step 4");
+            assert_eq!(1, unbonds.len());
+            assert_eq!(PublicKey::ed25519_from(KEY1), unbonds[0].validator);
+            assert_eq!(U512::from(500), unbonds[0].amount);
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
         }
     }
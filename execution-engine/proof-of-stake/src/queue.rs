@@ -5,7 +5,7 @@ use types::{
     account::PublicKey,
     bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
     system_contract_errors::pos::{Error, Result},
-    BlockTime, CLType, CLTyped, U512,
+    CLType, CLTyped, U512,
 };
 
 /// A pending entry in the bonding or unbonding queue.
@@ -15,17 +15,17 @@ pub struct QueueEntry {
     pub validator: PublicKey,
     /// The amount by which to change the stakes.
     pub amount: U512,
-    /// The timestamp when the request was made.
-    pub timestamp: BlockTime,
+    /// The height of the era in which the request was made.
+    pub era_height: u64,
 }
 
 impl QueueEntry {
-    /// Creates a new `QueueEntry` with the current block's timestamp.
-    fn new(validator: PublicKey, amount: U512, timestamp: BlockTime) -> QueueEntry {
+    /// Creates a new `QueueEntry` with the current era's height.
+    fn new(validator: PublicKey, amount: U512, era_height: u64) -> QueueEntry {
         QueueEntry {
             validator,
             amount,
-            timestamp,
+            era_height,
         }
     }
 }
@@ -35,14 +35,14 @@ impl ToBytes for QueueEntry {
         let mut bytes = bytesrepr::allocate_buffer(self)?;
         bytes.append(&mut self.validator.to_bytes()?);
         bytes.append(&mut self.amount.to_bytes()?);
-        bytes.append(&mut self.timestamp.to_bytes()?);
+        bytes.append(&mut self.era_height.to_bytes()?);
         Ok(bytes)
     }
 
     fn serialized_length(&self) -> usize {
         self.validator.serialized_length()
             + self.amount.serialized_length()
-            + self.timestamp.serialized_length()
+            + self.era_height.serialized_length()
     }
 }
 
@@ -50,11 +50,11 @@ impl FromBytes for QueueEntry {
     fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
         let (validator, bytes) = PublicKey::from_bytes(bytes)?;
         let (amount, bytes) = U512::from_bytes(bytes)?;
-        let (timestamp, bytes) = BlockTime::from_bytes(bytes)?;
+        let (era_height, bytes) = u64::from_bytes(bytes)?;
         let entry = QueueEntry {
             validator,
             amount,
-            timestamp,
+            era_height,
         };
         Ok((entry, bytes))
     }
@@ -66,7 +66,7 @@ impl CLTyped for QueueEntry {
     }
 }
 
-/// A queue of bonding or unbonding requests, sorted by timestamp in ascending order.
+/// A queue of bonding or unbonding requests, sorted by era height in ascending order.
 #[derive(Clone, Default, PartialEq)]
 pub struct Queue(pub Vec<QueueEntry>);
 
@@ -74,28 +74,38 @@ impl Queue {
     /// Pushes a new entry to the end of the queue.
     ///
     /// Returns an error if the validator already has a request in the queue.
-    pub fn push(&mut self, validator: PublicKey, amount: U512, timestamp: BlockTime) -> Result<()> {
+    pub fn push(&mut self, validator: PublicKey, amount: U512, era_height: u64) -> Result<()> {
         if self.0.iter().any(|entry| entry.validator == validator) {
             return Err(Error::MultipleRequests);
         }
         if let Some(entry) = self.0.last() {
-            if entry.timestamp > timestamp {
-                return Err(Error::TimeWentBackwards);
+            if entry.era_height > era_height {
+                return Err(Error::EraWentBackwards);
             }
         }
-        self.0.push(QueueEntry::new(validator, amount, timestamp));
+        self.0.push(QueueEntry::new(validator, amount, era_height));
         Ok(())
     }
 
-    /// Returns all queue entries at least as old as the specified timestamp.
-    pub fn pop_due(&mut self, timestamp: BlockTime) -> Vec<QueueEntry> {
+    /// Returns all queue entries at least as old as the specified era height.
+    pub fn pop_due(&mut self, era_height: u64) -> Vec<QueueEntry> {
         let (older_than, rest) = self
             .0
             .iter()
-            .partition(|entry| entry.timestamp <= timestamp);
+            .partition(|entry| entry.era_height <= era_height);
         self.0 = rest;
         older_than
     }
+
+    /// Removes and returns all entries of the given validator.
+    pub fn remove_validator(&mut self, validator: &PublicKey) -> Vec<QueueEntry> {
+        let (removed, rest) = self
+            .0
+            .iter()
+            .partition(|entry| entry.validator == *validator);
+        self.0 = rest;
+        removed
+    }
 }
 
 impl ToBytes for Queue {
@@ -136,9 +146,7 @@ impl CLTyped for Queue {
 mod tests {
     use alloc::vec;
 
-    use types::{
-        account::PublicKey, bytesrepr, system_contract_errors::pos::Error, BlockTime, U512,
-    };
+    use types::{account::PublicKey, bytesrepr, system_contract_errors::pos::Error, U512};
 
     use super::{Queue, QueueEntry};
 
@@ -152,15 +160,15 @@ mod tests {
         let val2 = PublicKey::ed25519_from(KEY2);
         let val3 = PublicKey::ed25519_from(KEY3);
         let mut queue: Queue = Default::default();
-        assert_eq!(Ok(()), queue.push(val1, U512::from(5), BlockTime::new(100)));
-        assert_eq!(Ok(()), queue.push(val2, U512::from(5), BlockTime::new(101)));
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), 100));
+        assert_eq!(Ok(()), queue.push(val2, U512::from(5), 101));
         assert_eq!(
             Err(Error::MultipleRequests),
-            queue.push(val1, U512::from(5), BlockTime::new(102))
+            queue.push(val1, U512::from(5), 102)
         );
         assert_eq!(
-            Err(Error::TimeWentBackwards),
-            queue.push(val3, U512::from(5), BlockTime::new(100))
+            Err(Error::EraWentBackwards),
+            queue.push(val3, U512::from(5), 100)
         );
     }
 
@@ -170,19 +178,37 @@ mod tests {
         let val2 = PublicKey::ed25519_from(KEY2);
         let val3 = PublicKey::ed25519_from(KEY3);
         let mut queue: Queue = Default::default();
-        assert_eq!(Ok(()), queue.push(val1, U512::from(5), BlockTime::new(100)));
-        assert_eq!(Ok(()), queue.push(val2, U512::from(6), BlockTime::new(101)));
-        assert_eq!(Ok(()), queue.push(val3, U512::from(7), BlockTime::new(102)));
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), 100));
+        assert_eq!(Ok(()), queue.push(val2, U512::from(6), 101));
+        assert_eq!(Ok(()), queue.push(val3, U512::from(7), 102));
         assert_eq!(
             vec![
-                QueueEntry::new(val1, U512::from(5), BlockTime::new(100)),
-                QueueEntry::new(val2, U512::from(6), BlockTime::new(101)),
+                QueueEntry::new(val1, U512::from(5), 100),
+                QueueEntry::new(val2, U512::from(6), 101),
             ],
-            queue.pop_due(BlockTime::new(101))
+            queue.pop_due(101)
+        );
+        assert_eq!(
+            vec![QueueEntry::new(val3, U512::from(7), 102),],
+            queue.pop_due(105)
+        );
+    }
+
+    #[test]
+    fn test_remove_validator() {
+        let val1 = PublicKey::ed25519_from(KEY1);
+        let val2 = PublicKey::ed25519_from(KEY2);
+        let mut queue: Queue = Default::default();
+        assert_eq!(Ok(()), queue.push(val1, U512::from(5), 100));
+        assert_eq!(Ok(()), queue.push(val2, U512::from(6), 101));
+        assert_eq!(
+            vec![QueueEntry::new(val1, U512::from(5), 100)],
+            queue.remove_validator(&val1)
         );
+        assert!(queue.remove_validator(&val1).is_empty());
         assert_eq!(
-            vec![QueueEntry::new(val3, U512::from(7), BlockTime::new(102)),],
-            queue.pop_due(BlockTime::new(105))
+            vec![QueueEntry::new(val2, U512::from(6), 101)],
+            queue.pop_due(105)
         );
     }
 
@@ -192,9 +218,9 @@ mod tests {
         let val2 = PublicKey::ed25519_from(KEY2);
         let val3 = PublicKey::ed25519_from(KEY3);
         let mut queue: Queue = Default::default();
-        queue.push(val1, U512::from(5), BlockTime::new(0)).unwrap();
-        queue.push(val2, U512::from(6), BlockTime::new(1)).unwrap();
-        queue.push(val3, U512::from(7), BlockTime::new(2)).unwrap();
+        queue.push(val1, U512::from(5), 0).unwrap();
+        queue.push(val2, U512::from(6), 1).unwrap();
+        queue.push(val3, U512::from(7), 2).unwrap();
         bytesrepr::test_serialization_roundtrip(&queue);
     }
 }
@@ -109,6 +109,24 @@ impl Stakes {
         Ok(stake)
     }
 
+    /// Subtracts `amount` from the validator's stakes, removing the validator if the stakes are
+    /// not greater than `amount`. Unlike unbonding, slashing is not subject to the spread and
+    /// maximum decrease limits.
+    ///
+    /// Returns the amount that was actually subtracted from the stakes, which is zero if the
+    /// validator was not bonded.
+    pub fn slash(&mut self, validator: &PublicKey, amount: U512) -> U512 {
+        let stake = match self.0.get_mut(validator) {
+            Some(stake) => stake,
+            None => return U512::zero(),
+        };
+        if *stake > amount {
+            *stake -= amount;
+            return amount;
+        }
+        self.0.remove(validator).unwrap_or_else(U512::zero)
+    }
+
     /// Adds `amount` to the validator's stakes.
     pub fn bond(&mut self, validator: &PublicKey, amount: U512) {
         self.0
@@ -259,6 +277,31 @@ mod tests {
         assert_eq!(new_stakes(&[(KEY1, 46)]), stakes);
     }
 
+    #[test]
+    fn test_slash() {
+        let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
+        assert_eq!(
+            U512::from(4),
+            stakes.slash(&PublicKey::ed25519_from(KEY1), U512::from(4))
+        );
+        assert_eq!(new_stakes(&[(KEY1, 46), (KEY2, 100)]), stakes);
+    }
+
+    #[test]
+    fn test_slash_entire_stake() {
+        let mut stakes = new_stakes(&[(KEY1, 5), (KEY2, 100)]);
+        assert_eq!(
+            U512::from(5),
+            stakes.slash(&PublicKey::ed25519_from(KEY1), U512::from(10))
+        );
+        assert_eq!(new_stakes(&[(KEY2, 100)]), stakes);
+        assert_eq!(
+            U512::zero(),
+            stakes.slash(&PublicKey::ed25519_from(KEY1), U512::from(10))
+        );
+        assert_eq!(new_stakes(&[(KEY2, 100)]), stakes);
+    }
+
     #[test]
     fn test_unbond_too_much_rel() {
         let mut stakes = new_stakes(&[(KEY1, 999), (KEY2, 1)]);
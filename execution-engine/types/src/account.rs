@@ -2,8 +2,10 @@
 
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cmp::Ordering,
     convert::TryFrom,
     fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
 };
 
 use failure::Fail;
@@ -143,18 +145,40 @@ impl CLTyped for Weight {
         CLType::U8
     }
 }
-/// The length in bytes of a [`PublicKey`].
+
+/// The length in bytes of an [`Ed25519`] public key.
 pub const ED25519_LENGTH: usize = 32;
 
 /// The number of bytes in a serialized [`Ed25519`].
 pub const ED25519_SERIALIZED_LENGTH: usize = ED25519_LENGTH;
 
+/// The length in bytes of a compressed [`Secp256k1`] public key.
+pub const SECP256K1_LENGTH: usize = 33;
+
+/// The number of bytes in a serialized [`Secp256k1`].
+pub const SECP256K1_SERIALIZED_LENGTH: usize = SECP256K1_LENGTH;
+
 /// The upper bound of bytes in a serialized [`PublicKey`].
-pub const PUBLIC_KEY_SERIALIZED_MAX_LENGTH: usize = ED25519_SERIALIZED_LENGTH;
+pub const PUBLIC_KEY_SERIALIZED_MAX_LENGTH: usize =
+    ED25519_SERIALIZED_LENGTH + SECP256K1_SERIALIZED_LENGTH;
 
 /// A type alias for the raw bytes of an Ed25519 public key.
 pub type Ed25519Bytes = [u8; ED25519_LENGTH];
 
+/// Serialized in front of a [`Secp256k1`] public key.
+///
+/// This deviates from the usual tagged encoding of an enum on purpose: Ed25519 keys keep their
+/// untagged 32-byte encoding, so the account hashes and stored keys created before secp256k1
+/// support don't need a migration.  The marker's y coordinate is the field prime 2^255 - 19, which
+/// RFC 8032 decoding rejects as non-canonical, so no Ed25519 key pair ever produces it as its
+/// public key.  Lenient decoders reduce it to the small-order point with y = 0, which isn't a key
+/// either.  Serializing an Ed25519 key equal to the marker fails rather than being read back as a
+/// secp256k1 key.
+const SECP256K1_MARKER: Ed25519Bytes = [
+    0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+];
+
 /// A newtype wrapping a [`Ed25519Bytes`] which is the raw bytes of
 /// the public key of an Ed25519 key pair.
 #[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
@@ -200,17 +224,98 @@ impl FromBytes for Ed25519 {
     }
 }
 
+/// A type alias for the raw bytes of a compressed secp256k1 public key.
+pub type Secp256k1Bytes = [u8; SECP256K1_LENGTH];
+
+/// A newtype wrapping a [`Secp256k1Bytes`] which is the raw bytes of
+/// the compressed public key of a secp256k1 key pair.
+#[derive(Clone, Copy)]
+pub struct Secp256k1(Secp256k1Bytes);
+
+impl Secp256k1 {
+    /// Constructs a new `Secp256k1` instance from the raw bytes of a compressed secp256k1 public
+    /// key.
+    pub const fn new(value: Secp256k1Bytes) -> Secp256k1 {
+        Secp256k1(value)
+    }
+
+    /// Returns the raw bytes of the public key as an array.
+    pub fn value(&self) -> Secp256k1Bytes {
+        self.0
+    }
+
+    /// Returns the raw bytes of the public key as a `slice`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// The std trait impls for arrays only cover lengths up to 32, so these are implemented in terms of
+// the slices.
+impl PartialEq for Secp256k1 {
+    fn eq(&self, other: &Secp256k1) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for Secp256k1 {}
+
+impl PartialOrd for Secp256k1 {
+    fn partial_cmp(&self, other: &Secp256k1) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Secp256k1 {
+    fn cmp(&self, other: &Secp256k1) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl Hash for Secp256k1 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
+
+impl Display for Secp256k1 {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "Secp256k1({})", HexFmt(&self.0[..]))
+    }
+}
+
+impl ToBytes for Secp256k1 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        SECP256K1_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for Secp256k1 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (bytes, rem) = <[u8; SECP256K1_LENGTH]>::from_bytes(bytes)?;
+        Ok((Secp256k1::new(bytes), rem))
+    }
+}
+
 /// An enum of supported public key types.
 #[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum PublicKey {
     /// An Ed25519 public key type.
     Ed25519(Ed25519),
+    /// A compressed secp256k1 public key type.
+    Secp256k1(Secp256k1),
 }
 
 impl Display for PublicKey {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
-        let PublicKey::Ed25519(ed25519) = self;
-        write!(f, "PublicKey({})", ed25519)
+        match self {
+            PublicKey::Ed25519(ed25519) => write!(f, "PublicKey({})", ed25519),
+            PublicKey::Secp256k1(secp256k1) => write!(f, "PublicKey({})", secp256k1),
+        }
     }
 }
 
@@ -228,17 +333,42 @@ impl PublicKey {
             .map_err(|_| TryFromSliceForPublicKeyError(()))
     }
 
-    /// Returns the raw bytes of the public key as an array.
-    #[doc(hidden)]
-    pub fn value(self) -> Ed25519Bytes {
-        let PublicKey::Ed25519(ed25519) = self;
-        ed25519.value()
+    /// Constructs a new `PublicKey` using compressed secp256k1 bytes.
+    pub const fn secp256k1_from(key: Secp256k1Bytes) -> PublicKey {
+        let secp256k1 = Secp256k1::new(key);
+        PublicKey::Secp256k1(secp256k1)
+    }
+
+    /// Attemps a new secp256k1 `PublicKey` creation using a slice of bytes.
+    pub fn secp256k1_try_from(bytes: &[u8]) -> Result<PublicKey, TryFromSliceForPublicKeyError> {
+        if bytes.len() != SECP256K1_LENGTH {
+            return Err(TryFromSliceForPublicKeyError(()));
+        }
+        let mut secp256k1_bytes = [0u8; SECP256K1_LENGTH];
+        secp256k1_bytes.copy_from_slice(bytes);
+        Ok(PublicKey::secp256k1_from(secp256k1_bytes))
     }
 
     /// Returns the raw bytes of the public key as a `slice`.
     pub fn as_bytes(&self) -> &[u8] {
-        let PublicKey::Ed25519(ed25519) = self;
-        ed25519.as_bytes()
+        match self {
+            PublicKey::Ed25519(ed25519) => ed25519.as_bytes(),
+            PublicKey::Secp256k1(secp256k1) => secp256k1.as_bytes(),
+        }
+    }
+}
+
+/// Creates a `PublicKey` from the raw bytes of a public key, using the length of `bytes` to tell
+/// the key types apart.
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = TryFromSliceForPublicKeyError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes.len() {
+            ED25519_LENGTH => PublicKey::ed25519_try_from(bytes),
+            SECP256K1_LENGTH => PublicKey::secp256k1_try_from(bytes),
+            _ => Err(TryFromSliceForPublicKeyError(())),
+        }
     }
 }
 
@@ -260,22 +390,46 @@ impl From<Ed25519> for PublicKey {
     }
 }
 
+impl From<Secp256k1> for PublicKey {
+    fn from(secp256k1: Secp256k1) -> PublicKey {
+        PublicKey::Secp256k1(secp256k1)
+    }
+}
+
 impl ToBytes for PublicKey {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        let PublicKey::Ed25519(ed25519) = self;
-        let mut bytes = Vec::with_capacity(PUBLIC_KEY_SERIALIZED_MAX_LENGTH);
-        bytes.append(&mut ed25519.to_bytes()?);
+        let mut bytes = Vec::with_capacity(self.serialized_length());
+        match self {
+            PublicKey::Ed25519(ed25519) => {
+                // Such a key would be read back as a secp256k1 key.
+                if ed25519.value() == SECP256K1_MARKER {
+                    return Err(Error::Formatting);
+                }
+                bytes.append(&mut ed25519.to_bytes()?);
+            }
+            PublicKey::Secp256k1(secp256k1) => {
+                bytes.append(&mut SECP256K1_MARKER.to_bytes()?);
+                bytes.append(&mut secp256k1.to_bytes()?);
+            }
+        }
         Ok(bytes)
     }
 
     fn serialized_length(&self) -> usize {
-        PUBLIC_KEY_SERIALIZED_MAX_LENGTH
+        match self {
+            PublicKey::Ed25519(_) => ED25519_SERIALIZED_LENGTH,
+            PublicKey::Secp256k1(_) => ED25519_SERIALIZED_LENGTH + SECP256K1_SERIALIZED_LENGTH,
+        }
     }
 }
 
 impl FromBytes for PublicKey {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
         let (ed25519, rem) = Ed25519::from_bytes(bytes)?;
+        if ed25519.value() == SECP256K1_MARKER {
+            let (secp256k1, rem) = Secp256k1::from_bytes(rem)?;
+            return Ok((PublicKey::from(secp256k1), rem));
+        }
         Ok((PublicKey::from(ed25519), rem))
     }
 }
@@ -389,6 +543,7 @@ mod tests {
     use std::{convert::TryFrom, vec::Vec};
 
     use super::*;
+    use crate::{bytesrepr, U256};
 
     #[test]
     fn ed25519_public_key_from_slice() {
@@ -408,6 +563,83 @@ mod tests {
             PublicKey::ed25519_try_from(&[0u8; 33][..]).expect_err("should not create public key");
     }
 
+    #[test]
+    fn secp256k1_public_key_from_slice() {
+        let bytes: Vec<u8> = (0..33).collect();
+        let public_key =
+            PublicKey::secp256k1_try_from(&bytes[..]).expect("should create public key");
+        assert_eq!(&bytes, &public_key.as_bytes());
+    }
+
+    #[test]
+    fn secp256k1_public_key_from_slice_wrong_length() {
+        let _public_key = PublicKey::secp256k1_try_from(&[0u8; 32][..])
+            .expect_err("should not create public key");
+        let _public_key = PublicKey::secp256k1_try_from(&[0u8; 34][..])
+            .expect_err("should not create public key");
+    }
+
+    #[test]
+    fn public_key_try_from_slice_by_length() {
+        assert_eq!(
+            PublicKey::try_from(&[1u8; ED25519_LENGTH][..]).expect("should create public key"),
+            PublicKey::ed25519_from([1u8; ED25519_LENGTH])
+        );
+        assert_eq!(
+            PublicKey::try_from(&[1u8; SECP256K1_LENGTH][..]).expect("should create public key"),
+            PublicKey::secp256k1_from([1u8; SECP256K1_LENGTH])
+        );
+        let _public_key =
+            PublicKey::try_from(&[0u8; 31][..]).expect_err("should not create public key");
+    }
+
+    #[test]
+    fn public_key_serialized_lengths() {
+        let ed25519 = PublicKey::ed25519_from([0u8; ED25519_LENGTH]);
+        let secp256k1 = PublicKey::secp256k1_from([0u8; SECP256K1_LENGTH]);
+        assert_eq!(
+            ed25519.to_bytes().unwrap().len(),
+            ed25519.serialized_length()
+        );
+        assert_eq!(
+            secp256k1.to_bytes().unwrap().len(),
+            PUBLIC_KEY_SERIALIZED_MAX_LENGTH
+        );
+    }
+
+    #[test]
+    fn ed25519_public_key_should_serialize_untagged() {
+        let public_key = PublicKey::ed25519_from([42u8; ED25519_LENGTH]);
+        assert_eq!(public_key.to_bytes().unwrap(), vec![42u8; ED25519_LENGTH]);
+        bytesrepr::test_serialization_roundtrip(&public_key);
+    }
+
+    #[test]
+    fn secp256k1_public_key_should_serialize_after_marker() {
+        let public_key = PublicKey::secp256k1_from([42u8; SECP256K1_LENGTH]);
+        let bytes = public_key.to_bytes().unwrap();
+        assert_eq!(&bytes[..ED25519_LENGTH], &SECP256K1_MARKER[..]);
+        assert_eq!(&bytes[ED25519_LENGTH..], &[42u8; SECP256K1_LENGTH][..]);
+        bytesrepr::test_serialization_roundtrip(&public_key);
+    }
+
+    #[test]
+    fn secp256k1_marker_should_not_be_a_canonical_ed25519_point() {
+        // The low 255 bits hold the y coordinate and the top bit the sign of x.
+        let mut y_bytes = SECP256K1_MARKER;
+        y_bytes[ED25519_LENGTH - 1] &= 0x7f;
+        let y = U256::from_little_endian(&y_bytes);
+        let field_prime = (U256::one() << 255) - U256::from(19);
+        assert!(y >= field_prime);
+        assert_eq!(y % field_prime, U256::zero());
+    }
+
+    #[test]
+    fn ed25519_public_key_equal_to_marker_should_not_serialize() {
+        let public_key = PublicKey::ed25519_from(SECP256K1_MARKER);
+        assert_eq!(public_key.to_bytes(), Err(Error::Formatting));
+    }
+
     #[test]
     fn try_from_i32_for_set_threshold_failure() {
         let max_valid_value_for_variant = SetThresholdFailure::InsufficientTotalWeight as i32;
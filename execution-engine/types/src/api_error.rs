@@ -265,6 +265,30 @@ const MINT_ERROR_OFFSET: u32 = (POS_ERROR_OFFSET - 1) - u8::MAX as u32; // 65024
 /// # show_and_check!(
 /// 65_306 => PosError::SetRefundPurseCalledOutsidePayment
 /// # );
+/// # show_and_check!(
+/// 65_307 => PosError::RewardsPurseBalanceNotFound
+/// # );
+/// # show_and_check!(
+/// 65_308 => PosError::InsufficientRewardsForDistribution
+/// # );
+/// # show_and_check!(
+/// 65_309 => PosError::FailedTransferToValidatorPurse
+/// # );
+/// # show_and_check!(
+/// 65_310 => PosError::SlashTransferFailed
+/// # );
+/// # show_and_check!(
+/// 65_311 => PosError::BondingDelayNotFound
+/// # );
+/// # show_and_check!(
+/// 65_312 => PosError::UnbondingDelayNotFound
+/// # );
+/// # show_and_check!(
+/// 65_313 => PosError::EraWentBackwards
+/// # );
+/// # show_and_check!(
+/// 65_314 => PosError::EraHeightWriteFailed
+/// # );
 ///
 /// // User-defined errors:
 /// # show_and_check!(
@@ -466,7 +466,7 @@ impl_to_from_bytes_for_byte_array! {
      0  1  2  3  4  5  6  7  8  9
     10 11 12 13 14 15 16 17 18 19
     20 21 22 23 24 25 26 27 28 29
-    30 31 32
+    30 31 32 33
     64 128 256 512
 }
 
@@ -867,6 +867,16 @@ mod proptests {
             bytesrepr::test_serialization_roundtrip(&cl_value);
         }
 
+        #[test]
+        fn test_entry_point_serialization(entry_point in entry_point_arb()) {
+            bytesrepr::test_serialization_roundtrip(&entry_point);
+        }
+
+        #[test]
+        fn test_runtime_args_serialization(runtime_args in runtime_args_arb()) {
+            bytesrepr::test_serialization_roundtrip(&runtime_args);
+        }
+
         #[test]
         fn test_access_rights(access_right in access_rights_arb()) {
             bytesrepr::test_serialization_roundtrip(&access_right);
@@ -0,0 +1,114 @@
+//! Types describing the functions a stored contract can be called at.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType,
+};
+
+/// A named, typed argument of an [`EntryPoint`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Parameter {
+    name: String,
+    cl_type: CLType,
+}
+
+impl Parameter {
+    /// Constructs a new `Parameter`.
+    pub fn new<T: Into<String>>(name: T, cl_type: CLType) -> Self {
+        Parameter {
+            name: name.into(),
+            cl_type,
+        }
+    }
+
+    /// The name of the argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The [`CLType`] of the argument.
+    pub fn cl_type(&self) -> &CLType {
+        &self.cl_type
+    }
+}
+
+impl ToBytes for Parameter {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.name.to_bytes()?);
+        self.cl_type.append_bytes(&mut result);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length() + self.cl_type.serialized_length()
+    }
+}
+
+impl FromBytes for Parameter {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (cl_type, remainder) = CLType::from_bytes(remainder)?;
+        Ok((Parameter { name, cl_type }, remainder))
+    }
+}
+
+/// The schema of a function exported by a stored contract: its name, the arguments it takes and
+/// the type of the value it returns.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct EntryPoint {
+    name: String,
+    args: Vec<Parameter>,
+    ret: CLType,
+}
+
+impl EntryPoint {
+    /// Constructs a new `EntryPoint`.
+    pub fn new<T: Into<String>>(name: T, args: Vec<Parameter>, ret: CLType) -> Self {
+        EntryPoint {
+            name: name.into(),
+            args,
+            ret,
+        }
+    }
+
+    /// The name under which the function is exported.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments the function takes, in the order they're passed.
+    pub fn args(&self) -> &[Parameter] {
+        &self.args
+    }
+
+    /// The [`CLType`] of the value the function returns.
+    pub fn ret(&self) -> &CLType {
+        &self.ret
+    }
+}
+
+impl ToBytes for EntryPoint {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.name.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        self.ret.append_bytes(&mut result);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length() + self.args.serialized_length() + self.ret.serialized_length()
+    }
+}
+
+impl FromBytes for EntryPoint {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (args, remainder) = Vec::<Parameter>::from_bytes(remainder)?;
+        let (ret, remainder) = CLType::from_bytes(remainder)?;
+        Ok((EntryPoint { name, args, ret }, remainder))
+    }
+}
@@ -13,8 +13,9 @@ use proptest::{
 };
 
 use crate::{
-    account::{PublicKey, Weight},
-    AccessRights, CLType, CLValue, Key, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
+    account::{PublicKey, Weight, SECP256K1_LENGTH},
+    AccessRights, CLType, CLValue, EntryPoint, Key, Parameter, Phase, ProtocolVersion, RuntimeArgs,
+    SemVer, URef, U128, U256, U512,
 };
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
@@ -64,10 +65,20 @@ pub fn key_arb() -> impl Strategy<Value = Key> {
     ]
 }
 
-pub fn public_key_arb() -> impl Strategy<Value = PublicKey> {
+pub fn ed25519_public_key_arb() -> impl Strategy<Value = PublicKey> {
     u8_slice_32().prop_map(PublicKey::ed25519_from)
 }
 
+pub fn secp256k1_public_key_arb() -> impl Strategy<Value = PublicKey> {
+    vec(any::<u8>(), SECP256K1_LENGTH).prop_map(|bytes| {
+        PublicKey::secp256k1_try_from(&bytes).expect("should have secp256k1 key length")
+    })
+}
+
+pub fn public_key_arb() -> impl Strategy<Value = PublicKey> {
+    prop_oneof![ed25519_public_key_arb(), secp256k1_public_key_arb()]
+}
+
 pub fn weight_arb() -> impl Strategy<Value = Weight> {
     any::<u8>().prop_map(Weight::new)
 }
@@ -153,6 +164,29 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
     ]
 }
 
+pub fn cl_type_arb() -> impl Strategy<Value = CLType> {
+    cl_value_arb().prop_map(|cl_value| cl_value.cl_type().clone())
+}
+
+pub fn parameter_arb() -> impl Strategy<Value = Parameter> {
+    ("[a-z_]{1,16}", cl_type_arb()).prop_map(|(name, cl_type)| Parameter::new(name, cl_type))
+}
+
+pub fn entry_point_arb() -> impl Strategy<Value = EntryPoint> {
+    ("[a-z_]{1,16}", vec(parameter_arb(), 0..5), cl_type_arb())
+        .prop_map(|(name, args, ret)| EntryPoint::new(name, args, ret))
+}
+
+pub fn runtime_args_arb() -> impl Strategy<Value = RuntimeArgs> {
+    vec(("[a-z_]{1,16}", cl_value_arb()), 0..10).prop_map(|named_args| {
+        let mut runtime_args = RuntimeArgs::new();
+        for (name, cl_value) in named_args {
+            runtime_args.insert_cl_value(name, cl_value);
+        }
+        runtime_args
+    })
+}
+
 pub fn result_arb() -> impl Strategy<Value = Result<u32, u32>> {
     result::maybe_ok(any::<u32>(), any::<u32>())
 }
@@ -8,7 +8,7 @@ use blake2::{
 use hex_fmt::HexFmt;
 
 use crate::{
-    account::PublicKey,
+    account::{PublicKey, PUBLIC_KEY_SERIALIZED_MAX_LENGTH},
     bytesrepr::{self, Error, FromBytes, ToBytes},
     AccessRights, ContractRef, URef, UREF_SERIALIZED_LENGTH,
 };
@@ -34,6 +34,8 @@ const KEY_HASH_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LE
 pub const KEY_UREF_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + UREF_SERIALIZED_LENGTH;
 const KEY_LOCAL_SERIALIZED_LENGTH: usize =
     KEY_ID_SERIALIZED_LENGTH + KEY_LOCAL_SEED_LENGTH + BLAKE2B_DIGEST_LENGTH;
+const KEY_ACCOUNT_SERIALIZED_MAX_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + PUBLIC_KEY_SERIALIZED_MAX_LENGTH;
 
 /// Creates a 32-byte BLAKE2b hash digest from a given a piece of data
 fn hash(bytes: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
@@ -90,7 +92,7 @@ impl Key {
 
     /// Returns the maximum size a [`Key`] can be serialized into.
     pub const fn max_serialized_length() -> usize {
-        KEY_LOCAL_SERIALIZED_LENGTH
+        KEY_ACCOUNT_SERIALIZED_MAX_LENGTH
     }
 
     /// If `self` is of type [`Key::URef`], returns `self` with the [`AccessRights`] stripped from
@@ -133,8 +135,12 @@ impl Key {
     pub fn as_string(&self) -> String {
         match self {
             Key::Account(PublicKey::Ed25519(addr)) => {
-                format!("account-ed25519-{}", base16::encode_lower(&addr.value()))
+                format!("account-ed25519-{}", base16::encode_lower(addr.as_bytes()))
             }
+            Key::Account(PublicKey::Secp256k1(addr)) => format!(
+                "account-secp256k1-{}",
+                base16::encode_lower(addr.as_bytes())
+            ),
             Key::Hash(addr) => format!("hash-{}", base16::encode_lower(addr)),
             Key::URef(uref) => uref.as_string(),
             Key::Local { hash, .. } => format!("local-{}", base16::encode_lower(hash)),
@@ -205,6 +211,9 @@ impl Display for Key {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Key::Account(PublicKey::Ed25519(ed25519)) => write!(f, "Key::Account({})", ed25519),
+            Key::Account(PublicKey::Secp256k1(secp256k1)) => {
+                write!(f, "Key::Account({})", secp256k1)
+            }
             Key::Hash(addr) => write!(f, "Key::Hash({})", HexFmt(addr)),
             Key::URef(uref) => write!(f, "Key::{}", uref), /* Display impl for URef will append */
             // URef(…).
@@ -251,6 +260,12 @@ impl From<URef> for Key {
     }
 }
 
+impl From<PublicKey> for Key {
+    fn from(public_key: PublicKey) -> Key {
+        Key::Account(public_key)
+    }
+}
+
 impl ToBytes for Key {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         let mut result = bytesrepr::unchecked_allocate_buffer(self);
@@ -382,6 +397,11 @@ mod tests {
             format!("{}", account_key),
             format!("Key::Account(Ed25519({}))", expected_hash)
         );
+        let secp256k1_account_key = Key::Account(PublicKey::secp256k1_from([0u8; 33]));
+        assert_eq!(
+            format!("{}", secp256k1_account_key),
+            format!("Key::Account(Secp256k1({}))", "0".repeat(66))
+        );
         let uref_key = Key::URef(URef::new(addr_array, AccessRights::READ));
         assert_eq!(
             format!("{}", uref_key),
@@ -504,6 +524,18 @@ mod tests {
         assert!(key1.into_local().is_none());
     }
 
+    #[test]
+    fn check_key_account_from_public_key() {
+        let ed25519 = PublicKey::ed25519_from([42; 32]);
+        assert_eq!(Key::from(ed25519), Key::Account(ed25519));
+        let secp256k1 = PublicKey::secp256k1_from([42; 33]);
+        assert_eq!(Key::from(secp256k1), Key::Account(secp256k1));
+        assert_eq!(
+            Key::from(secp256k1).as_string(),
+            format!("account-secp256k1-{}", "2a".repeat(33))
+        );
+    }
+
     #[test]
     fn check_key_hash_getters() {
         let hash = [42; KEY_HASH_LENGTH];
@@ -542,6 +574,12 @@ mod tests {
         let key_account = Key::Account(PublicKey::ed25519_from([42; 32]));
         assert!(key_account.serialized_length() < Key::max_serialized_length());
 
+        let key_account = Key::Account(PublicKey::secp256k1_from([42; 33]));
+        assert_eq!(
+            key_account.serialized_length(),
+            Key::max_serialized_length()
+        );
+
         let key_hash = Key::Hash([42; 32]);
         assert!(key_hash.serialized_length() < Key::max_serialized_length());
 
@@ -549,6 +587,6 @@ mod tests {
         assert!(key_uref.serialized_length() < Key::max_serialized_length());
 
         let key_local = Key::local([42; 32], &[42; 32]);
-        assert_eq!(key_local.serialized_length(), Key::max_serialized_length());
+        assert!(key_local.serialized_length() < Key::max_serialized_length());
     }
 }
@@ -28,11 +28,13 @@ pub mod bytesrepr;
 mod cl_type;
 mod cl_value;
 mod contract_ref;
+mod entry_point;
 #[cfg(any(feature = "gens", test))]
 pub mod gens;
 mod key;
 mod phase;
 mod protocol_version;
+mod runtime_args;
 mod semver;
 pub mod system_contract_errors;
 mod system_contract_type;
@@ -48,6 +50,7 @@ pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
 pub use cl_type::{named_key_type, CLType, CLTyped};
 pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
 pub use contract_ref::ContractRef;
+pub use entry_point::{EntryPoint, Parameter};
 #[doc(inline)]
 pub use key::{
     Key, BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH, KEY_LOCAL_LENGTH, KEY_LOCAL_SEED_LENGTH,
@@ -55,6 +58,7 @@ pub use key::{
 };
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
+pub use runtime_args::{deserialize_args, NamedArg, RuntimeArgs};
 pub use semver::SemVer;
 pub use system_contract_type::SystemContractType;
 pub use transfer_result::{TransferResult, TransferredTo};
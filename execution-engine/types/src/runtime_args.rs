@@ -0,0 +1,217 @@
+//! Home of [`RuntimeArgs`], the arguments passed to a contract by name.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    CLTyped, CLValue, CLValueError,
+};
+
+/// Written in place of the number of args at the start of serialized [`RuntimeArgs`].
+///
+/// Args passed by position are serialized as a `Vec<CLValue>`, which starts with the number of
+/// args.  No such `Vec` could hold `u32::max_value()` args, so the prefix tells the two encodings
+/// apart.
+const NAMED_ARGS_PREFIX: u32 = u32::max_value();
+
+/// A named argument passed to a contract.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NamedArg {
+    name: String,
+    value: CLValue,
+}
+
+impl NamedArg {
+    /// Constructs a new `NamedArg`.
+    pub fn new(name: String, value: CLValue) -> Self {
+        NamedArg { name, value }
+    }
+
+    /// The name of the argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the argument.
+    pub fn value(&self) -> &CLValue {
+        &self.value
+    }
+}
+
+impl ToBytes for NamedArg {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.name.to_bytes()?);
+        result.append(&mut self.value.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length() + self.value.serialized_length()
+    }
+}
+
+impl FromBytes for NamedArg {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (value, remainder) = CLValue::from_bytes(remainder)?;
+        Ok((NamedArg { name, value }, remainder))
+    }
+}
+
+/// The arguments passed to a contract by name, in the order they were inserted.
+///
+/// Unlike args passed by position, a contract can read these via
+/// `runtime::get_named_arg()`, so new optional args can be added to its interface without
+/// breaking existing callers.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct RuntimeArgs(Vec<NamedArg>);
+
+impl RuntimeArgs {
+    /// Constructs an empty `RuntimeArgs`.
+    pub fn new() -> Self {
+        RuntimeArgs::default()
+    }
+
+    /// Inserts `value` under `name`, replacing any value already held under that name.
+    pub fn insert<K: Into<String>, V: CLTyped + ToBytes>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> Result<(), CLValueError> {
+        let cl_value = CLValue::from_t(value)?;
+        self.insert_cl_value(name, cl_value);
+        Ok(())
+    }
+
+    /// Inserts `cl_value` under `name`, replacing any value already held under that name.
+    pub fn insert_cl_value<K: Into<String>>(&mut self, name: K, cl_value: CLValue) {
+        let name = name.into();
+        match self.0.iter_mut().find(|named_arg| named_arg.name == name) {
+            Some(named_arg) => named_arg.value = cl_value,
+            None => self.0.push(NamedArg::new(name, cl_value)),
+        }
+    }
+
+    /// Returns the value held under `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&CLValue> {
+        self.0
+            .iter()
+            .find(|named_arg| named_arg.name == name)
+            .map(NamedArg::value)
+    }
+
+    /// The number of args.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no args.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the args in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = &NamedArg> {
+        self.0.iter()
+    }
+
+    /// Returns the values of the args in insertion order, i.e. the args as they'd be seen by a
+    /// contract reading them by position.
+    pub fn to_values(&self) -> Vec<CLValue> {
+        self.0
+            .iter()
+            .map(|named_arg| named_arg.value.clone())
+            .collect()
+    }
+
+    /// Returns `true` if `bytes` hold serialized `RuntimeArgs` rather than args passed by
+    /// position.
+    pub fn is_named(bytes: &[u8]) -> bool {
+        bytes.len() >= U32_SERIALIZED_LENGTH
+            && bytes[..U32_SERIALIZED_LENGTH] == NAMED_ARGS_PREFIX.to_le_bytes()
+    }
+}
+
+impl ToBytes for RuntimeArgs {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut NAMED_ARGS_PREFIX.to_bytes()?);
+        result.append(&mut self.0.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U32_SERIALIZED_LENGTH + self.0.serialized_length()
+    }
+}
+
+impl FromBytes for RuntimeArgs {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (prefix, remainder) = u32::from_bytes(bytes)?;
+        if prefix != NAMED_ARGS_PREFIX {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (named_args, remainder) = Vec::<NamedArg>::from_bytes(remainder)?;
+        Ok((RuntimeArgs(named_args), remainder))
+    }
+}
+
+/// Deserializes args passed to a contract either by position or as [`RuntimeArgs`].
+///
+/// Returns the args by position and, if they were passed by name, the `RuntimeArgs` too.  Empty
+/// `bytes` are treated as no args.
+pub fn deserialize_args(bytes: Vec<u8>) -> Result<(Vec<CLValue>, RuntimeArgs), bytesrepr::Error> {
+    if bytes.is_empty() {
+        return Ok((Vec::new(), RuntimeArgs::new()));
+    }
+    if RuntimeArgs::is_named(&bytes) {
+        let named_args: RuntimeArgs = bytesrepr::deserialize(bytes)?;
+        return Ok((named_args.to_values(), named_args));
+    }
+    let args = bytesrepr::deserialize(bytes)?;
+    Ok((args, RuntimeArgs::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn should_replace_value_of_existing_name() {
+        let mut runtime_args = RuntimeArgs::new();
+        runtime_args.insert("a", 1u64).unwrap();
+        runtime_args.insert("b", 2u64).unwrap();
+        runtime_args.insert("a", 3u64).unwrap();
+
+        assert_eq!(runtime_args.len(), 2);
+        assert_eq!(runtime_args.get("a"), Some(&CLValue::from_t(3u64).unwrap()));
+        assert_eq!(
+            runtime_args.to_values(),
+            vec![
+                CLValue::from_t(3u64).unwrap(),
+                CLValue::from_t(2u64).unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn should_deserialize_positional_and_named_args() {
+        let positional = vec![CLValue::from_t(1u64).unwrap()];
+        let (args, named_args) = deserialize_args(positional.to_bytes().unwrap()).unwrap();
+        assert_eq!(args, positional);
+        assert!(named_args.is_empty());
+
+        let mut runtime_args = RuntimeArgs::new();
+        runtime_args.insert("a", 1u64).unwrap();
+        let (args, named_args) = deserialize_args(runtime_args.to_bytes().unwrap()).unwrap();
+        assert_eq!(args, positional);
+        assert_eq!(named_args, runtime_args);
+
+        let (args, named_args) = deserialize_args(Vec::new()).unwrap();
+        assert!(args.is_empty());
+        assert!(named_args.is_empty());
+    }
+}
@@ -77,6 +77,25 @@ pub enum Error {
     /// PoS contract's "set_refund_purse" method can only be called by the payment code of a
     /// deploy, but was called by the session code.
     SetRefundPurseCalledOutsidePayment,
+    /// Internal error: couldn't retrieve the balance for the PoS contract's rewards purse.
+    RewardsPurseBalanceNotFound,
+    /// Internal error: while distributing rewards, the total amount requested exceeded the balance
+    /// of the PoS contract's rewards purse.
+    InsufficientRewardsForDistribution,
+    /// Internal error: while distributing rewards, failed to pay a validator (the transfer from
+    /// the PoS contract's rewards purse to the validator's main purse failed).
+    FailedTransferToValidatorPurse,
+    /// Internal error: while slashing, failed to move the slashed motes (the transfer from the PoS
+    /// contract's bonding purse to rewards purse failed).
+    SlashTransferFailed,
+    /// Internal error: the PoS contract's bonding delay wasn't found.
+    BondingDelayNotFound,
+    /// Internal error: the PoS contract's unbonding delay wasn't found.
+    UnbondingDelayNotFound,
+    /// Internal error: an era height was unexpectedly lower than the current era height.
+    EraWentBackwards,
+    /// Internal error: the current era height couldn't be written.
+    EraHeightWriteFailed,
 }
 
 impl CLTyped for Error {
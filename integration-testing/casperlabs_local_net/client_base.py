@@ -13,7 +13,7 @@ class CasperLabsClientBase(ABC):
     def deploy(
         self,
         from_address: str = None,
-        gas_price: int = 1,
+        gas_price: int = 10,
         session_contract: Optional[str] = None,
         session_args: Optional[Union[str, bytes]] = None,
         payment_contract: Optional[str] = None,
@@ -391,7 +391,7 @@ class DockerNode(LoggingDockerBase):
         session_contract: str = Contract.TRANSFER_TO_ACCOUNT,
         payment_contract: str = Contract.STANDARD_PAYMENT,
         payment_args: bytes = MAX_PAYMENT_ABI,
-        gas_price: int = 1,
+        gas_price: int = 10,
         is_deploy_error_check: bool = True,
     ) -> str:
         """
@@ -482,7 +482,7 @@ class DockerNode(LoggingDockerBase):
         session_contract: str,
         from_account: Account,
         json_args: str,
-        gas_price: int = 1,
+        gas_price: int = 10,
     ) -> str:
 
         deploy_hash = self.p_client.deploy(
@@ -112,7 +112,7 @@ def test_error_in_payment_contract(payment_node_network):
         payment_contract=Contract.DIRECT_REVERT,
         public_key=from_account.public_key_path,
         private_key=from_account.private_key_path,
-        gas_price=1,
+        gas_price=CONV_RATE,
         session_args=session_args,
         payment_args=payment_args,
     )
@@ -187,7 +187,7 @@ def test_not_enough_to_run_session(trillion_payment_node_network):
         session_contract=Contract.ENDLESS_LOOP,
         public_key=account1.public_key_path,
         private_key=account1.private_key_path,
-        gas_price=1,
+        gas_price=CONV_RATE,
         session_args=None,
         payment_args=MAX_PAYMENT_ABI,
     )
@@ -226,7 +226,7 @@ def test_refund_after_session_code_error(payment_node_network):
         payment_contract=Contract.STANDARD_PAYMENT,
         public_key=GENESIS_ACCOUNT.public_key_path,
         private_key=GENESIS_ACCOUNT.private_key_path,
-        gas_price=1,
+        gas_price=CONV_RATE,
         session_args=ABI.args([ABI.u512("number", 100)]),
         payment_args=ABI.args([ABI.u32("amount", 10 ** 6)])
         # 100 is a revert code.
@@ -275,7 +275,7 @@ def test_not_enough_funds_to_run_payment_code(payment_node_network):
         payment_contract=Contract.STANDARD_PAYMENT,
         public_key=GENESIS_ACCOUNT.public_key_path,
         private_key=GENESIS_ACCOUNT.private_key_path,
-        gas_price=1,
+        gas_price=CONV_RATE,
         session_args=session_args,
         payment_args=ABI.args([ABI.u512("amount", 450)]),
     )
@@ -1,6 +1,7 @@
 package io.casperlabs.models.cltype
 
 import io.casperlabs.models.bytesrepr.{BytesView, FromBytes, ToBytes}
+import io.casperlabs.models.cltype
 import Account.{ActionThresholds, PublicKey, Weight}
 
 case class Account(
@@ -12,7 +13,7 @@ case class Account(
 )
 
 object Account {
-  type PublicKey = ByteArray32
+  type PublicKey = cltype.PublicKey
   type Weight    = Byte
   case class ActionThresholds(deployment: Weight, keyManagement: Weight)
 
@@ -47,10 +48,10 @@ object Account {
 
   val deserializer: FromBytes.Deserializer[Account] =
     for {
-      publicKey        <- ByteArray32.deserializer
+      publicKey        <- cltype.PublicKey.deserializer
       namedKeys        <- FromBytes.map(FromBytes.string, Key.deserializer)
       mainPurse        <- URef.deserializer
-      associatedKeys   <- FromBytes.map(ByteArray32.deserializer, FromBytes.byte)
+      associatedKeys   <- FromBytes.map(cltype.PublicKey.deserializer, FromBytes.byte)
       actionThresholds <- desActionThresholds
     } yield Account(publicKey, namedKeys, mainPurse, associatedKeys, actionThresholds)
 }
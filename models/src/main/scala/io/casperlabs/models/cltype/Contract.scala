@@ -2,18 +2,82 @@ package io.casperlabs.models.cltype
 
 import io.casperlabs.models.bytesrepr.{BytesView, FromBytes, ToBytes}
 
-case class Contract(bytes: IndexedSeq[Byte], namedKeys: Map[String, Key], protocolVersion: SemVer)
+/** A stored contract. `entryPoints` is empty if the contract can only be called at `call`,
+  * and doesn't describe the arguments it takes.
+  */
+case class Contract(
+    bytes: IndexedSeq[Byte],
+    namedKeys: Map[String, Key],
+    protocolVersion: SemVer,
+    entryPoints: Seq[EntryPoint] = Seq.empty
+)
 
 object Contract {
+  // Marks the entry points which follow the original layout; contracts without entry points
+  // are serialized in the original layout only.
+  val ENTRY_POINTS_VERSION: Byte = 1
+
   implicit val toBytesContract: ToBytes[Contract] = new ToBytes[Contract] {
-    override def toBytes(c: Contract): Array[Byte] =
-      ToBytes.toBytes(c.bytes) ++ ToBytes.toBytes(c.namedKeys) ++ ToBytes.toBytes(c.protocolVersion)
+    override def toBytes(c: Contract): Array[Byte] = {
+      val entryPoints =
+        if (c.entryPoints.isEmpty) Array.empty[Byte]
+        else ENTRY_POINTS_VERSION +: ToBytes.toBytes(c.entryPoints)
+
+      ToBytes.toBytes(c.bytes) ++
+        ToBytes.toBytes(c.namedKeys) ++
+        ToBytes.toBytes(c.protocolVersion) ++
+        entryPoints
+    }
   }
 
+  private val entryPointsDeserializer: FromBytes.Deserializer[Seq[EntryPoint]] =
+    FromBytes.getState.flatMap {
+      // Contracts stored before entry points were introduced end with the protocol version
+      case rem if rem.length == 0 => FromBytes.pure(Seq.empty[EntryPoint])
+      case _ =>
+        FromBytes.byte.flatMap {
+          case version if version == ENTRY_POINTS_VERSION =>
+            FromBytes.seq(EntryPoint.deserializer)
+          case other =>
+            FromBytes.raise(FromBytes.Error.InvalidVariantTag(other, "Contract entry points"))
+        }
+    }
+
   val deserializer: FromBytes.Deserializer[Contract] =
     for {
       contractBytes   <- FromBytes.bytes
       namedKeys       <- FromBytes.map(FromBytes.string, Key.deserializer)
       protocolVersion <- SemVer.deserializer
-    } yield Contract(contractBytes.toIndexedSeq, namedKeys, protocolVersion)
+      entryPoints     <- entryPointsDeserializer
+    } yield Contract(contractBytes.toIndexedSeq, namedKeys, protocolVersion, entryPoints)
+}
+
+/** The schema of a function exported by a stored contract. */
+case class EntryPoint(name: String, args: Seq[EntryPoint.Parameter], ret: CLType)
+
+object EntryPoint {
+  case class Parameter(name: String, clType: CLType)
+
+  implicit val toBytesParameter: ToBytes[Parameter] = new ToBytes[Parameter] {
+    override def toBytes(p: Parameter): Array[Byte] =
+      ToBytes.toBytes(p.name) ++ ToBytes.toBytes(p.clType)
+  }
+
+  val parameterDeserializer: FromBytes.Deserializer[Parameter] =
+    for {
+      name   <- FromBytes.string
+      clType <- CLType.deserializer
+    } yield Parameter(name, clType)
+
+  implicit val toBytesEntryPoint: ToBytes[EntryPoint] = new ToBytes[EntryPoint] {
+    override def toBytes(e: EntryPoint): Array[Byte] =
+      ToBytes.toBytes(e.name) ++ ToBytes.toBytes(e.args) ++ ToBytes.toBytes(e.ret)
+  }
+
+  val deserializer: FromBytes.Deserializer[EntryPoint] =
+    for {
+      name <- FromBytes.string
+      args <- FromBytes.seq(parameterDeserializer)
+      ret  <- CLType.deserializer
+    } yield EntryPoint(name, args, ret)
 }
@@ -9,9 +9,9 @@ sealed trait Key {
 }
 
 object Key {
-  case class Account(address: ByteArray32) extends Key {
+  case class Account(address: PublicKey) extends Key {
     override protected val tag: Byte        = Account.tag
-    protected def innerToBytes: Array[Byte] = ToBytes[ByteArray32].toBytes(address)
+    protected def innerToBytes: Array[Byte] = ToBytes[PublicKey].toBytes(address)
   }
 
   object Account {
@@ -54,7 +54,7 @@ object Key {
   val deserializer: FromBytes.Deserializer[Key] =
     FromBytes.byte.flatMap {
       case tag if tag == Account.tag =>
-        ByteArray32.deserializer.map[Key](address => Account(address))
+        PublicKey.deserializer.map[Key](address => Account(address))
       case tag if tag == Hash.tag => ByteArray32.deserializer.map[Key](address => Hash(address))
       case tag if tag == URef.tag => cltype.URef.deserializer.map[Key](uref => URef(uref))
       case tag if tag == Local.tag =>
@@ -0,0 +1,50 @@
+package io.casperlabs.models.cltype
+
+import io.casperlabs.models.bytesrepr.{FromBytes, ToBytes}
+
+/** The public key of an account. Ed25519 keys are serialized as their 32 bytes, while secp256k1
+  * keys follow a marker which is never the serialization of a valid Ed25519 key. */
+sealed trait PublicKey {
+  def bytes: IndexedSeq[Byte]
+}
+
+object PublicKey {
+  case class Ed25519(key: ByteArray32) extends PublicKey {
+    override def bytes: IndexedSeq[Byte] = key.bytes
+  }
+
+  case class Secp256k1 private (bytes: IndexedSeq[Byte]) extends PublicKey
+
+  object Secp256k1 {
+    val length: Int = 33
+
+    /** A non-canonical encoding of an Ed25519 point, serialized in front of secp256k1 keys. */
+    val marker: ByteArray32 =
+      ByteArray32((0xed.toByte +: Vector.fill(30)(0xff.toByte)) :+ 0x7f.toByte).get
+
+    def apply(bytes: IndexedSeq[Byte]): Option[Secp256k1] =
+      if (bytes.length == length) Some(new Secp256k1(bytes))
+      else None
+
+    val deserializer: FromBytes.Deserializer[Secp256k1] =
+      FromBytes.take(length).map(view => new Secp256k1(view.toArray))
+  }
+
+  /** Creates a public key from its raw bytes, using their length to tell the key types apart. */
+  def apply(bytes: IndexedSeq[Byte]): Option[PublicKey] =
+    ByteArray32(bytes).map(Ed25519(_)) orElse Secp256k1(bytes)
+
+  implicit val toBytesPublicKey: ToBytes[PublicKey] = new ToBytes[PublicKey] {
+    override def toBytes(k: PublicKey): Array[Byte] = k match {
+      case Ed25519(key)     => ToBytes.toBytes(key)
+      case Secp256k1(bytes) => ToBytes.toBytes(Secp256k1.marker) ++ bytes.toArray
+    }
+  }
+
+  val deserializer: FromBytes.Deserializer[PublicKey] =
+    ByteArray32.deserializer.flatMap {
+      case prefix if prefix == Secp256k1.marker =>
+        Secp256k1.deserializer.map[PublicKey](identity)
+      case key => FromBytes.pure[PublicKey](Ed25519(key))
+    }
+}
@@ -0,0 +1,25 @@
+package io.casperlabs.models.cltype
+
+import io.casperlabs.models.bytesrepr.{FromBytes, ToBytes}
+
+/** Args passed to a contract by name, in the order the contract sees them by position. */
+case class RuntimeArgs(args: Seq[(String, CLValue)])
+
+object RuntimeArgs {
+  // Written in place of the number of args passed by position (as `u32::max_value()`), which
+  // tells the two encodings apart.
+  val NAMED_ARGS_PREFIX: Int = -1
+
+  implicit val toBytesRuntimeArgs: ToBytes[RuntimeArgs] = new ToBytes[RuntimeArgs] {
+    override def toBytes(a: RuntimeArgs): Array[Byte] =
+      ToBytes.toBytes(NAMED_ARGS_PREFIX) ++ ToBytes.toBytes(a.args)
+  }
+
+  val deserializer: FromBytes.Deserializer[RuntimeArgs] =
+    FromBytes.int.flatMap {
+      case prefix if prefix == NAMED_ARGS_PREFIX =>
+        FromBytes.seq(FromBytes.tuple2(FromBytes.string, CLValue.deserializer)).map(RuntimeArgs(_))
+      case _ =>
+        FromBytes.raise(FromBytes.Error.FormatException("Args are not passed by name"))
+    }
+}
@@ -31,7 +31,16 @@ object Mappings {
   def toProto(c: Contract): state.Contract = state.Contract(
     body = ByteString.copyFrom(c.bytes.toArray),
     namedKeys = toProto(c.namedKeys),
-    protocolVersion = Some(toProto(c.protocolVersion))
+    protocolVersion = Some(toProto(c.protocolVersion)),
+    entryPoints = c.entryPoints.map(e => toProto(e))
+  )
+
+  def toProto(e: EntryPoint): state.EntryPoint = state.EntryPoint(
+    name = e.name,
+    args = e.args.map { p =>
+      state.EntryPoint.Parameter(name = p.name, clType = Some(toProto(p.clType)))
+    },
+    ret = Some(toProto(e.ret))
   )
 
   def toProto(a: Account): state.Account = state.Account(
@@ -267,7 +276,7 @@ object Mappings {
     case state.Key.Value.Empty => Left(Error.EmptyKeyVariant)
 
     case state.Key.Value.Address(state.Key.Address(address)) =>
-      toByteArray32(address).map(Key.Account.apply)
+      toPublicKey(address).map(Key.Account.apply)
 
     case state.Key.Value.Hash(state.Key.Hash(address)) =>
       toByteArray32(address).map(Key.Hash.apply)
@@ -584,6 +593,12 @@ object Mappings {
       } yield clValue
   }
 
+  private def toPublicKey(bytes: ByteString): Either[Error, PublicKey] =
+    PublicKey(bytes.toByteArray) match {
+      case None            => Left(Error.InvalidPublicKeyLength(foundLength = bytes.size))
+      case Some(publicKey) => Right(publicKey)
+    }
+
   private def toByteArray32(bytes: ByteString): Either[Error, ByteArray32] =
     ByteArray32(bytes.toByteArray) match {
       case None          => Left(Error.Expected32Bytes(foundLength = bytes.size))
@@ -610,6 +625,7 @@ object Mappings {
 
     case class Expected32Bytes(foundLength: Int) extends Error
     case class Expected64Bytes(foundLength: Int) extends Error
+    case class InvalidPublicKeyLength(foundLength: Int) extends Error
 
     case object MissingType          extends Error
     case object MissingInstance      extends Error
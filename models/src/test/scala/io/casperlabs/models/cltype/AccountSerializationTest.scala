@@ -16,13 +16,13 @@ object AccountSerializationTest {
   private val genWeight = Gen.choose[Byte](-128, 127)
 
   val genAccount: Gen[Account] = for {
-    publicKey <- ByteArray32SerializationTest.genByteArray32
+    publicKey <- PublicKeySerializationTest.genPublicKey
     namedKeys <- Gen.mapOf(
                   Gen.alphaStr.flatMap(s => KeySerializationTest.genKey.map(k => s -> k))
                 )
     mainPurse <- URefSerializationTest.genURef
     associatedKeys <- Gen.mapOf(
-                       ByteArray32SerializationTest.genByteArray32.flatMap(
+                       PublicKeySerializationTest.genPublicKey.flatMap(
                          k => genWeight.map(w => k -> w)
                        )
                      )
@@ -1,25 +1,80 @@
 package io.casperlabs.models.cltype
 
 import io.casperlabs.models.bytesrepr.SerializationTest.roundTrip
+import io.casperlabs.models.bytesrepr.{FromBytes, ToBytes}
 import org.scalacheck.{Arbitrary, Gen}
 import org.scalatest.{FlatSpec, Matchers}
 import org.scalatest.prop.PropertyChecks
-import ContractSerializationTest.arbContract
+import ContractSerializationTest.{arbContract, legacyBytes, typedBytes, typedContract}
 
 class ContractSerializationTest extends FlatSpec with Matchers with PropertyChecks {
   "Contracts" should "serialize properly" in forAll { (c: Contract) =>
     roundTrip(c, Contract.deserializer)
   }
+
+  it should "deserialize contracts stored without entry points" in {
+    val contract = FromBytes.deserialize(Contract.deserializer, legacyBytes)
+
+    contract shouldBe Right(Contract(Vector[Byte](0, 97, 115, 109), Map.empty, SemVer(1, 0, 0)))
+    ToBytes.toBytes(contract.right.get).toVector shouldBe legacyBytes.toVector
+  }
+
+  it should "follow the ABI spec for entry points" in {
+    ToBytes.toBytes(typedContract).toVector shouldBe typedBytes.toVector
+    FromBytes.deserialize(Contract.deserializer, typedBytes) shouldBe Right(typedContract)
+  }
+
+  it should "not deserialize an unknown entry points version" in {
+    val bytes = legacyBytes ++ Array[Byte](2, 0, 0, 0, 0)
+
+    FromBytes.deserialize(Contract.deserializer, bytes) shouldBe Left(
+      FromBytes.Error.InvalidVariantTag(2, "Contract entry points")
+    )
+  }
 }
 
 object ContractSerializationTest {
+  // A contract with the body `[0, 97, 115, 109]`, no named keys and protocol version 1.0.0
+  val legacyBytes: Array[Byte] = Array[Byte](4, 0, 0, 0, 0, 97, 115, 109) ++
+    Array[Byte](0, 0, 0, 0) ++
+    Array[Byte](1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+
+  // The same contract, with a `call` entry point taking a `U64` named "value" and returning `Unit`
+  val typedBytes: Array[Byte] = legacyBytes ++ Array[Byte](
+    Contract.ENTRY_POINTS_VERSION,
+    1, 0, 0, 0,
+    4, 0, 0, 0, 99, 97, 108, 108,
+    1, 0, 0, 0,
+    5, 0, 0, 0, 118, 97, 108, 117, 101, 5,
+    9
+  )
+
+  val typedContract: Contract = Contract(
+    Vector[Byte](0, 97, 115, 109),
+    Map.empty,
+    SemVer(1, 0, 0),
+    Seq(EntryPoint("call", Seq(EntryPoint.Parameter("value", CLType.U64)), CLType.Unit))
+  )
+
+  val genParameter: Gen[EntryPoint.Parameter] = for {
+    name   <- Gen.alphaStr
+    clType <- CLTypeSerializationTest.genCLType
+  } yield EntryPoint.Parameter(name, clType)
+
+  val genEntryPoint: Gen[EntryPoint] = for {
+    name <- Gen.alphaStr
+    args <- Gen.listOf(genParameter)
+    ret  <- CLTypeSerializationTest.genCLType
+  } yield EntryPoint(name, args, ret)
+
   val genContract: Gen[Contract] = for {
     bytes <- Gen.listOf(Gen.choose[Byte](-128, 127))
     namedKeys <- Gen.mapOf(
                   Gen.alphaStr.flatMap(s => KeySerializationTest.genKey.map(k => s -> k))
                 )
-    version <- SemVerSerializationTest.genSemVer
-  } yield Contract(bytes.toIndexedSeq, namedKeys, version)
+    version     <- SemVerSerializationTest.genSemVer
+    entryPoints <- Gen.listOf(genEntryPoint)
+  } yield Contract(bytes.toIndexedSeq, namedKeys, version, entryPoints)
 
   implicit val arbContract: Arbitrary[Contract] = Arbitrary(genContract)
 }
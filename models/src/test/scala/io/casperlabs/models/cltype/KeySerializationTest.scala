@@ -14,7 +14,7 @@ class KeySerializationTest extends FlatSpec with Matchers with PropertyChecks {
 
 object KeySerializationTest {
   val genAccountKey: Gen[Key.Account] =
-    ByteArray32SerializationTest.genByteArray32.map(Key.Account(_))
+    PublicKeySerializationTest.genPublicKey.map(Key.Account(_))
 
   val genHashKey: Gen[Key.Hash] = ByteArray32SerializationTest.genByteArray32.map(Key.Hash(_))
   val genURefKey: Gen[Key.URef] = URefSerializationTest.genURef.map(Key.URef(_))
@@ -0,0 +1,42 @@
+package io.casperlabs.models.cltype
+
+import io.casperlabs.models.bytesrepr.SerializationTest.roundTrip
+import io.casperlabs.models.bytesrepr.ToBytes
+import org.scalacheck.{Arbitrary, Gen}
+import org.scalatest.{FlatSpec, Matchers}
+import org.scalatest.prop.PropertyChecks
+import PublicKeySerializationTest.arbPublicKey
+
+class PublicKeySerializationTest extends FlatSpec with Matchers with PropertyChecks {
+  "PublicKeys" should "serialize properly" in forAll { (k: PublicKey) =>
+    roundTrip(k, PublicKey.deserializer)
+  }
+
+  "Ed25519 PublicKeys" should "serialize as their raw bytes" in forAll(
+    PublicKeySerializationTest.genEd25519
+  ) { k =>
+    ToBytes.toBytes[PublicKey](k) shouldBe k.bytes.toArray
+  }
+
+  "Secp256k1 PublicKeys" should "serialize after the marker" in forAll(
+    PublicKeySerializationTest.genSecp256k1
+  ) { k =>
+    ToBytes.toBytes[PublicKey](k) shouldBe (PublicKey.Secp256k1.marker.bytes ++ k.bytes).toArray
+  }
+}
+
+object PublicKeySerializationTest {
+  val genEd25519: Gen[PublicKey] =
+    ByteArray32SerializationTest.genByteArray32
+      .suchThat(_ != PublicKey.Secp256k1.marker)
+      .map(PublicKey.Ed25519(_))
+
+  val genSecp256k1: Gen[PublicKey] =
+    Gen
+      .listOfN[Byte](PublicKey.Secp256k1.length, Gen.choose[Byte](-128, 127))
+      .map(bytes => PublicKey.Secp256k1(bytes.toIndexedSeq).get)
+
+  val genPublicKey: Gen[PublicKey] = Gen.oneOf(genEd25519, genSecp256k1)
+
+  implicit val arbPublicKey: Arbitrary[PublicKey] = Arbitrary(genPublicKey)
+}
@@ -0,0 +1,35 @@
+package io.casperlabs.models.cltype
+
+import io.casperlabs.models.bytesrepr.SerializationTest.roundTrip
+import io.casperlabs.models.bytesrepr.ToBytes
+import org.scalacheck.{Arbitrary, Gen}
+import org.scalatest.{FlatSpec, Matchers}
+import org.scalatest.prop.PropertyChecks
+import RuntimeArgsSerializationTest.arbRuntimeArgs
+
+class RuntimeArgsSerializationTest extends FlatSpec with Matchers with PropertyChecks {
+  "RuntimeArgs" should "serialize properly" in forAll { (a: RuntimeArgs) =>
+    roundTrip(a, RuntimeArgs.deserializer)
+  }
+
+  it should "follow the ABI spec" in {
+    val args = RuntimeArgs(Seq("a" -> CLValue(CLType.U8, Vector[Byte](7))))
+
+    ToBytes.toBytes(args).toVector shouldBe Vector[Byte](
+      -1, -1, -1, -1, // named args prefix
+      1, 0, 0, 0,     // number of args
+      1, 0, 0, 0, 97, // name
+      1, 0, 0, 0, 7,  // value
+      3               // CLType.U8
+    )
+  }
+}
+
+object RuntimeArgsSerializationTest {
+  val genRuntimeArgs: Gen[RuntimeArgs] =
+    Gen
+      .listOf(Gen.alphaStr.flatMap(name => CLValueSerializationTest.genCLValue.map(name -> _)))
+      .map(RuntimeArgs(_))
+
+  implicit val arbRuntimeArgs: Arbitrary[RuntimeArgs] = Arbitrary(genRuntimeArgs)
+}
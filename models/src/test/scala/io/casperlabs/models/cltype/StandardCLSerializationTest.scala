@@ -262,7 +262,7 @@ object StandardCLSerializationTest {
     }
 
     def parseAccount(data: Tbl): Account = {
-      val pk = ByteArray32(readHex(getString(data, "public_key"))).get
+      val pk = PublicKey(readHex(getString(data, "public_key"))).get
       val namedKeys = getArr(data, "named_keys").map { t =>
         val k = getString(t, "key")
         val v = parseKey(getTable(t, "value"))
@@ -270,7 +270,7 @@ object StandardCLSerializationTest {
       }.toMap
       val mainPurse = parseURef(getTable(data, "main_purse"))
       val associatedKeys = getArr(data, "associated_keys").map { t =>
-        val k = ByteArray32(readHex(getString(t, "key"))).get
+        val k = PublicKey(readHex(getString(t, "key"))).get
         val v = getNumber(t, "value").toByte
         (k, v)
       }.toMap
@@ -292,7 +292,7 @@ object StandardCLSerializationTest {
     def parseKey(data: Tbl): Key = data.values.keys.head match {
       case key if key == "account" =>
         val address = readHex(getString(data, key))
-        Key.Account(ByteArray32(address).get)
+        Key.Account(PublicKey(address).get)
 
       case key if key == "hash" =>
         val address = readHex(getString(data, key))
@@ -3,7 +3,10 @@ package io.casperlabs.models.cltype.protobuf
 import org.scalacheck.{Arbitrary, Gen}
 import org.scalatest.{FlatSpec, Matchers}
 import org.scalatest.prop.PropertyChecks
-import io.casperlabs.models.cltype.{CLType, CLValueInstance}
+import io.casperlabs.casper.consensus.state
+import io.casperlabs.models.bytesrepr.FromBytes
+import io.casperlabs.models.cltype.{CLType, CLValueInstance, StoredValue}
+import io.casperlabs.models.cltype.ContractSerializationTest.typedBytes
 import io.casperlabs.models.cltype.CLTypeSerializationTest.arbCLType
 import io.casperlabs.models.cltype.CLValueInstanceTest.arbCLInstance
 
@@ -63,4 +66,21 @@ class MappingsTest extends FlatSpec with Matchers with PropertyChecks {
     Mappings.toProto(list) shouldBe dsl.instances.bytes(bytes)
     Mappings.toProto(fixedList) shouldBe dsl.instances.bytesFixedLength(bytes)
   }
+
+  "StoredValue.Contract toProto" should "expose the entry points of a queried contract" in {
+    // The bytes the execution engine returns when querying a contract with a typed entry point
+    val queryResult = StoredValue.CONTRACT_TAG +: typedBytes
+    val storedValue = FromBytes.deserialize(StoredValue.deserializer, queryResult).right.get
+
+    val instance = Mappings.toProto(storedValue).right.get
+    instance.getContract.entryPoints shouldBe Seq(
+      state.EntryPoint(
+        name = "call",
+        args = Seq(
+          state.EntryPoint.Parameter(name = "value", clType = Some(Mappings.toProto(CLType.U64)))
+        ),
+        ret = Some(Mappings.toProto(CLType.Unit))
+      )
+    )
+  }
 }
@@ -141,6 +141,23 @@ package object types {
     )
   )
 
+  lazy val EntryPointParameter = ObjectType(
+    "EntryPointParameter",
+    fields[Unit, cltype.EntryPoint.Parameter](
+      Field("name", StringType, resolve = _.value.name),
+      Field("clType", StringType, resolve = _.value.clType.toString)
+    )
+  )
+
+  lazy val EntryPoint = ObjectType(
+    "EntryPoint",
+    fields[Unit, cltype.EntryPoint](
+      Field("name", StringType, resolve = _.value.name),
+      Field("args", ListType(EntryPointParameter), resolve = _.value.args.toList),
+      Field("ret", StringType, resolve = _.value.ret.toString)
+    )
+  )
+
   lazy val Contract = ObjectType(
     "Contract",
     fields[Unit, cltype.Contract](
@@ -150,7 +167,8 @@ package object types {
         "protocolVersion",
         ProtocolVersionType,
         resolve = c => cltype.protobuf.Mappings.toProto(c.value.protocolVersion)
-      )
+      ),
+      Field("entryPoints", ListType(EntryPoint), resolve = _.value.entryPoints.toList)
     )
   )
 
@@ -72,8 +72,10 @@ object ChainSpec extends ParserImplicits {
   final case class GenesisConf(
       genesis: Genesis,
       wasmCosts: WasmCosts,
+      hostFunctionCosts: HostFunctionCosts,
       deploys: Deploy,
-      highway: Highway
+      highway: Highway,
+      proofOfStake: ProofOfStake
   )
   object GenesisConf extends ConfCompanion[GenesisConf](ConfParser.gen[GenesisConf]) {
     // Allow overriding genesis configuration so we can do things like bounce the network
@@ -97,7 +99,9 @@ object ChainSpec extends ParserImplicits {
   final case class UpgradeConf(
       upgrade: Upgrade,
       wasmCosts: Option[WasmCosts],
-      deploys: Option[Deploy]
+      hostFunctionCosts: Option[HostFunctionCosts],
+      deploys: Option[Deploy],
+      proofOfStake: Option[ProofOfStake]
   )
   object UpgradeConf extends ConfCompanion[UpgradeConf](ConfParser.gen[UpgradeConf])
 
@@ -107,7 +111,11 @@ object ChainSpec extends ParserImplicits {
       mintCodePath: Path,
       posCodePath: Path,
       initialAccountsPath: Path,
-      protocolVersion: ProtocolVersion
+      protocolVersion: ProtocolVersion,
+      // Motes taken from a deployer to pay for their payment code.
+      maxPayment: Long Refined Positive,
+      // Cumulative gas of the deploys in a block; 0 leaves it unlimited.
+      blockGasLimit: Long Refined NonNegative
   ) extends SubConfig
 
   final case class Highway(
@@ -124,7 +132,13 @@ object ChainSpec extends ParserImplicits {
   final case class Upgrade(
       activationPointRank: Long,
       installerCodePath: Option[Path],
-      protocolVersion: ProtocolVersion
+      protocolVersion: ProtocolVersion,
+      // Lowest gas price, in motes per gas, a deploy may offer; the current one is kept if missing.
+      minGasPrice: Option[Long Refined Positive],
+      // Motes taken from a deployer to pay for their payment code; kept if missing.
+      maxPayment: Option[Long Refined Positive],
+      // Cumulative gas of the deploys in a block; kept if missing.
+      blockGasLimit: Option[Long Refined Positive]
   ) extends SubConfig
 
   final case class WasmCosts(
@@ -140,6 +154,70 @@ object ChainSpec extends ParserImplicits {
       opcodesDivisor: Int Refined Positive
   ) extends SubConfig
 
+  /** Parsed from an inline table, e.g. `write = { fixed = 15000, per-byte = 10 }`. */
+  final case class HostFunctionCost(
+      fixed: Int,
+      perByte: Int
+  )
+
+  final case class HostFunctionCosts(
+      readValue: HostFunctionCost,
+      readValueLocal: HostFunctionCost,
+      loadNamedKeys: HostFunctionCost,
+      write: HostFunctionCost,
+      writeLocal: HostFunctionCost,
+      add: HostFunctionCost,
+      addLocal: HostFunctionCost,
+      newUref: HostFunctionCost,
+      getArgSize: HostFunctionCost,
+      getArg: HostFunctionCost,
+      ret: HostFunctionCost,
+      callContract: HostFunctionCost,
+      getKey: HostFunctionCost,
+      hasKey: HostFunctionCost,
+      putKey: HostFunctionCost,
+      removeKey: HostFunctionCost,
+      getCaller: HostFunctionCost,
+      getBlocktime: HostFunctionCost,
+      storeFunction: HostFunctionCost,
+      storeFunctionAtHash: HostFunctionCost,
+      isValidUref: HostFunctionCost,
+      revert: HostFunctionCost,
+      addAssociatedKey: HostFunctionCost,
+      removeAssociatedKey: HostFunctionCost,
+      updateAssociatedKey: HostFunctionCost,
+      setActionThreshold: HostFunctionCost,
+      createPurse: HostFunctionCost,
+      transferToAccount: HostFunctionCost,
+      transferFromPurseToAccount: HostFunctionCost,
+      transferFromPurseToPurse: HostFunctionCost,
+      getBalance: HostFunctionCost,
+      getPhase: HostFunctionCost,
+      upgradeContractAtUref: HostFunctionCost,
+      getSystemContract: HostFunctionCost,
+      getMainPurse: HostFunctionCost,
+      readHostBuffer: HostFunctionCost,
+      remove: HostFunctionCost,
+      emitEvent: HostFunctionCost,
+      tryCallContract: HostFunctionCost,
+      storeContract: HostFunctionCost,
+      storeContractAtHash: HostFunctionCost,
+      callEntryPoint: HostFunctionCost,
+      getNamedArgSize: HostFunctionCost,
+      getNamedArg: HostFunctionCost,
+      blake2b: HostFunctionCost,
+      ed25519Verify: HostFunctionCost,
+      secp256k1Verify: HostFunctionCost,
+      randomBytes: HostFunctionCost
+  ) extends SubConfig
+
+  final case class ProofOfStake(
+      // Number of eras after which a bonding request becomes effective.
+      bondingDelay: Int Refined NonNegative,
+      // Number of eras after which an unbonding request is paid out.
+      unbondingDelay: Int Refined NonNegative
+  ) extends SubConfig
+
   final case class Account(
       publicKey: PublicKey,
       initialBalance: BigInt,
@@ -301,7 +379,14 @@ object ChainSpecReader {
   implicit val `ChainSpecReader[GenesisConfig]` = new ChainSpecReader[ipc.ChainSpec.GenesisConfig] {
     override def fromDirectory(path: Path)(implicit resolver: Resolver) =
       withManifest[GenesisConf, ipc.ChainSpec.GenesisConfig](path, GenesisConf.parseManifest) {
-        case GenesisConf(genesis, wasmCosts, deployConfig, highwayConfig) =>
+        case GenesisConf(
+            genesis,
+            wasmCosts,
+            hostFunctionCosts,
+            deployConfig,
+            highwayConfig,
+            posConfig
+            ) =>
           for {
             mintCodeBytes <- resolver.asBytes(resolvePath(path, genesis.mintCodePath))
             posCodeBytes  <- resolver.asBytes(resolvePath(path, genesis.posCodePath))
@@ -328,9 +413,12 @@ object ChainSpecReader {
                     state.BigInt(account.initialBondedAmount.toString, bitWidth = 512)
                   )
               })
-              .withCosts(toCostTable(wasmCosts))
+              .withCosts(toCostTable(wasmCosts.some, hostFunctionCosts.some))
               .withDeployConfig(toDeployConfig(deployConfig))
               .withHighwayConfig(toHighwayConfig(highwayConfig))
+              .withPosConfig(toProofOfStakeConfig(posConfig))
+              .withMaxPayment(genesis.maxPayment.value)
+              .withBlockGasLimit(genesis.blockGasLimit.value)
           }
       }
   }
@@ -338,7 +426,13 @@ object ChainSpecReader {
   implicit val `ChainSpecReader[UpgradePoint]` = new ChainSpecReader[ipc.ChainSpec.UpgradePoint] {
     override def fromDirectory(path: Path)(implicit resolver: Resolver) =
       withManifest[UpgradeConf, ipc.ChainSpec.UpgradePoint](path, UpgradeConf.parseManifest) {
-        case UpgradeConf(upgrade, maybeWasmCosts, maybeDeployConfig) =>
+        case UpgradeConf(
+            upgrade,
+            maybeWasmCosts,
+            maybeHostFunctionCosts,
+            maybeDeployConfig,
+            maybePosConfig
+            ) =>
           upgrade.installerCodePath.fold(
             none[Array[Byte]].asRight[String]
           ) { file =>
@@ -351,8 +445,16 @@ object ChainSpecReader {
                     code = ByteString.copyFrom(bytes)
                   )
                 },
-                newCosts = maybeWasmCosts.map(toCostTable),
-                newDeployConfig = maybeDeployConfig.map(toDeployConfig)
+                // Costs left out of an upgrade keep their current values.
+                newCosts =
+                  if (maybeWasmCosts.isEmpty && maybeHostFunctionCosts.isEmpty) None
+                  else toCostTable(maybeWasmCosts, maybeHostFunctionCosts).some,
+                newDeployConfig = maybeDeployConfig.map(toDeployConfig),
+                newPosConfig = maybePosConfig.map(toProofOfStakeConfig),
+                // Zero keeps the current value.
+                newMinGasPrice = upgrade.minGasPrice.fold(0L)(_.value),
+                newMaxPayment = upgrade.maxPayment.fold(0L)(_.value),
+                newBlockGasLimit = upgrade.blockGasLimit.fold(0L)(_.value)
               )
               .withActivationPoint(
                 ipc.ChainSpec.ActivationPoint(upgrade.activationPointRank)
@@ -389,23 +491,85 @@ object ChainSpecReader {
     }
   }
 
-  private def toCostTable(wasmCosts: WasmCosts): ipc.ChainSpec.CostTable =
-    ipc.ChainSpec
-      .CostTable()
-      .withWasm(
-        ipc.ChainSpec.CostTable
-          .WasmCosts()
-          .withRegular(wasmCosts.regular.value)
-          .withDiv(wasmCosts.divMultiplier.value)
-          .withMul(wasmCosts.mulMultiplier.value)
-          .withMem(wasmCosts.memMultiplier.value)
-          .withInitialMem(wasmCosts.memInitialPages.value)
-          .withGrowMem(wasmCosts.memGrowPerPage.value)
-          .withMemcpy(wasmCosts.memCopyPerByte.value)
-          .withMaxStackHeight(wasmCosts.maxStackHeight.value)
-          .withOpcodesMul(wasmCosts.opcodesMultiplier.value)
-          .withOpcodesDiv(wasmCosts.opcodesDivisor.value)
-      )
+  private def toCostTable(
+      maybeWasmCosts: Option[WasmCosts],
+      maybeHostFunctionCosts: Option[HostFunctionCosts]
+  ): ipc.ChainSpec.CostTable =
+    ipc.ChainSpec.CostTable(
+      wasm = maybeWasmCosts.map(toWasmCosts),
+      hostFunctionCosts = maybeHostFunctionCosts.map(toHostFunctionCosts)
+    )
+
+  private def toWasmCosts(wasmCosts: WasmCosts): ipc.ChainSpec.CostTable.WasmCosts =
+    ipc.ChainSpec.CostTable
+      .WasmCosts()
+      .withRegular(wasmCosts.regular.value)
+      .withDiv(wasmCosts.divMultiplier.value)
+      .withMul(wasmCosts.mulMultiplier.value)
+      .withMem(wasmCosts.memMultiplier.value)
+      .withInitialMem(wasmCosts.memInitialPages.value)
+      .withGrowMem(wasmCosts.memGrowPerPage.value)
+      .withMemcpy(wasmCosts.memCopyPerByte.value)
+      .withMaxStackHeight(wasmCosts.maxStackHeight.value)
+      .withOpcodesMul(wasmCosts.opcodesMultiplier.value)
+      .withOpcodesDiv(wasmCosts.opcodesDivisor.value)
+
+  private def toHostFunctionCosts(
+      hostFunctionCosts: HostFunctionCosts
+  ): ipc.ChainSpec.CostTable.HostFunctionCosts =
+    ipc.ChainSpec.CostTable
+      .HostFunctionCosts()
+      .withReadValue(toHostFunction(hostFunctionCosts.readValue))
+      .withReadValueLocal(toHostFunction(hostFunctionCosts.readValueLocal))
+      .withLoadNamedKeys(toHostFunction(hostFunctionCosts.loadNamedKeys))
+      .withWrite(toHostFunction(hostFunctionCosts.write))
+      .withWriteLocal(toHostFunction(hostFunctionCosts.writeLocal))
+      .withAdd(toHostFunction(hostFunctionCosts.add))
+      .withAddLocal(toHostFunction(hostFunctionCosts.addLocal))
+      .withNewUref(toHostFunction(hostFunctionCosts.newUref))
+      .withGetArgSize(toHostFunction(hostFunctionCosts.getArgSize))
+      .withGetArg(toHostFunction(hostFunctionCosts.getArg))
+      .withRet(toHostFunction(hostFunctionCosts.ret))
+      .withCallContract(toHostFunction(hostFunctionCosts.callContract))
+      .withGetKey(toHostFunction(hostFunctionCosts.getKey))
+      .withHasKey(toHostFunction(hostFunctionCosts.hasKey))
+      .withPutKey(toHostFunction(hostFunctionCosts.putKey))
+      .withRemoveKey(toHostFunction(hostFunctionCosts.removeKey))
+      .withGetCaller(toHostFunction(hostFunctionCosts.getCaller))
+      .withGetBlocktime(toHostFunction(hostFunctionCosts.getBlocktime))
+      .withStoreFunction(toHostFunction(hostFunctionCosts.storeFunction))
+      .withStoreFunctionAtHash(toHostFunction(hostFunctionCosts.storeFunctionAtHash))
+      .withIsValidUref(toHostFunction(hostFunctionCosts.isValidUref))
+      .withRevert(toHostFunction(hostFunctionCosts.revert))
+      .withAddAssociatedKey(toHostFunction(hostFunctionCosts.addAssociatedKey))
+      .withRemoveAssociatedKey(toHostFunction(hostFunctionCosts.removeAssociatedKey))
+      .withUpdateAssociatedKey(toHostFunction(hostFunctionCosts.updateAssociatedKey))
+      .withSetActionThreshold(toHostFunction(hostFunctionCosts.setActionThreshold))
+      .withCreatePurse(toHostFunction(hostFunctionCosts.createPurse))
+      .withTransferToAccount(toHostFunction(hostFunctionCosts.transferToAccount))
+      .withTransferFromPurseToAccount(toHostFunction(hostFunctionCosts.transferFromPurseToAccount))
+      .withTransferFromPurseToPurse(toHostFunction(hostFunctionCosts.transferFromPurseToPurse))
+      .withGetBalance(toHostFunction(hostFunctionCosts.getBalance))
+      .withGetPhase(toHostFunction(hostFunctionCosts.getPhase))
+      .withUpgradeContractAtUref(toHostFunction(hostFunctionCosts.upgradeContractAtUref))
+      .withGetSystemContract(toHostFunction(hostFunctionCosts.getSystemContract))
+      .withGetMainPurse(toHostFunction(hostFunctionCosts.getMainPurse))
+      .withReadHostBuffer(toHostFunction(hostFunctionCosts.readHostBuffer))
+      .withRemove(toHostFunction(hostFunctionCosts.remove))
+      .withEmitEvent(toHostFunction(hostFunctionCosts.emitEvent))
+      .withTryCallContract(toHostFunction(hostFunctionCosts.tryCallContract))
+      .withStoreContract(toHostFunction(hostFunctionCosts.storeContract))
+      .withStoreContractAtHash(toHostFunction(hostFunctionCosts.storeContractAtHash))
+      .withCallEntryPoint(toHostFunction(hostFunctionCosts.callEntryPoint))
+      .withGetNamedArgSize(toHostFunction(hostFunctionCosts.getNamedArgSize))
+      .withGetNamedArg(toHostFunction(hostFunctionCosts.getNamedArg))
+      .withBlake2B(toHostFunction(hostFunctionCosts.blake2b))
+      .withEd25519Verify(toHostFunction(hostFunctionCosts.ed25519Verify))
+      .withSecp256K1Verify(toHostFunction(hostFunctionCosts.secp256k1Verify))
+      .withRandomBytes(toHostFunction(hostFunctionCosts.randomBytes))
+
+  private def toHostFunction(cost: HostFunctionCost): ipc.ChainSpec.CostTable.HostFunction =
+    ipc.ChainSpec.CostTable.HostFunction(cost.fixed, cost.perByte)
 
   private def toDeployConfig(deployConfig: Deploy): ipc.ChainSpec.DeployConfig =
     ipc.ChainSpec.DeployConfig(
@@ -425,6 +589,12 @@ object ChainSpecReader {
       highwayConfig.ftt.value
     )
 
+  private def toProofOfStakeConfig(posConfig: ProofOfStake): ipc.ChainSpec.ProofOfStakeConfig =
+    ipc.ChainSpec.ProofOfStakeConfig(
+      posConfig.bondingDelay.value,
+      posConfig.unbondingDelay.value
+    )
+
   private def withManifest[A, B](dir: Path, parseManifest: (=> Source) => ValidatedNel[String, A])(
       read: A => Either[String, B]
   )(implicit resolver: Resolver): ValidatedNel[String, B] = {
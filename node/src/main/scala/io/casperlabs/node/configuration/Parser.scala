@@ -61,6 +61,18 @@ private[configuration] trait ParserImplicits {
       }
   }
 
+  implicit val hostFunctionCostParser: Parser[ChainSpec.HostFunctionCost] = {
+    val InlineTable = """\{\s*fixed\s*=\s*(\d+)\s*,\s*per-byte\s*=\s*(\d+)\s*\}""".r
+    s =>
+      s match {
+        case InlineTable(fixed, perByte) =>
+          Try(ChainSpec.HostFunctionCost(fixed.toInt, perByte.toInt)).toEither
+            .leftMap(_.getMessage)
+        case _ =>
+          s"Unable to parse host function cost: $s".asLeft
+      }
+  }
+
   implicit val positiveIntParser: Parser[Refined[Int, Positive]] =
     s =>
       for {
@@ -75,6 +87,20 @@ private[configuration] trait ParserImplicits {
         p <- refineV[NonNegative](i)
       } yield p
 
+  implicit val positiveLongParser: Parser[Refined[Long, Positive]] =
+    s =>
+      for {
+        l <- Try(s.toLong).toEither.leftMap(_.getMessage)
+        p <- refineV[Positive](l)
+      } yield p
+
+  implicit val nonNegativeLongParser: Parser[Refined[Long, NonNegative]] =
+    s =>
+      for {
+        l <- Try(s.toLong).toEither.leftMap(_.getMessage)
+        p <- refineV[NonNegative](l)
+      } yield p
+
   implicit val gte1DoubleParser: Parser[Refined[Double, GreaterEqual[W.`1.0`.T]]] =
     s =>
       for {
@@ -90,7 +90,7 @@ private[configuration] object Utils {
 
   def parseToml(content: String): Map[CamelCase, String] = {
     val tableRegex = """\[(.+)\]""".r
-    val valueRegex = """([a-z\-]+)\s*=\s*\"?([^\"]*)\"?""".r
+    val valueRegex = """([a-z0-9\-]+)\s*=\s*\"?([^\"]*)\"?""".r
 
     val lines = content
       .split('\n')
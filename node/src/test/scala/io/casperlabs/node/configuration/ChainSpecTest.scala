@@ -22,9 +22,14 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
         conf.genesis.name shouldBe "test-chain"
         conf.genesis.timestamp shouldBe 1568805354071L
         conf.genesis.mintCodePath.toString shouldBe "mint.wasm"
+        conf.genesis.maxPayment.value shouldBe 13L
+        conf.genesis.blockGasLimit.value shouldBe 14L
         conf.wasmCosts.regular.value shouldBe 1
         conf.wasmCosts.memInitialPages.value shouldBe 5
         conf.wasmCosts.opcodesDivisor.value shouldBe 10
+        conf.hostFunctionCosts.readValue shouldBe ChainSpec.HostFunctionCost(1, 1)
+        conf.hostFunctionCosts.ed25519Verify shouldBe ChainSpec.HostFunctionCost(46, 1)
+        conf.hostFunctionCosts.randomBytes shouldBe ChainSpec.HostFunctionCost(48, 1)
       }
     }
 
@@ -50,10 +55,14 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
         conf.upgrade.protocolVersion shouldBe ChainSpec.ProtocolVersion(1, 0, 2)
         conf.upgrade.activationPointRank shouldBe 20L
         conf.upgrade.installerCodePath.get.toString shouldBe "installer.wasm"
+        conf.upgrade.minGasPrice.map(_.value) shouldBe Some(33L)
+        conf.upgrade.maxPayment.map(_.value) shouldBe Some(34L)
+        conf.upgrade.blockGasLimit.map(_.value) shouldBe Some(35L)
         conf.wasmCosts should not be empty
         conf.wasmCosts.get.regular.value shouldBe 21
         conf.wasmCosts.get.memInitialPages.value shouldBe 25
         conf.wasmCosts.get.opcodesDivisor.value shouldBe 210
+        conf.hostFunctionCosts shouldBe empty
       }
     }
 
@@ -64,6 +73,9 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
         conf.upgrade.activationPointRank shouldBe 30L
         conf.upgrade.protocolVersion shouldBe ChainSpec.ProtocolVersion(1, 1, 0)
         conf.upgrade.installerCodePath shouldBe empty
+        conf.upgrade.minGasPrice shouldBe empty
+        conf.upgrade.maxPayment shouldBe empty
+        conf.upgrade.blockGasLimit shouldBe empty
         conf.wasmCosts shouldBe empty
       }
     }
@@ -124,6 +136,21 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
           wasmCosts.maxStackHeight shouldBe 8
           wasmCosts.opcodesMul shouldBe 9
           wasmCosts.opcodesDiv shouldBe 10
+
+          val hostFunctionCosts = genesis.getCosts.getHostFunctionCosts
+          hostFunctionCosts.getReadValue.fixed shouldBe 1
+          hostFunctionCosts.getReadValue.perByte shouldBe 1
+          hostFunctionCosts.getWrite.fixed shouldBe 4
+          hostFunctionCosts.getBlake2B.fixed shouldBe 45
+          hostFunctionCosts.getSecp256K1Verify.fixed shouldBe 47
+          hostFunctionCosts.getRandomBytes.fixed shouldBe 48
+
+          val posConfig = genesis.getPosConfig
+          posConfig.bondingDelay shouldBe 11
+          posConfig.unbondingDelay shouldBe 12
+
+          genesis.maxPayment shouldBe 13L
+          genesis.blockGasLimit shouldBe 14L
         }
       }
 
@@ -148,6 +175,15 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
           wasmCosts.maxStackHeight shouldBe 28
           wasmCosts.opcodesMul shouldBe 29
           wasmCosts.opcodesDiv shouldBe 210
+          upgrade.getNewCosts.hostFunctionCosts shouldBe empty
+
+          val posConfig = upgrade.getNewPosConfig
+          posConfig.bondingDelay shouldBe 31
+          posConfig.unbondingDelay shouldBe 32
+
+          upgrade.newMinGasPrice shouldBe 33L
+          upgrade.newMaxPayment shouldBe 34L
+          upgrade.newBlockGasLimit shouldBe 35L
         }
       }
 
@@ -162,6 +198,7 @@ class ChainSpecTest extends WordSpecLike with Matchers with Inspectors {
           upgrade.upgradeInstaller shouldBe empty
 
           upgrade.newCosts shouldBe empty
+          upgrade.newPosConfig shouldBe empty
         }
       }
     }
@@ -57,6 +57,14 @@ import io.netty.handler.ssl.ApplicationProtocolConfig.Protocol
       path: Seq[String],
       protocolVersion: ProtocolVersion
   ): F[Either[Throwable, StoredValue]]
+
+  /** Applies the bonds and pays out the unbonds which are due in the era with the given height.
+    * The effects are committed by the execution engine. */
+  def unbondPayout(
+      prestate: ByteString,
+      eraHeight: Long,
+      protocolVersion: ProtocolVersion
+  ): F[Either[Throwable, ExecutionEngineService.CommitResult]]
 }
 
 class GrpcExecutionEngineService[F[_]: Defer: Concurrent: Log: TaskLift: Metrics] private[smartcontracts] (
@@ -220,6 +228,29 @@ class GrpcExecutionEngineService[F[_]: Defer: Concurrent: Log: TaskLift: Metrics
         case QueryResponse.Result.Failure(err) => Left(SmartContractEngineError(err))
       }
     }
+
+  override def unbondPayout(
+      prestate: ByteString,
+      eraHeight: Long,
+      protocolVersion: ProtocolVersion
+  ): F[Either[Throwable, ExecutionEngineService.CommitResult]] =
+    Metrics[F].timer("eeUnbondPayout") {
+      sendMessage(
+        UnbondPayoutRequest(prestate, eraHeight, Some(protocolVersion)),
+        _.unbondPayout
+      ) {
+        _.result match {
+          case UnbondPayoutResponse.Result.Success(commitResult) =>
+            Right(ExecutionEngineService.CommitResult(commitResult))
+          case UnbondPayoutResponse.Result.Empty =>
+            Left(SmartContractEngineError("empty response"))
+          case UnbondPayoutResponse.Result.MissingParent(RootNotFound(hash)) =>
+            Left(SmartContractEngineError(s"Missing pre-state: ${Base16.encode(hash.toByteArray)}"))
+          case UnbondPayoutResponse.Result.Error(err) =>
+            Left(SmartContractEngineError(s"Error paying out unbonds: $err"))
+        }
+      }
+    }
 }
 
 object ExecutionEngineService {